@@ -1,4 +1,4 @@
-//! Example of use of the crate, to lex and parse a file.
+//! Example of use of the crate, to preprocess, lex and parse a file.
 
 #![expect(
     clippy::print_stdout,
@@ -10,7 +10,7 @@
 
 use std::{env, fs};
 
-use c_parser::{display_tokens, lex, linearise, parse};
+use c_parser::{PreprocessorOptions, SourceFiles, display_tokens, linearise, parse, preprocess};
 
 /// Parses the argvs to print nice errors on misuse, and returns the filename
 /// otherwise.
@@ -32,10 +32,12 @@ fn main() {
     let Some(filename) = parse_args() else { return };
     let content =
         fs::read_to_string(&filename).unwrap_or_else(|_| panic!("Failed to read {filename}"));
-    let files = [(0, filename.as_str(), content.as_str())];
-    let tokens = lex(&content, 0)
-        .unwrap_or_display(files.as_slice())
-        .expect("no tokens found");
+    let mut source_files = SourceFiles::default();
+    let tokens =
+        preprocess(&filename, &content, &PreprocessorOptions::default(), &mut source_files)
+            .unwrap_or_display(&source_files.as_list())
+            .expect("no tokens found");
+    let files = source_files.as_list();
     println!("LEX: {}", display_tokens(&tokens));
     let ast = parse(tokens)
         .unwrap_or_display(files.as_slice())
@@ -0,0 +1,292 @@
+//! Analyses an open document, and answers the requests on it.
+
+use c_parser::{
+    Ast, BracedBlock, CompileError, Definition, ErrorLevel, ErrorLocation, PreprocessorOptions, SourceFiles, SymbolIndex, linearise, parse, preprocess
+};
+
+use crate::json::Json;
+
+/// Id of the document in the files of the preprocessor, as it is the first
+/// file to be added.
+const DOCUMENT_ID: u32 = 0;
+/// `SymbolKind` of the functions in the protocol.
+const FUNCTION_KIND: u32 = 12;
+/// `SymbolKind` of the variables in the protocol.
+const VARIABLE_KIND: u32 = 13;
+
+/// Function or variable declared at the top level of a document.
+struct TopLevelSymbol {
+    /// `SymbolKind` of the symbol.
+    kind: u32,
+    /// Location of the whole declaration.
+    location: ErrorLocation,
+    /// Name of the symbol.
+    name: String,
+    /// Location of the name in the declaration.
+    name_location: ErrorLocation,
+}
+
+/// Open document, analysed with the lexer, the parser and the lineariser.
+pub struct Document {
+    /// Content of the document.
+    content: String,
+    /// Errors found in the document and in the files it includes.
+    errors: Vec<CompileError>,
+    /// Names of the document and of the files it includes, by file id.
+    file_names: Vec<String>,
+    /// Declarations and uses of the symbols, if the document could be
+    /// linearised.
+    index: Option<SymbolIndex>,
+    /// Functions and variables declared at the top level.
+    symbols: Vec<TopLevelSymbol>,
+}
+
+impl Document {
+    /// Returns the location of the declaration of the symbol at a position.
+    ///
+    /// The declarations in the included files aren't returned, as they have no
+    /// URI.
+    pub fn definition(&self, uri: &str, position: Option<&Json>) -> Json {
+        self.definition_at(position)
+            .filter(|def| {
+                file_line_col(def.as_location()).is_some_and(|(file, ..)| file == DOCUMENT_ID)
+            })
+            .map_or(Json::Null, |def| {
+                Json::object([
+                    ("uri", uri.into()),
+                    ("range", self.range(def.as_location())),
+                ])
+            })
+    }
+
+    /// Returns the declaration of the symbol at a position.
+    fn definition_at(&self, position: Option<&Json>) -> Option<&Definition> {
+        let (line, col) = self.line_col(position?)?;
+        self.index.as_ref()?.definition_at(DOCUMENT_ID, line, col)
+    }
+
+    /// Returns the errors of the document, as LSP diagnostics.
+    ///
+    /// The errors of the included files are reported at the start of the
+    /// document, with their file, line and column in the message.
+    pub fn diagnostics(&self) -> Json {
+        Json::Array(
+            self.errors
+                .iter()
+                .map(|err| {
+                    let message = match file_line_col(err.as_location()) {
+                        Some((file, line, col)) if file != DOCUMENT_ID => format!(
+                            "{}:{line}:{col}: {}",
+                            self.file_names
+                                .get(usize::try_from(file).unwrap_or(usize::MAX))
+                                .map_or("", String::as_str),
+                            err.as_message()
+                        ),
+                        Some(_) | None => err.as_message().to_owned(),
+                    };
+                    Json::object([
+                        ("range", self.range(err.as_location())),
+                        ("severity", severity(err.as_level()).into()),
+                        ("source", "c-parser".into()),
+                        ("message", message.into()),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the top-level functions and variables, as LSP document
+    /// symbols.
+    pub fn document_symbols(&self) -> Json {
+        Json::Array(
+            self.symbols
+                .iter()
+                .map(|symbol| {
+                    Json::object([
+                        ("name", symbol.name.as_str().into()),
+                        ("kind", symbol.kind.into()),
+                        ("range", self.range(symbol.location)),
+                        ("selectionRange", self.range(symbol.name_location)),
+                    ])
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the type of the symbol at a position, as an LSP hover.
+    pub fn hover(&self, position: Option<&Json>) -> Json {
+        self.definition_at(position).map_or(Json::Null, |def| {
+            Json::object([(
+                "contents",
+                Json::object([
+                    ("kind", "markdown".into()),
+                    ("value", format!("```c\n{}\n```", def.as_signature()).into()),
+                ]),
+            )])
+        })
+    }
+
+    /// Returns a line of the document, starting at 1.
+    fn line(&self, line: u32) -> &str {
+        self.content
+            .split('\n')
+            .nth(usize::try_from(line.saturating_sub(1)).unwrap_or(usize::MAX))
+            .unwrap_or_default()
+    }
+
+    /// Converts an LSP position into a line and column of the document.
+    fn line_col(&self, position: &Json) -> Option<(u32, u32)> {
+        let line = position.get("line")?.as_u32()?;
+        let character = position.get("character")?.as_u32()?;
+        let text = self.line(line.checked_add(1)?);
+        let mut utf16 = 0usize;
+        let col = text
+            .chars()
+            .take_while(|ch| {
+                utf16 = utf16.saturating_add(ch.len_utf16());
+                utf16 <= usize::try_from(character).unwrap_or(usize::MAX)
+            })
+            .count();
+        Some((line.checked_add(1)?, u32::try_from(col).ok()?.checked_add(1)?))
+    }
+
+    /// Analyses the content of a document, with the preprocessor, the parser
+    /// and the lineariser.
+    ///
+    /// The included files are searched from the path of the document, then in
+    /// the include paths of the options.
+    pub fn new(uri: &str, content: String, options: &PreprocessorOptions) -> Self {
+        let mut files = SourceFiles::default();
+        let name = uri_path(uri);
+        let (tokens, mut errors) = preprocess(&name, &content, options, &mut files).into_parts();
+        let file_names = files
+            .as_list()
+            .into_iter()
+            .map(|(_, file_name, _)| file_name.to_owned())
+            .collect();
+        let mut is_valid = true;
+        let ast = tokens.and_then(|tok| {
+            let (ast, parse_errors) = parse(tok).into_parts();
+            // The errors of the preprocessor, like a missing include, don't
+            // prevent the lineariser from indexing the symbols.
+            is_valid = !parse_errors
+                .iter()
+                .any(|err| matches!(err.as_level(), ErrorLevel::Crash | ErrorLevel::Fault));
+            errors.extend(parse_errors);
+            ast
+        });
+        let symbols = ast.as_ref().map(top_level_symbols).unwrap_or_default();
+        let index = ast.filter(|_| is_valid).and_then(|valid_ast| {
+            let (ssa, linearise_errors) = linearise(valid_ast).into_parts();
+            errors.extend(linearise_errors);
+            ssa.map(|valid| valid.index)
+        });
+        Self { content, errors, file_names, index, symbols }
+    }
+
+    /// Converts a line and column of the document into an LSP position.
+    fn position(&self, line: u32, col: u32) -> Json {
+        let character: usize = self
+            .line(line)
+            .chars()
+            .take(usize::try_from(col.saturating_sub(1)).unwrap_or(usize::MAX))
+            .map(char::len_utf16)
+            .sum();
+        Json::object([
+            ("line", line.saturating_sub(1).into()),
+            ("character", u32::try_from(character).unwrap_or(u32::MAX).into()),
+        ])
+    }
+
+    /// Converts a location into an LSP range.
+    ///
+    /// The locations in the included files are converted to the start of the
+    /// document.
+    fn range(&self, location: ErrorLocation) -> Json {
+        let (start, end) = match location {
+            ErrorLocation::Block(DOCUMENT_ID, line, col, end_line, end_col) =>
+                ((line, col), (end_line, end_col.saturating_add(1))),
+            ErrorLocation::Char(DOCUMENT_ID, line, col) =>
+                ((line, col), (line, col.saturating_add(1))),
+            ErrorLocation::Token(DOCUMENT_ID, line, col, len) =>
+                ((line, col), (line, col.saturating_add(len))),
+            ErrorLocation::TwoTokens(DOCUMENT_ID, line, col, _, line2, col2, len2) =>
+                ((line, col), (line2, col2.saturating_add(len2))),
+            ErrorLocation::None | _ => ((1, 1), (1, 1)),
+        };
+        Json::object([
+            ("start", self.position(start.0, start.1)),
+            ("end", self.position(end.0, end.1)),
+        ])
+    }
+}
+
+/// Returns the file, the line and the column of the start of a location.
+const fn file_line_col(location: ErrorLocation) -> Option<(u32, u32, u32)> {
+    match location {
+        ErrorLocation::Block(file, line, col, ..)
+        | ErrorLocation::Char(file, line, col)
+        | ErrorLocation::Token(file, line, col, _)
+        | ErrorLocation::TwoTokens(file, line, col, ..) => Some((file, line, col)),
+        ErrorLocation::None | _ => None,
+    }
+}
+
+/// Returns the path of a `file://` URI, with its percent-encoded bytes
+/// decoded.
+fn uri_path(uri: &str) -> String {
+    let encoded = uri.strip_prefix("file://").unwrap_or(uri).as_bytes();
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut iter = encoded.iter();
+    while let Some(&byte) = iter.next() {
+        let decoded = (byte == b'%')
+            .then(|| iter.as_slice().get(..2))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(str::from_utf8(hex).ok()?, 16).ok());
+        if let Some(value) = decoded {
+            bytes.push(value);
+            iter.nth(1);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Converts the level of an error into an LSP `DiagnosticSeverity`.
+const fn severity(level: ErrorLevel) -> u32 {
+    match level {
+        ErrorLevel::Warning => 2,
+        ErrorLevel::Suggestion => 4,
+        ErrorLevel::Crash | ErrorLevel::Fault | _ => 1,
+    }
+}
+
+/// Finds the functions and variables declared at the top level.
+fn top_level_symbols(ast: &BracedBlock) -> Vec<TopLevelSymbol> {
+    let mut symbols = vec![];
+    for elt in &ast.elts {
+        let (kind, location, variable) = if let Ast::FunctionCall(func) = elt
+            && let Some(callee) = func.as_variable()
+        {
+            (FUNCTION_KIND, Some(func.location()), callee)
+        } else if let Ast::Variable(var) = elt {
+            (VARIABLE_KIND, None, var)
+        } else {
+            continue;
+        };
+        if let Some(attr) = variable.as_attribute_variable() {
+            for decl in attr.declarations.iter().flatten().filter(|decl| {
+                file_line_col(decl.name.as_location()).is_some_and(|(file, ..)| file == DOCUMENT_ID)
+            }) {
+                symbols.push(TopLevelSymbol {
+                    kind,
+                    location: location.unwrap_or_else(|| decl.location()),
+                    name: decl.name.as_value().clone(),
+                    name_location: decl.name.as_location(),
+                });
+            }
+        }
+    }
+    symbols
+}
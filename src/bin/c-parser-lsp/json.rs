@@ -0,0 +1,284 @@
+//! Minimal JSON values, to read and write the JSON-RPC messages.
+
+use core::fmt;
+use core::iter::Peekable;
+use core::str::Chars;
+
+/// JSON value.
+///
+/// The numbers are kept as written, as the protocol only uses integers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Json {
+    /// Array of values, e.g. `[1, 2]`.
+    Array(Vec<Self>),
+    /// Boolean, `true` or `false`.
+    Bool(bool),
+    /// The `null` value.
+    Null,
+    /// Number, as written in the message.
+    Number(String),
+    /// Object, with its fields in order, e.g. `{"a": 1}`.
+    Object(Vec<(String, Self)>),
+    /// String, unescaped.
+    String(String),
+}
+
+impl Json {
+    /// Returns the elements of an array.
+    pub fn as_array(&self) -> Option<&[Self]> {
+        if let Self::Array(elts) = self {
+            Some(elts)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the fields of an object, in order.
+    pub fn as_object(&self) -> Option<&[(String, Self)]> {
+        if let Self::Object(fields) = self {
+            Some(fields)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of a string.
+    pub fn as_str(&self) -> Option<&str> {
+        if let Self::String(value) = self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of a non-negative integer that fits in a `u32`.
+    pub fn as_u32(&self) -> Option<u32> {
+        if let Self::Number(value) = self {
+            value.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value of a field of an object.
+    pub fn get(&self, key: &str) -> Option<&Self> {
+        if let Self::Object(fields) = self {
+            fields
+                .iter()
+                .find_map(|(name, value)| (name == key).then_some(value))
+        } else {
+            None
+        }
+    }
+
+    /// Builds an object from its fields.
+    pub fn object<const N: usize>(fields: [(&str, Self); N]) -> Self {
+        Self::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    /// Parses a JSON value.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut chars = text.chars().peekable();
+        let value = parse_value(&mut chars)?;
+        skip_whitespace(&mut chars);
+        chars
+            .next()
+            .map_or(Ok(value), |ch| Err(format!("Unexpected '{ch}' after JSON value")))
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Array(elts) => {
+                "[".fmt(f)?;
+                for (idx, elt) in elts.iter().enumerate() {
+                    if idx != 0 {
+                        ",".fmt(f)?;
+                    }
+                    elt.fmt(f)?;
+                }
+                "]".fmt(f)
+            }
+            Self::Bool(value) => value.fmt(f),
+            Self::Null => "null".fmt(f),
+            Self::Number(value) => value.fmt(f),
+            Self::Object(fields) => {
+                "{".fmt(f)?;
+                for (idx, (name, value)) in fields.iter().enumerate() {
+                    if idx != 0 {
+                        ",".fmt(f)?;
+                    }
+                    write!(f, "{}:{value}", Self::String(name.clone()))?;
+                }
+                "}".fmt(f)
+            }
+            Self::String(value) => {
+                "\"".fmt(f)?;
+                for ch in value.chars() {
+                    match ch {
+                        '"' => "\\\"".fmt(f)?,
+                        '\\' => "\\\\".fmt(f)?,
+                        '\n' => "\\n".fmt(f)?,
+                        '\r' => "\\r".fmt(f)?,
+                        '\t' => "\\t".fmt(f)?,
+                        _ if ch.is_control() => write!(f, "\\u{:04x}", u32::from(ch))?,
+                        _ => ch.fmt(f)?,
+                    }
+                }
+                "\"".fmt(f)
+            }
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<u32> for Json {
+    fn from(value: u32) -> Self {
+        Self::Number(value.to_string())
+    }
+}
+
+/// Checks that the next characters are the expected keyword, e.g. `true`.
+fn expect_keyword(chars: &mut Peekable<Chars<'_>>, keyword: &str) -> Result<(), String> {
+    if keyword
+        .chars()
+        .all(|expected| chars.next() == Some(expected))
+    {
+        Ok(())
+    } else {
+        Err(format!("Invalid JSON value, expected '{keyword}'"))
+    }
+}
+
+/// Parses the 4 hexadecimal digits of an `\u` escape.
+fn parse_hex4(chars: &mut Peekable<Chars<'_>>) -> Result<u32, String> {
+    (0..4).try_fold(0, |acc: u32, _| {
+        chars
+            .next()
+            .and_then(|ch| ch.to_digit(16))
+            .and_then(|digit| acc.checked_mul(16)?.checked_add(digit))
+            .ok_or_else(|| "Invalid unicode escape in JSON string".to_owned())
+    })
+}
+
+/// Parses a JSON array or object, after the opening bracket or brace.
+fn parse_list<T, F: FnMut(&mut Peekable<Chars<'_>>) -> Result<T, String>>(
+    chars: &mut Peekable<Chars<'_>>,
+    close: char,
+    mut parse_elt: F,
+) -> Result<Vec<T>, String> {
+    let mut elts = vec![];
+    skip_whitespace(chars);
+    if chars.next_if_eq(&close).is_some() {
+        return Ok(elts);
+    }
+    loop {
+        skip_whitespace(chars);
+        elts.push(parse_elt(chars)?);
+        skip_whitespace(chars);
+        match chars.next() {
+            Some(',') => (),
+            Some(ch) if ch == close => return Ok(elts),
+            _ => return Err(format!("Expected ',' or '{close}' in JSON")),
+        }
+    }
+}
+
+/// Parses a JSON string, after the opening quote.
+fn parse_string(chars: &mut Peekable<Chars<'_>>) -> Result<String, String> {
+    let mut value = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(value),
+            Some('\\') => {
+                let escaped = match chars.next() {
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => parse_unicode(chars)?,
+                    Some(ch @ ('"' | '\\' | '/')) => ch,
+                    _ => return Err("Invalid escape in JSON string".to_owned()),
+                };
+                value.push(escaped);
+            }
+            Some(ch) => value.push(ch),
+            None => return Err("Unterminated JSON string".to_owned()),
+        }
+    }
+}
+
+/// Parses an `\u` escape, after the `u`, with the low surrogate if needed.
+fn parse_unicode(chars: &mut Peekable<Chars<'_>>) -> Result<char, String> {
+    let high = parse_hex4(chars)?;
+    let code = if (0xD800..0xDC00).contains(&high) {
+        expect_keyword(chars, "\\u")?;
+        let low = parse_hex4(chars)?;
+        high.checked_sub(0xD800)
+            .and_then(|value| value.checked_shl(10))
+            .and_then(|value| value.checked_add(low.checked_sub(0xDC00)?))
+            .and_then(|value| value.checked_add(0x1_0000))
+    } else {
+        Some(high)
+    };
+    code.and_then(char::from_u32)
+        .ok_or_else(|| "Invalid unicode escape in JSON string".to_owned())
+}
+
+/// Parses any JSON value.
+fn parse_value(chars: &mut Peekable<Chars<'_>>) -> Result<Json, String> {
+    skip_whitespace(chars);
+    match chars.next() {
+        Some('{') => parse_list(chars, '}', |inner| {
+            if inner.next() != Some('"') {
+                return Err("Expected string key in JSON object".to_owned());
+            }
+            let key = parse_string(inner)?;
+            skip_whitespace(inner);
+            if inner.next() != Some(':') {
+                return Err("Expected ':' in JSON object".to_owned());
+            }
+            Ok((key, parse_value(inner)?))
+        })
+        .map(Json::Object),
+        Some('[') => parse_list(chars, ']', parse_value).map(Json::Array),
+        Some('"') => parse_string(chars).map(Json::String),
+        Some('t') => expect_keyword(chars, "rue").map(|()| Json::Bool(true)),
+        Some('f') => expect_keyword(chars, "alse").map(|()| Json::Bool(false)),
+        Some('n') => expect_keyword(chars, "ull").map(|()| Json::Null),
+        Some(first @ ('-' | '0'..='9')) => {
+            let mut number = first.to_string();
+            while let Some(ch) =
+                chars.next_if(|ch| ch.is_ascii_digit() || matches!(ch, '.' | 'e' | 'E' | '+' | '-'))
+            {
+                number.push(ch);
+            }
+            Ok(Json::Number(number))
+        }
+        Some(ch) => Err(format!("Unexpected '{ch}' in JSON")),
+        None => Err("Unexpected end of JSON".to_owned()),
+    }
+}
+
+/// Skips the whitespace between the tokens.
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars.next_if(char::is_ascii_whitespace).is_some() {}
+}
@@ -0,0 +1,43 @@
+//! Language server for C, based on the diagnostics of the lexer, the parser
+//! and the lineariser.
+//!
+//! The server communicates with JSON-RPC messages on the standard input and
+//! output. It supports the diagnostics, hover with the types of the symbols,
+//! go-to-definition and the document symbols.
+
+/// Analysis of the open documents.
+mod document;
+/// Minimal JSON values.
+mod json;
+/// Framing of the JSON-RPC messages.
+mod rpc;
+/// State of the server and dispatch of the messages.
+mod server;
+
+use std::io;
+
+use crate::json::Json;
+use crate::rpc::{read_message, write_message};
+use crate::server::{Server, error, response};
+
+/// JSON-RPC error code of a message that isn't valid JSON.
+const PARSE_ERROR: i32 = -32700;
+
+fn main() -> io::Result<()> {
+    let mut input = io::stdin().lock();
+    let mut output = io::stdout().lock();
+    let mut server = Server::default();
+    while let Some(message) = read_message(&mut input)? {
+        let replies = match message {
+            Ok(json) => server.handle(&json),
+            Err(err) => vec![response(&Json::Null, "error", error(PARSE_ERROR, &err))],
+        };
+        for reply in &replies {
+            write_message(&mut output, reply)?;
+        }
+        if server.has_exited() {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Reads and writes the JSON-RPC messages, framed with a `Content-Length`
+//! header.
+
+use std::io::{self, BufRead, Read as _, Write};
+
+use crate::json::Json;
+
+/// Maximum length of the body of a message, to refuse allocating a buffer of
+/// any size announced by the client.
+const MAX_CONTENT_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Reads the next message from the client.
+///
+/// # Returns
+///
+/// - `Ok(None)` if the input was closed;
+/// - `Ok(Some(Err(_)))` if the message isn't valid JSON, or if it is longer
+///   than [`MAX_CONTENT_LENGTH`].
+pub fn read_message<R: BufRead>(input: &mut R) -> io::Result<Option<Result<Json, String>>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if input.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let trimmed = header.trim_end();
+        if trimmed.is_empty() {
+            if length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((name, value)) = trimmed.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let size = length.unwrap_or_default();
+    if size > MAX_CONTENT_LENGTH {
+        io::copy(&mut input.take(u64::try_from(size).unwrap_or(u64::MAX)), &mut io::sink())?;
+        return Ok(Some(Err(format!(
+            "Message of {size} bytes is longer than the maximum of {MAX_CONTENT_LENGTH} bytes"
+        ))));
+    }
+    let mut body = vec![0; size];
+    input.read_exact(&mut body)?;
+    Ok(Some(
+        String::from_utf8(body)
+            .map_err(|err| format!("Message isn't valid UTF-8: {err}"))
+            .and_then(|text| Json::parse(&text)),
+    ))
+}
+
+/// Writes a message to the client.
+pub fn write_message<W: Write>(output: &mut W, message: &Json) -> io::Result<()> {
+    let body = message.to_string();
+    write!(output, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    output.flush()
+}
@@ -0,0 +1,200 @@
+//! State of the server, and dispatch of the messages of the client.
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use std::path::PathBuf;
+
+use c_parser::PreprocessorOptions;
+
+use crate::document::Document;
+use crate::json::Json;
+
+/// JSON-RPC error code of a request to an unknown method.
+const METHOD_NOT_FOUND: i32 = -32601;
+/// JSON-RPC error code of a request that isn't valid.
+const INVALID_REQUEST: i32 = -32600;
+
+/// Language server, with the documents opened by the client.
+#[derive(Default)]
+pub struct Server {
+    /// Open documents, by URI.
+    documents: BTreeMap<String, Document>,
+    /// Indicates if the client sent the `exit` notification.
+    exited: bool,
+    /// Options of the preprocessor, read from the `initializationOptions`.
+    options: PreprocessorOptions,
+    /// Indicates if the client sent the `shutdown` request.
+    shutdown: bool,
+}
+
+impl Server {
+    /// Handles a message from the client.
+    ///
+    /// # Returns
+    ///
+    /// The messages to send back to the client: the response if the message
+    /// is a request, and the diagnostics of the changed documents.
+    pub fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let method = message
+            .get("method")
+            .and_then(Json::as_str)
+            .unwrap_or_default();
+        let params = message.get("params");
+        match message.get("id") {
+            Some(id) => vec![self.handle_request(method, params).map_or_else(
+                |(code, msg)| response(id, "error", error(code, msg)),
+                |result| response(id, "result", result),
+            )],
+            None => self.handle_notification(method, params),
+        }
+    }
+
+    /// Handles a notification, that doesn't expect a response.
+    fn handle_notification(&mut self, method: &str, params: Option<&Json>) -> Vec<Json> {
+        let uri = text_document_field(params, "uri")
+            .and_then(Json::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        match method {
+            "exit" => self.exited = true,
+            "textDocument/didOpen" =>
+                if let Some(text) = text_document_field(params, "text").and_then(Json::as_str) {
+                    self.documents
+                        .insert(uri.clone(), Document::new(&uri, text.to_owned(), &self.options));
+                },
+            "textDocument/didChange" => {
+                let text = params
+                    .and_then(|param| param.get("contentChanges"))
+                    .and_then(Json::as_array)
+                    .and_then(<[Json]>::last)
+                    .and_then(|change| change.get("text"))
+                    .and_then(Json::as_str);
+                if let Some(new_text) = text {
+                    self.documents.insert(
+                        uri.clone(),
+                        Document::new(&uri, new_text.to_owned(), &self.options),
+                    );
+                }
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(&uri);
+                return vec![publish_diagnostics(uri, Json::Array(vec![]))];
+            }
+            _ => return vec![],
+        }
+        self.documents
+            .get(&uri)
+            .map(|doc| publish_diagnostics(uri, doc.diagnostics()))
+            .into_iter()
+            .collect()
+    }
+
+    /// Handles a request, and returns its result.
+    fn handle_request(
+        &mut self,
+        method: &str,
+        params: Option<&Json>,
+    ) -> Result<Json, (i32, &'static str)> {
+        if self.shutdown {
+            return Err((INVALID_REQUEST, "Server was shut down"));
+        }
+        let uri = text_document_field(params, "uri")
+            .and_then(Json::as_str)
+            .unwrap_or_default();
+        let position = params.and_then(|param| param.get("position"));
+        let document = self.documents.get(uri);
+        match method {
+            "initialize" => {
+                self.options = preprocessor_options(
+                    params.and_then(|param| param.get("initializationOptions")),
+                );
+                Ok(capabilities())
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Json::Null)
+            }
+            "textDocument/definition" =>
+                Ok(document.map_or(Json::Null, |doc| doc.definition(uri, position))),
+            "textDocument/documentSymbol" =>
+                Ok(document.map_or(Json::Null, Document::document_symbols)),
+            "textDocument/hover" => Ok(document.map_or(Json::Null, |doc| doc.hover(position))),
+            _ => Err((METHOD_NOT_FOUND, "Method not found")),
+        }
+    }
+
+    /// Checks if the client asked the server to exit.
+    pub const fn has_exited(&self) -> bool {
+        self.exited
+    }
+}
+
+/// Returns the result of the `initialize` request.
+fn capabilities() -> Json {
+    Json::object([
+        (
+            "capabilities",
+            Json::object([
+                ("textDocumentSync", 1.into()),
+                ("hoverProvider", Json::Bool(true)),
+                ("definitionProvider", Json::Bool(true)),
+                ("documentSymbolProvider", Json::Bool(true)),
+            ]),
+        ),
+        (
+            "serverInfo",
+            Json::object([
+                ("name", env!("CARGO_BIN_NAME").into()),
+                ("version", env!("CARGO_PKG_VERSION").into()),
+            ]),
+        ),
+    ])
+}
+
+/// Builds a JSON-RPC error.
+pub fn error(code: i32, message: &str) -> Json {
+    Json::object([
+        ("code", Json::Number(code.to_string())),
+        ("message", message.into()),
+    ])
+}
+
+/// Reads the options of the preprocessor from the `initializationOptions`,
+/// like `{"includePaths": ["/usr/include"], "defines": {"DEBUG": "1"}}`.
+fn preprocessor_options(initialization: Option<&Json>) -> PreprocessorOptions {
+    let mut options = PreprocessorOptions::default();
+    let field = |name| initialization?.get(name);
+    options.include_paths = field("includePaths")
+        .and_then(Json::as_array)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Json::as_str)
+        .map(PathBuf::from)
+        .collect();
+    options.defines = field("defines")
+        .and_then(Json::as_object)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|(name, value)| Some((name.clone(), value.as_str()?.to_owned())))
+        .collect();
+    options
+}
+
+/// Builds a `textDocument/publishDiagnostics` notification.
+fn publish_diagnostics(uri: String, diagnostics: Json) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("method", "textDocument/publishDiagnostics".into()),
+        ("params", Json::object([("uri", uri.into()), ("diagnostics", diagnostics)])),
+    ])
+}
+
+/// Builds a JSON-RPC response, with a `result` or an `error`.
+pub fn response(id: &Json, kind: &str, value: Json) -> Json {
+    Json::object([("jsonrpc", "2.0".into()), ("id", id.clone()), (kind, value)])
+}
+
+/// Returns a field of the `textDocument` parameter.
+fn text_document_field<'json>(params: Option<&'json Json>, field: &str) -> Option<&'json Json> {
+    params?.get("textDocument")?.get(field)
+}
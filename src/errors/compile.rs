@@ -34,12 +34,31 @@ pub struct CompileError {
 }
 
 impl CompileError {
+    /// Returns the severity of the error.
+    #[must_use]
+    pub const fn as_level(&self) -> ErrorLevel {
+        self.err_lvl
+    }
+
+    /// Returns the location of the error.
+    #[must_use]
+    pub const fn as_location(&self) -> ErrorLocation {
+        self.location
+    }
+
+    /// Returns the message of the error.
+    #[must_use]
+    pub fn as_message(&self) -> &str {
+        &self.message
+    }
+
     /// Returns the referenced data of a `CompileError`.
     pub(super) fn as_values(&self) -> (ErrorLocation, &str, String) {
         (self.location, &self.message, self.err_lvl.to_string())
     }
 
     /// Returns a result from the current error.
+    #[must_use]
     pub fn into_res<T>(self) -> Res<T> {
         Res::from_err(self)
     }
@@ -72,6 +91,7 @@ impl From<Vec<CompileError>> for CompileErrorList {
 
 /// Different levels of errors
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
 pub enum ErrorLevel {
     /// The compiler stops compiling and fails.
     ///
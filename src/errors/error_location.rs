@@ -18,6 +18,7 @@ use crate::errors::api::Located;
 /// In order to respect the click links from terminals, the line and column of
 /// a file start at 1 and not 0.
 #[derive(Clone, Default, Copy, Debug)]
+#[non_exhaustive]
 pub enum ErrorLocation {
     /// Location a block of the source file
     ///
@@ -71,8 +72,9 @@ impl ErrorLocation {
     #[expect(clippy::panic, reason = "todo")]
     fn as_pos(self) -> (u32, u32, u32, u32) {
         match self {
-            Self::Block(_, line_s, col_s, line_e, col_e) => (line_s, col_s, line_e, col_e),
-            Self::Char(_, line, col) => (line, col, line, col),
+            Self::Block(_, line_s, col_s, line_e, col_e) =>
+                (line_s, col_s, line_e, col_e.saturating_add(1)),
+            Self::Char(_, line, col) => (line, col, line, col.saturating_add(1)),
             Self::Token(_, line, col, len) => (line, col, line, col + len),
             Self::TwoTokens(..) => panic!("can not be extended"),
             Self::None => unreachable!("never built"),
@@ -87,16 +89,18 @@ impl ErrorLocation {
     /// Returns a [`ErrorLocation`] that is the combination of the span covered
     /// by the 2 inputs.
     ///
+    /// If the locations are in different files, like a token expanded from a
+    /// macro defined in another file, the first location is returned.
+    ///
     /// # Panics
     ///
     /// If the second provided [`ErrorLocation`] is before or overlaps the
     /// first.
+    #[must_use]
     pub fn into_extended(self, other: Self) -> Self {
-        debug_assert_eq!(
-            self.as_filename(),
-            other.as_filename(),
-            "can't merge 2 locations from different files"
-        );
+        if self.as_filename() != other.as_filename() {
+            return self;
+        }
         let first = self.as_pos();
         let second = other.as_pos();
         let (min, max) = if first.0 < second.0 || (first.0 == second.0 && first.1 <= second.1) {
@@ -108,7 +112,7 @@ impl ErrorLocation {
         if min.0 == max.2 {
             Self::Token(file, min.0, min.1, max.3.saturating_sub(min.1))
         } else {
-            Self::Block(file, min.0, min.1, max.2, max.3)
+            Self::Block(file, min.0, min.1, max.2, max.3.saturating_sub(1))
         }
     }
 
@@ -118,6 +122,7 @@ impl ErrorLocation {
     ///
     /// If one of the given error locations isn't a token.
     #[expect(clippy::panic, reason = "todo")]
+    #[must_use]
     pub fn into_two_tokens(self, other: Self) -> Self {
         if let Self::Token(file1, line1, col1, len1) = self
             && let Self::Token(file2, line2, col2, len2) = other
@@ -134,33 +139,49 @@ impl ErrorLocation {
     }
 
     /// Creates a new [`ErrorLocation`] of type char at the given position
+    #[must_use]
     pub const fn new_char(file: u32, line: u32, col: u32) -> Self {
         Self::Char(file, line, col)
     }
 
+    /// Returns the line and column of the first character of the location.
+    pub(crate) const fn start(self) -> Option<(u32, u32)> {
+        match self {
+            Self::Block(_, line, col, ..)
+            | Self::Char(_, line, col)
+            | Self::Token(_, line, col, _)
+            | Self::TwoTokens(_, line, col, ..) => Some((line, col)),
+            Self::None => None,
+        }
+    }
+
     /// Adds a value to the error location to make a [`Located`].
-    pub fn wrap<T>(self, value: T) -> Located<T> {
+    pub(crate) fn wrap<T>(self, value: T) -> Located<T> {
         Located::from((value, self))
     }
 }
 
 impl ErrorLocation {
     /// Creates a [`CompileError`] of level [`ErrorLevel::Crash`].
+    #[must_use]
     pub fn crash(self, msg: String) -> CompileError {
         CompileError::from((self, msg, ErrorLevel::Crash))
     }
 
     /// Creates a [`CompileError`] of level [`ErrorLevel::Fault`].
+    #[must_use]
     pub fn fail(self, msg: String) -> CompileError {
         CompileError::from((self, msg, ErrorLevel::Fault))
     }
 
     /// Creates a [`CompileError`] of level [`ErrorLevel::Suggestion`].
+    #[must_use]
     pub fn suggest(self, msg: String) -> CompileError {
         CompileError::from((self, msg, ErrorLevel::Suggestion))
     }
 
     /// Creates a [`CompileError`] of level [`ErrorLevel::Warning`].
+    #[must_use]
     pub fn warn(self, msg: String) -> CompileError {
         CompileError::from((self, msg, ErrorLevel::Warning))
     }
@@ -0,0 +1,239 @@
+//! Module to export the errors in machine-readable formats
+//!
+//! Implements the [`Diagnostic`] struct, a structured version of a
+//! [`CompileError`], and the functions to serialise the diagnostics into JSON
+//! Lines and SARIF 2.1.0.
+
+use core::fmt::{self, Write as _};
+use std::collections::HashMap;
+
+use super::api::ErrorLocation;
+use super::compile::{CompileError, CompileErrorList, ErrorLevel};
+
+/// URI of the JSON schema of SARIF 2.1.0.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Structured version of a [`CompileError`], to be exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Diagnostic {
+    /// Name of the file in which the error occurred
+    pub file: String,
+    /// Severity of the error
+    pub level: ErrorLevel,
+    /// Error message
+    pub message: String,
+    /// Second token of the error, for errors on two distinct tokens
+    pub secondary: Option<Span>,
+    /// Location of the error in the file
+    pub span: Span,
+}
+
+impl Diagnostic {
+    /// Converts a [`CompileError`] into a [`Diagnostic`].
+    fn new(error: &CompileError, file_names: &HashMap<u32, &str>) -> Self {
+        let (location, message, _) = error.as_values();
+        let (file, span, secondary) = match location {
+            ErrorLocation::Block(file, start_line, start_col, end_line, end_col) => (
+                file,
+                Span { end_col: end_col.saturating_add(1), end_line, start_col, start_line },
+                None,
+            ),
+            ErrorLocation::Char(file, line, col) => (file, Span::on_line(line, col, 1), None),
+            ErrorLocation::None => unreachable!("never built"),
+            ErrorLocation::Token(file, line, col, len) =>
+                (file, Span::on_line(line, col, len), None),
+            ErrorLocation::TwoTokens(file, line1, col1, len1, line2, col2, len2) =>
+                (file, Span::on_line(line1, col1, len1), Some(Span::on_line(line2, col2, len2))),
+        };
+        Self {
+            file: file_names
+                .get(&file)
+                .map_or_else(|| file.to_string(), |name| (*name).to_owned()),
+            level: error.as_level(),
+            message: message.to_owned(),
+            secondary,
+            span,
+        }
+    }
+
+    /// Writes the diagnostic as one line of JSON.
+    fn write_json_line(&self, buf: &mut String) -> fmt::Result {
+        buf.push_str(r#"{"level":"#);
+        write_json_string(buf, level_name(self.level))?;
+        buf.push_str(r#","message":"#);
+        write_json_string(buf, &self.message)?;
+        buf.push_str(r#","file":"#);
+        write_json_string(buf, &self.file)?;
+        buf.push(',');
+        self.span.write_json_fields(buf)?;
+        if let Some(secondary) = self.secondary {
+            buf.push_str(r#","secondary":{"#);
+            secondary.write_json_fields(buf)?;
+            buf.push('}');
+        } else {
+            buf.push_str(r#","secondary":null"#);
+        }
+        buf.push_str("}\n");
+        Ok(())
+    }
+
+    /// Writes a span of the file of the diagnostic as a SARIF
+    /// `physicalLocation` object.
+    fn write_sarif_location(&self, buf: &mut String, span: Span) -> fmt::Result {
+        buf.push_str(r#"{"artifactLocation":{"uri":"#);
+        write_json_string(buf, &self.file)?;
+        write!(
+            buf,
+            r#"}},"region":{{"startLine":{},"startColumn":{},"endLine":{},"endColumn":{}}}}}"#,
+            span.start_line, span.start_col, span.end_line, span.end_col
+        )
+    }
+
+    /// Writes the diagnostic as a SARIF `result` object.
+    fn write_sarif_result(&self, buf: &mut String) -> fmt::Result {
+        write!(buf, r#"{{"level":"{}","message":{{"text":"#, sarif_level(self.level))?;
+        write_json_string(buf, &self.message)?;
+        buf.push_str(r#"},"locations":[{"physicalLocation":"#);
+        self.write_sarif_location(buf, self.span)?;
+        buf.push_str("}]");
+        if let Some(secondary) = self.secondary {
+            buf.push_str(r#","relatedLocations":[{"id":0,"physicalLocation":"#);
+            self.write_sarif_location(buf, secondary)?;
+            buf.push_str("}]");
+        }
+        buf.push('}');
+        Ok(())
+    }
+}
+
+/// Span of C source code inside a file.
+///
+/// As for the [`ErrorLocation`], the lines and columns start at 1. The end
+/// column is the column of the character that follows the span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Span {
+    /// Column of the character after the end of the span
+    pub end_col: u32,
+    /// Line of the end of the span
+    pub end_line: u32,
+    /// Column of the first character of the span
+    pub start_col: u32,
+    /// Line of the first character of the span
+    pub start_line: u32,
+}
+
+impl Span {
+    /// Creates the span of a token, that holds in one line.
+    const fn on_line(line: u32, col: u32, len: u32) -> Self {
+        Self {
+            end_col: col.saturating_add(len),
+            end_line: line,
+            start_col: col,
+            start_line: line,
+        }
+    }
+
+    /// Writes the lines and columns of the span as fields of a JSON object.
+    fn write_json_fields(self, buf: &mut String) -> fmt::Result {
+        write!(
+            buf,
+            r#""start_line":{},"start_col":{},"end_line":{},"end_col":{}"#,
+            self.start_line, self.start_col, self.end_line, self.end_col
+        )
+    }
+}
+
+/// Converts the [`CompileError`]s into [`Diagnostic`]s.
+///
+/// See [`Res::as_diagnostics`](super::result::Res::as_diagnostics) for extra
+/// information.
+pub(super) fn diagnostics(
+    errors: &CompileErrorList,
+    files: &[(u32, &str, &str)],
+) -> Vec<Diagnostic> {
+    let file_names: HashMap<u32, &str> = files
+        .iter()
+        .map(|(id, filename, _)| (*id, *filename))
+        .collect();
+    errors
+        .0
+        .iter()
+        .map(|error| Diagnostic::new(error, &file_names))
+        .collect()
+}
+
+/// Serialises the diagnostics in JSON Lines, with one JSON object per line.
+///
+/// # Errors
+///
+/// Returns an error when the writing on the string buffer fails.
+pub(super) fn json_lines(diagnostics: &[Diagnostic]) -> Result<String, fmt::Error> {
+    let mut buf = String::new();
+    for diagnostic in diagnostics {
+        diagnostic.write_json_line(&mut buf)?;
+    }
+    Ok(buf)
+}
+
+/// Returns the name of a level in the JSON Lines output.
+const fn level_name(level: ErrorLevel) -> &'static str {
+    match level {
+        ErrorLevel::Crash => "crash",
+        ErrorLevel::Fault => "fault",
+        ErrorLevel::Suggestion => "suggestion",
+        ErrorLevel::Warning => "warning",
+    }
+}
+
+/// Serialises the diagnostics into a SARIF 2.1.0 log, with one run.
+///
+/// # Errors
+///
+/// Returns an error when the writing on the string buffer fails.
+pub(super) fn sarif(diagnostics: &[Diagnostic]) -> Result<String, fmt::Error> {
+    let mut buf = String::new();
+    write!(
+        buf,
+        r#"{{"$schema":"{SARIF_SCHEMA}","version":"2.1.0","runs":[{{"tool":{{"driver":{{"name":"{}","version":"{}","informationUri":"{}"}}}},"columnKind":"unicodeCodePoints","results":["#,
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_REPOSITORY"),
+    )?;
+    for (idx, diagnostic) in diagnostics.iter().enumerate() {
+        if idx != 0 {
+            buf.push(',');
+        }
+        diagnostic.write_sarif_result(&mut buf)?;
+    }
+    buf.push_str("]}]}\n");
+    Ok(buf)
+}
+
+/// Returns the SARIF level of an [`ErrorLevel`].
+const fn sarif_level(level: ErrorLevel) -> &'static str {
+    match level {
+        ErrorLevel::Crash | ErrorLevel::Fault => "error",
+        ErrorLevel::Suggestion => "note",
+        ErrorLevel::Warning => "warning",
+    }
+}
+
+/// Writes a string as a JSON string literal, with the quotes.
+fn write_json_string(buf: &mut String, value: &str) -> fmt::Result {
+    buf.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            _ if ch.is_control() => write!(buf, "\\u{:04x}", u32::from(ch))?,
+            _ => buf.push(ch),
+        }
+    }
+    buf.push('"');
+    Ok(())
+}
@@ -17,6 +17,11 @@ impl<T> Located<T> {
         self.1
     }
 
+    /// Mutably references the location.
+    pub(crate) const fn as_location_mut(&mut self) -> &mut ErrorLocation {
+        &mut self.1
+    }
+
     /// Transfers the mutable reference to the value.
     pub const fn as_ref(&self) -> Located<&T> {
         Located(&self.0, self.1)
@@ -27,6 +32,11 @@ impl<T> Located<T> {
         &self.0
     }
 
+    /// Mutably references the value.
+    pub const fn as_value_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
     /// Drops the location and returns the value.
     pub fn drop_location(self) -> T {
         self.0
@@ -57,11 +57,27 @@ impl LocationPointer {
         }
     }
 
+    /// Returns the pointer moved back a few characters on the current line.
+    ///
+    /// If the offset is too big, the column is set to 0.
+    pub(crate) fn rewind(self, offset: usize) -> Self {
+        Self { col: self.col.saturating_sub(usize_to_u32(offset)), ..self }
+    }
+
     /// Creates a new file with the given file index.
     pub(crate) const fn start_file(file: u32) -> Self {
         Self { col: 0, file, line: 0 }
     }
 
+    /// Creates a pointer in the given file, placed just before the line
+    /// `line + 1`.
+    ///
+    /// This is used to lex a chunk of a file that doesn't start at the first
+    /// line.
+    pub(crate) const fn start_file_at(file: u32, line: u32) -> Self {
+        Self { col: 0, file, line }
+    }
+
     /// Moves the location back a few character on the current line
     ///
     /// If the offset is too big, the column is set to minimal (1) without any
@@ -70,9 +86,7 @@ impl LocationPointer {
         ErrorLocation::Token(
             self.file,
             self.line,
-            self.col
-                .checked_sub(usize_to_u32(offset))
-                .expect("never happens"),
+            self.col.saturating_sub(usize_to_u32(offset)).max(1),
             usize_to_u32(len),
         )
     }
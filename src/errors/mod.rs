@@ -10,10 +10,11 @@ pub mod api {
 
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
-    pub use super::compile::CompileError;
+    pub use super::compile::{CompileError, ErrorLevel};
     #[cfg(feature = "debug")]
     pub use super::debug::Print;
     pub use super::error_location::ErrorLocation;
+    pub use super::export::{Diagnostic, Span};
     pub use super::located::Located;
     pub use super::location_ptr::LocationPointer;
     pub use super::result::{CompileRes, Res};
@@ -24,6 +25,7 @@ mod compile;
 mod debug;
 mod display;
 mod error_location;
+mod export;
 mod located;
 mod location_ptr;
 mod result;
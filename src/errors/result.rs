@@ -10,6 +10,7 @@ use std::process::exit;
 
 use super::compile::CompileError;
 use super::display::display_errors;
+use super::export::{Diagnostic, diagnostics, json_lines, sarif};
 use crate::errors::compile::CompileErrorList;
 
 /// [`Result`] alias for [`CompileError`]
@@ -56,6 +57,27 @@ impl<T> Res<T> {
         }
     }
 
+    /// Returns all the errors as structured records.
+    ///
+    /// The `files` are the same as for [`Res::as_displayed_errors`], and are
+    /// used to get the names of the files of the errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_parser::{ErrorLevel, lex};
+    ///
+    /// let diagnostics = lex("int m@in() { }", 0).as_diagnostics(&[(0, "filename.c", "")]);
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].file, "filename.c");
+    /// assert_eq!(diagnostics[0].level, ErrorLevel::Fault);
+    /// assert_eq!((diagnostics[0].span.start_col, diagnostics[0].span.end_col), (6, 7));
+    /// ```
+    #[must_use]
+    pub fn as_diagnostics(&self, files: &[(u32, &str, &str)]) -> Vec<Diagnostic> {
+        diagnostics(&self.errors, files)
+    }
+
     /// Returns all the errors in a user-readable format.
     ///
     /// # Returns
@@ -92,6 +114,61 @@ impl<T> Res<T> {
         )
     }
 
+    /// Returns all the errors in JSON Lines format, with one JSON object per
+    /// error.
+    ///
+    /// Each object contains the level, the message, the file, the start and
+    /// end lines and columns, and the `secondary` span of the errors on two
+    /// tokens (or `null`). The end column is exclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_parser::lex;
+    ///
+    /// let (_, errors) = lex("int m@in() { }", 0).as_json_lines(&[(0, "filename.c", "")]);
+    /// let expected = r#"{"level":"fault","message":"Character '@' not supported.","file":"filename.c","start_line":1,"start_col":6,"end_line":1,"end_col":7,"secondary":null}
+    /// "#;
+    ///
+    /// assert_eq!(errors, expected);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If there are too many errors, a buffer overflow occurs
+    pub fn as_json_lines(self, files: &[(u32, &str, &str)]) -> (Option<T>, String) {
+        let diags = diagnostics(&self.errors, files);
+        (
+            self.result,
+            json_lines(&diags).expect("Buffer overflow, failed to export errors"),
+        )
+    }
+
+    /// Returns all the errors as a SARIF 2.1.0 log, to be read by code
+    /// scanning tools.
+    ///
+    /// The errors on two tokens have the second token as related location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use c_parser::lex;
+    ///
+    /// let (_, log) = lex("int m@in() { }", 0).as_sarif(&[(0, "filename.c", "")]);
+    ///
+    /// assert!(log.starts_with(r#"{"$schema":"https://json.schemastore.org/sarif-2.1.0.json","version":"2.1.0","#));
+    /// assert!(log.ends_with(r#""results":[{"level":"error","message":{"text":"Character '@' not supported."},"locations":[{"physicalLocation":{"artifactLocation":{"uri":"filename.c"},"region":{"startLine":1,"startColumn":6,"endLine":1,"endColumn":7}}}]}]}]}
+    /// "#));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If there are too many errors, a buffer overflow occurs
+    pub fn as_sarif(self, files: &[(u32, &str, &str)]) -> (Option<T>, String) {
+        let diags = diagnostics(&self.errors, files);
+        (self.result, sarif(&diags).expect("Buffer overflow, failed to export errors"))
+    }
+
     /// Checks if the ``errors`` field is empty
     ///
     /// # Examples
@@ -124,6 +201,12 @@ impl<T> Res<T> {
         self.errors.0.iter().any(CompileError::is_failure)
     }
 
+    /// Returns the result and the errors, to process the errors without
+    /// displaying them.
+    pub fn into_parts(self) -> (Option<T>, Vec<CompileError>) {
+        (self.result, self.errors.0)
+    }
+
     /// Applies a function to the result, if it has a result.
     pub fn map<U, F: FnOnce(T) -> U>(self, func: F) -> Res<U> {
         Res { errors: self.errors, result: self.result.map(func) }
@@ -7,6 +7,7 @@ use super::state::api::{
 };
 use super::types::api::{LexingData, Token};
 use crate::errors::api::{LocationPointer, Res};
+use crate::target::TargetInfo;
 
 /// Function to manage one character.
 ///
@@ -38,7 +39,7 @@ fn lex_char(
         }
 
         /* Escaped character */
-        (_, state @ (LS::Str(_) | LS::Char(None)), escape @ Some(_)) =>
+        (_, state @ (LS::Str(_) | LS::Char(None, _)), escape @ Some(_)) =>
             if let Some(additional) = handle_escape(ch, state, lex_data, escape, location) {
                 lex_char(additional, location, lex_data, state, escape, eol);
             },
@@ -51,7 +52,7 @@ fn lex_char(
         }
 
         /* Escape character */
-        ('\\', LS::Char(None) | LS::Str(_), escape) => *escape = Some(EscapeState::Single),
+        ('\\', LS::Char(None, _) | LS::Str(_), escape) => *escape = Some(EscapeState::Single),
         ('\\', _, escape) if eol => *escape = Some(EscapeState::Single),
         ('\\', _, _) => {
             lex_data.push_err(
@@ -64,26 +65,36 @@ fn lex_char(
         // open/close
         ('\'', LS::Symbols(symbol_state), _) if symbol_state.is_trigraph_prefix() => {
             if let Some((size, symbol)) = symbol_state.push(ch, lex_data, location) {
-                lex_data.push_token(Token::from_symbol(symbol, size, location));
+                lex_data.push_token(Token::from_symbol_with_offset(symbol, size, 3, location));
             }
         }
-        ('\'', state @ LS::Char(_), _) => end_current(state, lex_data, location),
+        ('\'', state @ LS::Char(..), _) => end_current(state, lex_data, location),
+        // digit separator
+        ('\'', LS::Ident(ident), _) if ident.is_number() => ident.push(ch),
         ('\'', state, _) if !matches!(state, LS::Str(_)) => {
+            let encoding = state.take_prefix();
             end_current(state, lex_data, location);
-            *state = LS::Char(None);
+            *state = LS::Char(None, encoding);
         }
         ('\"', state @ LS::Str(_), _) => {
             end_current(state, lex_data, location);
         }
-        ('\"', state, _) if !matches!(state, LS::Char(_)) => {
+        ('\"', state, _) if !matches!(state, LS::Char(..)) => {
+            let encoding = state.take_prefix();
             end_current(state, lex_data, location);
-            *state = LS::Str((String::new(), location.to_owned()));
+            let start = location.rewind(encoding.prefix().len());
+            *state = LS::Str((String::new(), encoding, start));
         }
         // middle
-        (_, LS::Char(Some(_)), _) =>
+        (_, LS::Char(Some(_), _), _) =>
             lex_data.push_err(location.fail("A char must contain only one character.".to_owned())),
-        (_, state @ LS::Char(None), _) => *state = LS::Char(Some(ch)),
-        (_, LS::Str((val, _)), _) => val.push(ch),
+        (_, LS::Char(inner @ None, encoding), _) => {
+            if let Some(msg) = encoding.not_one_unit(ch) {
+                lex_data.push_err(location.fail(msg));
+            }
+            *inner = Some(ch);
+        }
+        (_, LS::Str((val, ..)), _) => val.push(ch),
 
         /* Operator symbols */
         ('/', state, _) if state.symbol_and_last_is('/') => {
@@ -145,7 +156,7 @@ fn lex_char_symbol(
 ) {
     if let LS::Symbols(symbol_state) = state {
         if let Some((size, symbol)) = symbol_state.push(ch, lex_data, location) {
-            lex_data.push_token(Token::from_symbol(symbol, size, location));
+            lex_data.push_token(Token::from_symbol_with_offset(symbol, size, 3, location));
         }
     } else {
         end_current(state, lex_data, location);
@@ -159,10 +170,59 @@ fn lex_char_symbol(
 /// functions. Every character is parsed one by one, and the state is modified
 /// accordingly. When the state changes, the buffers of the state are empty into
 /// the data.
+///
+/// The number constants are typed for the default [`TargetInfo`].
 #[must_use]
 pub fn lex(content: &str, file_id: u32) -> Res<Vec<Token>> {
-    let mut location = LocationPointer::start_file(file_id);
-    let mut lex_data = LexingData::default();
+    lex_with(content, file_id, &TargetInfo::default())
+}
+
+/// Function that lexes a chunk of a source file, starting at the given line.
+///
+/// The first line of `content` is considered to be the line `first_line + 1`
+/// of the file, so that the locations of the tokens point to the original
+/// file.
+pub fn lex_from_line(
+    content: &str,
+    file_id: u32,
+    first_line: u32,
+    target: &TargetInfo,
+) -> Res<Vec<Token>> {
+    lex_with_location(content, LocationPointer::start_file_at(file_id, first_line), target, false)
+}
+
+/// Function that lexes a chunk of a source file for the preprocessor, like
+/// [`lex_from_line`], but keeps the number constants as identifiers, as they
+/// can still be pasted with the `##` operator.
+pub fn lex_pp_numbers(
+    content: &str,
+    file_id: u32,
+    first_line: u32,
+    target: &TargetInfo,
+) -> Res<Vec<Token>> {
+    lex_with_location(content, LocationPointer::start_file_at(file_id, first_line), target, true)
+}
+
+/// Function that lexes a whole source file, for the given target.
+///
+/// The target defines the ranges of the number types, and thus the types of
+/// the number constants. See [`lex`] for more information.
+#[must_use]
+pub fn lex_with(content: &str, file_id: u32, target: &TargetInfo) -> Res<Vec<Token>> {
+    lex_with_location(content, LocationPointer::start_file(file_id), target, false)
+}
+
+/// Function that lexes some content from a given starting location.
+///
+/// See [`lex`] and [`lex_pp_numbers`] for more information.
+fn lex_with_location(
+    content: &str,
+    start: LocationPointer,
+    target: &TargetInfo,
+    pp_numbers: bool,
+) -> Res<Vec<Token>> {
+    let mut location = start;
+    let mut lex_data = LexingData::new(target.clone(), pp_numbers);
     let mut lex_state = LS::default();
     let mut escape_state = None;
 
@@ -8,12 +8,15 @@ pub mod api {
 
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
-    pub use super::lex_content::lex;
-    pub use super::numbers::api::Number;
-    pub use super::types::api::{Keyword, Symbol, Token, TokenValue, display_tokens};
+    pub use super::lex_content::{lex, lex_from_line, lex_pp_numbers, lex_with};
+    pub use super::numbers::api::{BitInt, Number, NumberType};
+    pub use super::relex::relex;
+    pub use super::relex::types::{Position, Shift, TextEdit, TokenChange};
+    pub use super::types::api::{Encoding, Keyword, Symbol, Token, TokenValue, display_tokens};
 }
 
 mod lex_content;
 mod numbers;
+mod relex;
 mod state;
 mod types;
@@ -1,6 +1,7 @@
 //! Module to parse decimal-represented number constants
 
 use crate::errors::api::ErrorLocation;
+use crate::lexer::numbers::base::long_double::decimal_to_long_double;
 use crate::lexer::numbers::parse::OverParseRes;
 use crate::lexer::numbers::types::arch_types::{
     Double, Float, Int, Long, LongLong, UInt, ULong, ULongLong
@@ -10,15 +11,17 @@ use crate::lexer::numbers::types::{ERR_PREFIX, Number, NumberType};
 /// Parses the stringifies version of a decimal number in a specific integer
 /// or floating point type.
 ///
-/// # Panics
-///
-/// If the float parsing fails, i.e. contains an invalid character.
+/// The float parsing fails if the exponent has no digits, like in `1e`.
 macro_rules! parse_number {
     ($location:ident, $nb_type:ident, $literal:tt, $($int:ident)*, $($float:ident)*) => {
         match $nb_type {
-            NumberType::LongDouble => OverParseRes::from($location.fail(format!("{ERR_PREFIX}`long double` not supported yet."))), //TODO: f128 not implemented
+            NumberType::LongDouble => decimal_to_long_double($literal, $location),
+            NumberType::BitInt | NumberType::UBitInt => unreachable!("parsed as big integers"),
             $(NumberType::$int => $crate::lexer::numbers::macros::safe_parse_int!(ERR_PREFIX, $int, $location, $literal.parse::<$int>(), |nb| Number::$int(nb)),)*
-            $(NumberType::$float => OverParseRes::from(Number::$float($literal.parse::<$float>().expect("only crashes with invalid character"))),)*
+            $(NumberType::$float => $literal.parse::<$float>().map_or_else(
+                |_| OverParseRes::from($location.fail(format!("{ERR_PREFIX}Illegal floating point constant: found empty exponent, but at least one digit was expected."))),
+                |nb| OverParseRes::from(Number::$float(nb)),
+            ),)*
         }
     };
 }
@@ -44,9 +47,9 @@ macro_rules! parse_number {
 /// use crate::lexer::numbers::types::{Number, NumberType};
 ///
 /// assert!(
-///     to_decimal_value("123", &NumberType::Int, &LocationPointer::from(String::new()))
-///         == OverParseRes::Value(Number::Int(123))
-/// );
+///     to_decimal_value("123", &NumberType::Int,
+/// &LocationPointer::from(String::new()))         ==
+/// OverParseRes::Value(Number::Int(123)) );
 /// assert!(
 ///     to_decimal_value(
 ///         "1e33",
@@ -55,8 +58,8 @@ macro_rules! parse_number {
 ///     ) == OverParseRes::ValueOverflow(2i32.pow(31) - 1)
 /// );
 /// assert!(matches!(
-///     to_decimal_value("1fe3", &NumberType::Int, &LocationPointer::from(String::new())),
-///     OverParseRes::Err(_)
+///     to_decimal_value("1fe3", &NumberType::Int,
+/// &LocationPointer::from(String::new())),     OverParseRes::Err(_)
 /// ));
 /// ```
 pub fn to_decimal_value(
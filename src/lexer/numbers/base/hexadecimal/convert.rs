@@ -1,13 +1,11 @@
 use crate::lexer::numbers::base::hexadecimal::parse::HexFloatData;
-use crate::lexer::numbers::types::arch_types::{
-    Double, DoubleIntPart, Float, FloatIntPart, LongDouble, LongDoubleIntPart
-};
+use crate::lexer::numbers::types::arch_types::{Double, DoubleIntPart, Float, FloatIntPart};
 use crate::lexer::numbers::types::{Number, NumberType};
 
 /// Parses the stringified version of a number into a [`HexFloatData`].
 macro_rules! parse_hexadecimal_float {
     ($overflow:expr, $nb_type:ident, $float_parse:ident, $($t:ident)*) => {{
-        #[expect(clippy::float_arithmetic, clippy::arithmetic_side_effects, clippy::as_conversions, reason="todo")]
+        #[expect(clippy::float_arithmetic, clippy::as_conversions, reason="todo")]
         match $nb_type {
             $(NumberType::$t => {
                 let int_part = $t::from_unsigned(
@@ -84,7 +82,7 @@ trait FloatingPoint<T> {
     fn from_usize(val: usize, overflow: &mut bool) -> Self;
 }
 
-impl_floating_point!(23, Double Float LongDouble);
+impl_floating_point!(23, Double Float);
 
 /// Parsed an hexadecimal float.
 ///
@@ -95,7 +93,7 @@ pub fn to_hex_float_value(
     nb_type: NumberType,
     float_data: &HexFloatData,
 ) -> Result<Number, String> {
-    Ok(parse_hexadecimal_float!(overflow, nb_type, float_data, Float Double LongDouble))
+    Ok(parse_hexadecimal_float!(overflow, nb_type, float_data, Float Double))
 }
 
 /// Converts a hexadecimal digit to its value.
@@ -3,12 +3,13 @@
 /// Converts the parse hexadecimal constant to a [`Number`]
 mod convert;
 /// Parses the literal string into an hexadecimal value;
-mod parse;
+pub mod parse;
 
 use crate::errors::api::ErrorLocation;
 use crate::lexer::numbers::api::OverParseRes;
 use crate::lexer::numbers::base::hexadecimal::convert::to_hex_float_value;
 use crate::lexer::numbers::base::hexadecimal::parse::{HexFloatParseState, as_hex_float_data};
+use crate::lexer::numbers::base::long_double::hex_to_long_double;
 use crate::lexer::numbers::macros::parse_int_from_radix;
 use crate::lexer::numbers::types::arch_types::{Int, Long, LongLong, UInt, ULong, ULongLong};
 use crate::lexer::numbers::types::{ERR_PREFIX, Number, NumberType};
@@ -67,6 +68,8 @@ pub fn to_hex_value(
         parse_int_from_radix!(location,
            nb_type, literal, "never fails", 16, Int Long LongLong UInt ULong ULongLong
         )
+    } else if matches!(nb_type, NumberType::LongDouble) {
+        hex_to_long_double(&float_data)
     } else {
         let mut overflow = false;
         match to_hex_float_value(&mut overflow, nb_type, &float_data) {
@@ -0,0 +1,211 @@
+//! Unsigned integers of arbitrary size, to convert the `long double`
+//! constants without losing precision.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::repeat_n;
+
+/// Power of 10 used to write the integer in decimal, 9 digits at a time.
+const DECIMAL_CHUNK: u32 = 1_000_000_000;
+/// Number of bits in a limb.
+const LIMB_BITS: u64 = 32;
+
+/// Unsigned integer of arbitrary size.
+///
+/// It only supports the few operations needed to round a constant and to store
+/// the `_BitInt` constants: building it from digits, multiplying and dividing
+/// by small factors, shifting, and subtracting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Limbs of the integer, least significant first, without leading zero
+    /// limbs.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    /// Returns the number of bits needed to write the integer.
+    pub fn bit_len(&self) -> u64 {
+        self.limbs.last().map_or(0, |last| {
+            u64::try_from(self.limbs.len())
+                .unwrap_or(u64::MAX)
+                .saturating_sub(1)
+                .saturating_mul(LIMB_BITS)
+                .saturating_add(u64::from(u32::BITS.saturating_sub(last.leading_zeros())))
+        })
+    }
+
+    /// Divides `self` by `den`, knowing that the quotient has less than 128
+    /// bits.
+    ///
+    /// # Returns
+    ///
+    /// The quotient, and the remainder.
+    pub fn div_rem(mut self, den: &Self) -> (u128, Self) {
+        let mut quotient = 0;
+        let start = self.bit_len().saturating_sub(den.bit_len()).min(127);
+        for shift in (0..=start).rev() {
+            let shifted = den.shl(shift);
+            if self >= shifted {
+                self.sub_assign(&shifted);
+                quotient |= 1u128
+                    .checked_shl(u32::try_from(shift).expect("<= 127"))
+                    .expect("<= 127");
+            }
+        }
+        (quotient, self)
+    }
+
+    /// Divides the integer by `den`, and returns the remainder.
+    ///
+    /// # Panics
+    ///
+    /// If `den` is zero.
+    pub fn div_rem_small(&mut self, den: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let value = rem.checked_shl(u32::BITS).expect("< 64") | u64::from(*limb);
+            *limb = u32::try_from(value.checked_div(u64::from(den)).expect("den != 0"))
+                .expect("rem < den");
+            rem = value.checked_rem(u64::from(den)).expect("den != 0");
+        }
+        self.trim();
+        u32::try_from(rem).expect("rem < den")
+    }
+
+    /// Builds an integer from its digits in the given radix.
+    ///
+    /// # Panics
+    ///
+    /// If a character isn't a valid digit in that radix.
+    pub fn from_digits(digits: &str, radix: u32) -> Self {
+        let mut value = Self::from(0);
+        for ch in digits.chars() {
+            value.mul_add(radix, ch.to_digit(radix).expect("checked by caller"));
+        }
+        value
+    }
+
+    /// Checks if the integer is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Multiplies the integer by `factor` and adds `term`.
+    pub fn mul_add(&mut self, factor: u32, term: u32) {
+        let mut carry = u64::from(term);
+        for limb in &mut self.limbs {
+            let value = u64::from(*limb)
+                .wrapping_mul(u64::from(factor))
+                .wrapping_add(carry);
+            (*limb, carry) = split(value);
+        }
+        if carry != 0 {
+            self.limbs.push(u32::try_from(carry).expect("high part"));
+        }
+        self.trim();
+    }
+
+    /// Returns `factor^exp`.
+    pub fn pow(factor: u32, exp: u64) -> Self {
+        let mut value = Self::from(1);
+        for _ in 0..exp {
+            value.mul_add(factor, 0);
+        }
+        value
+    }
+
+    /// Returns the integer shifted to the left by `shift` bits.
+    pub fn shl(&self, shift: u64) -> Self {
+        let bits = u32::try_from(shift.checked_rem(LIMB_BITS).unwrap_or_default()).expect("< 32");
+        let limbs = usize::try_from(shift.checked_div(LIMB_BITS).unwrap_or_default())
+            .expect("integer fits in memory");
+        let mut shifted: Vec<u32> = repeat_n(0, limbs).collect();
+        let mut carry = 0;
+        for limb in &self.limbs {
+            let (low, high) = split(u64::from(*limb).checked_shl(bits).expect("< 32"));
+            shifted.push(low | carry);
+            carry = u32::try_from(high).expect("high part");
+        }
+        shifted.push(carry);
+        let mut value = Self { limbs: shifted };
+        value.trim();
+        value
+    }
+
+    /// Subtracts `other` from the integer.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is bigger than the integer.
+    pub fn sub_assign(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (idx, limb) in self.limbs.iter_mut().enumerate() {
+            let rhs = other.limbs.get(idx).copied().unwrap_or_default();
+            let (partial, under) = limb.overflowing_sub(rhs);
+            let (diff, under_borrow) = partial.overflowing_sub(u32::from(borrow));
+            *limb = diff;
+            borrow = under || under_borrow;
+        }
+        assert!(!borrow, "subtracted a bigger integer");
+        self.trim();
+    }
+
+    /// Returns the value of the integer, if it fits in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.as_slice() {
+            [] => Some(0),
+            [low] => Some(u64::from(*low)),
+            [low, high] => Some(u64::from(*high).checked_shl(u32::BITS)? | u64::from(*low)),
+            _ => None,
+        }
+    }
+
+    /// Removes the leading zero limbs.
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.clone();
+        let mut chunks = vec![value.div_rem_small(DECIMAL_CHUNK)];
+        while !value.is_zero() {
+            chunks.push(value.div_rem_small(DECIMAL_CHUNK));
+        }
+        let mut rev = chunks.iter().rev();
+        write!(f, "{}", rev.next().expect("pushed one"))?;
+        rev.try_for_each(|chunk| write!(f, "{chunk:09}"))
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(value: u32) -> Self {
+        let mut big = Self { limbs: vec![value] };
+        big.trim();
+        big
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Splits a 64-bit value into its low limb and its high part.
+fn split(value: u64) -> (u32, u64) {
+    let low = u32::try_from(value & u64::from(u32::MAX)).expect("masked");
+    (low, value.checked_shr(u32::BITS).expect("< 64"))
+}
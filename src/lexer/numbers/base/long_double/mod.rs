@@ -0,0 +1,165 @@
+//! Module to convert the `long double` constants, in decimal or hexadecimal
+//! representation, to a correctly rounded [`f128`].
+//!
+//! The standard library can't parse an [`f128`], so the value is computed
+//! exactly with big integers, and then rounded to the nearest representable
+//! value (ties to even).
+
+/// Unsigned integers of arbitrary size.
+pub mod big;
+
+use big::BigUint;
+
+use crate::errors::api::ErrorLocation;
+use crate::lexer::numbers::base::hexadecimal::parse::HexFloatData;
+use crate::lexer::numbers::parse::OverParseRes;
+use crate::lexer::numbers::types::arch_types::LongDouble;
+use crate::lexer::numbers::types::{ERR_PREFIX, Number};
+
+/// Exponent of the least significant bit of the smallest subnormal value,
+/// i.e., `MIN_EXP - MANTISSA_DIGITS`.
+const MIN_LSB_EXP: i64 = -16_494;
+/// Lower bound of `log10` of half the smallest subnormal value.
+const MIN_10_LSB_EXP: i64 = -4965;
+/// Number of digits of the significand, with the implicit leading bit.
+const PRECISION: u32 = LongDouble::MANTISSA_DIGITS;
+
+/// Parses a decimal `long double` constant.
+///
+/// The input doesn't contain the suffix ('L').
+///
+/// # Examples
+///
+/// ```ignore
+/// assert!(matches!(
+///     decimal_to_long_double("1.5", location),
+///     OverParseRes::Value(Number::LongDouble(1.5))
+/// ));
+/// assert!(matches!(decimal_to_long_double("1e5000", location), OverParseRes::ValueOverflow(_)));
+/// ```
+pub fn decimal_to_long_double(literal: &str, location: ErrorLocation) -> OverParseRes<Number> {
+    let (mantissa, exponent_str) = literal.split_once(['e', 'E']).unwrap_or((literal, "0"));
+    let (int_part, decimal_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let Some(exponent) = parse_exponent(exponent_str) else {
+        return OverParseRes::from(location.fail(format!(
+            "{ERR_PREFIX}Illegal floating point constant: invalid exponent '{exponent_str}'."
+        )));
+    };
+    if !is_digits(int_part) || !is_digits(decimal_part) || mantissa == "." {
+        return OverParseRes::from(
+            location.fail(format!("{ERR_PREFIX}Illegal floating point constant: '{literal}'.")),
+        );
+    }
+    let all_digits = format!("{int_part}{decimal_part}");
+    let digits = all_digits.trim_start_matches('0');
+    let exp10 = exponent.saturating_sub(len(decimal_part));
+    let magnitude = exp10.saturating_add(len(digits));
+    if digits.is_empty() {
+        OverParseRes::from(Number::LongDouble(0.))
+    } else if magnitude > i64::from(LongDouble::MAX_10_EXP).saturating_add(1) {
+        OverParseRes::from_value_overflow(Number::LongDouble(LongDouble::INFINITY))
+    } else if magnitude < MIN_10_LSB_EXP {
+        OverParseRes::from_value_underflow(Number::LongDouble(0.))
+    } else {
+        // 10^exp10 = 5^exp10 * 2^exp10
+        let mut num = BigUint::from_digits(digits, 10);
+        let den = BigUint::pow(5, exp10.min(0).unsigned_abs());
+        for _ in 0..exp10.max(0) {
+            num.mul_add(5, 0);
+        }
+        round(&num, &den, exp10)
+    }
+}
+
+/// Parses an hexadecimal `long double` constant, from its parts.
+pub fn hex_to_long_double(float_data: &HexFloatData) -> OverParseRes<Number> {
+    let abs_exponent = parse_exponent(&float_data.exponent).expect("checked by parser");
+    let exponent = if float_data.exponent_neg.unwrap_or(false) {
+        abs_exponent.saturating_neg()
+    } else {
+        abs_exponent
+    };
+    let digits = format!("{}{}", float_data.int_part, float_data.decimal_part);
+    let num = BigUint::from_digits(digits.trim_start_matches('0'), 16);
+    let exp2 = exponent.saturating_sub(len(&float_data.decimal_part).saturating_mul(4));
+    let magnitude = exp2.saturating_add(i64::try_from(num.bit_len()).unwrap_or(i64::MAX));
+    if num.is_zero() {
+        OverParseRes::from(Number::LongDouble(0.))
+    } else if magnitude > i64::from(LongDouble::MAX_EXP) {
+        OverParseRes::from_value_overflow(Number::LongDouble(LongDouble::INFINITY))
+    } else if magnitude < MIN_LSB_EXP {
+        OverParseRes::from_value_underflow(Number::LongDouble(0.))
+    } else {
+        round(&num, &BigUint::from(1), exp2)
+    }
+}
+
+/// Checks that a string only contains decimal digits.
+fn is_digits(value: &str) -> bool {
+    value.chars().all(|ch| ch.is_ascii_digit())
+}
+
+/// Returns the length of a string, as a signed integer.
+fn len(value: &str) -> i64 {
+    i64::try_from(value.len()).unwrap_or(i64::MAX)
+}
+
+/// Parses an exponent, with an optional sign.
+///
+/// Huge exponents are clamped, as they overflow or underflow anyway.
+fn parse_exponent(exponent: &str) -> Option<i64> {
+    let (neg, digits) = exponent.strip_prefix('-').map_or_else(
+        || (false, exponent.strip_prefix('+').unwrap_or(exponent)),
+        |digits| (true, digits),
+    );
+    if digits.is_empty() || !is_digits(digits) {
+        return None;
+    }
+    let value = digits.chars().fold(0i64, |acc, ch| {
+        acc.saturating_mul(10)
+            .saturating_add(i64::from(ch.to_digit(10).expect("checked")))
+    });
+    Some(if neg { value.saturating_neg() } else { value })
+}
+
+/// Rounds `num / den * 2^exp2` to the nearest `long double`, ties to even.
+fn round(num: &BigUint, den: &BigUint, exp2: i64) -> OverParseRes<Number> {
+    let bits = |big: &BigUint| i64::try_from(big.bit_len()).unwrap_or(i64::MAX);
+    let magnitude = exp2.saturating_add(bits(num)).saturating_sub(bits(den));
+    // exponent of the least significant bit of the significand
+    let mut exp = magnitude
+        .saturating_sub(i64::from(PRECISION))
+        .max(MIN_LSB_EXP);
+    let (mut significand, twice_rem, divisor) = loop {
+        let shift = exp2.saturating_sub(exp);
+        let (shifted_num, divisor) = if shift >= 0 {
+            (num.shl(shift.unsigned_abs()), den.clone())
+        } else {
+            (num.clone(), den.shl(shift.unsigned_abs()))
+        };
+        let (quotient, rem) = shifted_num.div_rem(&divisor);
+        if quotient.checked_shr(PRECISION).unwrap_or_default() == 0 {
+            break (quotient, rem.shl(1), divisor);
+        }
+        exp = exp.saturating_add(1);
+    };
+    if twice_rem > divisor || (twice_rem == divisor && significand & 1 == 1) {
+        significand = significand.saturating_add(1);
+        if significand.checked_shr(PRECISION).unwrap_or_default() != 0 {
+            significand = significand.checked_shr(1).unwrap_or_default();
+            exp = exp.saturating_add(1);
+        }
+    }
+    // The implicit bit of a normal value adds one to the biased exponent.
+    let biased = u128::try_from(exp.saturating_sub(MIN_LSB_EXP)).expect("exp >= MIN_LSB_EXP");
+    let repr = biased
+        .checked_shl(PRECISION.saturating_sub(1))
+        .and_then(|high| high.checked_add(significand));
+    match repr.map(LongDouble::from_bits) {
+        Some(value) if value.is_finite() && significand != 0 =>
+            OverParseRes::from(Number::LongDouble(value)),
+        Some(_) if significand == 0 => OverParseRes::from_value_underflow(Number::LongDouble(0.)),
+        Some(_) | None =>
+            OverParseRes::from_value_overflow(Number::LongDouble(LongDouble::INFINITY)),
+    }
+}
@@ -3,4 +3,61 @@
 pub mod binary;
 pub mod decimal;
 pub mod hexadecimal;
+pub mod long_double;
 pub mod octal;
+
+use crate::utils::display;
+
+/// Base of a number representation.
+#[derive(Debug)]
+pub enum Base {
+    /// Binary representation: `[0-1]`.
+    Binary,
+    /// Decimal representation: `[0-10]`.
+    Decimal,
+    /// Hexadecimal representation: `[0-16]`.
+    Hexadecimal,
+    /// Octal representation: `[0-8]`.
+    Octal,
+}
+
+impl Base {
+    /// Returns the prefix size for this specific base
+    ///
+    /// | Base        | Prefix | Size |
+    /// | :---------: | :----: | :--: |
+    /// | Binary      | "0b"   | 2    |
+    /// | Hexadecimal | "0x"   | 2    |
+    /// | Decimal     | ∅     | 0    |
+    /// | Octal       | "0"    | 1    |
+    pub const fn prefix_size(&self) -> usize {
+        match self {
+            Self::Binary | Self::Hexadecimal => 2,
+            Self::Decimal => 0,
+            Self::Octal => 1,
+        }
+    }
+
+    /// Returns the radix of the base, e.g. 16 for hexadecimal.
+    pub const fn radix(&self) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+            Self::Octal => 8,
+        }
+    }
+}
+
+display!(
+    Base,
+    self,
+    f,
+    match self {
+        Self::Binary => "binary",
+        Self::Decimal => "decimal",
+        Self::Hexadecimal => "hexadecimal",
+        Self::Octal => "octal",
+    }
+    .fmt(f)
+);
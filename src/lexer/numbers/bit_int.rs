@@ -0,0 +1,50 @@
+//! Module that defines the value of the bit-precise integer constants.
+//!
+//! See [`BitInt`].
+
+use super::base::long_double::big::BigUint;
+use crate::utils::display;
+
+/// Value of a bit-precise integer constant, of type `_BitInt(N)` or `unsigned
+/// _BitInt(N)`.
+///
+/// These constants have a `wb` or `uwb` suffix, like `1'000wb`. Their value is
+/// stored with arbitrary precision, and the width `N` is the smallest width
+/// that can hold the value, sign bit included for `_BitInt(N)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitInt {
+    /// Value of the constant
+    value: BigUint,
+    /// Width `N` of the type of the constant
+    width: u64,
+}
+
+impl BitInt {
+    /// Builds a constant from its digits in the given radix.
+    ///
+    /// The width is at least 2 for signed constants, and 1 for unsigned ones.
+    pub(super) fn from_digits(digits: &str, radix: u32, unsigned: bool) -> Self {
+        let value = BigUint::from_digits(digits, radix);
+        let bits = value.bit_len();
+        let width = if unsigned {
+            bits.max(1)
+        } else {
+            bits.saturating_add(1).max(2)
+        };
+        Self { value, width }
+    }
+
+    /// Returns the value of the constant, if it fits in a `u64`.
+    #[must_use]
+    pub fn to_u64(&self) -> Option<u64> {
+        self.value.to_u64()
+    }
+
+    /// Returns the width `N` of the `_BitInt(N)` type of the constant.
+    #[must_use]
+    pub const fn width(&self) -> u64 {
+        self.width
+    }
+}
+
+display!(BitInt, self, f, self.value.fmt(f));
@@ -3,15 +3,21 @@
 
 use core::str;
 
-use super::base::{binary, decimal, hexadecimal, octal};
+use super::base::{Base, binary, decimal, hexadecimal, octal};
+use super::bit_int::BitInt;
+use super::separators::remove_separators;
 use super::types::arch_types::Int;
-use super::types::{Base, ERR_PREFIX, Number, NumberSign, NumberType};
+use super::types::{ERR_PREFIX, Number, NumberSign, NumberType};
 use crate::Res;
 use crate::errors::api::{ErrorLocation, LocationPointer};
 use crate::lexer::types::api::{Ident, LexingData};
+use crate::target::TargetInfo;
 
 /// Finds the base of the number constant by looking at the prefix
 ///
+/// The literal mustn't contain the suffix, so that `0u` is read as a decimal
+/// `0`.
+///
 /// # Returns
 ///
 /// This function returns
@@ -23,7 +29,9 @@ use crate::lexer::types::api::{Ident, LexingData};
 fn as_base(literal: &str, nb_type: NumberType, location: ErrorLocation) -> Res<Base> {
     let mut chars = literal.chars();
     let first = chars.next().expect("len >= 1");
-    let second = chars.next().expect("len >= 2");
+    let Some(second) = chars.next() else {
+        return Res::ok(Base::Decimal);
+    };
 
     match (first, second) {
         ('0', 'x') => Res::ok(Base::Hexadecimal),
@@ -59,6 +67,28 @@ fn as_first_invalid_char(literal: &str, base: &Base) -> Option<char> {
     }
 }
 
+/// Gets the type of a bit-precise integer constant, if the literal has a `wb`
+/// suffix.
+///
+/// The suffix is `wb` or `WB`, and can be preceded or followed by a 'u' for
+/// `unsigned _BitInt(N)` constants.
+fn as_bit_int_type(literal: &str, location: ErrorLocation) -> Option<Res<NumberType>> {
+    let (rest, unsigned_after) = literal
+        .strip_suffix(['u', 'U'])
+        .map_or((literal, false), |rest| (rest, true));
+    let unsigned_before = rest
+        .strip_suffix("wb")
+        .or_else(|| rest.strip_suffix("WB"))?
+        .ends_with(['u', 'U']);
+    Some(match (unsigned_before, unsigned_after) {
+        (true, true) => location
+            .fail("found 2 'u' characters.".to_owned())
+            .into_res(),
+        (true, false) | (false, true) => Res::ok(NumberType::UBitInt),
+        (false, false) => Res::ok(NumberType::BitInt),
+    })
+}
+
 /// Gets the type of the number constant by looking at the suffix.
 ///
 /// # Returns
@@ -68,6 +98,7 @@ fn as_first_invalid_char(literal: &str, base: &Base) -> Option<char> {
 ///
 /// - a 'l' suffix means `Long`, 'll' means `Long Long`;
 /// - a 'u' suffix means 'Unsigned';
+/// - a 'wb' suffix means `_BitInt(N)`, see [`as_bit_int_type`];
 /// - the suffix is case insensitive;
 /// - you can combine the rules: 'ul' is `ULong` (`unsigned long`).
 ///
@@ -95,6 +126,16 @@ fn as_number_type(literal: &str, location: ErrorLocation) -> Res<NumberType> {
         || (is_hex && (literal.contains(['p', 'P'])))
         || (!is_hex && (literal.contains(['e', 'E'])));
 
+    if let Some(bit_int) = as_bit_int_type(literal, location) {
+        return if double_or_float {
+            location
+                .fail(format!("{ERR_PREFIX}a `_BitInt` must be an integer."))
+                .into_res()
+        } else {
+            bit_int
+        };
+    }
+
     // will be computed below
     let chars = literal.chars().rev();
     let mut l_count: u32 = 0;
@@ -176,22 +217,30 @@ pub fn literal_to_number(
 
     let len = literal.len();
     let begin_location = location.to_past(len, len);
+    let value = remove_separators(literal.value(), lex_data, location);
 
-    literal_to_number_err(literal.value(), begin_location, lex_data.last_is_minus())
+    literal_to_number_err(&value, begin_location, lex_data.last_is_minus(), lex_data.target())
         .store_errors(&mut |err| lex_data.push_err(err))
 }
 
 /// Tried to convert a literal to a number by computing the exact base and size.
 ///
-/// If the size isn't big enough, the compiler returns a warning and tried to
-/// increase the size (cf. [`NumberType::incr_size`]).
-fn literal_to_number_err(literal: &str, location: ErrorLocation, signed: bool) -> Res<Number> {
+/// If the size isn't big enough on the target, the compiler returns a warning
+/// and tried to increase the size (cf. [`NumberType::incr_size`]).
+fn literal_to_number_err(
+    literal: &str,
+    location: ErrorLocation,
+    signed: bool,
+    target: &TargetInfo,
+) -> Res<Number> {
     as_number_type(literal, location).and_then(|mut nb_type| {
-    as_base(literal, nb_type, location).and_then(|base| {
-
+    let without_suffix = literal
+        .get(..literal.len().checked_sub(nb_type.suffix_size()).expect("literal contains the suffix"))
+        .expect("suffix is ascii");
+    as_base(without_suffix, nb_type, location).and_then(|base| {
 
-    let value = literal
-        .get(base.prefix_size()..literal.len().checked_sub(nb_type.suffix_size()).expect("literal contains the suffix"))
+    let value = without_suffix
+        .get(base.prefix_size()..)
         .expect("never happens as suffix size + prefix size <= len, as 'x' and 'b' can't be used as suffix");
 
     if value.is_empty() {
@@ -215,16 +264,25 @@ fn literal_to_number_err(literal: &str, location: ErrorLocation, signed: bool) -
         }
         (true, false) => NumberSign::Unsigned,
         (false, true) => NumberSign::Signed,
+        (false, false) if matches!(base, Base::Decimal) => NumberSign::Signed,
         (false, false) => NumberSign::None,
     };
 
+    if nb_type.is_bit_int() {
+        let bit_int = BitInt::from_digits(value, base.radix(), nb_type.is_unsigned());
+        let number =
+            if nb_type.is_unsigned() { Number::UBitInt(bit_int) } else { Number::BitInt(bit_int) };
+        return Res::from((number, error.into_iter().collect::<Vec<_>>()));
+    }
+
     loop {
         let parse_res = match base {
             Base::Binary => binary::to_bin_value(value, nb_type, location),
             Base::Decimal => decimal::to_decimal_value(value, nb_type, location),
             Base::Hexadecimal => hexadecimal::to_hex_value(value, nb_type, location),
             Base::Octal => octal::to_oct_value(value, nb_type, location),
-        };
+        }
+        .check_target(target);
         if parse_res.overflowed()
             && let Some(new_type) = nb_type.incr_size(sign)
         {
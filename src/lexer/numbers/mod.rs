@@ -12,6 +12,10 @@
 //! - Parse the string with the specified base and type
 //! - If an overflow occurs, try to increase the type (only for integers).
 //!
+//! The digit separators (`1'000`) are removed first, and the bit-precise
+//! integers (`1wb`) are stored with arbitrary precision, see
+//! [`bit_int::BitInt`].
+//!
 //! The main function of this module is [`from_literal::literal_to_number`].
 
 #[expect(clippy::inline_modules, reason = "clearer api")]
@@ -20,13 +24,16 @@ pub mod api {
 
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
+    pub use super::bit_int::BitInt;
     pub use super::from_literal::literal_to_number;
     pub use super::parse::OverParseRes;
-    pub use super::types::Number;
+    pub use super::types::{Number, NumberType};
 }
 
 mod base;
+mod bit_int;
 mod from_literal;
 mod macros;
 mod parse;
+mod separators;
 mod types;
@@ -5,6 +5,8 @@ use core::fmt;
 
 use crate::Res;
 use crate::errors::api::{CompileError, ErrorLocation};
+use crate::lexer::numbers::types::Number;
+use crate::target::TargetInfo;
 
 /// Number parse result with overflow
 ///
@@ -25,6 +27,9 @@ pub enum OverParseRes<T> {
     /// Number parsing succeeded; but with a warning
     /// Number parsing succeeded; but with an overflow
     ValueOverflow(T),
+    /// Number parsing succeeded; but the value was too small and was rounded
+    /// to zero
+    ValueUnderflow(T),
 }
 
 impl<T> OverParseRes<T> {
@@ -39,6 +44,12 @@ impl<T> OverParseRes<T> {
         Self::ValueOverflow(value)
     }
 
+    /// Creates a [`OverParseRes`] from a value rounded to zero and an
+    /// underflow parsing error.
+    pub const fn from_value_underflow(value: T) -> Self {
+        Self::ValueUnderflow(value)
+    }
+
     /// Clamps to value if there is an overflow.
     pub fn ignore_overflow(self, value: &str, location: ErrorLocation) -> Res<T> {
         match self {
@@ -46,6 +57,12 @@ impl<T> OverParseRes<T> {
                 val,
                 vec![location.warn(format!("Overflow: {value} is too big in traditional number"))],
             )),
+            Self::ValueUnderflow(val) => Res::from((
+                val,
+                vec![
+                    location.warn(format!("Underflow: {value} is too small in traditional number")),
+                ],
+            )),
             Self::Overflow => location
                 .fail(format!("Overflow: {value} is too big in traditional number"))
                 .into_res(),
@@ -60,6 +77,21 @@ impl<T> OverParseRes<T> {
     }
 }
 
+impl OverParseRes<Number> {
+    /// Marks the value as overflowed if it doesn't fit in its type on the
+    /// target, for types whose size depends on the target, like `long`.
+    pub fn check_target(self, target: &TargetInfo) -> Self {
+        match self {
+            Self::Value(nb) if !nb.fits(target) => Self::ValueOverflow(nb),
+            other @ (Self::Err(_)
+            | Self::Overflow
+            | Self::Value(_)
+            | Self::ValueOverflow(_)
+            | Self::ValueUnderflow(_)) => other,
+        }
+    }
+}
+
 impl<T> From<CompileError> for OverParseRes<T> {
     fn from(value: CompileError) -> Self {
         Self::Err(value)
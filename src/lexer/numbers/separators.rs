@@ -0,0 +1,50 @@
+//! Module to remove the digit separators of the number constants.
+//!
+//! Since C23, the digits of a number constant can be separated with single
+//! quotes, like in `1'000'000` or `0xFF'FF`.
+
+use super::types::ERR_PREFIX;
+use crate::errors::api::LocationPointer;
+use crate::lexer::types::api::LexingData;
+
+/// Removes the digit separators of a number constant.
+///
+/// A separator must be placed between two digits of the constant: it can't
+/// start or end the constant, or be next to the prefix, the suffix, the full
+/// stop, the exponent character or another separator. An error is pushed at
+/// the location of every misplaced separator.
+///
+/// `location` points to the character just after the constant.
+pub fn remove_separators(
+    literal: &str,
+    lex_data: &mut LexingData,
+    location: &LocationPointer,
+) -> String {
+    let is_hex = literal.starts_with("0x") || literal.starts_with("0X");
+    let is_digit = |ch: Option<&char>| {
+        ch.is_some_and(|digit| {
+            if is_hex {
+                digit.is_ascii_hexdigit()
+            } else {
+                digit.is_ascii_digit()
+            }
+        })
+    };
+    let len = literal.chars().count();
+    let mut value = String::with_capacity(literal.len());
+    let mut chars = literal.chars().enumerate().peekable();
+    let mut previous = None;
+    while let Some((idx, ch)) = chars.next() {
+        if ch == '\'' {
+            if !is_digit(previous.as_ref()) || !is_digit(chars.peek().map(|(_, next)| next)) {
+                lex_data.push_err(location.to_past(1, len.saturating_sub(idx)).fail(format!(
+                    "{ERR_PREFIX}a digit separator must be placed between two digits."
+                )));
+            }
+        } else {
+            value.push(ch);
+        }
+        previous = Some(ch);
+    }
+    value
+}
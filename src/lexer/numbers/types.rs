@@ -4,35 +4,39 @@
 
 #[expect(clippy::inline_modules, reason = "cleaner scoping")]
 pub mod arch_types {
-    //! Types sizes defined for the different architectures.
+    //! Types used to store the values of the number constants.
+    //!
+    //! They are big enough for every supported target: the actual range of
+    //! each type depends on the [`TargetInfo`](crate::TargetInfo), and is
+    //! checked when lexing the constant.
     #![allow(clippy::missing_docs_in_private_items, reason = "unnecessary")]
 
+    use super::super::bit_int;
+
     pub type Int = i32;
-    #[cfg(target_pointer_width = "32")]
-    pub type Long = Int;
-    #[cfg(target_pointer_width = "64")]
     pub type Long = LongLong;
     pub type LongLong = i64;
     pub type Float = f32;
     pub type Double = f64;
     pub type LongDouble = f128;
     pub type UInt = u32;
-    #[cfg(target_pointer_width = "32")]
-    pub type ULong = UInt;
-    #[cfg(target_pointer_width = "64")]
     pub type ULong = ULongLong;
     pub type ULongLong = u64;
+    pub type BitInt = bit_int::BitInt;
+    pub type UBitInt = BitInt;
 
     pub type FloatIntPart = u32;
     pub type DoubleIntPart = u64;
-    pub type LongDoubleIntPart = u128;
 }
 
 use core::hash::{Hash, Hasher};
 use core::mem::discriminant;
 
-use arch_types::{Double, Float, Int, Long, LongDouble, LongLong, UInt, ULong, ULongLong};
+use arch_types::{
+    BitInt, Double, Float, Int, Long, LongDouble, LongLong, UBitInt, UInt, ULong, ULongLong
+};
 
+use crate::target::{Scalar, TargetInfo};
 use crate::utils::display;
 
 /// Defines the [`Number`] and [`NumberType`] enums
@@ -40,7 +44,7 @@ macro_rules! define_nb_types {
     ($($t:ident)*) => {
         /// Token value for a number constant
         #[non_exhaustive]
-        #[derive(Debug, PartialEq)]
+        #[derive(Debug, PartialEq, Clone)]
         pub enum Number {
             $(
                 /// $t C type
@@ -48,61 +52,26 @@ macro_rules! define_nb_types {
             )*
         }
 
-        #[derive(Debug, Copy, Clone)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq)]
         pub enum NumberType {
             $($t,)*
         }
 
+        impl Number {
+            /// Returns the type of the number constant.
+            pub(crate) const fn get_type(&self) -> NumberType {
+                match self {
+                    $(Self::$t(_) => NumberType::$t,)*
+                }
+            }
+        }
+
     };
 }
 
 /// String prefix used at all the beginnings of error messages.
 pub const ERR_PREFIX: &str = "Invalid number constant: ";
 
-/// Base of a number representation.
-#[derive(Debug)]
-pub enum Base {
-    /// Binary representation: `[0-1]`.
-    Binary,
-    /// Decimal representation: `[0-10]`.
-    Decimal,
-    /// Hexadecimal representation: `[0-16]`.
-    Hexadecimal,
-    /// Octal representation: `[0-8]`.
-    Octal,
-}
-
-impl Base {
-    /// Returns the prefix size for this specific base
-    ///
-    /// | Base        | Prefix | Size |
-    /// | :---------: | :----: | :--: |
-    /// | Binary      | "0b"   | 2    |
-    /// | Hexadecimal | "0x"   | 2    |
-    /// | Decimal     | ∅     | 0    |
-    /// | Octal       | "0"    | 1    |
-    pub const fn prefix_size(&self) -> usize {
-        match self {
-            Self::Binary | Self::Hexadecimal => 2,
-            Self::Decimal => 0,
-            Self::Octal => 1,
-        }
-    }
-}
-
-display!(
-    Base,
-    self,
-    f,
-    match self {
-        Self::Binary => "binary",
-        Self::Decimal => "decimal",
-        Self::Hexadecimal => "hexadecimal",
-        Self::Octal => "octal",
-    }
-    .fmt(f)
-);
-
 /// Sign of the number
 ///
 /// This comes from the context: the symbol before the number constant and the
@@ -111,13 +80,34 @@ display!(
 pub enum NumberSign {
     /// No information.
     None,
-    /// A `-` sign was found before the number
+    /// The type can't become unsigned: a `-` sign was found before the
+    /// number, or it is a decimal constant without `u` suffix.
     Signed,
     /// A `u` was found in the suffix
     Unsigned,
 }
 
-define_nb_types!(Int Long LongLong Float Double LongDouble UInt ULong ULongLong);
+define_nb_types!(Int Long LongLong Float Double LongDouble UInt ULong ULongLong BitInt UBitInt);
+
+impl Number {
+    /// Checks if the value is in the range of its type on the target.
+    #[must_use]
+    pub fn fits(&self, target: &TargetInfo) -> bool {
+        match self {
+            Self::Int(val) => fits_signed(i128::from(*val), target.bits(Scalar::Int)),
+            Self::Long(val) => fits_signed(i128::from(*val), target.bits(Scalar::Long)),
+            Self::LongLong(val) => fits_signed(i128::from(*val), target.bits(Scalar::LongLong)),
+            Self::UInt(val) => fits_unsigned(u128::from(*val), target.bits(Scalar::Int)),
+            Self::ULong(val) => fits_unsigned(u128::from(*val), target.bits(Scalar::Long)),
+            Self::ULongLong(val) => fits_unsigned(u128::from(*val), target.bits(Scalar::LongLong)),
+            Self::Float(_)
+            | Self::Double(_)
+            | Self::LongDouble(_)
+            | Self::BitInt(_)
+            | Self::UBitInt(_) => true,
+        }
+    }
+}
 
 impl Hash for Number {
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -133,6 +123,8 @@ impl Hash for Number {
             Self::UInt(val) => val.hash(state),
             Self::ULong(val) => val.hash(state),
             Self::ULongLong(val) => val.hash(state),
+            Self::BitInt(val) => val.hash(state),
+            Self::UBitInt(val) => val.hash(state),
         }
     }
 }
@@ -155,6 +147,8 @@ display!(
             Self::UInt(x) => x.to_string(),
             Self::ULong(x) => x.to_string(),
             Self::ULongLong(x) => x.to_string(),
+            Self::BitInt(x) => format!("{x}wb"),
+            Self::UBitInt(x) => format!("{x}uwb"),
         }
     )
 );
@@ -166,12 +160,14 @@ impl NumberType {
     ///
     /// ``M(Int) < M(UInt) < M(Long) < M(ULong) < M(LongLong) < M(ULongLong)``
     ///
-    /// However, if the number is negative, (`signed = true`), we can't convert
-    /// a signed type to an unsigned.
+    /// However, if the number is negative or decimal (`sign` is
+    /// [`NumberSign::Signed`]), we can't convert a signed type to an unsigned,
+    /// so `UInt` and `ULong` are skipped.
     ///
     /// # Note
     ///
-    /// Non-integer-types cannot be incremented.
+    /// Non-integer-types cannot be incremented, and neither can the `_BitInt`
+    /// types, as their width is already computed from the value.
     pub(crate) const fn incr_size(self, sign: NumberSign) -> Option<Self> {
         Some(match sign {
             NumberSign::None => match self {
@@ -180,7 +176,12 @@ impl NumberType {
                 Self::LongLong => Self::ULongLong,
                 Self::UInt => Self::Long,
                 Self::ULong => Self::LongLong,
-                Self::Float | Self::Double | Self::LongDouble | Self::ULongLong => return None,
+                Self::Float
+                | Self::Double
+                | Self::LongDouble
+                | Self::ULongLong
+                | Self::BitInt
+                | Self::UBitInt => return None,
             },
             NumberSign::Signed => match self {
                 Self::Int | Self::UInt => Self::Long,
@@ -189,22 +190,31 @@ impl NumberType {
                 | Self::LongLong
                 | Self::Float
                 | Self::Double
-                | Self::LongDouble => return None,
+                | Self::LongDouble
+                | Self::BitInt
+                | Self::UBitInt => return None,
             },
             NumberSign::Unsigned => match self {
                 Self::UInt => Self::ULong,
                 Self::ULong => Self::ULongLong,
-                Self::ULongLong => return None,
+                Self::ULongLong | Self::UBitInt => return None,
                 Self::Int
                 | Self::Long
                 | Self::LongLong
                 | Self::Float
                 | Self::Double
-                | Self::LongDouble => unreachable!(),
+                | Self::LongDouble
+                | Self::BitInt => unreachable!(),
             },
         })
     }
 
+    /// Checks that the type is a bit-precise integer type, i.e. `_BitInt(N)`
+    /// or `unsigned _BitInt(N)`.
+    pub(crate) const fn is_bit_int(self) -> bool {
+        matches!(self, Self::BitInt | Self::UBitInt)
+    }
+
     /// Checks that the type is an integer type
     pub(crate) const fn is_int(self) -> bool {
         !matches!(self, Self::Double | Self::Float | Self::LongDouble)
@@ -214,10 +224,10 @@ impl NumberType {
     ///
     /// # Returns
     ///
-    /// `true` iff the type is [`NumberType::UInt`], [`NumberType::ULong`] or
-    /// [`NumberType::ULongLong`].
-    pub(super) const fn is_unsigned(self) -> bool {
-        matches!(self, Self::UInt | Self::ULong | Self::ULongLong)
+    /// `true` iff the type is [`NumberType::UInt`], [`NumberType::ULong`],
+    /// [`NumberType::ULongLong`] or [`NumberType::UBitInt`].
+    pub(crate) const fn is_unsigned(self) -> bool {
+        matches!(self, Self::UInt | Self::ULong | Self::ULongLong | Self::UBitInt)
     }
 
     /// Returns the size of the suffix of the type.
@@ -238,6 +248,8 @@ impl NumberType {
             Self::UInt => 1,
             Self::ULong => 2,
             Self::ULongLong => 3,
+            Self::BitInt => 2,
+            Self::UBitInt => 3,
         }
     }
 }
@@ -259,6 +271,24 @@ display!(
             Self::UInt => "unsigned int",
             Self::ULong => "unsigned long",
             Self::ULongLong => "unsigned long long",
+            Self::BitInt => "_BitInt",
+            Self::UBitInt => "unsigned _BitInt",
         }
     )
 );
+
+/// Checks if a value can be stored in a signed integer of `bits` bits.
+fn fits_signed(value: i128, bits: u64) -> bool {
+    u32::try_from(bits.saturating_sub(1))
+        .ok()
+        .and_then(|shift| 1i128.checked_shl(shift))
+        .is_none_or(|max| (max.saturating_neg()..max).contains(&value))
+}
+
+/// Checks if a value can be stored in an unsigned integer of `bits` bits.
+fn fits_unsigned(value: u128, bits: u64) -> bool {
+    u32::try_from(bits)
+        .ok()
+        .and_then(|shift| 1u128.checked_shl(shift))
+        .is_none_or(|max| value < max)
+}
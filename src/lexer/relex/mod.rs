@@ -0,0 +1,254 @@
+//! Module to re-lex a source file after an edit, only lexing the lines
+//! affected by the edit.
+//!
+//! The lexer keeps a state from one line to the next: a block comment or a
+//! line ending with `\` continues on the next line, two successive string
+//! literals are merged, and a number constant depends on whether it follows a
+//! `-`. The re-lexing thus starts and stops at the start of lines where the
+//! lexer is proven to be in its initial state, both before and after the
+//! edit, see [`line_end_state`]. If no such line is found before the edit, the
+//! file is re-lexed from the start, and if none is found after the edit, it is
+//! re-lexed until the end.
+
+/// Types describing an edit and the tokens it changed.
+pub mod types;
+
+use types::{Position, Shift, TextEdit, TokenChange};
+
+use crate::Res;
+use crate::lexer::api::{Symbol, Token, TokenValue, lex_from_line};
+use crate::target::TargetInfo;
+use crate::utils::usize_to_u32;
+
+/// Number of lines lexed after the edit before trying to find a line to
+/// resume with the old tokens.
+const FIRST_WINDOW: u32 = 4;
+
+/// Returns the lines of `lines` from `first` to `last`, and whether the end of
+/// the file was reached.
+///
+/// The lines are numbered from 1, and keep their newline characters, as the
+/// lexer behaves differently when a `\` is followed by the end of the file.
+fn chunk(lines: &[&str], first: u32, last: u32) -> (String, bool) {
+    let skipped = usize::try_from(first.saturating_sub(1)).expect("u32 fits in usize");
+    let taken =
+        usize::try_from(last.saturating_sub(first).saturating_add(1)).expect("u32 fits in usize");
+    let selected = lines.iter().skip(skipped).take(taken);
+    let eof = skipped.saturating_add(taken) >= lines.len();
+    (selected.copied().collect(), eof)
+}
+
+/// Returns the index of the first token that starts on the line `line` or
+/// after it.
+fn first_on_line(tokens: &[Token], line: u32) -> usize {
+    tokens.partition_point(|tok| {
+        tok.as_location()
+            .start()
+            .is_some_and(|start| start.0 < line)
+    })
+}
+
+/// Checks if a token starts on the line `line`.
+fn has_token_on(tokens: &[Token], line: u32) -> bool {
+    tokens
+        .get(first_on_line(tokens, line))
+        .and_then(|tok| tok.as_location().start())
+        .is_some_and(|start| start.0 == line)
+}
+
+/// Checks if a token starts on the line `line`, and the line before it
+/// doesn't end with `\`.
+///
+/// The tokens that follow a `\` and a newline can be located on the next
+/// line even if they started on the line of the `\`, like the `*` in
+/// `int*/\` followed by `*/`.
+fn starts_with_token(lines: &[&str], tokens: &[Token], line: u32) -> bool {
+    has_token_on(tokens, line)
+        && line
+            .checked_sub(2)
+            .and_then(|idx| lines.get(usize::try_from(idx).ok()?))
+            .is_none_or(|text| !text.trim_end().ends_with('\\'))
+}
+
+/// Checks if the lexer is left in its initial state at the end of a line.
+///
+/// A line that ends with `\` continues on the next one, and a line that
+/// contains `/*` may open a block comment. Otherwise, a token that starts on
+/// the line proves that the line didn't start inside a block comment, so the
+/// lexer is reset at its end.
+///
+/// # Returns
+///
+/// [`None`] if the line has no token and no comment delimiter, and thus keeps
+/// the state of the line before it.
+fn line_end_state(text: &str, has_token: bool) -> Option<bool> {
+    let trimmed = text.trim_end();
+    if trimmed.ends_with('\\') || trimmed.contains("/*") {
+        Some(false)
+    } else if has_token {
+        Some(true)
+    } else if trimmed.contains("*/") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Checks if a token can change the tokens that follow it: a string literal
+/// is merged with the next one, and a `-` changes the type of the number
+/// constant that follows it.
+const fn is_sticky(token: &Token) -> bool {
+    matches!(token.as_value(), TokenValue::Str(..) | TokenValue::Symbol(Symbol::Minus))
+}
+
+/// Returns the line and column of the byte at `offset` in `content`.
+fn position(content: &str, offset: usize) -> Position {
+    let before = content.get(..offset).expect("edit on char boundaries");
+    let line_start = before.rfind('\n').map_or(0, |idx| idx.saturating_add(1));
+    let line = before.bytes().filter(|byte| *byte == b'\n').count();
+    let col = before
+        .get(line_start..)
+        .expect("after a newline")
+        .chars()
+        .count();
+    (usize_to_u32(line.saturating_add(1)), usize_to_u32(col.saturating_add(1)))
+}
+
+/// Applies an edit on a source file and updates its tokens, only re-lexing
+/// the lines affected by the edit.
+///
+/// `content` and `tokens` must be the content of the file before the edit,
+/// and the tokens lexed from it. The edit is applied on `content`.
+///
+/// # Returns
+///
+/// The description of the tokens that changed, to re-parse the file with
+/// [`reparse`](crate::reparse). Only the errors found in the re-lexed lines
+/// are returned.
+///
+/// # Panics
+///
+/// If the range of the edit is out of bounds or doesn't lie on char
+/// boundaries.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::{TargetInfo, TextEdit, lex, relex};
+///
+/// let mut content = "int x;\nint y;".to_owned();
+/// let mut tokens = lex(&content, 0).unwrap_or_display(&[]).unwrap();
+/// let edit = TextEdit::new(4..5, "abc".to_owned());
+/// relex(&mut content, &mut tokens, &edit, 0, &TargetInfo::default());
+/// assert!(content == "int abc;\nint y;");
+/// assert!(tokens.len() == 6);
+/// ```
+pub fn relex(
+    content: &mut String,
+    tokens: &mut Vec<Token>,
+    edit: &TextEdit,
+    file_id: u32,
+    target: &TargetInfo,
+) -> Res<TokenChange> {
+    let edit_start = position(content, edit.range.start);
+    let old_end = position(content, edit.range.end);
+    content.replace_range(edit.range.clone(), &edit.text);
+    let new_end = position(content, edit.range.start.saturating_add(edit.text.len()));
+    let shift = Shift::new(old_end, new_end);
+    let lines = content.split_inclusive('\n').collect::<Vec<_>>();
+    let first = restart_line(&lines, tokens, edit_start.0);
+    let start = first_on_line(tokens, first);
+    let mut window = FIRST_WINDOW;
+    loop {
+        let last = new_end.0.saturating_add(window);
+        let (text, eof) = chunk(&lines, first, last);
+        let mut errors = vec![];
+        let fresh = lex_from_line(&text, file_id, first.saturating_sub(1), target)
+            .store_errors(&mut |err| errors.push(err))
+            .unwrap_or_default();
+        if let Some((resume, old_resume)) = resume_line(&lines, &fresh, tokens, shift, last) {
+            errors.retain(|err| err.as_location().start().is_none_or(|pos| pos.0 < resume));
+            let kept = first_on_line(&fresh, resume);
+            let end = start.saturating_add(kept);
+            let old_idx = first_on_line(tokens, old_resume);
+            tokens.splice(start..old_idx, fresh.into_iter().take(kept));
+            if shift.moves_lines() {
+                for token in tokens.get_mut(end..).unwrap_or_default() {
+                    let location = token.as_location_mut();
+                    *location = shift.location(*location);
+                }
+            }
+            let change = TokenChange::new(start, end, Some((old_resume, 1)), shift);
+            return Res::from((change, errors));
+        }
+        if eof {
+            tokens.truncate(start);
+            tokens.extend(fresh);
+            let end = tokens.len();
+            return Res::from((TokenChange::new(start, end, None, shift), errors));
+        }
+        window = window.saturating_mul(2);
+    }
+}
+
+/// Finds the last line, not after the line `edit_line` of the start of the
+/// edit, at whose start the lexer is in its initial state, to start the
+/// re-lexing there.
+///
+/// The lines before the edit are the same before and after it, and so are
+/// the old tokens on these lines.
+fn restart_line(lines: &[&str], tokens: &[Token], edit_line: u32) -> u32 {
+    let mut candidate = edit_line;
+    let mut line = edit_line;
+    while let Some(previous) = line.checked_sub(1).filter(|previous| *previous >= 1) {
+        let text = lines
+            .get(usize::try_from(previous.saturating_sub(1)).expect("u32 fits in usize"))
+            .copied()
+            .unwrap_or_default();
+        match line_end_state(text, starts_with_token(lines, tokens, previous)) {
+            Some(true)
+                if tokens
+                    .get(..first_on_line(tokens, candidate))
+                    .and_then(<[Token]>::last)
+                    .is_none_or(|last| !is_sticky(last)) =>
+                return candidate,
+            Some(_) => candidate = previous,
+            None => (),
+        }
+        line = previous;
+    }
+    1
+}
+
+/// Finds the first line after the edit at whose start the lexer is in its
+/// initial state, both when lexing the old and the new content, to resume
+/// with the old tokens.
+///
+/// The line before it must be fully after the edit, and must have been
+/// re-lexed, i.e., must not be after the line `last`.
+///
+/// # Returns
+///
+/// The line in the new content and the same line in the original content.
+fn resume_line(
+    lines: &[&str],
+    fresh: &[Token],
+    tokens: &[Token],
+    shift: Shift,
+    last: u32,
+) -> Option<(u32, u32)> {
+    let new_end = shift.new_end().0;
+    (new_end.saturating_add(2)..=last.saturating_add(1)).find_map(|line| {
+        let previous = line.saturating_sub(1);
+        let old_line = shift.revert((line, 1)).0;
+        let text = lines.get(usize::try_from(previous.saturating_sub(1)).ok()?)?;
+        let fresh_before = fresh.get(..first_on_line(fresh, line))?;
+        let old_before = tokens.get(..first_on_line(tokens, old_line))?;
+        (line_end_state(text, true) == Some(true)
+            && starts_with_token(lines, fresh, previous)
+            && has_token_on(tokens, old_line.saturating_sub(1))
+            && fresh_before.last().is_none_or(|tok| !is_sticky(tok))
+            && old_before.last().is_none_or(|tok| !is_sticky(tok)))
+        .then_some((line, old_line))
+    })
+}
@@ -0,0 +1,175 @@
+//! Types describing an edit of a source file, and the tokens it changed.
+
+use core::ops::Range;
+
+use crate::errors::api::ErrorLocation;
+
+/// Line and column of a character in a file, starting at 1.
+pub type Position = (u32, u32);
+
+/// Edit of a source file: replaces a range of bytes by a new text.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::TextEdit;
+///
+/// let edit = TextEdit::new(4..5, "y".to_owned());
+/// assert!(edit.apply("int x;") == "int y;");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TextEdit {
+    /// Range of bytes of the original content that is replaced.
+    pub range: Range<usize>,
+    /// Text inserted instead of the range.
+    pub text: String,
+}
+
+impl TextEdit {
+    /// Returns the content after the edit.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds or doesn't lie on char boundaries.
+    #[must_use]
+    pub fn apply(&self, content: &str) -> String {
+        let mut edited = content.to_owned();
+        edited.replace_range(self.range.clone(), &self.text);
+        edited
+    }
+
+    /// Creates an edit that replaces `range` by `text`.
+    #[must_use]
+    pub const fn new(range: Range<usize>, text: String) -> Self {
+        Self { range, text }
+    }
+}
+
+/// Moves the positions that follow an edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shift {
+    /// Position of the end of the edit, in the new content.
+    new: Position,
+    /// Position of the end of the edit, in the original content.
+    old: Position,
+}
+
+impl Shift {
+    /// Returns the position in the new content of a position of the original
+    /// content that isn't before the end of the edit.
+    pub(crate) const fn apply(self, (line, col): Position) -> Position {
+        if line == self.old.0 {
+            (self.new.0, col.saturating_sub(self.old.1).saturating_add(self.new.1))
+        } else {
+            (line.saturating_sub(self.old.0).saturating_add(self.new.0), col)
+        }
+    }
+
+    /// Checks if the positions after the edit are left unchanged.
+    pub(crate) fn is_identity(self) -> bool {
+        self.old == self.new
+    }
+
+    /// Returns the location in the new content of a location of the original
+    /// content that isn't before the end of the edit.
+    pub(crate) const fn location(self, location: ErrorLocation) -> ErrorLocation {
+        match location {
+            ErrorLocation::Block(file, line, col, end_line, end_col) => {
+                let (new_line, new_col) = self.apply((line, col));
+                let (new_end_line, new_end_col) = self.apply((end_line, end_col));
+                ErrorLocation::Block(file, new_line, new_col, new_end_line, new_end_col)
+            }
+            ErrorLocation::Char(file, line, col) => {
+                let (new_line, new_col) = self.apply((line, col));
+                ErrorLocation::Char(file, new_line, new_col)
+            }
+            ErrorLocation::Token(file, line, col, len) => {
+                let (new_line, new_col) = self.apply((line, col));
+                ErrorLocation::Token(file, new_line, new_col, len)
+            }
+            ErrorLocation::TwoTokens(file, line, col, len, line2, col2, len2) => {
+                let (new_line, new_col) = self.apply((line, col));
+                let (new_line2, new_col2) = self.apply((line2, col2));
+                ErrorLocation::TwoTokens(file, new_line, new_col, len, new_line2, new_col2, len2)
+            }
+            ErrorLocation::None => ErrorLocation::None,
+        }
+    }
+
+    /// Checks if the edit changes the number of lines.
+    pub(crate) const fn moves_lines(self) -> bool {
+        self.old.0 != self.new.0
+    }
+
+    /// Creates the shift of an edit, from the position of its end before and
+    /// after the edit.
+    pub(crate) const fn new(old: Position, new: Position) -> Self {
+        Self { new, old }
+    }
+
+    /// Returns the position of the end of the edit, in the new content.
+    pub(crate) const fn new_end(self) -> Position {
+        self.new
+    }
+
+    /// Returns the line of the end of the edit, in the original content.
+    pub(crate) const fn old_line(self) -> u32 {
+        self.old.0
+    }
+
+    /// Returns the position in the original content of a position of the new
+    /// content that isn't before the end of the edit.
+    pub(crate) const fn revert(self, pos: Position) -> Position {
+        Self { new: self.old, old: self.new }.apply(pos)
+    }
+}
+
+/// Tokens changed by an edit, returned by [`relex`](super::relex)] to re-parse
+/// the file with [`reparse`](crate::reparse).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenChange {
+    /// Index of the first token that follows the re-lexed ones, in the new
+    /// tokens.
+    end: usize,
+    /// Position in the original content of the first token that follows the
+    /// re-lexed ones, if the file wasn't re-lexed until the end.
+    resume: Option<Position>,
+    /// Shift of the positions after the edit.
+    shift: Shift,
+    /// Index of the first re-lexed token.
+    start: usize,
+}
+
+impl TokenChange {
+    /// Returns the index of the first token that follows the re-lexed ones.
+    pub(crate) const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Creates the description of the tokens changed by an edit.
+    pub(crate) const fn new(
+        start: usize,
+        end: usize,
+        resume: Option<Position>,
+        shift: Shift,
+    ) -> Self {
+        Self { end, resume, shift, start }
+    }
+
+    /// Returns the position, in the original content, of the first token that
+    /// follows the re-lexed ones.
+    pub(crate) const fn resume(&self) -> Option<Position> {
+        self.resume
+    }
+
+    /// Returns the shift of the positions after the edit.
+    pub(crate) const fn shift(&self) -> Shift {
+        self.shift
+    }
+
+    /// Returns the index of the first re-lexed token.
+    pub(crate) const fn start(&self) -> usize {
+        self.start
+    }
+}
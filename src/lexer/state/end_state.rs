@@ -21,17 +21,18 @@ pub fn end_current(
         LexingState::Comment(_) | LexingState::Unset | LexingState::StartOfLine => return,
         LexingState::Symbols(symbol_state) => end_symbols(symbol_state, lex_data, location),
         LexingState::Ident(ident) => end_ident(ident, lex_data, location),
-        LexingState::Char(None) => {
+        LexingState::Char(None, _) => {
             lex_data.push_err(
                 location.fail(
                     "Found an empty char, but chars must contain one character. Did you mean '\\''?".to_owned(),
                 ),
             );
         }
-        LexingState::Char(Some(ch)) => lex_data.push_token(Token::from_char(*ch, location)),
+        LexingState::Char(Some(ch), encoding) =>
+            lex_data.push_token(Token::from_char(*ch, *encoding, location)),
         LexingState::Str(_) => {
-            if let LexingState::Str((string, initial_location)) = mem::take(lex_state) {
-                lex_data.push_token(Token::from_str(string, initial_location, location));
+            if let LexingState::Str((string, encoding, initial_location)) = mem::take(lex_state) {
+                lex_data.push_token(Token::from_str(string, encoding, initial_location, location));
             } else {
                 unreachable!()
             }
@@ -47,9 +48,14 @@ pub fn end_current(
 /// into its value.
 fn end_ident(literal: &mut Ident, lex_data: &mut LexingData, location: &LocationPointer) {
     debug_assert!(!literal.is_empty(), "initialised with one");
-    let possible_number = literal_to_number(lex_data, literal, location);
     let len = literal.len();
     let error_location = location.to_past(len, len);
+    if lex_data.keeps_pp_numbers() && literal.is_number() {
+        let token = Token::from_identifier(lex_data, literal, error_location);
+        lex_data.push_token(token);
+        return;
+    }
+    let possible_number = literal_to_number(lex_data, literal, location);
     match possible_number {
         None =>
             if !literal.first().unwrap_or('0').is_ascii_digit() {
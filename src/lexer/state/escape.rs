@@ -3,7 +3,7 @@
 
 use crate::errors::api::LocationPointer;
 use crate::lexer::state::api::LexingState;
-use crate::lexer::types::api::{EscapeSequence, LexingData};
+use crate::lexer::types::api::{Encoding, EscapeSequence, LexingData};
 
 /// Used to store the current escape state and the escape sequence values if
 /// needed.
@@ -23,9 +23,13 @@ impl EscapeState {
     ///
     /// Returns the character obtained from the single character escape if it
     /// not a sequence, else it initialised the sequence and returns `None`.
+    ///
+    /// The sequence is initialised with the `encoding` of the string or char,
+    /// and `in_char` tells whether it is inside a char.
     fn handle_first_escaped_char(
         &mut self,
         ch: char,
+        (encoding, in_char): (Encoding, bool),
         lex_data: &mut LexingData,
         location: &LocationPointer,
     ) -> Option<char> {
@@ -42,9 +46,9 @@ impl EscapeState {
             '\'' => return Some('\u{0027}'), // apostrophe or single quotation mark
             '?' => return Some('\u{003F}'),  // question mark (used to avoid trigraphs)
             '\\' => return Some('\u{005C}'), // backslash
-            'u' | 'U' => *self = EscapeSequence::new_unicode(ch == 'u').into(),
-            'x' => *self = EscapeSequence::new_hex().into(),
-            '0'..='9' => *self = EscapeSequence::new_octal(ch).into(),
+            'u' | 'U' => *self = EscapeSequence::new_unicode(ch == 'u', encoding, in_char).into(),
+            'x' => *self = EscapeSequence::new_hex(encoding, in_char).into(),
+            '0'..='9' => *self = EscapeSequence::new_octal(ch, encoding, in_char).into(),
             _ => {
                 lex_data.push_err(location.to_past(2, 1).warn(format!(
                 "Escape ignored. Escaping character '{ch}' has no effect. Please remove the '\\'.",
@@ -64,6 +68,7 @@ impl EscapeState {
     fn push_one_char_in_escape(
         &mut self,
         ch: char,
+        context: (Encoding, bool),
         lex_data: &mut LexingData,
         location: &LocationPointer,
     ) -> Option<(char, Option<char>)> {
@@ -83,7 +88,7 @@ impl EscapeState {
                     (escaped, additional)
                 }),
             Self::Single => self
-                .handle_first_escaped_char(ch, lex_data, location)
+                .handle_first_escaped_char(ch, context, lex_data, location)
                 .map(|escaped| (escaped, None)),
         }
     }
@@ -128,14 +133,20 @@ pub fn handle_escape(
     location: &LocationPointer,
 ) -> Option<char> {
     use LexingState as LS;
+    let context = match lex_state {
+        LS::Str((_, encoding, _)) => (*encoding, false),
+        LS::Char(_, encoding) => (*encoding, true),
+        LS::Comment(_) | LS::Ident(_) | LS::StartOfLine | LS::Symbols(_) | LS::Unset =>
+            unreachable!(),
+    };
     let (escaped, additional) = escape
         .as_mut()
         .expect("see match above")
-        .push_one_char_in_escape(ch, lex_data, location)?;
+        .push_one_char_in_escape(ch, context, lex_data, location)?;
     match lex_state {
         LS::Str(str) => str.0.push(escaped),
-        LS::Char(inner @ None) => *inner = Some(escaped),
-        LS::Char(_)
+        LS::Char(inner @ None, _) => *inner = Some(escaped),
+        LS::Char(..)
         | LS::Comment(_)
         | LS::Ident(_)
         | LS::StartOfLine
@@ -4,7 +4,7 @@ use core::fmt;
 
 use crate::errors::api::LocationPointer;
 use crate::lexer::state::api::SymbolState;
-use crate::lexer::types::api::Ident;
+use crate::lexer::types::api::{Encoding, Ident};
 
 /// State of the comments
 ///
@@ -30,9 +30,10 @@ pub enum CommentState {
 pub enum LexingState {
     /// Reading a char
     ///
-    /// - When `'` is read, the state becomes `Char(None)`.
-    /// - The next character is stored inside `Char(_)`.
-    Char(Option<char>),
+    /// - When `'` is read, the state becomes `Char(None, _)`, with the encoding
+    ///   of the prefix if any.
+    /// - The next character is stored inside `Char(_, _)`.
+    Char(Option<char>, Encoding),
     /// Reading a block comment.
     Comment(CommentState),
     /// Reading an identifier.
@@ -41,7 +42,10 @@ pub enum LexingState {
     #[default]
     StartOfLine,
     /// Reading a string literal, between double quotes.
-    Str((String, LocationPointer)),
+    ///
+    /// The location is the location of the start of the string, prefix
+    /// included.
+    Str((String, Encoding, LocationPointer)),
     /// Reading symbols.
     Symbols(SymbolState),
     /// Default variant for when all the buffers are cleared.
@@ -80,15 +84,31 @@ impl LexingState {
             false
         }
     }
+
+    /// Takes the encoding prefix of a string or char constant.
+    ///
+    /// If the current identifier is an encoding prefix, like `L` or `u8`, it
+    /// is removed and its [`Encoding`] is returned. Otherwise, the state is
+    /// left untouched and [`Encoding::Plain`] is returned.
+    pub fn take_prefix(&mut self) -> Encoding {
+        if let Self::Ident(ident) = self
+            && let Some(encoding) = Encoding::from_prefix(ident.value())
+        {
+            *self = Self::Unset;
+            encoding
+        } else {
+            Encoding::Plain
+        }
+    }
 }
 
 #[coverage(off)]
 impl fmt::Debug for LexingState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::Char(arg0) => f.debug_tuple("Char").field(arg0).finish(),
+            Self::Char(arg0, arg1) => f.debug_tuple("Char").field(arg0).field(arg1).finish(),
             Self::StartOfLine => write!(f, "StartOfLine"),
-            Self::Str((str, _)) => str.fmt(f),
+            Self::Str((str, _, _)) => str.fmt(f),
             Self::Symbols(arg0) => write!(f, "{arg0:?}"),
             Self::Comment(arg0) => write!(f, "{arg0:?}"),
             Self::Ident(arg0) => write!(f, "{arg0:?}"),
@@ -171,6 +171,7 @@ impl SymbolState {
             lex_data.push_err(new_location.fail(msg));
         }
         let result = match (self.first, self.second, self.third) {
+            ('.', '.', '.') => Some((3, Symbol::Ellipsis)),
             ('<', '<', '=') => Some((3, Symbol::ShiftLeftAssign)),
             ('>', '>', '=') => Some((3, Symbol::ShiftRightAssign)),
             ('-', '>', _) => Some((2, Symbol::Arrow)),
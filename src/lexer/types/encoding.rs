@@ -0,0 +1,123 @@
+//! Module to define the encoding of the string and char constants.
+//!
+//! See [`Encoding`].
+
+use crate::utils::display;
+
+/// Encoding of a string or char constant, given by its prefix.
+///
+/// The encoding defines the type of the code units of the constant.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Encoding {
+    /// No prefix, made of `char`, e.g. `"abc"`
+    #[default]
+    Plain,
+    /// `u` prefix, made of `char16_t`, e.g. `u"abc"`
+    Utf16,
+    /// `U` prefix, made of `char32_t`, e.g. `U"abc"`
+    Utf32,
+    /// `u8` prefix, made of `char8_t`, e.g. `u8"abc"`
+    Utf8,
+    /// `L` prefix, made of `wchar_t`, e.g. `L"abc"`
+    Wide,
+}
+
+impl Encoding {
+    /// Returns the number of code units of a string constant of this
+    /// encoding, without the null terminator.
+    pub(crate) fn code_units(self, string: &str) -> usize {
+        match self {
+            Self::Plain | Self::Utf8 => string.len(),
+            Self::Utf16 => string.encode_utf16().count(),
+            Self::Utf32 | Self::Wide => string.chars().count(),
+        }
+    }
+
+    /// Returns the encoding of two concatenated string constants.
+    ///
+    /// A string without prefix takes the encoding of the other one, but two
+    /// different prefixes can't be concatenated.
+    pub(crate) fn concat(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (Self::Plain, _) => Some(other),
+            (_, Self::Plain) => Some(self),
+            _ => (self == other).then_some(self),
+        }
+    }
+
+    /// Returns the encoding of an identifier that precedes a quote, if this
+    /// identifier is a prefix.
+    pub(crate) fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "u8" => Some(Self::Utf8),
+            "u" => Some(Self::Utf16),
+            "U" => Some(Self::Utf32),
+            "L" => Some(Self::Wide),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of hexdigits of a code unit.
+    ///
+    /// This is the number of hexdigits kept in a `\x` escape sequence.
+    pub(crate) const fn hexdigits(self) -> usize {
+        match self {
+            Self::Plain | Self::Utf8 => 2,
+            Self::Utf16 => 4,
+            Self::Utf32 | Self::Wide => 8,
+        }
+    }
+
+    /// Returns the maximum value of a code unit.
+    pub(crate) const fn max_code_unit(self) -> u32 {
+        match self {
+            Self::Plain | Self::Utf8 => 0xFF,
+            Self::Utf16 => 0xFFFF,
+            Self::Utf32 | Self::Wide => u32::MAX,
+        }
+    }
+
+    /// Checks if a character can be stored in a single code unit, as needed
+    /// by char constants, and returns an error message if it can't.
+    pub(crate) fn not_one_unit(self, ch: char) -> Option<String> {
+        let holds = match self {
+            Self::Plain | Self::Utf32 | Self::Wide => true,
+            Self::Utf8 => ch.is_ascii(),
+            Self::Utf16 => ch.len_utf16() == 1,
+        };
+        (!holds).then(|| {
+            format!(
+                "Character U+{:04X} doesn't hold in a single {} code unit.",
+                u32::from(ch),
+                self.unit_type()
+            )
+        })
+    }
+
+    /// Returns the prefix of the constants of this encoding, e.g. `u8`.
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Plain => "",
+            Self::Utf8 => "u8",
+            Self::Utf16 => "u",
+            Self::Utf32 => "U",
+            Self::Wide => "L",
+        }
+    }
+
+    /// Returns the name of the type of a code unit, e.g. `char16_t`.
+    #[must_use]
+    pub const fn unit_type(self) -> &'static str {
+        match self {
+            Self::Plain => "char",
+            Self::Utf8 => "char8_t",
+            Self::Utf16 => "char16_t",
+            Self::Utf32 => "char32_t",
+            Self::Wide => "wchar_t",
+        }
+    }
+}
+
+display!(Encoding, self, f, self.prefix().fmt(f));
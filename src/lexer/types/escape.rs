@@ -3,13 +3,15 @@
 //! See [`EscapeSequence`].
 
 use crate::errors::api::LocationPointer;
-use crate::lexer::types::api::LexingData;
+use crate::lexer::types::api::{Encoding, LexingData};
 
 /// Type to store the value of an escape sequence.
 #[derive(Debug, Copy, Clone)]
 enum EscapeSequenceValue {
     /// Escape sequences that begin with \x followed by any number hexadecimal
     /// digits.
+    ///
+    /// Only the last digits that hold in one code unit are kept.
     Hexadecimal(u32),
     /// Escape sequences that begin with a \ followed by octal digits.
     Octal(u32),
@@ -67,9 +69,14 @@ impl EscapeSequenceValue {
 pub struct EscapeSequence {
     /// Number of characters used for this escape sequence.
     char_nb: usize,
+    /// Encoding of the string or char that contains the sequence.
+    encoding: Encoding,
     /// Whether the `\o` has overflowed it's max value, and will thus be
     /// computed modulo one byte.
     has_octal_overflow: bool,
+    /// Whether the sequence is inside a char, and must thus hold in one code
+    /// unit.
+    in_char: bool,
     /// Value that the escape sequence corresponds to.
     value: EscapeSequenceValue,
 }
@@ -81,13 +88,27 @@ impl EscapeSequence {
     /// This is done at the end of parsing to guarantee the location pointer
     /// points to the end of the escape group.
     fn as_value(&self, lex_data: &mut LexingData, location: &LocationPointer) -> u32 {
-        if matches!(self.value, EscapeSequenceValue::Hexadecimal(_)) && self.char_nb >= 2 {
+        if matches!(self.value, EscapeSequenceValue::Hexadecimal(_))
+            && self.char_nb > self.encoding.hexdigits()
+        {
             self.too_many_hexdigits(lex_data, location);
         }
         if self.has_octal_overflow {
             self.octal_too_big(lex_data, location);
         }
-        self.value.as_value()
+        let value = self.value.as_value();
+        if self.in_char
+            && matches!(
+                self.value,
+                EscapeSequenceValue::ShortUnicode(_) | EscapeSequenceValue::Unicode(_)
+            )
+            && let Some(ch) = char::from_u32(value)
+            && let Some(msg) = self.encoding.not_one_unit(ch)
+        {
+            let len = self.len();
+            lex_data.push_err(location.to_past(len, len).fail(msg));
+        }
+        value
     }
 
     /// Creates a failure that corresponds to a wrong character being pushed in
@@ -115,24 +136,24 @@ impl EscapeSequence {
     }
 
     /// Increments the count of chars pushed into the sequence.
-    #[expect(clippy::arithmetic_side_effects, reason = "<= 8")]
-    fn increment(&mut self) {
-        self.char_nb = 8.min(self.char_nb + 1);
+    const fn increment(&mut self) {
+        self.char_nb = self.char_nb.saturating_add(1);
     }
 
     /// Returns the length of the sequence, `\` included.
-    #[expect(clippy::arithmetic_side_effects, reason = "<= 10")]
     pub const fn len(self) -> usize {
-        self.value.len() + self.char_nb
+        self.value.len().saturating_add(self.char_nb)
+    }
+
+    /// Creates a new escape sequence, inside a string or a char of the given
+    /// encoding.
+    const fn new(value: EscapeSequenceValue, encoding: Encoding, in_char: bool) -> Self {
+        Self { char_nb: 0, encoding, has_octal_overflow: false, in_char, value }
     }
 
     /// Creartes a new hexadecimal escape sequence.
-    pub const fn new_hex() -> Self {
-        Self {
-            char_nb: 0,
-            has_octal_overflow: false,
-            value: EscapeSequenceValue::Hexadecimal(0),
-        }
+    pub const fn new_hex(encoding: Encoding, in_char: bool) -> Self {
+        Self::new(EscapeSequenceValue::Hexadecimal(0), encoding, in_char)
     }
 
     /// Creates a new octal escape sequence from the given number.
@@ -140,23 +161,23 @@ impl EscapeSequence {
     /// # Panics
     ///
     /// When the provided char is not in range '0'..='9'
-    pub fn new_octal(first: char) -> Self {
+    pub fn new_octal(first: char, encoding: Encoding, in_char: bool) -> Self {
         debug_assert!(first.is_ascii_digit(), "invalid input: expected ascii digit, found {first}");
-        Self {
-            char_nb: 1,
-            has_octal_overflow: false,
-            value: EscapeSequenceValue::Octal(hex_val(first).expect("in 0..=9").into()),
-        }
+        let value = EscapeSequenceValue::Octal(hex_val(first).expect("in 0..=9").into());
+        Self { char_nb: 1, ..Self::new(value, encoding, in_char) }
     }
 
     /// Creates a new unicode escape sequence.
-    pub fn new_unicode(short: bool) -> Self {
-        (if short {
+    ///
+    /// Inside a char, the unicode character must hold in one code unit of the
+    /// encoding.
+    pub const fn new_unicode(short: bool, encoding: Encoding, in_char: bool) -> Self {
+        let value = if short {
             EscapeSequenceValue::ShortUnicode(0)
         } else {
             EscapeSequenceValue::Unicode(0)
-        })
-        .into()
+        };
+        Self::new(value, encoding, in_char)
     }
 
     /// Returns an error to inform user that \oXXX will be taken modulo 256
@@ -182,13 +203,13 @@ impl EscapeSequence {
         };
         match &mut self.value {
             EscapeSequenceValue::Hexadecimal(val) => {
-                *val = ((*val & 0xf) << 4u32) | bit;
+                *val = ((*val << 4u32) | bit) & self.encoding.max_code_unit();
             }
             EscapeSequenceValue::Octal(val) if bit > 7 => return Some((*val, Some(ch))),
             EscapeSequenceValue::Octal(val) => {
                 *val = (*val << 3u32) | bit;
-                self.has_octal_overflow |= *val > 0xff;
-                *val &= 0xff;
+                self.has_octal_overflow |= *val > self.encoding.max_code_unit();
+                *val &= self.encoding.max_code_unit();
             }
             EscapeSequenceValue::Unicode(val) | EscapeSequenceValue::ShortUnicode(val) =>
                 *val = (*val << 4u32) | bit,
@@ -201,13 +222,7 @@ impl EscapeSequence {
     /// Returns an error to inform user that \xXXX will be clamped to \xXX
     fn too_many_hexdigits(self, lex_data: &mut LexingData, location: &LocationPointer) {
         let len = self.len();
-        lex_data.push_err(location.to_past(len , len ).warn("too many hexdigits after \\x: all hexdigits will be taken but only the trailing 2 will be kept".to_owned()));
-    }
-}
-
-impl From<EscapeSequenceValue> for EscapeSequence {
-    fn from(value: EscapeSequenceValue) -> Self {
-        Self { char_nb: 0, value, has_octal_overflow: false }
+        lex_data.push_err(location.to_past(len , len ).warn(format!("too many hexdigits after \\x: all hexdigits will be taken but only the trailing {} will be kept", self.encoding.hexdigits())));
     }
 }
 
@@ -0,0 +1,74 @@
+//! Module to define an identifier
+//!
+//! This module contains the definition of [`Ident`], the buffer used to store
+//! the identifiers while they are being lexed.
+
+use core::mem;
+use core::str::pattern;
+
+/// Represents an identifier
+///
+/// An identifier is a token that contains a succession of alphanumeric digits
+/// (or underscores).
+///
+/// Identifiers are used as variable names, custom types, number constants etc.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Ident(String);
+
+impl Ident {
+    /// Checks if the underlying string contains a pattern
+    pub fn contains<P: pattern::Pattern>(&self, pat: P) -> bool {
+        self.0.contains(pat)
+    }
+
+    /// Returns the first character of the underlying string
+    pub fn first(&self) -> Option<char> {
+        self.0.chars().next()
+    }
+
+    /// Checks if the underlying string is empty
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Checks if the first character is a valid ascii digit (`[0-9]`).
+    pub fn is_number(&self) -> bool {
+        self.first().unwrap_or('x').is_ascii_digit()
+    }
+
+    /// Checks if last character of the string
+    pub fn last_is_exp(&self) -> bool {
+        self.is_number()
+            && match self.0.chars().last() {
+                Some('p' | 'P') => self.0.starts_with("0x"),
+                Some('e' | 'E') => !self.0.starts_with("0x"), /* if the number expression starts with 0 and contains an exponent, the number is considered decimal, not octal. */
+                Some(_) | None => false,
+            }
+    }
+
+    /// Returns the length of the underlying string
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Pushes a character to the underlying string
+    pub fn push(&mut self, ch: char) {
+        self.0.push(ch);
+    }
+
+    /// Takes the value of the underlying string
+    pub fn take_value(&mut self) -> String {
+        mem::take(&mut self.0)
+    }
+
+    /// Returns a reference to the underlying string
+    pub fn value(&self) -> &str {
+        self.0.as_ref()
+    }
+}
+
+impl From<String> for Ident {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
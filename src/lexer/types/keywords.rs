@@ -9,7 +9,7 @@ macro_rules! impl_keywords {
         /// Keywords of the language
         ///
         /// See [CppReference](https://en.cppreference.com/w/c/keyword) for the list of C keywords.
-        #[derive(Debug)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         pub enum Keyword {
             $($pascal,)*
         }
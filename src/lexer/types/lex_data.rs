@@ -4,12 +4,13 @@ use super::api::{Token, TokenValue};
 use super::symbols::Symbol;
 use crate::Res;
 use crate::errors::api::CompileError;
+use crate::target::TargetInfo;
 
 /// Lexing data
 ///
 /// Contains the data needed will lexing. It contains buffers and information
 /// needed to be stored.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct LexingData {
     /// Boolean to indicate if the lexer needs to fail this line and try the
     /// next.
@@ -18,6 +19,15 @@ pub struct LexingData {
     end_line: bool,
     /// Errors that have occurred while lexing.
     errors: Vec<CompileError>,
+    /// Indicates if the number constants are kept as written, for the
+    /// preprocessor.
+    ///
+    /// They are then returned as identifiers, and converted into numbers
+    /// after the macro expansion.
+    pp_numbers: bool,
+    /// Machine the code is compiled for, that defines the ranges of the
+    /// number types.
+    target: TargetInfo,
     /// Tokens that have been lexed
     tokens: Vec<Token>,
 }
@@ -38,6 +48,12 @@ impl LexingData {
         self.end_line
     }
 
+    /// Checks if the number constants are kept as written, for the
+    /// preprocessor.
+    pub const fn keeps_pp_numbers(&self) -> bool {
+        self.pp_numbers
+    }
+
     /// Checks if the last parsed token was a minus sign.
     ///
     /// This is useful to know whether the last accepted token was `-`. This is
@@ -49,6 +65,13 @@ impl LexingData {
         )
     }
 
+    /// Creates the lexing data for the given target.
+    ///
+    /// If `pp_numbers` is set, the number constants are kept as written.
+    pub const fn new(target: TargetInfo, pp_numbers: bool) -> Self {
+        Self { end_line: false, errors: vec![], pp_numbers, target, tokens: vec![] }
+    }
+
     /// Resets the lexing data for a new line.
     pub const fn newline(&mut self) {
         self.end_line = false;
@@ -85,6 +108,11 @@ impl LexingData {
     pub const fn set_end_line(&mut self) {
         self.end_line = true;
     }
+
+    /// Returns the target the code is lexed for.
+    pub const fn target(&self) -> &TargetInfo {
+        &self.target
+    }
 }
 
 /// Function to display tokens in a user-readable format.
@@ -9,15 +9,20 @@ pub mod api {
 
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
+    pub use super::encoding::Encoding;
     pub use super::escape::EscapeSequence;
+    pub use super::ident::Ident;
     pub use super::keywords::Keyword;
     pub use super::lex_data::{LexingData, display_tokens};
     pub use super::symbols::Symbol;
-    pub use super::tokens::{Ident, Token, TokenValue};
+    pub use super::tokens::{Token, TokenValue};
 }
 
+mod encoding;
 mod escape;
+mod ident;
 mod keywords;
 mod lex_data;
+mod spelling;
 mod symbols;
 mod tokens;
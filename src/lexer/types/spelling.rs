@@ -0,0 +1,97 @@
+//! Module to write the tokens back as they would appear in the source code.
+
+use core::fmt::Write as _;
+
+use super::symbols::Symbol;
+use super::tokens::TokenValue;
+
+impl TokenValue {
+    /// Returns the spelling of a token built from its value, like it would be
+    /// written in the source code.
+    pub(crate) fn spelling(&self) -> String {
+        match self {
+            Self::Char(ch, encoding) => format!("{encoding}'{}'", escape(&ch.to_string())),
+            Self::Ident(ident) => ident.to_owned(),
+            Self::Keyword(keyword) => keyword.to_string(),
+            Self::Number(number) => number.to_string(),
+            Self::Str(string, encoding) => format!("{encoding}\"{}\"", escape(string)),
+            Self::Symbol(symbol) => symbol_spelling(*symbol).to_owned(),
+        }
+    }
+}
+
+/// Escapes the special characters of a string, to make it a valid C string
+/// literal.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\'' => escaped.push_str("\\'"),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\0' => escaped.push_str("\\0"),
+            _ if ch.is_control() => {
+                write!(escaped, "\\x{:x}", u32::from(ch)).expect("writing to a string can't fail");
+            }
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Returns the spelling of a symbol.
+const fn symbol_spelling(symbol: Symbol) -> &'static str {
+    match symbol {
+        Symbol::AddAssign => "+=",
+        Symbol::Ampersand => "&",
+        Symbol::AndAssign => "&=",
+        Symbol::Arrow => "->",
+        Symbol::Assign => "=",
+        Symbol::BitwiseNot => "~",
+        Symbol::BitwiseOr => "|",
+        Symbol::BitwiseXor => "^",
+        Symbol::BraceClose => "}",
+        Symbol::BraceOpen => "{",
+        Symbol::BracketClose => "]",
+        Symbol::BracketOpen => "[",
+        Symbol::Colon => ":",
+        Symbol::Comma => ",",
+        Symbol::Concat => "##",
+        Symbol::Decrement => "--",
+        Symbol::Different => "!=",
+        Symbol::DivAssign => "/=",
+        Symbol::Divide => "/",
+        Symbol::Dot => ".",
+        Symbol::Ellipsis => "...",
+        Symbol::Equal => "==",
+        Symbol::Ge => ">=",
+        Symbol::Gt => ">",
+        Symbol::Hash => "#",
+        Symbol::Increment => "++",
+        Symbol::Interrogation => "?",
+        Symbol::Le => "<=",
+        Symbol::LogicalAnd => "&&",
+        Symbol::LogicalNot => "!",
+        Symbol::LogicalOr => "||",
+        Symbol::Lt => "<",
+        Symbol::Minus => "-",
+        Symbol::ModAssign => "%=",
+        Symbol::Modulo => "%",
+        Symbol::MulAssign => "*=",
+        Symbol::OrAssign => "|=",
+        Symbol::ParenthesisClose => ")",
+        Symbol::ParenthesisOpen => "(",
+        Symbol::Plus => "+",
+        Symbol::SemiColon => ";",
+        Symbol::ShiftLeft => "<<",
+        Symbol::ShiftLeftAssign => "<<=",
+        Symbol::ShiftRight => ">>",
+        Symbol::ShiftRightAssign => ">>=",
+        Symbol::Star => "*",
+        Symbol::SubAssign => "-=",
+        Symbol::XorAssign => "^=",
+    }
+}
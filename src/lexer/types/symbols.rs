@@ -4,7 +4,7 @@
 ///
 /// See [`SymbolState`](crate::lexer::state::api::SymbolState) for more
 /// information.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Symbol {
     /// +=
     AddAssign,
@@ -46,6 +46,8 @@ pub enum Symbol {
     Divide,
     /// .
     Dot,
+    /// ...
+    Ellipsis,
     /// ==
     Equal,
     /// >=
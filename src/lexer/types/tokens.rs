@@ -4,9 +4,8 @@
 //! store and pass on the values of the token that were lexed. They are stored
 //! in [`LexingData`] during lexing and then returned.
 
-use core::mem;
-use core::str::pattern;
-
+use super::encoding::Encoding;
+use super::ident::Ident;
 use super::keywords::{Keyword, TryKeyword};
 use super::symbols::Symbol;
 use crate::errors::api::{ErrorLocation, LocationPointer};
@@ -14,75 +13,8 @@ use crate::lexer::numbers::api::Number;
 use crate::lexer::types::api::LexingData;
 use crate::utils::display;
 
-/// Represents an identifier
-///
-/// An identifier is a token that contains a succession of alphanumeric digits
-/// (or underscores).
-///
-/// Identifiers are used as variable names, custom types, number constants etc.
-#[derive(Debug, Default, PartialEq, Eq)]
-pub struct Ident(String);
-
-impl Ident {
-    /// Checks if the underlying string contains a pattern
-    pub fn contains<P: pattern::Pattern>(&self, pat: P) -> bool {
-        self.0.contains(pat)
-    }
-
-    /// Returns the first character of the underlying string
-    pub fn first(&self) -> Option<char> {
-        self.0.chars().next()
-    }
-
-    /// Checks if the underlying string is empty
-    pub const fn is_empty(&self) -> bool {
-        self.0.is_empty()
-    }
-
-    /// Checks if the first character is a valid ascii digit (`[0-9]`).
-    pub fn is_number(&self) -> bool {
-        self.first().unwrap_or('x').is_ascii_digit()
-    }
-
-    /// Checks if last character of the string
-    pub fn last_is_exp(&self) -> bool {
-        self.is_number()
-            && match self.0.chars().last() {
-                Some('p' | 'P') => self.0.starts_with("0x"),
-                Some('e' | 'E') => !self.0.starts_with("0x"), /* if the number expression starts with 0 and contains an exponent, the number is considered decimal, not octal. */
-                Some(_) | None => false,
-            }
-    }
-
-    /// Returns the length of the underlying string
-    pub const fn len(&self) -> usize {
-        self.0.len()
-    }
-
-    /// Pushes a character to the underlying string
-    pub fn push(&mut self, ch: char) {
-        self.0.push(ch);
-    }
-
-    /// Takes the value of the underlying string
-    pub fn take_value(&mut self) -> String {
-        mem::take(&mut self.0)
-    }
-
-    /// Returns a reference to the underlying string
-    pub fn value(&self) -> &str {
-        self.0.as_ref()
-    }
-}
-
-impl From<String> for Ident {
-    fn from(value: String) -> Self {
-        Self(value)
-    }
-}
-
 /// Struct that stores a lexed token
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Token {
     /// Location of the token
     ///
@@ -93,6 +25,16 @@ pub struct Token {
 }
 
 impl Token {
+    /// Returns the location of the [`Token`]
+    pub(crate) const fn as_location(&self) -> ErrorLocation {
+        self.location
+    }
+
+    /// Returns a mutable reference to the location of the [`Token`]
+    pub(crate) const fn as_location_mut(&mut self) -> &mut ErrorLocation {
+        &mut self.location
+    }
+
     /// Returns a reference to the value of the [`Token`]
     #[must_use]
     pub const fn as_value(&self) -> &TokenValue {
@@ -110,8 +52,12 @@ impl Token {
     }
 
     /// Converts a `char` into a token of value [`TokenValue::Char`]
-    pub(crate) fn from_char(ch: char, location: &LocationPointer) -> Self {
-        Self { value: TokenValue::Char(ch), location: location.to_past(3, 2) }
+    pub(crate) fn from_char(ch: char, encoding: Encoding, location: &LocationPointer) -> Self {
+        let prefix = encoding.prefix().len();
+        Self {
+            value: TokenValue::Char(ch, encoding),
+            location: location.to_past(prefix.saturating_add(3), prefix.saturating_add(2)),
+        }
     }
 
     /// Converts an identifier into a token of value
@@ -156,21 +102,16 @@ impl Token {
     /// [`TokenValue::Str`]
     pub(crate) const fn from_str(
         string: String,
+        encoding: Encoding,
         start_location: LocationPointer,
         end_location: &LocationPointer,
     ) -> Self {
         Self {
             location: start_location.into_block(end_location),
-            value: TokenValue::Str(string),
+            value: TokenValue::Str(string, encoding),
         }
     }
 
-    /// Converts a [`Symbol`] into a token of value
-    /// [`TokenValue::Symbol`].
-    pub(crate) fn from_symbol(symbol: Symbol, len: usize, location: &LocationPointer) -> Self {
-        Self { value: TokenValue::Symbol(symbol), location: location.to_past(len, len) }
-    }
-
     /// Converts a [`Symbol`] into a token of value
     /// [`TokenValue::Symbol`] with an offset.
     ///
@@ -185,6 +126,11 @@ impl Token {
         Self { value: TokenValue::Symbol(symbol), location: location.to_past(len, offset) }
     }
 
+    /// Builds a token from its value and its location.
+    pub(crate) const fn from_value_location(value: TokenValue, location: ErrorLocation) -> Self {
+        Self { location, value }
+    }
+
     /// Returns the value and the location of the [`Token`]
     pub(crate) fn into_value_location(self) -> (TokenValue, ErrorLocation) {
         (self.value, self.location)
@@ -192,14 +138,19 @@ impl Token {
 
     /// Puhes the next token in the previous one, if both are strings.
     ///
+    /// The strings are only merged if their encodings are compatible, see
+    /// [`Encoding::concat`].
+    ///
     /// # Returns
     ///
     /// The token if not pushed.
     pub(crate) fn push_token(&mut self, other: Self) -> Option<Self> {
-        if let TokenValue::Str(self_str) = self.as_value_mut()
-            && let TokenValue::Str(other_str) = &other.value
+        if let TokenValue::Str(self_str, self_encoding) = self.as_value_mut()
+            && let TokenValue::Str(other_str, other_encoding) = &other.value
+            && let Some(encoding) = self_encoding.concat(*other_encoding)
         {
             self_str.push_str(other_str);
+            *self_encoding = encoding;
             self.extend_location(other.location);
             None
         } else {
@@ -211,7 +162,7 @@ impl Token {
 display!(Token, self, f, self.value.fmt(f));
 
 /// Enum that contains the value of the Token.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
 pub enum TokenValue {
     /// Chars
@@ -220,11 +171,13 @@ pub enum TokenValue {
     ///
     /// - Delimited with single quotes `'`
     /// - Contain a single character.
+    /// - Can be prefixed by an [`Encoding`], and the character must then hold
+    ///   in one code unit.
     ///
     /// # Examples
     ///
-    /// `'o'` and `'\u2205'`
-    Char(char),
+    /// `'o'`, `'\u2205'` and `u8'a'`
+    Char(char, Encoding),
     /// Identifiers
     ///
     /// # Rules
@@ -261,12 +214,13 @@ pub enum TokenValue {
     /// # Rules
     ///
     /// - Delimited by double quotes
+    /// - Can be prefixed by an [`Encoding`]
     /// - Successive quotes are merged
     ///
     /// # Examples
     ///
-    /// `""`, `"Hello world"` and `"Hello""World"`
-    Str(String),
+    /// `""`, `"Hello world"`, `L"Hello"` and `"Hello""World"`
+    Str(String, Encoding),
     /// Symbols
     ///
     /// # Rules
@@ -285,11 +239,11 @@ display!(
     f,
     #[expect(clippy::use_debug, reason = "print enum variant name")]
     match self {
-        Self::Char(arg0) => write!(f, "'{arg0}'"),
+        Self::Char(arg0, arg1) => write!(f, "{arg1}'{arg0}'"),
         Self::Keyword(arg0) => write!(f, "Keyword({arg0})"),
         Self::Number(arg0) => write!(f, "{arg0}"),
         Self::Symbol(arg0) => write!(f, "{arg0:?}"),
         Self::Ident(arg0) => write!(f, "Ident({arg0})"),
-        Self::Str(arg0) => write!(f, "\"{arg0}\""),
+        Self::Str(arg0, arg1) => write!(f, "{arg1}\"{arg0}\""),
     }
 );
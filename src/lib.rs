@@ -14,6 +14,8 @@ mod errors;
 mod lexer;
 mod lineariser;
 mod parser;
+mod preprocessor;
+mod target;
 mod utils;
 
 #[expect(
@@ -21,25 +23,53 @@ mod utils;
     clippy::pub_use,
     reason = "re-export for better API"
 )]
-pub use crate::errors::api::Res;
+pub use crate::errors::api::{CompileError, Diagnostic, ErrorLevel, ErrorLocation, Res, Span};
 #[expect(
     clippy::useless_attribute,
     clippy::pub_use,
     reason = "re-export for better API"
 )]
-pub use crate::lexer::api::{Number, Token, TokenValue, display_tokens, lex};
+pub use crate::lexer::api::{
+    BitInt, Encoding, Number, Position, TextEdit, Token, TokenChange, TokenValue, display_tokens, lex, lex_with, relex
+};
 #[expect(
     clippy::useless_attribute,
     clippy::pub_use,
     reason = "re-export for better API"
 )]
-pub use crate::lineariser::linearise;
+pub use crate::lineariser::const_eval::{const_eval, const_eval_with};
 #[expect(
     clippy::useless_attribute,
     clippy::pub_use,
     reason = "re-export for better API"
 )]
-pub use crate::parser::api::{Ast, BracedBlock, parse};
+pub use crate::lineariser::index::{Definition, SymbolIndex};
+#[expect(
+    clippy::useless_attribute,
+    clippy::pub_use,
+    reason = "re-export for better API"
+)]
+pub use crate::lineariser::{linearise, linearise_with};
+#[expect(
+    clippy::useless_attribute,
+    clippy::pub_use,
+    reason = "re-export for better API"
+)]
+pub use crate::parser::api::{
+    ArrayDeclarator, ArraySize, Ast, AstColonAstCtrl, Attribute, AttributeVariable, Binary, BracedBlock, Cast, ColonAstCtrl, ColonIdentCtrl, CompoundLiteral, ConditionCtrl, ControlFlowNode, Declaration, DoWhileCtrl, FunctionCall, Generic, GenericAssociation, IdentBlockCtrl, ListInitialiser, Literal, ParameterList, ParensBlock, ParensBlockCtrl, ParensDeclarator, ReturnCtrl, SemiColonCtrl, StandardAttribute, Ternary, TypedefCtrl, Unary, Variable, VariableName, Visit, VisitMut, Walk, parse, reparse
+};
+#[expect(
+    clippy::useless_attribute,
+    clippy::pub_use,
+    reason = "re-export for better API"
+)]
+pub use crate::preprocessor::api::{PreprocessorOptions, SourceFiles, preprocess};
+#[expect(
+    clippy::useless_attribute,
+    clippy::pub_use,
+    reason = "re-export for better API"
+)]
+pub use crate::target::{Endianness, Layout, TargetInfo};
 
 /// String to represent an empty node when displaying the AST in a
 /// human-readable way.
@@ -4,14 +4,51 @@
 use crate::EMPTY;
 use crate::lineariser::state::LState;
 use crate::lineariser::types::Type;
+use crate::lineariser::walk::ast::push_statements;
 use crate::parser::api::BracedBlock;
 use crate::utils::display;
 
 /// List of instructions that can exist in a basic block.
 #[derive(Debug)]
 pub enum Instruction {
-    /// `return <expr>`
-    Return(usize),
+    /// `br BB<target>`
+    Branch(usize),
+    /// `br x<cond>, BB<success>, BB<failure>`
+    CondBranch(usize, usize, usize),
+    /// `x<id> = phi [x<value>, BB<from>]...`
+    Phi(usize, Type, Vec<(usize, usize)>),
+    /// `return <expr>`, or `return` in a function returning `void`
+    Return(Option<usize>),
+    /// `switch x<value>, BB<default> [x<case>: BB<target>...]`
+    Switch(usize, Vec<(usize, usize)>, usize),
+}
+
+impl Instruction {
+    /// Checks if the instruction ends a basic block.
+    const fn is_terminator(&self) -> bool {
+        !matches!(self, Self::Phi(..))
+    }
+
+    /// Replaces every use of the value `from` by the value `to`.
+    fn replace(&mut self, from: usize, to: usize) {
+        let swap = |id: &mut usize| {
+            if *id == from {
+                *id = to;
+            }
+        };
+        match self {
+            Self::Branch(_) => (),
+            Self::CondBranch(cond, ..) => swap(cond),
+            Self::Phi(_, _, incoming) => incoming.iter_mut().for_each(|(value, _)| swap(value)),
+            Self::Return(value) => value.iter_mut().for_each(swap),
+            Self::Switch(value, cases, _) => {
+                swap(value);
+                for (case, _) in cases.iter_mut() {
+                    swap(case);
+                }
+            }
+        }
+    }
 }
 
 /// Id wrapper to avoid stopping when calling undeclared variables.
@@ -40,7 +77,28 @@ display!(
     self,
     f,
     match self {
-        Self::Return(lit) => write!(f, "return x{lit}"),
+        Self::Branch(target) => write!(f, "br BB{target}"),
+        Self::CondBranch(cond, succ, fail) => write!(f, "br x{cond}, BB{succ}, BB{fail}"),
+        Self::Phi(id, ty, incoming) => write!(
+            f,
+            "{ty} x{id} = phi {}",
+            incoming
+                .iter()
+                .map(|(value, from)| format!("[x{value}, BB{from}]"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Self::Return(Some(lit)) => write!(f, "return x{lit}"),
+        Self::Return(None) => "return".fmt(f),
+        Self::Switch(value, cases, default) => write!(
+            f,
+            "switch x{value}, BB{default} [{}]",
+            cases
+                .iter()
+                .map(|(case, target)| format!("x{case}: BB{target}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
     }
 );
 
@@ -50,22 +108,101 @@ pub struct BasicBlocks(Vec<Vec<Instruction>>);
 
 impl BasicBlocks {
     /// Adds a line to the last basic block
+    ///
+    /// If the last basic block was already terminated, the line is
+    /// unreachable and is put in a new basic block.
     pub fn add(&mut self, inst: Instruction) {
-        if let Some(last) = self.0.last_mut() {
-            last.push(inst);
-        } else {
-            self.0.push(vec![inst]);
-        }
+        let current = self.current();
+        self.add_to(current, inst);
+    }
+
+    /// Adds a line at the end of the given basic block.
+    pub fn add_to(&mut self, bb: usize, inst: Instruction) {
+        self.0
+            .get_mut(bb)
+            .expect("basic block was created")
+            .push(inst);
+    }
+
+    /// Returns the index of the basic block in which the instructions are
+    /// currently written, creating one if needed.
+    pub fn current(&mut self) -> usize {
+        self.open().unwrap_or_else(|| self.new_block())
     }
 
     /// Creates a new basic block from the given braced block.
     pub fn from_braced_block(body: BracedBlock, state: &mut LState) -> Self {
         let mut this = Self(vec![]);
-        for ast in body.elts {
-            ast.push_in(&mut this, state);
-        }
+        push_statements(body.elts, &mut this, state);
         this
     }
+
+    /// Creates a new empty basic block and returns its index.
+    pub fn new_block(&mut self) -> usize {
+        self.0.push(vec![]);
+        self.0.len().saturating_sub(1)
+    }
+
+    /// Returns the index of the last basic block, if it is not yet terminated.
+    pub fn open(&self) -> Option<usize> {
+        self.0
+            .last()
+            .filter(|bb| !bb.last().is_some_and(Instruction::is_terminator))
+            .map(|_| self.0.len().saturating_sub(1))
+    }
+
+    /// Adds an incoming value to the phi node `phi` of the basic block `bb`.
+    pub fn push_incoming(&mut self, bb: usize, phi: usize, value: usize, from: usize) {
+        if let Some(Instruction::Phi(.., incoming)) = self
+            .0
+            .get_mut(bb)
+            .expect("basic block was created")
+            .iter_mut()
+            .find(|inst| matches!(inst, Instruction::Phi(id, ..) if *id == phi))
+        {
+            incoming.push((value, from));
+        }
+    }
+
+    /// Removes the phi nodes that only merge one value.
+    ///
+    /// # Returns
+    ///
+    /// The replaced phi nodes, with the value that replaces them.
+    pub fn remove_trivial_phis(&mut self) -> Vec<(usize, usize)> {
+        let mut replaced = vec![];
+        while let Some((phi, value)) = self.0.iter().flatten().find_map(|inst| {
+            if let Instruction::Phi(id, _, incoming) = inst {
+                let mut values = incoming
+                    .iter()
+                    .map(|(value, _)| *value)
+                    .filter(|value| value != id);
+                let first = values.next()?;
+                values.all(|value| value == first).then_some((*id, first))
+            } else {
+                None
+            }
+        }) {
+            for bb in &mut self.0 {
+                bb.retain(|inst| !matches!(inst, Instruction::Phi(id, ..) if *id == phi));
+                for inst in bb.iter_mut() {
+                    inst.replace(phi, value);
+                }
+            }
+            replaced.push((phi, value));
+        }
+        replaced
+    }
+
+    /// Sets the terminator of the basic block `bb`, replacing the current one
+    /// if it already exists.
+    pub fn terminate(&mut self, bb: usize, inst: Instruction) {
+        let block = self.0.get_mut(bb).expect("basic block was created");
+        if block.last().is_some_and(Instruction::is_terminator) {
+            block.pop();
+        }
+        block.push(inst);
+    }
 }
 
 display!(
@@ -0,0 +1,255 @@
+//! Applies the arithmetic operators on the values of the constant expressions.
+
+use core::cmp::Ordering;
+
+use crate::errors::api::{CompileError, ErrorLocation};
+use crate::lexer::api::NumberType;
+use crate::lineariser::const_eval::value::{Value, common_type, int_bits};
+use crate::parser::api::{BinaryOperator, UnaryOperator};
+use crate::target::TargetInfo;
+
+impl Value {
+    /// Applies a binary operator, after converting both operands to their
+    /// common type.
+    ///
+    /// The logical operators `&&` and `||` are evaluated by the caller, as
+    /// their second operand isn't always evaluated.
+    pub fn binary(
+        self,
+        op: BinaryOperator,
+        other: Self,
+        loc: ErrorLocation,
+        target: &TargetInfo,
+    ) -> Result<Self, CompileError> {
+        if matches!(op, BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight) {
+            return self.shift(op, other, loc, target);
+        }
+        let ty = common_type(self.ty(), other.ty(), target);
+        match (self.convert(ty, loc, target)?, other.convert(ty, loc, target)?) {
+            (Self::Float(left, _), Self::Float(right, _)) => float_binary(op, left, right, ty, loc),
+            (Self::Int(left, _), Self::Int(right, _)) => {
+                let value = match op {
+                    BinaryOperator::Add => left.wrapping_add(right),
+                    BinaryOperator::BitwiseAnd => left & right,
+                    BinaryOperator::BitwiseOr => left | right,
+                    BinaryOperator::BitwiseXor => left ^ right,
+                    BinaryOperator::Divide | BinaryOperator::Modulo if right == 0 =>
+                        return Err(loc.fail("Division by zero in constant expression".to_owned())),
+                    BinaryOperator::Divide => left.checked_div(right).expect("non-zero divisor"),
+                    BinaryOperator::Modulo => left.checked_rem(right).expect("non-zero divisor"),
+                    BinaryOperator::Multiply => left.wrapping_mul(right),
+                    BinaryOperator::Subtract => left.wrapping_sub(right),
+                    BinaryOperator::Different
+                    | BinaryOperator::Equal
+                    | BinaryOperator::Ge
+                    | BinaryOperator::Gt
+                    | BinaryOperator::Le
+                    | BinaryOperator::Lt => return Ok(compare(op, left.cmp(&right))),
+                    BinaryOperator::AddAssign
+                    | BinaryOperator::AndAssign
+                    | BinaryOperator::ArraySubscript
+                    | BinaryOperator::Assign
+                    | BinaryOperator::Comma
+                    | BinaryOperator::DivAssign
+                    | BinaryOperator::LogicalAnd
+                    | BinaryOperator::LogicalOr
+                    | BinaryOperator::ModAssign
+                    | BinaryOperator::MulAssign
+                    | BinaryOperator::OrAssign
+                    | BinaryOperator::ShiftLeft
+                    | BinaryOperator::ShiftLeftAssign
+                    | BinaryOperator::ShiftRight
+                    | BinaryOperator::ShiftRightAssign
+                    | BinaryOperator::StructEnumMemberAccess
+                    | BinaryOperator::StructEnumMemberPointerAccess
+                    | BinaryOperator::SubAssign
+                    | BinaryOperator::XorAssign => return Err(not_allowed(op, loc)),
+                };
+                Self::int(value, ty, loc, target)
+            }
+            (Self::Float(..), Self::Int(..)) | (Self::Int(..), Self::Float(..)) =>
+                unreachable!("converted to the same type"),
+        }
+    }
+
+    /// Applies a shift operator.
+    ///
+    /// The result has the type of the left operand, and the right operand must
+    /// be smaller than the number of bits of that type.
+    fn shift(
+        self,
+        op: BinaryOperator,
+        other: Self,
+        loc: ErrorLocation,
+        target: &TargetInfo,
+    ) -> Result<Self, CompileError> {
+        let (Self::Int(left, ty), Self::Int(right, _)) = (self, other) else {
+            return Err(invalid_operand(op, self.ty(), other.ty(), loc));
+        };
+        let bits = int_bits(ty, target);
+        let Some(count) = u32::try_from(right)
+            .ok()
+            .filter(|count| u64::from(*count) < bits)
+        else {
+            return Err(loc.fail(format!(
+                "Shift count {right} is out of range for type {ty} of {bits} bits"
+            )));
+        };
+        if op == BinaryOperator::ShiftRight {
+            return Ok(Self::Int(left.wrapping_shr(count), ty));
+        }
+        if left < 0 {
+            return Err(loc.fail(format!("Left shift of negative value {left}")));
+        }
+        Self::int(left.wrapping_shl(count), ty, loc, target)
+    }
+
+    /// Applies a unary operator, after promoting the operand.
+    #[expect(
+        clippy::float_arithmetic,
+        clippy::arithmetic_side_effects,
+        reason = "negates C floating constants"
+    )]
+    pub fn unary(
+        self,
+        op: UnaryOperator,
+        loc: ErrorLocation,
+        target: &TargetInfo,
+    ) -> Result<Self, CompileError> {
+        match (op, self) {
+            (UnaryOperator::LogicalNot, _) => Ok(Self::boolean(self.is_zero())),
+            (UnaryOperator::Plus, _) => Ok(self),
+            (UnaryOperator::Minus, Self::Float(value, ty)) => Ok(Self::Float(-value, ty)),
+            (UnaryOperator::Minus, Self::Int(value, ty)) =>
+                Self::int(value.wrapping_neg(), ty, loc, target),
+            (UnaryOperator::BitwiseNot, Self::Int(value, ty)) => Self::int(!value, ty, loc, target),
+            (UnaryOperator::BitwiseNot, Self::Float(_, ty)) => Err(loc.fail(format!(
+                "Invalid operand of type {ty} for operator {op} in constant expression"
+            ))),
+            (UnaryOperator::Alignof | UnaryOperator::Sizeof, _) =>
+                unreachable!("computed from the layout of the operand"),
+            (
+                UnaryOperator::AddressOf
+                | UnaryOperator::Indirection
+                | UnaryOperator::PostfixDecrement
+                | UnaryOperator::PostfixIncrement
+                | UnaryOperator::PrefixDecrement
+                | UnaryOperator::PrefixIncrement,
+                _,
+            ) => Err(loc.fail(format!("Operator {op} is not allowed in constant expressions"))),
+        }
+    }
+}
+
+/// Applies a comparison operator, given the ordering of the operands.
+fn compare(op: BinaryOperator, ordering: Ordering) -> Value {
+    Value::boolean(match op {
+        BinaryOperator::Different => ordering.is_ne(),
+        BinaryOperator::Equal => ordering.is_eq(),
+        BinaryOperator::Ge => ordering.is_ge(),
+        BinaryOperator::Gt => ordering.is_gt(),
+        BinaryOperator::Le => ordering.is_le(),
+        BinaryOperator::Lt => ordering.is_lt(),
+        BinaryOperator::Add
+        | BinaryOperator::AddAssign
+        | BinaryOperator::AndAssign
+        | BinaryOperator::ArraySubscript
+        | BinaryOperator::Assign
+        | BinaryOperator::BitwiseAnd
+        | BinaryOperator::BitwiseOr
+        | BinaryOperator::BitwiseXor
+        | BinaryOperator::Comma
+        | BinaryOperator::DivAssign
+        | BinaryOperator::Divide
+        | BinaryOperator::LogicalAnd
+        | BinaryOperator::LogicalOr
+        | BinaryOperator::ModAssign
+        | BinaryOperator::Modulo
+        | BinaryOperator::MulAssign
+        | BinaryOperator::Multiply
+        | BinaryOperator::OrAssign
+        | BinaryOperator::ShiftLeft
+        | BinaryOperator::ShiftLeftAssign
+        | BinaryOperator::ShiftRight
+        | BinaryOperator::ShiftRightAssign
+        | BinaryOperator::StructEnumMemberAccess
+        | BinaryOperator::StructEnumMemberPointerAccess
+        | BinaryOperator::SubAssign
+        | BinaryOperator::Subtract
+        | BinaryOperator::XorAssign => unreachable!("not a comparison operator"),
+    })
+}
+
+/// Applies a binary operator on two floating values of the same type.
+#[expect(
+    clippy::float_arithmetic,
+    clippy::arithmetic_side_effects,
+    reason = "evaluates C floating constants"
+)]
+fn float_binary(
+    op: BinaryOperator,
+    left: f128,
+    right: f128,
+    ty: NumberType,
+    loc: ErrorLocation,
+) -> Result<Value, CompileError> {
+    let value = match op {
+        BinaryOperator::Add => left + right,
+        BinaryOperator::Divide => left / right,
+        BinaryOperator::Multiply => left * right,
+        BinaryOperator::Subtract => left - right,
+        BinaryOperator::BitwiseAnd
+        | BinaryOperator::BitwiseOr
+        | BinaryOperator::BitwiseXor
+        | BinaryOperator::Modulo => return Err(invalid_operand(op, ty, ty, loc)),
+        BinaryOperator::Different
+        | BinaryOperator::Equal
+        | BinaryOperator::Ge
+        | BinaryOperator::Gt
+        | BinaryOperator::Le
+        | BinaryOperator::Lt => {
+            let ordering = left
+                .partial_cmp(&right)
+                .ok_or_else(|| loc.fail("Comparison with NaN in constant expression".to_owned()))?;
+            return Ok(compare(op, ordering));
+        }
+        BinaryOperator::AddAssign
+        | BinaryOperator::AndAssign
+        | BinaryOperator::ArraySubscript
+        | BinaryOperator::Assign
+        | BinaryOperator::Comma
+        | BinaryOperator::DivAssign
+        | BinaryOperator::LogicalAnd
+        | BinaryOperator::LogicalOr
+        | BinaryOperator::ModAssign
+        | BinaryOperator::MulAssign
+        | BinaryOperator::OrAssign
+        | BinaryOperator::ShiftLeft
+        | BinaryOperator::ShiftLeftAssign
+        | BinaryOperator::ShiftRight
+        | BinaryOperator::ShiftRightAssign
+        | BinaryOperator::StructEnumMemberAccess
+        | BinaryOperator::StructEnumMemberPointerAccess
+        | BinaryOperator::SubAssign
+        | BinaryOperator::XorAssign => return Err(not_allowed(op, loc)),
+    };
+    Ok(Value::float(value, ty))
+}
+
+/// Returns the error for operands of invalid types for the operator.
+fn invalid_operand(
+    op: BinaryOperator,
+    left: NumberType,
+    right: NumberType,
+    loc: ErrorLocation,
+) -> CompileError {
+    loc.fail(format!(
+        "Invalid operands of types {left} and {right} for operator {op} in constant expression"
+    ))
+}
+
+/// Returns the error for an operator that can't appear in constant
+/// expressions.
+pub fn not_allowed(op: BinaryOperator, loc: ErrorLocation) -> CompileError {
+    loc.fail(format!("Operator {op} is not allowed in constant expressions"))
+}
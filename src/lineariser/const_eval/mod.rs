@@ -0,0 +1,236 @@
+//! Evaluates the constant expressions at compile time.
+//!
+//! Constant expressions are used for `static_assert`, the sizes of the arrays,
+//! the `case` labels, the values of the enum constants and the initialisers of
+//! the `constexpr` objects. They are evaluated on the [`Number`] values,
+//! following the usual arithmetic conversions for the target.
+
+mod arithmetic;
+pub mod value;
+
+use crate::errors::api::{CompileError, ErrorLocation, Res};
+use crate::lexer::api::{Encoding, NumberType};
+use crate::lineariser::const_eval::arithmetic::not_allowed;
+use crate::lineariser::const_eval::value::{Value, common_type};
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::walk::generic::select_association;
+use crate::lineariser::walk::sizeof::eval_type_operator;
+use crate::parser::api::{Ast, Binary, BinaryOperator, Literal, Ternary, Unary};
+use crate::{Number, TargetInfo};
+
+/// Evaluates a constant expression, for the default [`TargetInfo`].
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::*;
+///
+/// let ast = lex("(1 << 4) + 2u", 0)
+///     .and_then(parse)
+///     .unwrap_or_display(&[])
+///     .unwrap();
+/// let value = const_eval(ast.elts.first().unwrap())
+///     .unwrap_or_display(&[])
+///     .unwrap();
+/// assert!(value == Number::UInt(18));
+/// ```
+#[must_use]
+pub fn const_eval(ast: &Ast) -> Res<Number> {
+    const_eval_with(ast, TargetInfo::default())
+}
+
+/// Evaluates a constant expression, for the given target.
+///
+/// The sizes of the integer types depend on the target, and so do the
+/// overflows and the results of the usual arithmetic conversions.
+#[must_use]
+pub fn const_eval_with(ast: &Ast, target: TargetInfo) -> Res<Number> {
+    let mut state = LState::default();
+    state.init(target);
+    Res::ok(eval_constant(ast, &state)?)
+}
+
+/// Evaluates an expression.
+fn eval(ast: &Ast, state: &LState) -> Result<Value, CompileError> {
+    let target = state.target();
+    match ast {
+        Ast::Binary(Binary { op, arg_r, .. }) if arg_r.is_empty() => Err(op
+            .as_location()
+            .fail("Missing RHS of binary operator".to_owned())),
+        Ast::Binary(Binary { op, arg_l, arg_r }) => {
+            let loc = op.as_location();
+            let operator = *op.as_value();
+            if operator == BinaryOperator::LogicalAnd {
+                Ok(Value::boolean(!eval(arg_l, state)?.is_zero() && !eval(arg_r, state)?.is_zero()))
+            } else if operator == BinaryOperator::LogicalOr {
+                Ok(Value::boolean(!eval(arg_l, state)?.is_zero() || !eval(arg_r, state)?.is_zero()))
+            } else if matches!(
+                operator,
+                BinaryOperator::ArraySubscript
+                    | BinaryOperator::Comma
+                    | BinaryOperator::StructEnumMemberAccess
+                    | BinaryOperator::StructEnumMemberPointerAccess
+            ) {
+                Err(not_allowed(operator, loc))
+            } else {
+                eval(arg_l, state)?.binary(operator, eval(arg_r, state)?, loc, target)
+            }
+        }
+        Ast::Cast(cast) if cast.value.is_empty() => Err(cast
+            .parens_location
+            .fail("Missing value after cast".to_owned())),
+        Ast::Cast(cast) => {
+            let value = eval(&cast.value, state)?;
+            let (dest, errors) = Type::from_attributes(&cast.dest_type, state).into_parts();
+            if let Some(err) = errors.into_iter().next() {
+                return Err(err);
+            }
+            let ty = dest.expect("never none");
+            let Some((scalar, unsigned)) = ty.arithmetic_scalar(target) else {
+                return Err(cast.parens_location.fail(format!(
+                    "Cast to non-arithmetic type {ty} is not allowed in constant expressions"
+                )));
+            };
+            value.cast(scalar, unsigned, cast.parens_location, target)
+        }
+        Ast::FunctionCall(call) if call.function_body.is_none() => Err(call
+            .callee
+            .location()
+            .fail(format!("Function call {} is not allowed in constant expressions", call.callee))),
+        Ast::Generic(generic) => match select_association(generic, state).into_parts() {
+            (Some(index), _) => generic
+                .associations
+                .get(index)
+                .map_or_else(|| Err(not_constant(ast)), |assoc| eval(&assoc.value, state)),
+            (None, errors) => Err(errors
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| not_constant(ast))),
+        },
+        Ast::Leaf(lit) => eval_literal(lit.as_value(), lit.as_location())
+            .unwrap_or_else(|| Err(not_constant(ast))),
+        Ast::ParensBlock(parens) if parens.as_value().is_empty() => Err(parens
+            .as_location()
+            .fail("Expected expression in parenthesis".to_owned())),
+        Ast::ParensBlock(parens) => eval(parens.as_value(), state),
+        Ast::Ternary(Ternary { condition, failure: Some((colon, failure)), success })
+            if !failure.is_empty() && !success.is_empty() =>
+        {
+            let (selected, other) = if eval(condition, state)?.is_zero() {
+                (failure, success)
+            } else {
+                (success, failure)
+            };
+            let value = eval(selected, state)?;
+            eval(other, state).map_or(Ok(value), |other_value| {
+                value.convert(common_type(value.ty(), other_value.ty(), target), *colon, target)
+            })
+        }
+        Ast::Unary(Unary { op, arg }) if op.as_value().is_type_operator() =>
+            Value::from_number(&eval_type_operator(op, arg, state)?, op.as_location()),
+        Ast::Unary(Unary { op, arg }) if arg.is_empty() =>
+            Err(op.as_location().fail(format!("Missing operand of `{op}`"))),
+        Ast::Unary(Unary { op, arg }) =>
+            eval(arg, state)?.unary(*op.as_value(), op.as_location(), target),
+        Ast::Variable(var) => Value::from_number(
+            var.as_user_defined_name()
+                .and_then(|name| state.tags().find_constant(name))
+                .ok_or_else(|| not_constant(ast))?,
+            ast.location(),
+        ),
+        Ast::BracedBlock(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::ControlFlow(_)
+        | Ast::Empty
+        | Ast::FunctionArgsBuild(..)
+        | Ast::FunctionCall(_)
+        | Ast::ListInitialiser(_)
+        | Ast::Ternary(_) => Err(not_constant(ast)),
+    }
+}
+
+/// Evaluates the label of a `case`, that must be an integer constant
+/// expression, and converts it to `ty`, the promoted type of the controlling
+/// expression of the `switch`.
+pub fn eval_case_label(ast: &Ast, ty: &Type, state: &LState) -> Result<Number, CompileError> {
+    let target = state.target();
+    let loc = ast.location();
+    let value = eval(ast, state)?;
+    if !value.ty().is_int() {
+        return Err(loc.fail(format!(
+            "Case label must be an integer constant expression, found {}",
+            value.ty()
+        )));
+    }
+    let (scalar, unsigned) = ty
+        .arithmetic_scalar(target)
+        .expect("controlling expression is an integer");
+    Ok(value.cast(scalar, unsigned, loc, target)?.into_number())
+}
+
+/// Evaluates a constant expression, with the enum constants and the types
+/// declared in the state.
+pub fn eval_constant(ast: &Ast, state: &LState) -> Result<Number, CompileError> {
+    eval(ast, state).map(Value::into_number)
+}
+
+/// Checks if an expression is a null pointer constant written as an integer
+/// literal of value zero, like `0` or `0L`.
+pub fn is_null_constant(ast: &Ast, state: &LState) -> bool {
+    if let Ast::ParensBlock(parens) = ast {
+        is_null_constant(parens.as_value(), state)
+    } else {
+        matches!(ast, Ast::Leaf(_)) && matches!(eval(ast, state), Ok(Value::Int(0, _)))
+    }
+}
+
+/// Evaluates a literal, or returns `None` if it isn't an arithmetic constant,
+/// like a string literal.
+fn eval_literal(literal: &Literal, loc: ErrorLocation) -> Option<Result<Value, CompileError>> {
+    match literal {
+        Literal::Char(ch, Encoding::Utf32) =>
+            Some(Ok(Value::Int(i128::from(u32::from(*ch)), NumberType::UInt))),
+        Literal::Char(ch, _) => Some(Ok(Value::Int(i128::from(u32::from(*ch)), NumberType::Int))),
+        Literal::ConstantBool(value) => Some(Ok(Value::boolean(*value))),
+        Literal::Number(number) => Some(Value::from_number(number, loc)),
+        Literal::Null | Literal::Str(..) => None,
+    }
+}
+
+/// Checks if a literal keeps its value when converted to the arithmetic type
+/// `ty`, like `1` to `unsigned char`, but not `-1.5` to `int`.
+pub fn is_representable(literal: &Literal, ty: &Type, loc: ErrorLocation, state: &LState) -> bool {
+    let target = state.target();
+    let Some((scalar, unsigned)) = ty.arithmetic_scalar(target) else {
+        return false;
+    };
+    eval_literal(literal, loc)
+        .and_then(Result::ok)
+        .is_some_and(|value| {
+            value
+                .cast(scalar, unsigned, loc, target)
+                .is_ok_and(|converted| same_value(value, converted))
+        })
+}
+
+/// Returns the error for an expression that isn't a constant expression.
+fn not_constant(ast: &Ast) -> CompileError {
+    ast.location()
+        .fail(format!("Expected constant expression, found {ast}"))
+}
+
+/// Checks if two values are equal, regardless of their types.
+#[expect(
+    clippy::as_conversions,
+    clippy::float_cmp,
+    reason = "exact comparison of the values"
+)]
+fn same_value(left: Value, right: Value) -> bool {
+    match (left, right) {
+        (Value::Float(lhs, _), Value::Float(rhs, _)) => lhs == rhs,
+        (Value::Float(float, _), Value::Int(int, _))
+        | (Value::Int(int, _), Value::Float(float, _)) => int as f128 == float,
+        (Value::Int(lhs, _), Value::Int(rhs, _)) => lhs == rhs,
+    }
+}
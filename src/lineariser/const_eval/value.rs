@@ -0,0 +1,299 @@
+//! Defines the values of the constant expressions, and the conversions between
+//! their types.
+
+use crate::Number;
+use crate::errors::api::{CompileError, ErrorLocation};
+use crate::lexer::api::NumberType;
+use crate::target::{Scalar, TargetInfo};
+
+/// Value of a constant expression, with its type.
+///
+/// The integers are stored on 128 bits, which is enough to compute the result
+/// of any operation on two 64-bit integers before checking that it fits in its
+/// type on the target.
+#[derive(Debug, Clone, Copy)]
+pub enum Value {
+    /// Value of a real floating type: `float`, `double` or `long double`.
+    Float(f128, NumberType),
+    /// Value of an integer type, whose rank is at least the one of `int`.
+    Int(i128, NumberType),
+}
+
+impl Value {
+    /// Returns `1` if the condition holds, `0` otherwise, as an `int`.
+    pub fn boolean(condition: bool) -> Self {
+        Self::Int(i128::from(condition), NumberType::Int)
+    }
+
+    /// Converts the value to an arithmetic type, as done by a cast.
+    ///
+    /// Conversions to `bool`, `char` and `short` return an `int`, as the
+    /// result is promoted as soon as it is used.
+    pub fn cast(
+        self,
+        scalar: Scalar,
+        unsigned: bool,
+        loc: ErrorLocation,
+        target: &TargetInfo,
+    ) -> Result<Self, CompileError> {
+        let ty = match (scalar, unsigned) {
+            (Scalar::Bool, _) => return Ok(Self::boolean(!self.is_zero())),
+            (Scalar::Char | Scalar::Short, _) => {
+                let Self::Int(value, _) = self.convert(NumberType::LongLong, loc, target)? else {
+                    unreachable!("converted to an integer")
+                };
+                let small = wrap(value, target.bits(scalar), !unsigned);
+                return Ok(Self::Int(small, NumberType::Int));
+            }
+            (Scalar::Double, _) => NumberType::Double,
+            (Scalar::Float, _) => NumberType::Float,
+            (Scalar::Int, false) => NumberType::Int,
+            (Scalar::Int, true) => NumberType::UInt,
+            (Scalar::Long, false) => NumberType::Long,
+            (Scalar::Long, true) => NumberType::ULong,
+            (Scalar::LongDouble, _) => NumberType::LongDouble,
+            (Scalar::LongLong, false) => NumberType::LongLong,
+            (Scalar::LongLong, true) => NumberType::ULongLong,
+            (Scalar::Pointer, _) => unreachable!("pointers aren't arithmetic types"),
+        };
+        self.convert(ty, loc, target)
+    }
+
+    /// Converts the value to the given type.
+    ///
+    /// Integers are wrapped in the range of their new type, but a floating
+    /// value that doesn't fit in the integer type is an error.
+    #[expect(
+        clippy::as_conversions,
+        clippy::cast_possible_truncation,
+        reason = "only way to convert an f128, and the range is checked"
+    )]
+    pub fn convert(
+        self,
+        ty: NumberType,
+        loc: ErrorLocation,
+        target: &TargetInfo,
+    ) -> Result<Self, CompileError> {
+        match self {
+            Self::Float(value, _) if ty.is_int() => {
+                let truncated = value as i128;
+                if value.is_nan() || !fits(truncated, ty, target) {
+                    Err(loc.fail(format!(
+                        "Overflow in constant expression: {} doesn't fit in {ty}",
+                        self.into_number()
+                    )))
+                } else {
+                    Ok(Self::Int(truncated, ty))
+                }
+            }
+            Self::Float(value, _) => Ok(Self::float(value, ty)),
+            Self::Int(value, _) if ty.is_int() =>
+                Ok(Self::Int(wrap(value, int_bits(ty, target), !ty.is_unsigned()), ty)),
+            Self::Int(value, _) => Ok(Self::float(value as f128, ty)),
+        }
+    }
+
+    /// Builds a floating value, rounded to the precision of its type.
+    #[expect(clippy::as_conversions, reason = "only way to convert an f128")]
+    pub fn float(value: f128, ty: NumberType) -> Self {
+        let rounded = match ty {
+            NumberType::Float => f128::from(value as f32),
+            NumberType::Double => f128::from(value as f64),
+            NumberType::BitInt
+            | NumberType::Int
+            | NumberType::Long
+            | NumberType::LongDouble
+            | NumberType::LongLong
+            | NumberType::UBitInt
+            | NumberType::UInt
+            | NumberType::ULong
+            | NumberType::ULongLong => value,
+        };
+        Self::Float(rounded, ty)
+    }
+
+    /// Builds the value of a number constant.
+    pub fn from_number(number: &Number, loc: ErrorLocation) -> Result<Self, CompileError> {
+        let ty = number.get_type();
+        Ok(match number {
+            Number::Int(value) => Self::Int(i128::from(*value), ty),
+            Number::Long(value) | Number::LongLong(value) => Self::Int(i128::from(*value), ty),
+            Number::UInt(value) => Self::Int(i128::from(*value), ty),
+            Number::ULong(value) | Number::ULongLong(value) => Self::Int(i128::from(*value), ty),
+            Number::Float(value) => Self::Float(f128::from(*value), ty),
+            Number::Double(value) => Self::Float(f128::from(*value), ty),
+            Number::LongDouble(value) => Self::Float(*value, ty),
+            Number::BitInt(_) | Number::UBitInt(_) =>
+                return Err(loc.fail(format!(
+                    "Constant {number} of type {ty} isn't supported in constant expressions"
+                ))),
+        })
+    }
+
+    /// Builds an integer value of the given type, that must be in the range
+    /// of the type.
+    ///
+    /// The unsigned values are wrapped, as unsigned arithmetic never
+    /// overflows, but signed values out of range are an error.
+    pub fn int(
+        value: i128,
+        ty: NumberType,
+        loc: ErrorLocation,
+        target: &TargetInfo,
+    ) -> Result<Self, CompileError> {
+        if ty.is_unsigned() {
+            Ok(Self::Int(wrap(value, int_bits(ty, target), false), ty))
+        } else if fits(value, ty, target) {
+            Ok(Self::Int(value, ty))
+        } else {
+            Err(loc.fail(format!("Overflow in constant expression: {value} doesn't fit in {ty}")))
+        }
+    }
+
+    /// Converts the value back to a number constant.
+    #[expect(clippy::as_conversions, reason = "only way to convert an f128")]
+    pub fn into_number(self) -> Number {
+        match self {
+            Self::Float(value, NumberType::Float) => Number::Float(value as f32),
+            Self::Float(value, NumberType::Double) => Number::Double(value as f64),
+            Self::Float(value, _) => Number::LongDouble(value),
+            Self::Int(value, NumberType::Long) =>
+                Number::Long(i64::try_from(value).expect("in range of long")),
+            Self::Int(value, NumberType::LongLong) =>
+                Number::LongLong(i64::try_from(value).expect("in range of long long")),
+            Self::Int(value, NumberType::UInt) =>
+                Number::UInt(u32::try_from(value).expect("in range of unsigned int")),
+            Self::Int(value, NumberType::ULong) =>
+                Number::ULong(u64::try_from(value).expect("in range of unsigned long")),
+            Self::Int(value, NumberType::ULongLong) =>
+                Number::ULongLong(u64::try_from(value).expect("in range of unsigned long long")),
+            Self::Int(value, _) => Number::Int(i32::try_from(value).expect("in range of int")),
+        }
+    }
+
+    /// Checks if the value is `0`.
+    #[expect(clippy::float_cmp, reason = "both `0.0` and `-0.0` are zero")]
+    pub const fn is_zero(self) -> bool {
+        match self {
+            Self::Float(value, _) => value == 0.0,
+            Self::Int(value, _) => value == 0,
+        }
+    }
+
+    /// Returns the type of the value.
+    pub const fn ty(self) -> NumberType {
+        match self {
+            Self::Float(_, ty) | Self::Int(_, ty) => ty,
+        }
+    }
+}
+
+/// Returns the type to which both operands are converted, by the usual
+/// arithmetic conversions.
+pub const fn common_type(left: NumberType, right: NumberType, target: &TargetInfo) -> NumberType {
+    if !left.is_int() || !right.is_int() {
+        return if float_rank(left) >= float_rank(right) {
+            left
+        } else {
+            right
+        };
+    }
+    let (unsigned, signed) = match (left.is_unsigned(), right.is_unsigned()) {
+        (true, false) => (left, right),
+        (false, true) => (right, left),
+        (true, true) | (false, false) =>
+            return if int_rank(left) >= int_rank(right) {
+                left
+            } else {
+                right
+            },
+    };
+    if int_rank(unsigned) >= int_rank(signed) {
+        unsigned
+    } else if int_bits(signed, target) > int_bits(unsigned, target) {
+        signed
+    } else {
+        match signed {
+            NumberType::Long => NumberType::ULong,
+            NumberType::LongLong => NumberType::ULongLong,
+            NumberType::BitInt
+            | NumberType::Double
+            | NumberType::Float
+            | NumberType::Int
+            | NumberType::LongDouble
+            | NumberType::UBitInt
+            | NumberType::UInt
+            | NumberType::ULong
+            | NumberType::ULongLong => NumberType::UInt,
+        }
+    }
+}
+
+/// Checks that a value is in the range of an integer type on the target.
+fn fits(value: i128, ty: NumberType, target: &TargetInfo) -> bool {
+    wrap(value, int_bits(ty, target), !ty.is_unsigned()) == value
+}
+
+/// Returns the rank of a floating type.
+const fn float_rank(ty: NumberType) -> u8 {
+    match ty {
+        NumberType::LongDouble => 3,
+        NumberType::Double => 2,
+        NumberType::Float => 1,
+        NumberType::BitInt
+        | NumberType::Int
+        | NumberType::Long
+        | NumberType::LongLong
+        | NumberType::UBitInt
+        | NumberType::UInt
+        | NumberType::ULong
+        | NumberType::ULongLong => 0,
+    }
+}
+
+/// Returns the number of bits of an integer type on the target.
+pub const fn int_bits(ty: NumberType, target: &TargetInfo) -> u64 {
+    target.bits(match ty {
+        NumberType::Long | NumberType::ULong => Scalar::Long,
+        NumberType::LongLong | NumberType::ULongLong => Scalar::LongLong,
+        NumberType::BitInt
+        | NumberType::Double
+        | NumberType::Float
+        | NumberType::Int
+        | NumberType::LongDouble
+        | NumberType::UBitInt
+        | NumberType::UInt => Scalar::Int,
+    })
+}
+
+/// Returns the conversion rank of an integer type.
+const fn int_rank(ty: NumberType) -> u8 {
+    match ty {
+        NumberType::LongLong | NumberType::ULongLong => 3,
+        NumberType::Long | NumberType::ULong => 2,
+        NumberType::BitInt
+        | NumberType::Double
+        | NumberType::Float
+        | NumberType::Int
+        | NumberType::LongDouble
+        | NumberType::UBitInt
+        | NumberType::UInt => 1,
+    }
+}
+
+/// Wraps a value in the range of an integer of `bits` bits, with two's
+/// complement for the signed integers.
+fn wrap(value: i128, bits: u64, signed: bool) -> i128 {
+    let Some(modulus) = u32::try_from(bits)
+        .ok()
+        .and_then(|shift| 1i128.checked_shl(shift))
+    else {
+        return value;
+    };
+    let low = value.checked_rem_euclid(modulus).unwrap_or(value);
+    if signed && low >= modulus.checked_div(2).unwrap_or(modulus) {
+        low.wrapping_sub(modulus)
+    } else {
+        low
+    }
+}
@@ -0,0 +1,114 @@
+//! Index of the declared symbols and of their uses, to find the declaration
+//! of a name from its location in the source file.
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+
+use crate::errors::api::ErrorLocation;
+
+/// Declaration of a variable or a function.
+#[derive(Debug, Clone)]
+pub struct Definition {
+    /// Location of the name in the declaration.
+    location: ErrorLocation,
+    /// Name of the symbol.
+    name: String,
+    /// C declaration of the symbol, e.g. `unsigned long int x` or
+    /// `int f(int, char *)`.
+    signature: String,
+}
+
+impl Definition {
+    /// Returns the location of the name in the declaration.
+    #[must_use]
+    pub const fn as_location(&self) -> ErrorLocation {
+        self.location
+    }
+
+    /// Returns the name of the symbol.
+    #[must_use]
+    pub fn as_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the C declaration of the symbol, with its type.
+    #[must_use]
+    pub fn as_signature(&self) -> &str {
+        &self.signature
+    }
+}
+
+/// Index of the symbols declared in a file, and of the places where they are
+/// used.
+///
+/// It is built by the lineariser, as it resolves the names with the scopes.
+#[derive(Debug, Default)]
+pub struct SymbolIndex {
+    /// Declarations, by symbol id.
+    definitions: BTreeMap<usize, Definition>,
+    /// Location of each use of a symbol, with its id.
+    references: Vec<(ErrorLocation, usize)>,
+}
+
+impl SymbolIndex {
+    /// Records the declaration of a symbol.
+    ///
+    /// If the symbol was already declared, the declaration is only replaced
+    /// by a definition, i.e., by a declaration with a value or a body.
+    pub(crate) fn declare(
+        &mut self,
+        id: usize,
+        location: ErrorLocation,
+        name: String,
+        signature: String,
+        is_definition: bool,
+    ) {
+        if is_definition || !self.definitions.contains_key(&id) {
+            self.definitions
+                .insert(id, Definition { location, name, signature });
+        }
+    }
+
+    /// Returns the declaration of the symbol at the given line and column of
+    /// a file, whether it is a use of the symbol or its declaration.
+    #[must_use]
+    pub fn definition_at(&self, file: u32, line: u32, col: u32) -> Option<&Definition> {
+        self.references
+            .iter()
+            .find(|(location, _)| contains(*location, file, line, col))
+            .and_then(|(_, id)| self.definitions.get(id))
+            .or_else(|| {
+                self.definitions
+                    .values()
+                    .find(|def| contains(def.location, file, line, col))
+            })
+    }
+
+    /// Returns the declarations of all the symbols.
+    pub fn definitions(&self) -> impl Iterator<Item = &Definition> {
+        self.definitions.values()
+    }
+
+    /// Records a use of a symbol.
+    pub(crate) fn refer(&mut self, location: ErrorLocation, id: usize) {
+        self.references.push((location, id));
+    }
+}
+
+/// Checks if a location contains the character at the given line and column
+/// of a file.
+fn contains(location: ErrorLocation, file: u32, line: u32, col: u32) -> bool {
+    match location {
+        ErrorLocation::Token(tok_file, tok_line, tok_col, len) =>
+            file == tok_file
+                && line == tok_line
+                && tok_col <= col
+                && col < tok_col.saturating_add(len),
+        ErrorLocation::Char(char_file, char_line, char_col) =>
+            file == char_file && line == char_line && col == char_col,
+        ErrorLocation::Block(block_file, start_line, start_col, end_line, end_col) =>
+            file == block_file
+                && ((start_line, start_col)..(end_line, end_col)).contains(&(line, col)),
+        ErrorLocation::TwoTokens(..) | ErrorLocation::None => false,
+    }
+}
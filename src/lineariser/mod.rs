@@ -4,6 +4,8 @@
 #![expect(clippy::todo, reason = "in construction")]
 
 mod basic_block;
+pub mod const_eval;
+pub mod index;
 mod ssa;
 mod state;
 mod symbol;
@@ -13,15 +15,26 @@ mod walk;
 use crate::lineariser::basic_block::BasicBlocks;
 use crate::lineariser::ssa::Ssa;
 use crate::lineariser::state::LState;
-use crate::{BracedBlock, Res};
+use crate::{BracedBlock, Res, TargetInfo};
 
 /// Converts an Abstract Syntax Tree into a Static Single Assignment.
+///
+/// The layouts of the types are computed for the default [`TargetInfo`].
 #[must_use]
 pub fn linearise(ast: BracedBlock) -> Res<Ssa> {
+    linearise_with(ast, TargetInfo::default())
+}
+
+/// Converts an Abstract Syntax Tree into a Static Single Assignment, for the
+/// given target.
+#[must_use]
+pub fn linearise_with(ast: BracedBlock, target: TargetInfo) -> Res<Ssa> {
     let mut state = LState::default();
-    state.init();
-    let bbs = BasicBlocks::from_braced_block(ast, &mut state);
+    state.init(target);
+    let mut bbs = BasicBlocks::from_braced_block(ast, &mut state);
+    state.finish_flow(&mut bbs);
+    let index = state.take_index();
     state
         .into_symbol_list()
-        .map(|symbols| Ssa { basic_blocks: bbs, symbols })
+        .map(|symbols| Ssa { basic_blocks: bbs, index, symbols })
 }
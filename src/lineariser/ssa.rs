@@ -1,6 +1,7 @@
 //! Module to define the [`Ssa`] structure.
 
 use crate::lineariser::basic_block::BasicBlocks;
+use crate::lineariser::index::SymbolIndex;
 use crate::lineariser::symbol::Symbol;
 
 /// Static Single Assignment structure.
@@ -8,6 +9,8 @@ use crate::lineariser::symbol::Symbol;
 pub struct Ssa {
     /// Basic blocks
     pub basic_blocks: BasicBlocks,
+    /// Declarations and uses of the symbols, to navigate in the source file.
+    pub index: SymbolIndex,
     /// List of global symbols (variarbles, functions, etc.)
     pub symbols: Vec<Symbol>,
 }
@@ -0,0 +1,169 @@
+//! Applies the standard attributes, like `[[deprecated]]`, and emits the
+//! warnings they ask for.
+
+use std::collections::HashMap;
+
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::state::LState;
+use crate::parser::api::{
+    Ast, Attribute, ControlFlowNode, StandardAttribute, UserDefinedTypes, Variable
+};
+
+/// Standard attributes that don't need to be checked.
+const IGNORED: [&str; 5] = [
+    "maybe_unused",
+    "noreturn",
+    "_Noreturn",
+    "reproducible",
+    "unsequenced",
+];
+
+/// Standard attributes applied on the declared symbols.
+#[derive(Debug, Default)]
+pub struct Attributes {
+    /// Warning to emit when a deprecated symbol is used, by symbol id.
+    deprecated: HashMap<usize, String>,
+    /// Location of a `[[fallthrough]]` that wasn't yet followed by a label.
+    fallthrough: Option<ErrorLocation>,
+    /// Warning to emit when the value returned by a function is discarded, by
+    /// function id.
+    nodiscard: HashMap<usize, String>,
+}
+
+impl LState {
+    /// Applies the standard attributes of a declaration on the declared
+    /// symbol.
+    pub fn apply_attributes(
+        &mut self,
+        id: usize,
+        name: &str,
+        attrs: &[Located<Attribute>],
+        is_function: bool,
+    ) {
+        for (attr, loc) in standard_attributes(attrs) {
+            let reason = attr
+                .as_message()
+                .map_or_else(String::new, |msg| format!(": {msg}"));
+            if attr.is("deprecated") {
+                let msg = format!("`{name}` is deprecated{reason}");
+                self.attributes.deprecated.insert(id, msg);
+            } else if attr.is("nodiscard") && is_function {
+                let msg = format!(
+                    "Ignoring the value returned by `{name}`, declared with `nodiscard`{reason}"
+                );
+                self.attributes.nodiscard.insert(id, msg);
+            } else if attr.is("nodiscard") || attr.is("fallthrough") {
+                self.push_error(
+                    loc.warn(format!("Attribute {attr} ignored: it doesn't apply to `{name}`")),
+                );
+            } else {
+                self.check_known(attr, loc);
+            }
+        }
+    }
+
+    /// Applies the standard attributes of a struct, union or enum on its tag,
+    /// like `[[deprecated]]` in `struct [[deprecated]] A {}`.
+    pub fn apply_tag_attributes(
+        &mut self,
+        kind: UserDefinedTypes,
+        name: &str,
+        attrs: &[Located<Attribute>],
+    ) {
+        for (attr, loc) in standard_attributes(attrs) {
+            if attr.is("deprecated") {
+                let reason = attr
+                    .as_message()
+                    .map_or_else(String::new, |msg| format!(": {msg}"));
+                self.deprecate_tag(name, format!("`{kind} {name}` is deprecated{reason}"));
+            } else if attr.is("fallthrough") {
+                self.push_error(loc.warn(format!(
+                    "Attribute {attr} ignored: it doesn't apply to `{kind} {name}`"
+                )));
+            } else if !attr.is("nodiscard") {
+                self.check_known(attr, loc);
+            }
+        }
+    }
+
+    /// Warns if the statement discards the value returned by a function
+    /// declared with `[[nodiscard]]`.
+    pub fn check_discarded(&mut self, ast: &Ast) {
+        if let Ast::FunctionCall(call) = ast
+            && call.function_body.is_none()
+            && let Some(name) = call.as_variable().and_then(Variable::as_user_defined_name)
+            && let Some(func) = self.find_function(name)
+            && let Some(msg) = self.attributes.nodiscard.get(&func.id)
+        {
+            let warning = ast.location().warn(msg.to_owned());
+            self.push_error(warning);
+        }
+    }
+
+    /// Checks that a `[[fallthrough]]` is directly followed by a `case` or
+    /// `default` label.
+    ///
+    /// `next` is the statement that follows, or `None` at the end of the
+    /// `switch`.
+    pub fn check_fallthrough(&mut self, next: Option<&Ast>) {
+        if let Some(loc) = self.attributes.fallthrough.take()
+            && !next.is_some_and(|ast| {
+                matches!(ast, Ast::ControlFlow(ControlFlowNode::AstColonAst(_)))
+                    || matches!(ast, Ast::ControlFlow(ControlFlowNode::ColonAst(ctrl)) if ctrl.is_default())
+            })
+        {
+            self.push_error(
+                loc.warn(
+                    "Attribute [[fallthrough]] must be followed by a `case` or `default` label"
+                        .to_owned(),
+                ),
+            );
+        }
+    }
+
+    /// Warns if a standard attribute is unknown.
+    ///
+    /// The attributes with a vendor prefix, like `[[gnu::pure]]`, are not
+    /// checked.
+    fn check_known(&mut self, attr: &StandardAttribute, loc: ErrorLocation) {
+        if attr.prefix.is_none() && !IGNORED.contains(&attr.name.as_str()) {
+            self.push_error(loc.warn(format!("Unknown attribute {attr} ignored")));
+        }
+    }
+
+    /// Pushes a statement made only of attributes, like `[[fallthrough]];`.
+    pub fn push_attribute_statement(&mut self, attrs: &[Located<Attribute>]) {
+        for (attr, loc) in standard_attributes(attrs) {
+            if !attr.is("fallthrough") {
+                self.check_known(attr, loc);
+            } else if self.in_switch() {
+                self.attributes.fallthrough = Some(loc);
+            } else {
+                self.push_error(loc.warn(
+                    "Attribute [[fallthrough]] ignored: not within a switch statement".to_owned(),
+                ));
+            }
+        }
+    }
+
+    /// Warns if the used symbol is deprecated.
+    pub(super) fn warn_deprecated(&mut self, loc: ErrorLocation, id: usize) {
+        if let Some(msg) = self.attributes.deprecated.get(&id) {
+            let warning = loc.warn(msg.to_owned());
+            self.push_error(warning);
+        }
+    }
+}
+
+/// Returns the standard attributes among the attributes of a declaration.
+fn standard_attributes(
+    attrs: &[Located<Attribute>],
+) -> impl Iterator<Item = (&StandardAttribute, ErrorLocation)> {
+    attrs.iter().filter_map(|attr| {
+        if let Attribute::Standard(standard) = attr.as_value() {
+            Some((standard, attr.as_location()))
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,218 @@
+//! Handles the statements that jump to other parts of the function: `break`,
+//! `continue`, `goto` and the labels of `switch` statements.
+
+use core::{iter, mem};
+
+use crate::Number;
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::basic_block::{BasicBlocks, Instruction};
+use crate::lineariser::state::LState;
+use crate::lineariser::state::flow::{Breakable, Edge, SwitchCases};
+use crate::lineariser::types::Type;
+use crate::parser::api::Literal;
+
+impl LState {
+    /// Enters a loop, in which `break` and `continue` can be used.
+    pub fn enter_loop(&mut self) {
+        self.flow.breakables.push(Breakable {
+            breaks: vec![],
+            continues: Some(vec![]),
+            switch: None,
+        });
+    }
+
+    /// Enters a `switch` statement, and returns the basic block where the
+    /// value is switched on.
+    ///
+    /// The terminator of this basic block must be set by the caller once the
+    /// cases are known.
+    pub fn enter_switch(&mut self, bbs: &mut BasicBlocks, ty: Option<Type>) -> usize {
+        let dispatch = Edge { jump: false, ..self.jump(bbs) };
+        let from = dispatch.from;
+        let cases = SwitchCases { cases: vec![], default: None, dispatch, ty };
+        self.flow.breakables.push(Breakable {
+            breaks: vec![],
+            continues: None,
+            switch: Some(cases),
+        });
+        from
+    }
+
+    /// Exits the innermost loop or `switch` statement.
+    pub fn exit_breakable(&mut self) -> Breakable {
+        self.flow
+            .breakables
+            .pop()
+            .expect("exited after being entered")
+    }
+
+    /// Checks if the current statement is inside a `switch` statement.
+    pub fn in_switch(&self) -> bool {
+        self.flow
+            .breakables
+            .iter()
+            .any(|breakable| breakable.switch.is_some())
+    }
+
+    /// Pushes a `break` statement.
+    pub fn push_break(&mut self, bbs: &mut BasicBlocks, loc: ErrorLocation) {
+        if self.flow.breakables.is_empty() {
+            self.push_error(loc.fail("Break statement not within loop or switch".to_owned()));
+            return;
+        }
+        let edge = self.jump(bbs);
+        self.flow
+            .breakables
+            .last_mut()
+            .expect("checked above")
+            .breaks
+            .push(edge);
+    }
+
+    /// Pushes a `case` label, with its value if it is valid.
+    ///
+    /// The values are converted to the same type, so two labels with the same
+    /// value share the same literal.
+    pub fn push_case(
+        &mut self,
+        bbs: &mut BasicBlocks,
+        label: Option<Located<Number>>,
+        loc: ErrorLocation,
+    ) {
+        if let Some(block) = self.switch_target(bbs, loc, "Case")
+            && let Some(located) = label
+        {
+            let (number, label_loc) = located.into_inner();
+            let value = self.push_literal(Literal::Number(number.clone()));
+            if self
+                .switch_cases()
+                .cases
+                .iter()
+                .any(|(case, _)| *case == value)
+            {
+                self.push_error(label_loc.fail(format!("Duplicate case value {number}")));
+            } else {
+                self.switch_cases().cases.push((value, block));
+            }
+        }
+    }
+
+    /// Pushes a `continue` statement.
+    pub fn push_continue(&mut self, bbs: &mut BasicBlocks, loc: ErrorLocation) {
+        if !self
+            .flow
+            .breakables
+            .iter()
+            .any(|breakable| breakable.continues.is_some())
+        {
+            self.push_error(loc.fail("Continue statement not within a loop".to_owned()));
+            return;
+        }
+        let edge = self.jump(bbs);
+        self.flow
+            .breakables
+            .iter_mut()
+            .rev()
+            .find_map(|breakable| breakable.continues.as_mut())
+            .expect("checked above")
+            .push(edge);
+    }
+
+    /// Pushes a `default` label.
+    pub fn push_default(&mut self, bbs: &mut BasicBlocks, loc: ErrorLocation) {
+        if let Some(block) = self.switch_target(bbs, loc, "Default") {
+            let cases = self.switch_cases();
+            if cases.default.is_some() {
+                self.push_error(loc.fail("Multiple default labels in one switch".to_owned()));
+            } else {
+                cases.default = Some(block);
+            }
+        }
+    }
+
+    /// Pushes a `goto` statement.
+    pub fn push_goto(&mut self, bbs: &mut BasicBlocks, label: Located<String>) {
+        let (name, loc) = label.into_inner();
+        let edge = self.jump(bbs);
+        if let Some(header) = self
+            .flow
+            .labels
+            .get(&name)
+            .and_then(|found| found.header.clone())
+        {
+            bbs.terminate(edge.from, Instruction::Branch(header.block));
+            self.push_back_edge(bbs, &header, edge.from);
+        } else {
+            self.flow
+                .labels
+                .entry(name)
+                .or_default()
+                .pending
+                .push((edge, loc));
+        }
+    }
+
+    /// Pushes a label declaration.
+    pub fn push_label(&mut self, bbs: &mut BasicBlocks, name: String, loc: ErrorLocation) {
+        let label = self.flow.labels.entry(name.clone()).or_default();
+        if label.header.is_some() {
+            self.push_error(loc.fail(format!("Redefinition of label {name}")));
+            return;
+        }
+        let pending = mem::take(&mut label.pending);
+        let entry = self.edge(bbs.current(), true);
+        let edges = iter::once(entry)
+            .chain(pending.into_iter().map(|(edge, _)| edge))
+            .collect::<Vec<_>>();
+        let header = self.header(bbs, &edges);
+        self.flow.labels.entry(name).or_default().header = Some(header);
+    }
+
+    /// Returns the cases of the innermost `switch` statement.
+    fn switch_cases(&mut self) -> &mut SwitchCases {
+        self.flow
+            .breakables
+            .iter_mut()
+            .rev()
+            .find_map(|breakable| breakable.switch.as_mut())
+            .expect("checked by caller")
+    }
+
+    /// Creates the basic block of a `case` or `default` label of the
+    /// innermost `switch` statement.
+    fn switch_target(
+        &mut self,
+        bbs: &mut BasicBlocks,
+        loc: ErrorLocation,
+        kind: &str,
+    ) -> Option<usize> {
+        let Some(dispatch) = self
+            .flow
+            .breakables
+            .iter()
+            .rev()
+            .find_map(|breakable| breakable.switch.as_ref())
+            .map(|cases| cases.dispatch.clone())
+        else {
+            self.push_error(loc.fail(format!("{kind} label not within a switch statement")));
+            return None;
+        };
+        let edges = self
+            .fallthrough(bbs)
+            .into_iter()
+            .chain(iter::once(dispatch))
+            .collect::<Vec<_>>();
+        Some(self.join(bbs, &edges, false))
+    }
+
+    /// Returns the promoted type of the controlling expression of the
+    /// innermost `switch` statement, if it is a valid integer.
+    pub fn switch_type(&self) -> Option<&Type> {
+        self.flow
+            .breakables
+            .iter()
+            .rev()
+            .find_map(|breakable| breakable.switch.as_ref())
+            .and_then(|cases| cases.ty.as_ref())
+    }
+}
@@ -0,0 +1,275 @@
+//! Handles the jumps between the basic blocks of a function, and the versions
+//! of its local variables needed to insert the phi nodes.
+
+/// Jumps created by the loops, the `switch` statements and the labels.
+mod jumps;
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use core::mem;
+
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::basic_block::{BasicBlocks, Instruction};
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Symbol;
+use crate::lineariser::types::Type;
+use crate::parser::api::Ast;
+
+/// Current value of each local variable, indexed by the id of its declaration.
+pub type Versions = BTreeMap<usize, usize>;
+
+/// Loop or `switch` statement, that can be exited with `break`.
+#[derive(Debug)]
+pub struct Breakable {
+    /// Jumps created by the `break` statements.
+    pub breaks: Vec<Edge>,
+    /// Jumps created by the `continue` statements, if this is a loop.
+    pub continues: Option<Vec<Edge>>,
+    /// Cases of the statement, if this is a `switch`.
+    pub switch: Option<SwitchCases>,
+}
+
+/// Jump from one basic block to another one.
+#[derive(Debug, Clone)]
+pub struct Edge {
+    /// Basic block the jump starts from.
+    from: usize,
+    /// Whether the terminator of `from` is a branch to the target. If not,
+    /// the terminator is set by the caller.
+    jump: bool,
+    /// Versions of the local variables at the time of the jump.
+    versions: Versions,
+}
+
+/// Control flow state of the function being linearised.
+#[derive(Debug, Default)]
+pub struct FlowState {
+    /// Loops and `switch` statements the current statement is in.
+    breakables: Vec<Breakable>,
+    /// Index of the first symbol created by the function.
+    first_symbol: usize,
+    /// Labels of the function, declared or used by a `goto`.
+    labels: BTreeMap<String, Label>,
+    /// Types of the local variables.
+    locals: BTreeMap<usize, Type>,
+    /// Type of the values returned by the function, if inside a function.
+    return_type: Option<Type>,
+    /// Current value of each local variable.
+    versions: Versions,
+}
+
+impl FlowState {
+    /// Creates the flow state of a function whose symbols start at
+    /// `first_symbol`, and that returns values of type `return_type`.
+    pub fn at(first_symbol: usize, return_type: Type) -> Self {
+        Self { first_symbol, return_type: Some(return_type), ..Self::default() }
+    }
+
+    /// Removes a local variable that went out of scope.
+    pub fn forget_local(&mut self, decl: usize) {
+        self.locals.remove(&decl);
+        self.versions.remove(&decl);
+    }
+
+    /// Adds a local variable, that can be assigned new values.
+    pub fn push_local(&mut self, decl: usize, ty: &Type) {
+        self.locals.insert(decl, ty.clone());
+        self.versions.insert(decl, decl);
+    }
+}
+
+/// Basic block that can be jumped to from later basic blocks, like the
+/// entry of a loop or a label.
+///
+/// As the values of the later jumps are not yet known, it starts with a phi
+/// node for every local variable.
+#[derive(Debug, Clone)]
+pub struct Header {
+    /// Index of the basic block.
+    pub block: usize,
+    /// Phi node of each local variable.
+    pub phis: Versions,
+}
+
+/// Label of a function.
+#[derive(Debug, Default)]
+struct Label {
+    /// Basic block of the label, once declared.
+    header: Option<Header>,
+    /// `goto` statements found before the label was declared.
+    pending: Vec<(Edge, ErrorLocation)>,
+}
+
+/// Cases of a `switch` statement.
+#[derive(Debug)]
+pub struct SwitchCases {
+    /// Value and basic block of each `case`.
+    pub cases: Vec<(usize, usize)>,
+    /// Basic block of the `default` case.
+    pub default: Option<usize>,
+    /// Jump from the `switch` to one of the cases.
+    pub dispatch: Edge,
+    /// Promoted type of the controlling expression, to which the values of
+    /// the cases are converted, if it is a valid integer.
+    pub ty: Option<Type>,
+}
+
+impl LState {
+    /// Returns the id of the local variable modified by an assignment to the
+    /// given [`Ast`], if any.
+    ///
+    /// The parenthesis are ignored, like in `(x) = 3`.
+    pub fn assigned_local(&self, ast: &Ast) -> Option<usize> {
+        if let Ast::ParensBlock(parens) = ast {
+            self.assigned_local(parens.as_value())
+        } else if let Ast::Variable(var) = ast {
+            var.as_user_defined_name()
+                .and_then(|name| self.find_declaration(name))
+                .map(|decl| decl.metadata.id)
+                .filter(|id| self.flow.locals.contains_key(id))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an edge from the basic block `from` with the current versions.
+    ///
+    /// If `jump` is `false`, the terminator of `from` must be set by the
+    /// caller.
+    pub fn edge(&self, from: usize, jump: bool) -> Edge {
+        Edge { from, jump, versions: self.flow.versions.clone() }
+    }
+
+    /// Returns an edge from the current basic block to the next one, if the
+    /// current basic block isn't already terminated.
+    pub fn fallthrough(&self, bbs: &BasicBlocks) -> Option<Edge> {
+        bbs.open().map(|from| self.edge(from, true))
+    }
+
+    /// Finishes the control flow of a function: reports the unresolved
+    /// `goto` statements and removes the useless phi nodes.
+    pub fn finish_flow(&mut self, bbs: &mut BasicBlocks) {
+        for (name, label) in mem::take(&mut self.flow.labels) {
+            if label.header.is_none() {
+                for (_, loc) in label.pending {
+                    self.push_error(loc.fail(format!("Use of undeclared label {name}")));
+                }
+            }
+        }
+        for (phi, value) in bbs.remove_trivial_phis() {
+            for symbol in self.symbols.iter_mut().skip(self.flow.first_symbol) {
+                if let Symbol::Element { value: element, .. } = symbol {
+                    element.value.replace(phi, value);
+                }
+            }
+        }
+    }
+
+    /// Creates a [`Header`] basic block, reached from the given edges.
+    pub fn header(&mut self, bbs: &mut BasicBlocks, edges: &[Edge]) -> Header {
+        let block = bbs.new_block();
+        let mut phis = Versions::new();
+        for (decl, ty) in self.flow.locals.clone() {
+            let phi = self.get_and_bump_symbol_id().as_value();
+            let incoming = edges
+                .iter()
+                .filter_map(|edge| edge.versions.get(&decl).map(|value| (*value, edge.from)))
+                .collect();
+            bbs.add_to(block, Instruction::Phi(phi, ty, incoming));
+            phis.insert(decl, phi);
+        }
+        for edge in edges.iter().filter(|edge| edge.jump) {
+            bbs.terminate(edge.from, Instruction::Branch(block));
+        }
+        self.flow
+            .versions
+            .extend(phis.iter().map(|(decl, phi)| (*decl, *phi)));
+        Header { block, phis }
+    }
+
+    /// Creates a basic block where the given edges meet, adding phi nodes for
+    /// the local variables that have different values depending on the edge.
+    ///
+    /// If `reuse` is set and the only edge is a fallthrough, no basic block
+    /// is created and the current one is returned.
+    pub fn join(&mut self, bbs: &mut BasicBlocks, edges: &[Edge], reuse: bool) -> usize {
+        if reuse
+            && let [edge] = edges
+            && edge.jump
+            && bbs.open() == Some(edge.from)
+        {
+            self.flow.versions.clone_from(&edge.versions);
+            return edge.from;
+        }
+        let block = bbs.new_block();
+        for edge in edges.iter().filter(|edge| edge.jump) {
+            bbs.terminate(edge.from, Instruction::Branch(block));
+        }
+        if edges.is_empty() {
+            return block;
+        }
+        let mut versions = Versions::new();
+        for (decl, ty) in self.flow.locals.clone() {
+            let Some(incoming) = edges
+                .iter()
+                .map(|edge| edge.versions.get(&decl).map(|value| (*value, edge.from)))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let value = match incoming.as_slice() {
+                [(first, _), others @ ..] if others.iter().all(|(value, _)| value == first) =>
+                    *first,
+                _ => {
+                    let phi = self.get_and_bump_symbol_id().as_value();
+                    bbs.add_to(block, Instruction::Phi(phi, ty, incoming));
+                    phi
+                }
+            };
+            versions.insert(decl, value);
+        }
+        self.flow.versions = versions;
+        block
+    }
+
+    /// Terminates the current basic block with a jump whose target is set
+    /// later, and returns the corresponding edge.
+    pub fn jump(&self, bbs: &mut BasicBlocks) -> Edge {
+        let from = bbs.current();
+        bbs.add_to(from, Instruction::Branch(from));
+        self.edge(from, true)
+    }
+
+    /// Adds the incoming values of a jump from `from` to a [`Header`].
+    ///
+    /// The terminator of `from` must be set by the caller.
+    pub fn push_back_edge(&self, bbs: &mut BasicBlocks, header: &Header, from: usize) {
+        for (decl, phi) in &header.phis {
+            if let Some(value) = self.flow.versions.get(decl) {
+                bbs.push_incoming(header.block, *phi, *value, from);
+            }
+        }
+    }
+
+    /// Restores the versions of the local variables at the time of the given
+    /// edge.
+    pub fn restore_versions(&mut self, edge: &Edge) {
+        self.flow.versions.clone_from(&edge.versions);
+    }
+
+    /// Returns the type of the values returned by the current function, if
+    /// inside a function.
+    pub const fn return_type(&self) -> Option<&Type> {
+        self.flow.return_type.as_ref()
+    }
+
+    /// Sets a new value to a local variable.
+    pub fn set_version(&mut self, decl: usize, value: usize) {
+        self.flow.versions.insert(decl, value);
+    }
+
+    /// Returns the current value of a variable.
+    pub fn version(&self, decl: usize) -> usize {
+        self.flow.versions.get(&decl).copied().unwrap_or(decl)
+    }
+}
@@ -0,0 +1,194 @@
+//! Declares the functions and linearises their bodies.
+
+extern crate alloc;
+use alloc::collections::btree_map::Entry;
+use core::mem;
+use std::collections::HashSet;
+
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::basic_block::BasicBlocks;
+use crate::lineariser::state::LState;
+use crate::lineariser::state::flow::FlowState;
+use crate::lineariser::symbol::{FunctionBuilder, Value};
+use crate::lineariser::types::{ReturnType, Type};
+use crate::parser::api::BracedBlock;
+
+impl LState {
+    /// Declares the arguments of a function in the current scope.
+    ///
+    /// The unnamed arguments, like in `int f(int)`, are declared-only
+    /// arguments that aren't added to the scope.
+    ///
+    /// # Returns
+    ///
+    /// The ids and types of the arguments, and the names that were declared.
+    fn declare_arguments(
+        &mut self,
+        args: Vec<(Located<String>, Type)>,
+    ) -> (Vec<(usize, Type)>, HashSet<String>) {
+        let mut symbol_args = vec![];
+        let mut names = HashSet::new();
+        for arg in args {
+            let dup = !names.insert(arg.0.as_value().to_owned());
+            if !arg.0.as_value().is_empty() {
+                if dup {
+                    self.errors.push(
+                        arg.0
+                            .as_location()
+                            .fail("Multiple arguments have the same name".to_owned()),
+                    );
+                } else if self.find_declaration(arg.0.as_value()).is_some() {
+                    self.errors.push(
+                        arg.0
+                            .as_location()
+                            .warn("Function argument shadows global variable".to_owned()),
+                    );
+                }
+            }
+            let id = if arg.0.as_value().is_empty() {
+                // unnamed parameters can't be referred to, so they only need an id
+                self.get_and_bump_symbol_id().as_value()
+            } else {
+                self.push_declaration(arg.0.clone(), &arg.1, Value::DeclaredOnly)
+            };
+            symbol_args.push((id, arg.1));
+        }
+        (symbol_args, names)
+    }
+
+    /// Records the declaration of a function in the index, with its
+    /// signature, e.g. `int f(int, char *)`.
+    fn index_function(&mut self, name: String, loc: ErrorLocation, is_definition: bool) {
+        let func = self.functions.get(&name).expect("declared");
+        let args = func
+            .args
+            .iter()
+            .map(|(_, ty)| ty.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ellipsis = match (func.variadic, func.args.is_empty()) {
+            (true, true) => "...",
+            (true, false) => ", ...",
+            (false, _) => "",
+        };
+        let signature = format!("{} {name}({args}{ellipsis})", func.ret);
+        self.index
+            .declare(func.id, loc, name, signature, is_definition);
+    }
+
+    /// Creates a function [`Symbol`](crate::lineariser::symbol::Symbol).
+    ///
+    /// # Note
+    ///
+    /// The function is pushed into the function tables before the body being
+    /// linearised to ensure recursion calls don't trigger a 'call to
+    /// undeclared function'.
+    ///
+    /// A function is unprototyped if its parameter list was empty, like in
+    /// `int f()`.
+    pub fn push_function(
+        &mut self,
+        name: Located<String>,
+        args: Vec<(Located<String>, Type)>,
+        ret: ReturnType,
+        variadic: bool,
+        unprototyped: bool,
+        maybe_fn_body: Option<BracedBlock>,
+    ) {
+        let (name_v, loc) = name.into_inner();
+        if self.declarations.len() > 1 {
+            self.errors
+                .push(loc.fail("Non top-level functions is a GCC extension.".to_owned()));
+        }
+        self.increment_depth();
+        let outer_flow = mem::replace(
+            &mut self.flow,
+            FlowState::at(self.symbols.len(), ret.clone().into_type()),
+        );
+
+        if self.find_declaration(&name_v).is_some() {
+            self.errors
+                .push(loc.warn(format!("Function declaration shadows variable {name_v}")));
+        }
+
+        let (symbol_args, names) = self.declare_arguments(args);
+
+        let mut id = self.get_and_bump_symbol_id();
+        match self.functions.entry(name_v.clone()) {
+            Entry::Vacant(vacant) => {
+                vacant.insert(FunctionBuilder {
+                    args: symbol_args,
+                    body: None,
+                    ret,
+                    id: id.as_value(),
+                    unprototyped,
+                    variadic,
+                });
+            }
+            Entry::Occupied(mut occupied) => {
+                let old_symbol = occupied.get_mut();
+                match old_symbol {
+                    FunctionBuilder {
+                        args: old_args,
+                        ret: old_ret,
+                        variadic: old_variadic,
+                        ..
+                    } if symbol_args.len() != old_args.len()
+                        || variadic != *old_variadic
+                        || symbol_args
+                            .iter()
+                            .zip(old_args.iter())
+                            .any(|((_, new_ty), (_, old_ty))| new_ty != old_ty)
+                        || ret != *old_ret =>
+                        self.errors.push(loc.crash(format!(
+                            "Redeclaration of function {name_v} with a different signature"
+                        ))),
+                    FunctionBuilder { body: Some(_), .. } =>
+                        if maybe_fn_body.is_some() {
+                            self.errors
+                                .push(loc.crash(format!("Redefinition of function {name_v}")));
+                        },
+                    FunctionBuilder { body: None, args: old_args, .. } =>
+                        if maybe_fn_body.is_some() {
+                            // the body refers to the parameters of the definition
+                            *old_args = symbol_args;
+                        },
+                }
+            }
+        }
+
+        self.reset_symbol_id(id);
+
+        self.index_function(name_v.clone(), loc, maybe_fn_body.is_some());
+
+        if let Some(body) = maybe_fn_body {
+            self.increment_depth();
+            let mut bbs = BasicBlocks::from_braced_block(body, self);
+            self.decrement_depth();
+            self.finish_flow(&mut bbs);
+            self.functions
+                .get_mut(&name_v)
+                .expect("just populated")
+                .body = Some(bbs);
+        }
+
+        let scope = self.declarations.last_mut().expect("never empty");
+        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
+        for arg_name in names {
+            if !arg_name.is_empty() {
+                let ok = scope.remove(&arg_name);
+                debug_assert!(ok.is_some(), "was declared in this scope");
+            }
+        }
+        debug_assert!(
+            self.declarations
+                .last_mut()
+                .expect("never empty")
+                .is_empty(),
+            "created on purpose"
+        );
+
+        self.flow = outer_flow;
+        self.decrement_depth();
+    }
+}
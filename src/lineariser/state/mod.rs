@@ -1,29 +1,52 @@
 //! Walks the [`Ast`](crate::parser::api::Ast) and converts it to the
 //! [`Ssa`](super::ssa::Ssa).
 
+/// Standard attributes of the declared symbols.
+mod attributes;
+/// Jumps between basic blocks and versions of the local variables.
+pub mod flow;
+/// Declaration of functions.
+mod function;
+/// Registry of the user-defined types.
+pub mod tags;
+/// Registry of the type aliases.
+mod typedefs;
+
 extern crate alloc;
 use alloc::collections::BTreeMap;
 use alloc::collections::btree_map::Entry;
-use std::collections::{HashMap, HashSet};
+use core::mem;
+use std::collections::HashMap;
 
 use crate::Res;
 use crate::errors::api::{CompileError, ErrorLocation, Located};
-use crate::lineariser::basic_block::BasicBlocks;
+use crate::lineariser::index::SymbolIndex;
+use crate::lineariser::state::attributes::Attributes;
+use crate::lineariser::state::flow::FlowState;
+use crate::lineariser::state::tags::Tags;
+use crate::lineariser::state::typedefs::Typedefs;
 use crate::lineariser::symbol::{ElementBuilder, FunctionBuilder, LiteralBuilder, Symbol, Value};
-use crate::lineariser::types::{ReturnType, Type};
-use crate::parser::api::{BracedBlock, Literal};
+use crate::lineariser::types::Type;
+use crate::parser::api::Literal;
+use crate::target::TargetInfo;
 use crate::utils::SingleUse;
 
 /// Linearising State used to convert the parsed
 /// [`Ast`](crate::parser::api::Ast) into a [`Ssa`](super::ssa::Ssa).
 #[derive(Default, Debug)]
 pub struct LState {
+    /// Standard attributes of the declared symbols.
+    attributes: Attributes,
     /// Array of length `depth` containing the variables declared in this scope.
     declarations: Vec<BTreeMap<String, ElementBuilder>>,
     /// Errors that occurred while linearising the Ast.
     errors: Vec<CompileError>,
+    /// Control flow of the function being linearised.
+    flow: FlowState,
     /// Declared functions.
     functions: BTreeMap<String, FunctionBuilder>,
+    /// Declarations and uses of the symbols, for editors.
+    index: SymbolIndex,
     /// Literals to put in rodata.
     literals: HashMap<Literal, LiteralBuilder>,
     /// Unique id of the next symbol to be declared.
@@ -31,6 +54,12 @@ pub struct LState {
     /// The actual values of the built symbols, ready to be handed over to the
     /// Ssa.
     symbols: Vec<Symbol>,
+    /// User-defined types, declared with `struct`, `union` or `enum`.
+    tags: Tags,
+    /// Machine the code is compiled for.
+    target: TargetInfo,
+    /// Type aliases, declared with `typedef`.
+    typedefs: Typedefs,
 }
 
 impl LState {
@@ -41,8 +70,11 @@ impl LState {
             .pop()
             .expect("can't decrement without first incrementing")
         {
+            self.flow.forget_local(element.metadata.id);
             self.symbols.push(element.with_name(name));
         }
+        self.tags.pop();
+        self.typedefs.pop();
     }
 
     /// Returns the ID of a declaration by name, if found.
@@ -60,6 +92,13 @@ impl LState {
         self.functions.get(fname)
     }
 
+    /// Returns the literal of the symbol of the given id, if it is a literal.
+    pub fn find_literal(&self, id: usize) -> Option<&Literal> {
+        self.literals
+            .iter()
+            .find_map(|(literal, builder)| (builder.id == id).then_some(literal))
+    }
+
     /// Increment the id and return the one that can be used.
     ///
     /// This function ensures that every id is unique.
@@ -76,11 +115,14 @@ impl LState {
     /// Increments the depth: enters a block.
     pub fn increment_depth(&mut self) {
         self.declarations.push(BTreeMap::new());
+        self.tags.push();
+        self.typedefs.push();
     }
 
-    /// Creates the state to parse the global scope.
-    pub fn init(&mut self) {
-        self.declarations.push(BTreeMap::new());
+    /// Creates the state to parse the global scope, for the given target.
+    pub fn init(&mut self, target: TargetInfo) {
+        self.target = target;
+        self.increment_depth();
     }
 
     /// Returns the inner [`Ssa`](super::ssa::Ssa).
@@ -102,6 +144,11 @@ impl LState {
         Res::from((self.symbols, self.errors))
     }
 
+    /// Checks if the current scope is a block scope, and not the file scope.
+    pub const fn is_block_scope(&self) -> bool {
+        self.declarations.len() > 1
+    }
+
     /// Creates a variable [`Symbol`].
     pub fn push_declaration(&mut self, name: Located<String>, ty: &Type, value: Value) -> usize {
         let (name_v, loc) = name.into_inner();
@@ -109,19 +156,35 @@ impl LState {
             self.errors
                 .push(loc.fail(format!("Variable declaration shadows function {name_v}")));
         }
+        if self.typedefs.is_declared_in_scope(&name_v) {
+            self.errors
+                .push(loc.fail(format!("Redefinition of {name_v} as different kind of symbol")));
+        }
         let mut id = self.get_and_bump_symbol_id();
         let last = self.declarations.last_mut().expect("depth>=1");
         match last.entry(name_v.clone()) {
             Entry::Vacant(vacant) => {
+                self.index.declare(
+                    id.as_value(),
+                    loc,
+                    name_v.clone(),
+                    format!("{ty} {name_v}"),
+                    !matches!(value, Value::DeclaredOnly),
+                );
                 let symbol = ElementBuilder {
                     metadata: LiteralBuilder { id: id.as_value(), ty: ty.to_owned() },
                     value,
                 };
                 vacant.insert(symbol);
+                if self.declarations.len() > 1 {
+                    self.flow.push_local(id.as_value(), ty);
+                }
                 id.as_value()
             }
             Entry::Occupied(mut occupied) => {
                 let old_symbol = occupied.get_mut();
+                let defines = matches!(old_symbol.value, Value::DeclaredOnly)
+                    && !matches!(value, Value::DeclaredOnly);
                 match old_symbol {
                     ElementBuilder { metadata, .. } if *ty != metadata.ty => self.errors.push(
                         loc.crash(format!("Redeclaration of {name_v} with a different type")),
@@ -134,6 +197,10 @@ impl LState {
                         },
                 }
                 let symbol_id = old_symbol.metadata.id;
+                if defines {
+                    let signature = format!("{ty} {name_v}");
+                    self.index.declare(symbol_id, loc, name_v, signature, true);
+                }
                 self.reset_symbol_id(id);
                 symbol_id
             }
@@ -155,122 +222,6 @@ impl LState {
         self.errors.push(err);
     }
 
-    /// Creates a function [`Symbol`].
-    ///
-    /// # Note
-    ///
-    /// The function is pushed into the function tables before the body being
-    /// linearised to ensure recursion calls don't trigger a 'call to
-    /// undeclared function'.
-    pub fn push_function(
-        &mut self,
-        name: Located<String>,
-        args: Vec<(Located<String>, Type)>,
-        ret: ReturnType,
-        maybe_fn_body: Option<BracedBlock>,
-    ) {
-        let (name_v, loc) = name.into_inner();
-        if self.declarations.len() > 1 {
-            self.errors
-                .push(loc.fail("Non top-level functions is a GCC extension.".to_owned()));
-        }
-        self.increment_depth();
-
-        if self.find_declaration(&name_v).is_some() {
-            self.errors
-                .push(loc.warn(format!("Function declaration shadows variable {name_v}")));
-        }
-
-        let mut symbol_args = vec![];
-        let mut names = HashSet::new();
-        for arg in args {
-            let dup = !names.insert(arg.0.as_value().to_owned());
-            if !arg.0.as_value().is_empty() {
-                if dup {
-                    self.errors.push(
-                        arg.0
-                            .as_location()
-                            .fail("Multiple arguments have the same name".to_owned()),
-                    );
-                } else if self.find_declaration(arg.0.as_value()).is_some() {
-                    self.errors.push(
-                        arg.0
-                            .as_location()
-                            .warn("Function argument shadows global variable".to_owned()),
-                    );
-                }
-            }
-            let id = if arg.0.as_value().is_empty() {
-                self.push_literal(Literal::Null)
-            } else {
-                self.push_declaration(arg.0.clone(), &arg.1, Value::DeclaredOnly)
-            };
-            symbol_args.push((id, arg.1));
-        }
-
-        let mut id = self.get_and_bump_symbol_id();
-        match self.functions.entry(name_v.clone()) {
-            Entry::Vacant(vacant) => {
-                vacant.insert(FunctionBuilder {
-                    args: symbol_args,
-                    body: None,
-                    ret,
-                    id: id.as_value(),
-                });
-            }
-            Entry::Occupied(mut occupied) => {
-                let old_symbol = occupied.get_mut();
-                match old_symbol {
-                    FunctionBuilder { args: old_args, ret: old_ret, .. }
-                        if symbol_args.len() != old_args.len()
-                            || symbol_args
-                                .iter()
-                                .zip(old_args.iter())
-                                .any(|((_, new_ty), (_, old_ty))| new_ty != old_ty)
-                            || ret != *old_ret =>
-                        self.errors.push(loc.crash(format!(
-                            "Redeclaration of function {name_v} with a different signature"
-                        ))),
-                    FunctionBuilder { body: Some(_), .. } =>
-                        if maybe_fn_body.is_some() {
-                            self.errors
-                                .push(loc.crash(format!("Redefinition of function {name_v}")));
-                        },
-                    FunctionBuilder { body: None, .. } => (),
-                }
-            }
-        }
-
-        self.reset_symbol_id(id);
-
-        if let Some(body) = maybe_fn_body {
-            self.increment_depth();
-            self.functions
-                .get_mut(&name_v)
-                .expect("just populated")
-                .body = Some(BasicBlocks::from_braced_block(body, self));
-            self.decrement_depth();
-        }
-
-        let scope = self.declarations.last_mut().expect("never empty");
-        #[expect(clippy::iter_over_hash_type, reason = "order doesn't matter")]
-        for arg_name in names {
-            if !arg_name.is_empty() {
-                let ok = scope.remove(&arg_name);
-                debug_assert!(ok.is_some(), "was declared in this scope");
-            }
-        }
-        debug_assert!(
-            self.declarations
-                .last_mut()
-                .expect("never empty")
-                .is_empty(),
-            "created on purpose"
-        );
-
-        self.decrement_depth();
-    }
-
     /// Creates a new symbol for a literal value.
     pub fn push_literal(&mut self, literal: Literal) -> usize {
         if let Some(sym) = self.literals.get(&literal) {
@@ -282,6 +233,12 @@ impl LState {
         id
     }
 
+    /// Records a use of the symbol of the given id.
+    pub fn refer(&mut self, loc: ErrorLocation, id: usize) {
+        self.warn_deprecated(loc, id);
+        self.index.refer(loc, id);
+    }
+
     /// Resets the symbol id to the given value.
     const fn reset_symbol_id(&mut self, value: SingleUse<usize>) {
         if let Some(old) = value.try_into_value() {
@@ -293,4 +250,14 @@ impl LState {
     pub fn stat_not_expr(&mut self, loc: ErrorLocation, scope: &str) {
         self.push_error(loc.fail(format!("Expected expression in {scope}, got statement")));
     }
+
+    /// Returns the index of the declared symbols, leaving an empty one.
+    pub fn take_index(&mut self) -> SymbolIndex {
+        mem::take(&mut self.index)
+    }
+
+    /// Returns the machine the code is compiled for.
+    pub const fn target(&self) -> &TargetInfo {
+        &self.target
+    }
 }
@@ -0,0 +1,296 @@
+//! Registry of the user-defined types, declared with `struct`, `union` or
+//! `enum`, and of the enum constants.
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+
+use crate::Number;
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::layout::RecordLayout;
+use crate::parser::api::{BinaryOperator, UserDefinedTypes};
+use crate::target::{Layout, Scalar};
+
+/// Member of a struct or a union.
+#[derive(Clone, Debug)]
+pub struct Member {
+    /// Name of the member.
+    ///
+    /// It is empty for anonymous structs and unions, whose members are
+    /// accessed as members of the outer record.
+    pub name: String,
+    /// Offset of the member from the start of the record, in bytes.
+    pub offset: u64,
+    /// Type of the member.
+    pub ty: Type,
+}
+
+/// Type declared with a tag: `struct`, `union` or `enum`.
+#[derive(Debug)]
+pub struct Tag {
+    /// Warning to emit when the tag is used, if declared with
+    /// `[[deprecated]]`.
+    pub deprecated: Option<String>,
+    /// Kind of user-defined type.
+    pub kind: UserDefinedTypes,
+    /// Size and alignment of the type, or `None` while it is incomplete.
+    pub layout: Option<Layout>,
+    /// Members of a struct or union, in order of declaration.
+    pub members: Vec<Member>,
+    /// Integer type storing the values of a defined enum.
+    pub underlying: Option<Scalar>,
+}
+
+/// Scoped registry of the user-defined types.
+#[derive(Debug, Default)]
+pub struct Tags {
+    /// Number of anonymous types defined so far, to give them unique names.
+    anonymous: usize,
+    /// Array of length `depth` containing the enum constants defined in this
+    /// scope.
+    constants: Vec<BTreeMap<String, Number>>,
+    /// Array of length `depth` containing the tags declared in this scope.
+    scopes: Vec<BTreeMap<String, Tag>>,
+}
+
+impl Tags {
+    /// Returns a tag by name, if found.
+    pub fn find(&self, name: &str) -> Option<&Tag> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    /// Returns the value of an enum constant by name, if found.
+    pub fn find_constant(&self, name: &str) -> Option<&Number> {
+        self.constants
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name))
+    }
+
+    /// Exits a block, forgetting the tags declared inside.
+    pub fn pop(&mut self) {
+        self.constants.pop();
+        self.scopes.pop();
+    }
+
+    /// Enters a block.
+    pub fn push(&mut self) {
+        self.constants.push(BTreeMap::new());
+        self.scopes.push(BTreeMap::new());
+    }
+
+    /// Returns the integer type storing the values of an enum, which is `int`
+    /// unless the enum is defined with larger values.
+    pub fn underlying(&self, name: &str) -> Scalar {
+        self.find(name)
+            .and_then(|tag| tag.underlying)
+            .unwrap_or(Scalar::Int)
+    }
+}
+
+impl LState {
+    /// Returns a unique name for an anonymous struct, union or enum.
+    pub fn anonymous_tag(&mut self) -> String {
+        self.tags.anonymous = self.tags.anonymous.saturating_add(1);
+        format!("(anonymous {})", self.tags.anonymous)
+    }
+
+    /// Returns the name of a const-qualified member of a struct or union,
+    /// also looking in the nested structs and unions, like `x` in
+    /// `struct { const int x; }`.
+    pub fn const_member(&self, ty: &Type) -> Option<String> {
+        let (UserDefinedTypes::Struct | UserDefinedTypes::Union, name) = ty.as_tag()? else {
+            return None;
+        };
+        self.tags.find(name)?.members.iter().find_map(|field| {
+            if field.ty.is_const() {
+                Some(field.name.clone())
+            } else {
+                self.const_member(&field.ty)
+            }
+        })
+    }
+
+    /// Declares a tag in the current scope, without defining it.
+    ///
+    /// If a tag of the same name is visible, `struct A` refers to it and
+    /// nothing is declared.
+    pub fn declare_tag(
+        &mut self,
+        kind: UserDefinedTypes,
+        name: String,
+        loc: ErrorLocation,
+    ) -> Type {
+        match self.tags.find(&name) {
+            Some(tag) if tag.kind != kind => self.push_error(loc.fail(format!(
+                "Use of {name} with tag type {kind} that doesn't match previous declaration as {}",
+                tag.kind
+            ))),
+            Some(_) => (),
+            None => {
+                self.tags.scopes.last_mut().expect("depth>=1").insert(
+                    name.clone(),
+                    Tag { deprecated: None, kind, layout: None, members: vec![], underlying: None },
+                );
+            }
+        }
+        Type::from_tag(kind, name, &self.tags)
+    }
+
+    /// Defines an enum in the current scope, stored as `underlying`.
+    pub fn define_enum(&mut self, name: String, underlying: Scalar, loc: ErrorLocation) -> Type {
+        let tag = Tag {
+            deprecated: None,
+            kind: UserDefinedTypes::Enum,
+            layout: Some(self.target.layout(underlying)),
+            members: vec![],
+            underlying: Some(underlying),
+        };
+        self.insert_tag(name, tag, loc)
+    }
+
+    /// Defines a struct or union in the current scope.
+    ///
+    /// The layout is computed from its members.
+    pub fn define_tag(
+        &mut self,
+        kind: UserDefinedTypes,
+        name: String,
+        members: Vec<(String, Type, Layout, Option<u64>)>,
+        loc: ErrorLocation,
+    ) -> Type {
+        let mut record = RecordLayout::new(kind);
+        let placed = members
+            .into_iter()
+            .map(|(member, ty, layout, width)| Member {
+                name: member,
+                offset: record.push(layout, width),
+                ty,
+            })
+            .collect();
+        let tag = Tag {
+            deprecated: None,
+            kind,
+            layout: Some(record.finish()),
+            members: placed,
+            underlying: None,
+        };
+        self.insert_tag(name, tag, loc)
+    }
+
+    /// Marks the visible tag of that name as deprecated, with the warning to
+    /// emit when it is used.
+    pub fn deprecate_tag(&mut self, name: &str, msg: String) {
+        if let Some(tag) = self
+            .tags
+            .scopes
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(name))
+        {
+            tag.deprecated = Some(msg);
+        }
+    }
+
+    /// Returns the offset and the type of a member, accessed with `.` or `->`
+    /// on a value of type `ty`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error message if the type isn't a (pointer to a) complete
+    /// struct or union, or if it has no member of that name.
+    pub fn find_member(
+        &self,
+        ty: &Type,
+        op: BinaryOperator,
+        member: &str,
+    ) -> Result<(u64, Type), String> {
+        let record = if op == BinaryOperator::StructEnumMemberPointerAccess {
+            ty.pointee().ok_or_else(|| {
+                format!("Member reference type {ty} is not a pointer{}", hint(ty, "."))
+            })?
+        } else if let Some(pointee) = ty.pointee() {
+            return Err(format!("Member reference type {ty} is a pointer{}", hint(&pointee, "->")));
+        } else {
+            ty.clone()
+        };
+        let Some((UserDefinedTypes::Struct | UserDefinedTypes::Union, name)) = record.as_tag()
+        else {
+            return Err(format!("Member reference base type {record} is not a structure or union"));
+        };
+        match self.tags.find(name) {
+            Some(tag @ Tag { layout: Some(_), .. }) => self
+                .find_member_in(tag, member)
+                .ok_or_else(|| format!("No member named {member} in {record}")),
+            Some(_) | None => Err(format!("Incomplete definition of type {record}")),
+        }
+    }
+
+    /// Looks for a member by name in a tag, also looking in the anonymous
+    /// structs and unions.
+    fn find_member_in(&self, tag: &Tag, member: &str) -> Option<(u64, Type)> {
+        tag.members.iter().find_map(|field| {
+            if field.name == member {
+                Some((field.offset, field.ty.clone()))
+            } else if field.name.is_empty()
+                && let Some((_, name)) = field.ty.as_tag()
+                && let Some(inner) = self.tags.find(name)
+            {
+                self.find_member_in(inner, member)
+                    .map(|(offset, ty)| (offset.saturating_add(field.offset), ty))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Inserts a defined tag in the current scope, unless it was already
+    /// defined.
+    fn insert_tag(&mut self, name: String, mut tag: Tag, loc: ErrorLocation) -> Type {
+        let kind = tag.kind;
+        let scope = self.tags.scopes.last_mut().expect("depth>=1");
+        tag.deprecated = scope.get(&name).and_then(|old| old.deprecated.clone());
+        match scope.get(&name) {
+            Some(Tag { layout: Some(_), kind: old, .. }) if *old == kind =>
+                self.errors.push(loc.fail(format!("Redefinition of {kind} {name}"))),
+            Some(Tag { kind: old, .. }) if *old != kind => self.errors.push(loc.fail(format!(
+                "Use of {name} with tag type {kind} that doesn't match previous declaration as {old}"
+            ))),
+            Some(_) | None => {
+                scope.insert(name.clone(), tag);
+            }
+        }
+        Type::from_tag(kind, name, &self.tags)
+    }
+
+    /// Returns the layout of a type on the target, or `None` if the type is
+    /// incomplete.
+    pub fn layout(&self, ty: &Type) -> Option<Layout> {
+        ty.layout(&self.target, &self.tags)
+    }
+
+    /// Defines an enum constant in the current scope.
+    pub fn push_enum_constant(&mut self, name: String, value: Number) {
+        self.tags
+            .constants
+            .last_mut()
+            .expect("depth>=1")
+            .insert(name, value);
+    }
+
+    /// Returns the tags registry.
+    pub const fn tags(&self) -> &Tags {
+        &self.tags
+    }
+}
+
+/// Returns the hint to use the other member access operator if it would be
+/// valid on this type.
+fn hint(ty: &Type, other: &str) -> String {
+    if matches!(ty.as_tag(), Some((UserDefinedTypes::Struct | UserDefinedTypes::Union, _))) {
+        format!("; did you mean to use '{other}'?")
+    } else {
+        String::new()
+    }
+}
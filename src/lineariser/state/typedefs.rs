@@ -0,0 +1,91 @@
+//! Registry of the type aliases, declared with `typedef`.
+
+extern crate alloc;
+use alloc::collections::BTreeMap;
+use alloc::collections::btree_map::Entry;
+
+use crate::errors::api::Located;
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+
+/// Scoped registry of the typedefs.
+#[derive(Debug, Default)]
+pub struct Typedefs {
+    /// Array of length `depth` containing the typedefs declared in this scope.
+    scopes: Vec<BTreeMap<String, Type>>,
+}
+
+impl Typedefs {
+    /// Checks if a typedef of that name was declared in the current scope.
+    pub fn is_declared_in_scope(&self, name: &str) -> bool {
+        self.scopes
+            .last()
+            .is_some_and(|scope| scope.contains_key(name))
+    }
+
+    /// Exits a block, forgetting the typedefs declared inside.
+    pub fn pop(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Enters a block.
+    pub fn push(&mut self) {
+        self.scopes.push(BTreeMap::new());
+    }
+}
+
+impl LState {
+    /// Returns the type aliased by a typedef name, if found.
+    ///
+    /// Typedef names and variables share the same namespace, so a variable
+    /// declared in an inner scope hides a typedef of the same name.
+    pub fn find_typedef(&self, name: &str) -> Option<&Type> {
+        self.typedefs
+            .scopes
+            .iter()
+            .zip(&self.declarations)
+            .rev()
+            .find_map(|(typedefs, declarations)| {
+                typedefs
+                    .get(name)
+                    .map(Some)
+                    .or_else(|| declarations.contains_key(name).then_some(None))
+            })
+            .flatten()
+    }
+
+    /// Declares a typedef in the current scope.
+    ///
+    /// A typedef can be redeclared in the same scope, but only with the same
+    /// type.
+    pub fn push_typedef(&mut self, name: Located<String>, ty: &Type) {
+        let (name_v, loc) = name.into_inner();
+        if self
+            .declarations
+            .last()
+            .is_some_and(|scope| scope.contains_key(&name_v))
+        {
+            self.push_error(
+                loc.fail(format!("Redefinition of {name_v} as different kind of symbol")),
+            );
+            return;
+        }
+        let aliased = ty.resolved();
+        let scope = self.typedefs.scopes.last_mut().expect("depth>=1");
+        match scope.entry(name_v) {
+            Entry::Vacant(vacant) => {
+                vacant.insert(aliased);
+            }
+            Entry::Occupied(occupied) =>
+                if *occupied.get() != aliased {
+                    let err = format!(
+                        "Typedef redefinition of {} with different types ({} vs {})",
+                        occupied.key(),
+                        ty,
+                        occupied.get()
+                    );
+                    self.errors.push(loc.fail(err));
+                },
+        }
+    }
+}
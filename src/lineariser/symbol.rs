@@ -3,6 +3,7 @@
 
 use crate::EMPTY;
 use crate::lineariser::basic_block::BasicBlocks;
+use crate::lineariser::types::function::Signature;
 use crate::lineariser::types::{ReturnType, Type};
 use crate::parser::api::{BinaryOperator, Literal, UnaryOperator};
 use crate::utils::display;
@@ -32,14 +33,26 @@ impl LiteralBuilder {
 /// Expression that gives a value.
 #[derive(Debug)]
 pub enum Value {
+    /// Constant aggregate initialising an object with static storage, with the
+    /// values of the initialised scalars and their offsets in bytes, the other
+    /// bytes being zero.
+    Aggregate(Vec<(u64, usize)>),
     /// Binary operator execution.
     Binary(BinaryOperator, usize, usize),
-    /// `call f(...)`
+    /// `call x(...)`, where `x` is a function or a pointer to a function.
     Call(usize, Vec<usize>),
+    /// Conversion of a value to the type of the symbol, like from `int` to
+    /// `double` in `1 + 2.5`.
+    Convert(usize),
     /// no value provided yet, the variable was only declared
     DeclaredOnly,
     /// constant literal value
     Literal(Literal),
+    /// Access to a member of a struct or union, at the given offset in bytes.
+    Member(BinaryOperator, usize, String, u64),
+    /// Store of a value in an object, at the given offset in bytes, like the
+    /// elements of an initialiser list or the assignments.
+    Store(usize, u64, usize),
     /// Ternary conditional operator.
     Ternary(usize, usize, usize),
     /// Unary operator execution.
@@ -48,22 +61,66 @@ pub enum Value {
     Variable(usize),
 }
 
+impl Value {
+    /// Replaces every use of the value `from` by the value `to`.
+    pub fn replace(&mut self, from: usize, to: usize) {
+        let swap = |id: &mut usize| {
+            if *id == from {
+                *id = to;
+            }
+        };
+        match self {
+            Self::Aggregate(values) => values.iter_mut().for_each(|(_, id)| swap(id)),
+            Self::Binary(_, left, right) | Self::Store(left, _, right) => {
+                swap(left);
+                swap(right);
+            }
+            Self::Call(callee, args) => {
+                swap(callee);
+                args.iter_mut().for_each(swap);
+            }
+            Self::DeclaredOnly | Self::Literal(_) => (),
+            Self::Ternary(cond, succ, fail) => {
+                swap(cond);
+                swap(succ);
+                swap(fail);
+            }
+            Self::Convert(arg)
+            | Self::Member(_, arg, ..)
+            | Self::Unary(_, arg)
+            | Self::Variable(arg) => swap(arg),
+        }
+    }
+}
+
 display!(
     Value,
     self,
     f,
     match self {
+        Self::Aggregate(values) => write!(
+            f,
+            "{{{}}}",
+            values
+                .iter()
+                .map(|(offset, id)| format!("x{id}@{offset}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
         Self::Binary(op, left, right) => write!(f, "{op} x{left} x{right}"),
-        Self::Call(name, args) => write!(
+        Self::Call(callee, args) => write!(
             f,
-            "call f{name}({})",
+            "call x{callee}({})",
             args.iter()
                 .map(|id| format!("x{id}"))
                 .collect::<Vec<_>>()
                 .join(", ")
         ),
+        Self::Convert(id) => write!(f, "convert x{id}"),
         Self::DeclaredOnly => EMPTY.fmt(f),
         Self::Literal(lit) => lit.fmt(f),
+        Self::Member(op, base, member, offset) => write!(f, "{op} x{base} {member}@{offset}"),
+        Self::Store(object, offset, value) => write!(f, "store x{object}@{offset} x{value}"),
         Self::Ternary(cond, succ, fail) => write!(f, "x{cond} ? x{succ} : x{fail}"),
         Self::Unary(op, arg) => write!(f, "{op} x{arg}"),
         Self::Variable(id) => write!(f, "x{id}"),
@@ -110,9 +167,21 @@ pub struct FunctionBuilder {
     pub id: usize,
     /// Return type.
     pub ret: ReturnType,
+    /// Whether the parameter list was empty, like in `int f()`.
+    pub unprototyped: bool,
+    /// Whether the function takes a variable number of arguments.
+    pub variadic: bool,
 }
 
 impl FunctionBuilder {
+    /// Returns the type of the function, like `int (int, char *)`.
+    pub fn ty(&self) -> Type {
+        let params = self.args.iter().map(|(_, ty)| ty.clone());
+        self.ret.clone().into_type().into_function(
+            Signature::new(params, self.variadic).with_unprototyped(self.unprototyped),
+        )
+    }
+
     /// Adds the missing data to create an ssa symbol.
     pub const fn with_name(self, name: String) -> Symbol {
         Symbol::Function { name, value: self }
@@ -125,13 +194,18 @@ display!(
     f,
     write!(
         f,
-        "f{}({}) -> {}{}",
+        "f{}({}{}) -> {}{}",
         self.id,
         self.args
             .iter()
             .map(|(name, ty)| format!("{ty} x{name}"))
             .collect::<Vec<_>>()
             .join(", "),
+        match (self.variadic, self.args.is_empty()) {
+            (true, true) => "...",
+            (true, false) => ", ...",
+            (false, _) => "",
+        },
         self.ret,
         self.body
             .as_ref()
@@ -142,7 +216,6 @@ display!(
 /// A symbol that can be defined or declared.
 #[derive(Debug)]
 pub enum Symbol {
-    //TODO: this shouldn't be an enum, a function is a variable.
     /// Simple element that can be assigned.
     Element {
         /// Name of the symbol.
@@ -153,6 +226,9 @@ pub enum Symbol {
         value: ElementBuilder,
     },
     /// Function that can be called
+    ///
+    /// A function is also a value of type [`FunctionBuilder::ty`]: its id can
+    /// be used as an operand, like the callee of a [`Value::Call`].
     Function {
         /// Name of the function.
         name: String,
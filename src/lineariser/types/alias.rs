@@ -0,0 +1,97 @@
+use crate::Res;
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::{IndirectionDecorator, TypeDecorator};
+use crate::lineariser::types::name::TypeName;
+use crate::lineariser::types::state::TypeParsingState;
+
+impl TypeParsingState {
+    /// Adds a user-defined type name, like `A` in `struct A` or `size_t`.
+    ///
+    /// When it isn't preceded by `struct`, `union` or `enum`, the name is a
+    /// typedef name, and is resolved with the typedefs visible in `state`.
+    /// Otherwise, a warning is emitted if the tag is deprecated.
+    pub fn add_user_type(&mut self, name: &str, loc: ErrorLocation, state: &LState) -> Res<()> {
+        let Self::NoBase(base_decorations, _, None, _) = self else {
+            let res = self.add_type(loc.wrap(TypeName::TypeDef(name.to_owned(), None)));
+            if let Self::Base(ret) = self
+                && let TypeName::Enum(tag, underlying) = &mut ret.ty.base
+            {
+                *underlying = state.tags().underlying(tag);
+            }
+            return match state
+                .tags()
+                .find(name)
+                .and_then(|tag| tag.deprecated.as_ref())
+            {
+                Some(msg) => res.add_err(loc.warn(msg.to_owned())),
+                None => res,
+            };
+        };
+        let Some(aliased) = state.find_typedef(name) else {
+            return self
+                .add_type(loc.wrap(TypeName::TypeDef(name.to_owned(), None)))
+                .add_err(loc.fail(format!("Unknown type name {name}")));
+        };
+        let modifier = base_decorations
+            .iter()
+            .find(|dec| matches!(dec.as_value(), TypeDecorator::Modifiers(_)))
+            .map(|dec| {
+                dec.as_location()
+                    .fail(format!("Can't combine {} with typedef name {name}", dec.as_value()))
+            });
+        let res = self.add_type(
+            loc.wrap(TypeName::TypeDef(name.to_owned(), Some(Box::new(aliased.clone())))),
+        );
+        if let Some(err) = modifier {
+            res.add_err(err)
+        } else {
+            res
+        }
+    }
+}
+
+impl Type {
+    /// Returns the type with the typedef names replaced by the types they
+    /// alias, until the base isn't a typedef name.
+    pub fn fully_resolved(&self) -> Self {
+        let mut ty = self.resolved();
+        while matches!(ty.base, TypeName::TypeDef(_, Some(_))) {
+            ty = ty.resolved();
+        }
+        ty
+    }
+
+    /// Returns the type with the typedef name replaced by the type it aliases.
+    ///
+    /// The qualifiers applied to the typedef name, like in `const T`, are
+    /// applied to the outermost level of the aliased type, or to its elements
+    /// if it is an array.
+    pub fn resolved(&self) -> Self {
+        let TypeName::TypeDef(_, Some(aliased)) = &self.base else {
+            return self.clone();
+        };
+        let mut ty = (**aliased).clone();
+        ty.base_decorations
+            .extend(self.base_decorations.iter().copied());
+        let mut indirections = self.indirections.iter();
+        if let Some(first) = indirections.next() {
+            ty.indirections
+                .iter_mut()
+                .rev()
+                .find(|level| {
+                    !level.iter().any(|dec| {
+                        matches!(
+                            dec,
+                            IndirectionDecorator::Array(_) | IndirectionDecorator::Function(_)
+                        )
+                    })
+                })
+                .expect("the base is never an array nor a function")
+                .extend(first.iter().cloned());
+        }
+        ty.indirections.extend(indirections.cloned());
+        ty
+    }
+}
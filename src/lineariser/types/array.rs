@@ -0,0 +1,150 @@
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::{ArrayLength, IndirectionDecorator};
+use crate::lineariser::types::name::TypeName;
+use crate::utils::bset;
+
+impl Type {
+    /// Returns the length of the array, if the type is an array.
+    pub fn array_length(&self) -> Option<ArrayLength> {
+        if self.indirections.len() == 1
+            && let TypeName::TypeDef(_, Some(aliased)) = &self.base
+        {
+            aliased.array_length()
+        } else if self.indirections.len() == 1 {
+            None
+        } else {
+            level_length(self.indirections.last()?)
+        }
+    }
+
+    /// Returns the type of an expression of this type after the
+    /// array-to-pointer and function-to-pointer conversions: an array becomes
+    /// a pointer to its first element, a function a pointer to the function,
+    /// and other types are unchanged.
+    pub fn decayed(self) -> Self {
+        if self.is_function() {
+            return self.into_pointer();
+        }
+        if !self.is_array() {
+            return self;
+        }
+        let mut ty = if self.indirections.len() == 1 {
+            self.fully_resolved()
+        } else {
+            self
+        };
+        ty.indirections
+            .last_mut()
+            .expect("is an array")
+            .retain(|dec| !matches!(dec, IndirectionDecorator::Array(_)));
+        ty
+    }
+
+    /// Checks if the type is an array whose length, or the length of one of its
+    /// elements, is only known at runtime.
+    pub fn has_variable_length(&self) -> bool {
+        self.split_arrays()
+            .0
+            .iter()
+            .any(|len| matches!(len, ArrayLength::Unspecified | ArrayLength::Variable(_)))
+    }
+
+    /// Returns the type of a parameter declared as an array of this type: a
+    /// pointer to its elements, with the given qualifiers.
+    pub fn into_adjusted_parameter<I: IntoIterator<Item = IndirectionDecorator>>(
+        self,
+        decorators: I,
+    ) -> Self {
+        let mut ty = self.decayed();
+        ty.indirections
+            .last_mut()
+            .expect("always has a first")
+            .extend(decorators);
+        ty
+    }
+
+    /// Returns the type of an array of `length` elements of this type.
+    pub fn into_array(mut self, length: ArrayLength) -> Self {
+        self.indirections
+            .push(bset![IndirectionDecorator::Array(length)]);
+        self
+    }
+
+    /// Returns the type of an array of incomplete length, like `int[]`, once
+    /// its length is known from its initialiser.
+    pub fn into_completed(self, length: u64) -> Self {
+        let mut ty = if self.indirections.len() == 1 {
+            self.fully_resolved()
+        } else {
+            self
+        };
+        if let Some(level) = ty.indirections.last_mut() {
+            level.retain(|dec| !matches!(dec, IndirectionDecorator::Array(_)));
+            level.insert(IndirectionDecorator::Array(ArrayLength::Fixed(length)));
+        }
+        ty
+    }
+
+    /// Checks if the type is an array.
+    pub fn is_array(&self) -> bool {
+        self.array_length().is_some()
+    }
+
+    /// Splits an array type into the lengths of its dimensions, from the
+    /// outermost, and the type of its elements, like `[2, 3]` and `int` for
+    /// `int[2][3]`.
+    ///
+    /// Types that are not arrays have no dimensions.
+    pub fn split_arrays(&self) -> (Vec<ArrayLength>, Self) {
+        let mut lengths = vec![];
+        let mut ty = self.clone();
+        while let Some(len) = ty.array_length() {
+            lengths.push(len);
+            if ty.indirections.len() == 1 {
+                ty = ty.fully_resolved();
+            }
+            ty.indirections.pop();
+        }
+        (lengths, ty)
+    }
+
+    /// Returns the type of the subscript of an expression of this type by an
+    /// expression of type `index`.
+    ///
+    /// As `a[i]` is `*(a + i)`, the array or pointer can be on either side,
+    /// like in `1[a]`.
+    pub fn subscript(self, index: Self) -> Result<Self, String> {
+        if self == Self::empty() || index == Self::empty() {
+            return Ok(Self::empty());
+        }
+        let base = self.decayed();
+        let offset = index.decayed();
+        let (element, integer) = if let Some(element) = base.pointee() {
+            (element, offset)
+        } else if let Some(element) = offset.pointee() {
+            (element, base)
+        } else {
+            return Err(format!(
+                "Subscripted value of type {base} is neither an array nor a pointer"
+            ));
+        };
+        if integer.is_integer() {
+            Ok(element)
+        } else {
+            Err(format!("Array subscript of type {integer} is not an integer"))
+        }
+    }
+}
+
+/// Returns the length of the array if the level of indirection is an array.
+pub(super) fn level_length<'dec, I: IntoIterator<Item = &'dec IndirectionDecorator>>(
+    level: I,
+) -> Option<ArrayLength> {
+    level.into_iter().find_map(|dec| {
+        if let IndirectionDecorator::Array(len) = dec {
+            Some(*len)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,133 @@
+use crate::lineariser::types::name::TypeName;
+use crate::lineariser::types::{Type, UNSIGNED};
+use crate::target::{Layout, Scalar, TargetInfo};
+use crate::utils::bset;
+
+/// Standard integer types that can store a bit-precise integer, from the
+/// narrowest to the widest.
+const STORAGE: [Scalar; 5] = [
+    Scalar::Char,
+    Scalar::Short,
+    Scalar::Int,
+    Scalar::Long,
+    Scalar::LongLong,
+];
+
+/// Integer operand of the usual arithmetic conversions, after the integer
+/// promotions.
+struct Rank {
+    /// Checks if the type is a standard integer type, whose rank is greater
+    /// than the bit-precise integer type of the same width.
+    standard: bool,
+    /// Type of the operand after the integer promotions.
+    ty: Type,
+    /// Checks if the type is unsigned.
+    unsigned: bool,
+    /// Width of the type, sign bit included.
+    width: u64,
+}
+
+impl Type {
+    /// Returns the width and the signedness of a bit-precise integer type,
+    /// like `(8, true)` for `unsigned _BitInt(8)`.
+    pub(super) fn as_bit_int(&self) -> Option<(u64, bool)> {
+        if self.indirections.len() != 1 {
+            return None;
+        }
+        match &self.base {
+            TypeName::BitInt(width) => Some((*width, self.base_decorations.contains(&UNSIGNED))),
+            TypeName::TypeDef(_, aliased) => aliased.as_ref()?.as_bit_int(),
+            TypeName::BasicDataType(_)
+            | TypeName::Enum(..)
+            | TypeName::Struct(_)
+            | TypeName::Union(_) => None,
+        }
+    }
+
+    /// Returns the common type of two integer operands, one of them at least
+    /// being a bit-precise integer, after the usual arithmetic conversions.
+    ///
+    /// The bit-precise integers aren't promoted, and their rank grows with
+    /// their width, but is lower than the rank of a standard integer type of
+    /// the same width. Returns `None` if one of the operands isn't an integer.
+    ///
+    /// # Examples
+    ///
+    /// `_BitInt(3)` and `unsigned _BitInt(2)` give `_BitInt(3)`, and
+    /// `_BitInt(8)` and `short` give `int`.
+    pub(super) fn combine_bit_int(&self, other: &Self, target: &TargetInfo) -> Option<Self> {
+        let (left, right) = (self.rank(target)?, other.rank(target)?);
+        let (high, low) = if (left.width, left.standard) >= (right.width, right.standard) {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        let unsigned = high.unsigned || (low.unsigned && high.width <= low.width);
+        Some(if high.standard {
+            let mut ty = high.ty;
+            if unsigned {
+                ty.base_decorations.insert(UNSIGNED);
+            }
+            ty
+        } else {
+            Self::from_bit_int(high.width, unsigned)
+        })
+    }
+
+    /// Builds the type of a bit-precise integer, like `_BitInt(8)`.
+    pub(super) fn from_bit_int(width: u64, unsigned: bool) -> Self {
+        Self {
+            base: TypeName::BitInt(width),
+            base_decorations: if unsigned { bset![UNSIGNED] } else { bset![] },
+            indirections: vec![bset![]],
+        }
+    }
+
+    /// Returns the rank of an integer operand, after the integer promotions.
+    fn rank(&self, target: &TargetInfo) -> Option<Rank> {
+        if let Some((width, unsigned)) = self.as_bit_int() {
+            return Some(Rank { standard: false, ty: self.promoted(target), unsigned, width });
+        }
+        let ty = self.promoted(target);
+        let (scalar, unsigned) = ty.arithmetic_scalar(target)?;
+        STORAGE.contains(&scalar).then(|| Rank {
+            standard: true,
+            ty,
+            unsigned,
+            width: target.bits(scalar),
+        })
+    }
+
+    /// Returns the number of bits of the value of an arithmetic type, stored as
+    /// `scalar`.
+    ///
+    /// It is the width `N` of a `_BitInt(N)`, and the size of the scalar for
+    /// the other types.
+    pub(super) fn width(&self, scalar: Scalar, target: &TargetInfo) -> u64 {
+        self.as_bit_int()
+            .map_or_else(|| target.bits(scalar), |(width, _)| width)
+    }
+}
+
+/// Returns the layout of a bit-precise integer of the given width.
+///
+/// It is stored like the narrowest standard integer type that can hold it,
+/// or in an array of `long long` if it is wider than all of them.
+pub fn bit_int_layout(width: u64, target: &TargetInfo) -> Layout {
+    let widest = target.layout(Scalar::LongLong);
+    let bits = target.bits(Scalar::LongLong);
+    if width > bits {
+        Layout { align: widest.align, size: width.div_ceil(bits).saturating_mul(widest.size) }
+    } else {
+        target.layout(bit_int_scalar(width, target))
+    }
+}
+
+/// Returns the narrowest standard integer type that can store a bit-precise
+/// integer of the given width.
+pub fn bit_int_scalar(width: u64, target: &TargetInfo) -> Scalar {
+    STORAGE
+        .into_iter()
+        .find(|scalar| target.bits(*scalar) >= width)
+        .unwrap_or(Scalar::LongLong)
+}
@@ -0,0 +1,88 @@
+use crate::Res;
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::IndirectionDecorator;
+use crate::lineariser::types::function::Category;
+use crate::parser::api::Qualifiers;
+use crate::utils::repr_vec;
+
+impl Type {
+    /// Checks if a value of type `value` can be cast to this type, like in
+    /// `(char *)p`.
+    ///
+    /// A value can be cast to `void`, and a scalar to another scalar, except
+    /// between pointers and floating types. A warning is emitted if the cast
+    /// drops the qualifiers of the pointed type, like `const char *` to
+    /// `char *`, or goes between function and object pointers.
+    pub fn check_cast(&self, value: &Self, loc: ErrorLocation) -> Res<()> {
+        if self.is_void() {
+            return Res::ok(());
+        }
+        if self.is_array() || self.is_function() {
+            return Res::from_err(loc.fail(format!("Cast to non-scalar type {self}")));
+        }
+        let dest = self.fully_resolved();
+        let source = value.clone().decayed().fully_resolved();
+        match (dest.category(), source.category()) {
+            (Category::Unknown, _) | (_, Category::Unknown) => Res::ok(()),
+            (Category::Record(_) | Category::Void, _) =>
+                Res::from_err(loc.fail(format!("Cast to non-scalar type {self}"))),
+            (_, Category::Record(_)) =>
+                Res::from_err(loc.fail(format!("Cast from non-scalar type {value} to {self}"))),
+            (_, Category::Void) =>
+                Res::from_err(loc.fail(format!("Cast of void expression to {self}"))),
+            (Category::Pointer(_), Category::Arithmetic) if !source.is_integer() => Res::from_err(
+                loc.fail(format!("Cast from floating type {value} to pointer type {self}")),
+            ),
+            (Category::Arithmetic, Category::Pointer(_)) if !dest.is_integer() => Res::from_err(
+                loc.fail(format!("Cast from pointer type {value} to floating type {self}")),
+            ),
+            (Category::Pointer(expected), Category::Pointer(found)) => {
+                let mut res = Res::ok(());
+                let dropped: Vec<Qualifiers> = found
+                    .qualifiers()
+                    .into_iter()
+                    .filter(|qual| !expected.qualifiers().contains(qual))
+                    .collect();
+                if !dropped.is_empty() {
+                    res = res.add_err(loc.warn(format!(
+                        "Cast from {value} to {self} drops {} qualifier{} of the pointed type",
+                        repr_vec(&dropped, " "),
+                        if dropped.len() > 1 { "s" } else { "" }
+                    )));
+                }
+                if expected.is_function() != found.is_function() {
+                    res = res.add_err(loc.warn(format!(
+                        "Cast from {value} to {self} converts between function and object pointers"
+                    )));
+                }
+                res
+            }
+            (
+                Category::Arithmetic | Category::Pointer(_),
+                Category::Arithmetic | Category::Pointer(_),
+            ) => Res::ok(()),
+        }
+    }
+
+    /// Returns the `const` and `volatile` qualifiers of the outermost level of
+    /// the type, like `const` in `const int`.
+    fn qualifiers(&self) -> Vec<Qualifiers> {
+        self.fully_resolved()
+            .indirections
+            .last()
+            .into_iter()
+            .flatten()
+            .filter_map(|dec| match dec {
+                IndirectionDecorator::Qualifiers(
+                    qual @ (Qualifiers::Const | Qualifiers::Volatile),
+                ) => Some(*qual),
+                IndirectionDecorator::Array(_)
+                | IndirectionDecorator::Function(_)
+                | IndirectionDecorator::Restrict
+                | IndirectionDecorator::Qualifiers(Qualifiers::Constexpr | Qualifiers::Default) =>
+                    None,
+            })
+            .collect()
+    }
+}
@@ -1,42 +1,53 @@
 use crate::Res;
 use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::types::decorators::{IndirectionDecorator, TypeDecorator};
 use crate::lineariser::types::{CONST, Type, UNSIGNED};
-use crate::parser::api::{BasicDataType, UnaryOperator};
+use crate::parser::api::{BasicDataType, Storage, UnaryOperator};
 use crate::utils::bset;
 
 impl Type {
     /// Returns the output of a type when passed to a unary operator.
+    ///
+    /// Arrays are converted to pointers, except when taking their address.
     pub fn apply_unary(self, op: &Located<UnaryOperator>) -> Res<Self> {
         let loc = op.as_location();
+        let ty = if *op.as_value() == UnaryOperator::AddressOf {
+            self
+        } else {
+            self.decayed()
+        };
         match op.as_value() {
-            UnaryOperator::AddressOf => self.drop_const().indirection(true, loc),
-            UnaryOperator::Indirection => self.drop_const().indirection(false, loc),
-            UnaryOperator::LogicalNot => Res::ok(Self::from_base(BasicDataType::Bool.into())),
-            UnaryOperator::Minus => self.drop_const().drop_unsigned(loc),
+            UnaryOperator::AddressOf => ty.drop_const().indirection(true, loc),
+            UnaryOperator::Indirection => ty.drop_const().indirection(false, loc),
+            UnaryOperator::LogicalNot => Res::ok(Self::from_base(BasicDataType::Int.into())),
+            UnaryOperator::Minus => ty.drop_const().drop_unsigned(loc),
             UnaryOperator::Plus
             | UnaryOperator::PostfixDecrement
             | UnaryOperator::PostfixIncrement
             | UnaryOperator::PrefixDecrement
-            | UnaryOperator::PrefixIncrement => Res::ok(self.drop_const()),
-            UnaryOperator::BitwiseNot => Res::ok(self.drop_const()).and_then(|ty| {
-                if ty.indirections.len() > 1 {
-                    Res::ok(ty).add_err(
+            | UnaryOperator::PrefixIncrement => Res::ok(ty.drop_const()),
+            UnaryOperator::Alignof | UnaryOperator::Sizeof =>
+                unreachable!("computed from the layout of the operand"),
+            UnaryOperator::BitwiseNot => Res::ok(ty.drop_const()).and_then(|value_ty| {
+                if value_ty.indirections.len() > 1 {
+                    Res::ok(value_ty).add_err(
                         loc.suggest("Taking bitwise not of pointer is confusing".to_owned()),
                     )
                 } else {
-                    Res::ok(ty)
+                    Res::ok(value_ty)
                 }
             }),
         }
     }
 
     /// Drops the const qualifier, if present.
-    fn drop_const(mut self) -> Self {
-        self.indirections
+    fn drop_const(self) -> Self {
+        let mut ty = self.resolved_top();
+        ty.indirections
             .last_mut()
             .expect(">=1")
             .retain(|dec| *dec != CONST);
-        self
+        ty
     }
 
     /// Drops the unsigned modifier, if present.
@@ -50,6 +61,18 @@ impl Type {
         }
     }
 
+    /// Checks if an object declared with this type at block scope has static
+    /// storage duration, i.e., if it is declared `static`, `extern` or
+    /// `thread_local`.
+    pub fn has_static_storage(&self) -> bool {
+        self.base_decorations.iter().any(|dec| {
+            matches!(
+                dec,
+                TypeDecorator::Storage(Storage::Extern | Storage::Static | Storage::ThreadLocal)
+            )
+        })
+    }
+
     /// Adds or removes an indirection.
     fn indirection(mut self, add: bool, loc: ErrorLocation) -> Res<Self> {
         if add {
@@ -62,4 +85,43 @@ impl Type {
         }
         Res::ok(self)
     }
+
+    /// Checks if the type is declared `extern`.
+    pub fn is_extern(&self) -> bool {
+        self.base_decorations
+            .contains(&TypeDecorator::Storage(Storage::Extern))
+    }
+
+    /// Resolves the typedef name if it is the outermost level of the type,
+    /// to be able to modify that level.
+    fn resolved_top(self) -> Self {
+        if self.indirections.len() == 1 {
+            self.resolved()
+        } else {
+            self
+        }
+    }
+
+    /// Returns the type of the value of an lvalue of this type.
+    ///
+    /// The typedef names are resolved, and the qualifiers of the outermost
+    /// level and the storage classes are dropped.
+    pub fn unqualified(&self) -> Self {
+        let mut ty = self.fully_resolved();
+        ty.base_decorations
+            .retain(|dec| matches!(dec, TypeDecorator::Modifiers(_)));
+        if let Some(last) = ty.indirections.last_mut() {
+            last.retain(|dec| matches!(dec, IndirectionDecorator::Function(_)));
+        }
+        ty
+    }
+
+    /// Returns the type without its storage-class specifiers, like `const int`
+    /// for `static const int`.
+    pub fn without_storage(&self) -> Self {
+        let mut ty = self.clone();
+        ty.base_decorations
+            .retain(|dec| !matches!(dec, TypeDecorator::Storage(_)));
+        ty
+    }
 }
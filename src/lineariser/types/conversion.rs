@@ -0,0 +1,163 @@
+use crate::lexer::api::NumberType;
+use crate::lineariser::const_eval::value::common_type;
+use crate::lineariser::types::name::TypeName;
+use crate::lineariser::types::{LONG, LONG_LONG, Type, UNSIGNED};
+use crate::parser::api::BasicDataType;
+use crate::target::{Scalar, TargetInfo};
+
+impl Type {
+    /// Returns the type of an argument passed for the `...` of a variadic
+    /// function, after the default argument promotions: the integer
+    /// promotions, and `float` becomes `double`.
+    pub fn argument_promoted(&self, target: &TargetInfo) -> Self {
+        if self.number_type(target) == Some(NumberType::Float) {
+            Self::from_number_type(NumberType::Double)
+        } else {
+            self.promoted(target)
+        }
+    }
+
+    /// Returns the common real type of two arithmetic operands, after the
+    /// usual arithmetic conversions, or `None` if one of them isn't a real
+    /// arithmetic type.
+    ///
+    /// # Examples
+    ///
+    /// `char` and `unsigned` give `unsigned int`, and `long` and `float` give
+    /// `float`. See [`Type::combine_bit_int`] for the bit-precise integers.
+    pub fn combine(&self, other: &Self, target: &TargetInfo) -> Option<Self> {
+        if (self.as_bit_int().is_some() || other.as_bit_int().is_some())
+            && let Some(common) = self.combine_bit_int(other, target)
+        {
+            return Some(common);
+        }
+        let common = common_type(self.number_type(target)?, other.number_type(target)?, target);
+        Some(Self::from_number_type(common))
+    }
+
+    /// Returns the reason why an implicit conversion from this type to `to`
+    /// can change the value, if it can.
+    ///
+    /// Conversions between integers are lossy if they narrow the type or
+    /// change its sign, conversions from floating types to integers always
+    /// are, and conversions to floating types are if the mantissa is too
+    /// small.
+    pub fn conversion_loss(&self, to: &Self, target: &TargetInfo) -> Option<String> {
+        let (src, src_unsigned) = self.arithmetic_scalar(target)?;
+        let (dest, dest_unsigned) = to.arithmetic_scalar(target)?;
+        let (src_float, dest_float) = (is_floating(src), is_floating(dest));
+        let (src_bits, dest_bits) = (self.width(src, target), to.width(dest, target));
+        if src == Scalar::Bool || dest == Scalar::Bool {
+            None
+        } else if src_float && !dest_float {
+            Some("Converts floating to integer which is lossy".to_owned())
+        } else if dest_float {
+            let bits = if src_float {
+                mantissa(src, target)
+            } else {
+                src_bits.saturating_sub(u64::from(!src_unsigned))
+            };
+            (bits > mantissa(dest, target))
+                .then(|| format!("Converts {self} to {to} which loses precision"))
+        } else if dest_bits < src_bits {
+            Some(format!("Converts {self} to narrower type {to} which is lossy"))
+        } else if !src_unsigned && dest_unsigned {
+            Some("Converts signed to unsigned which is lossy".to_owned())
+        } else if src_unsigned && !dest_unsigned && dest_bits == src_bits {
+            Some("Converts unsigned to signed which is lossy".to_owned())
+        } else {
+            None
+        }
+    }
+
+    /// Builds the type of the values of a number type, like `unsigned long`.
+    pub(super) fn from_number_type(number: NumberType) -> Self {
+        let (basic, size, unsigned) = match number {
+            NumberType::BitInt | NumberType::Int => (BasicDataType::Int, None, false),
+            NumberType::Double => (BasicDataType::Double, None, false),
+            NumberType::Float => (BasicDataType::Float, None, false),
+            NumberType::Long => (BasicDataType::Int, Some(LONG), false),
+            NumberType::LongDouble => (BasicDataType::Double, Some(LONG), false),
+            NumberType::LongLong => (BasicDataType::Int, Some(LONG_LONG), false),
+            NumberType::UBitInt | NumberType::UInt => (BasicDataType::Int, None, true),
+            NumberType::ULong => (BasicDataType::Int, Some(LONG), true),
+            NumberType::ULongLong => (BasicDataType::Int, Some(LONG_LONG), true),
+        };
+        let mut ty = Self::from_base(TypeName::BasicDataType(basic));
+        ty.base_decorations.extend(size);
+        if unsigned {
+            ty.base_decorations.insert(UNSIGNED);
+        }
+        ty
+    }
+
+    /// Checks if a value of this type must be converted to be used as a value
+    /// of type `to`, i.e., if the representation of the value changes.
+    ///
+    /// This is the case between arithmetic types of different sizes or
+    /// signedness, from a null pointer constant to a pointer, from a pointer
+    /// to `bool`, and between pointers to different types.
+    pub fn needs_conversion(&self, to: &Self, null: bool, target: &TargetInfo) -> bool {
+        match (self.arithmetic_scalar(target), to.arithmetic_scalar(target)) {
+            (Some(src), Some(dest)) => src != dest,
+            (Some(_), None) => null && to.pointee().is_some(),
+            (None, Some((dest, _))) => dest == Scalar::Bool && self.pointee().is_some(),
+            (None, None) => match (self.clone().decayed().pointee(), to.pointee()) {
+                (Some(src), Some(dest)) => src.unqualified() != dest.unqualified(),
+                (Some(_) | None, _) => false,
+            },
+        }
+    }
+
+    /// Returns the type of an arithmetic value after the integer promotions,
+    /// as a number type.
+    ///
+    /// `bool`, `char`, `short` and the enums become `int`, or `unsigned int`
+    /// if `int` can't represent all their values.
+    fn number_type(&self, target: &TargetInfo) -> Option<NumberType> {
+        let (scalar, unsigned) = self.arithmetic_scalar(target)?;
+        Some(match (scalar, unsigned) {
+            (Scalar::Bool | Scalar::Char | Scalar::Short, _)
+                if target.bits(scalar) < target.bits(Scalar::Int) =>
+                NumberType::Int,
+            (Scalar::Bool | Scalar::Char | Scalar::Int | Scalar::Short, false) => NumberType::Int,
+            (Scalar::Bool | Scalar::Char | Scalar::Int | Scalar::Short, true) => NumberType::UInt,
+            (Scalar::Double, _) => NumberType::Double,
+            (Scalar::Float, _) => NumberType::Float,
+            (Scalar::Long, false) => NumberType::Long,
+            (Scalar::Long, true) => NumberType::ULong,
+            (Scalar::LongDouble, _) => NumberType::LongDouble,
+            (Scalar::LongLong, false) => NumberType::LongLong,
+            (Scalar::LongLong, true) => NumberType::ULongLong,
+            (Scalar::Pointer, _) => return None,
+        })
+    }
+
+    /// Returns the type of an arithmetic value after the integer promotions.
+    ///
+    /// The bit-precise integers and the other types are returned without their
+    /// qualifiers.
+    pub fn promoted(&self, target: &TargetInfo) -> Self {
+        if let Some((width, unsigned)) = self.as_bit_int() {
+            return Self::from_bit_int(width, unsigned);
+        }
+        self.number_type(target)
+            .filter(|ty| ty.is_int())
+            .map_or_else(|| self.unqualified(), Self::from_number_type)
+    }
+}
+
+/// Checks if a scalar is a real floating type.
+const fn is_floating(scalar: Scalar) -> bool {
+    matches!(scalar, Scalar::Double | Scalar::Float | Scalar::LongDouble)
+}
+
+/// Returns the number of bits of the mantissa of a floating type, including
+/// the implicit bit.
+const fn mantissa(scalar: Scalar, target: &TargetInfo) -> u64 {
+    match target.bits(scalar) {
+        32 => 24,
+        64 => 53,
+        _ => 64,
+    }
+}
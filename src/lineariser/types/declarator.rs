@@ -0,0 +1,79 @@
+use crate::Res;
+use crate::errors::api::Located;
+use crate::lineariser::state::LState;
+use crate::lineariser::types::array::level_length;
+use crate::lineariser::types::decorators::{ArrayLength, IndirectionDecorator};
+use crate::lineariser::types::function::level_signature;
+use crate::lineariser::types::state::TypeParsingState;
+use crate::lineariser::types::{ReturnType, Type};
+use crate::parser::api::Attribute;
+use crate::utils::{bset, repr_vec};
+
+impl Type {
+    /// Returns the abstract declarator of the type, like `*[3]` in `int *[3]`,
+    /// `(*)[3]` in `int (*)[3]` or `(*)(int)` in `int (*)(int)`.
+    pub(super) fn abstract_declarator(&self) -> String {
+        let mut declarator = String::new();
+        for level in self.indirections.iter().skip(1).rev() {
+            let quals = level
+                .iter()
+                .filter(|dec| {
+                    !matches!(
+                        dec,
+                        IndirectionDecorator::Array(_) | IndirectionDecorator::Function(_)
+                    )
+                })
+                .collect::<Vec<_>>();
+            let quals_repr = repr_vec(quals.iter().copied(), " ");
+            if let Some(signature) = level_signature(level) {
+                if declarator.starts_with('*') {
+                    declarator = format!("({declarator})");
+                }
+                declarator.push('(');
+                declarator.push_str(&signature.to_string());
+                declarator.push(')');
+            } else if let Some(len) = level_length(level) {
+                if declarator.starts_with('*') {
+                    declarator = format!("({declarator})");
+                }
+                let sep = if quals.is_empty() || len == ArrayLength::Incomplete {
+                    ""
+                } else {
+                    " "
+                };
+                declarator = format!("{declarator}[{quals_repr}{sep}{len}]");
+            } else {
+                let mut pointer = "*".to_owned();
+                if !quals.is_empty() {
+                    pointer.push(' ');
+                    pointer.push_str(&quals_repr);
+                }
+                if !declarator.is_empty()
+                    && !declarator.starts_with('[')
+                    && (!declarator.starts_with('(') || declarator.starts_with("(*"))
+                {
+                    pointer.push(' ');
+                }
+                pointer.push_str(&declarator);
+                declarator = pointer;
+            }
+        }
+        declarator
+    }
+    /// Returns the type of a pointer to this type, with the pointers and their
+    /// qualifiers of a declarator in parenthesis, like `* const` in `int (*
+    /// const cb)(int)`.
+    pub fn with_pointers(self, pointers: &[Located<Attribute>], state: &LState) -> Res<Self> {
+        let mut parsing = TypeParsingState::Base(ReturnType { attrs: bset![], ty: self });
+        let mut errors = vec![];
+        for pointer in pointers {
+            parsing
+                .add_attribute(pointer, state)
+                .store_errors(&mut |err| errors.push(err));
+        }
+        let TypeParsingState::Base(ret) = parsing else {
+            unreachable!("the base was already found")
+        };
+        Res::ok(ret.ty).add_errs(errors)
+    }
+}
@@ -1,6 +1,32 @@
+use crate::lineariser::types::function::Signature;
 use crate::parser::api::{Modifiers, Qualifiers, Storage};
 use crate::utils::{display, from};
 
+/// Number of elements of an array type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ArrayLength {
+    /// Length known at compile time, like in `int a[10]`.
+    Fixed(u64),
+    /// Length not given, like in `extern int a[]`.
+    Incomplete,
+    /// Variable length of unspecified size, like in `void f(int a[*])`.
+    Unspecified,
+    /// Variable length array, whose length is the value of the given id.
+    Variable(usize),
+}
+
+display!(
+    ArrayLength,
+    self,
+    f,
+    match self {
+        Self::Fixed(len) => len.fmt(f),
+        Self::Incomplete => Ok(()),
+        Self::Unspecified => "*".fmt(f),
+        Self::Variable(id) => write!(f, "x{id}"),
+    }
+);
+
 /// Attributes that are only valid on function return types.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum FunctionAttribute {
@@ -23,8 +49,13 @@ display!(
 
 /// All qualifiers, modifiers, etc. that can be added around an indirection or
 /// type name.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum IndirectionDecorator {
+    /// Array derivation: the level is an array and not a pointer.
+    Array(ArrayLength),
+    /// Function derivation: the level is a function with these parameters,
+    /// returning the type of the previous level, and not a pointer.
+    Function(Signature),
     /// cf. [`Qualifiers`]
     Qualifiers(Qualifiers),
     /// Restrict keyword
@@ -36,6 +67,8 @@ display!(
     self,
     f,
     match self {
+        Self::Array(len) => write!(f, "[{len}]"),
+        Self::Function(signature) => write!(f, "({signature})"),
         Self::Qualifiers(qual) => qual.fmt(f),
         Self::Restrict => "restrict".fmt(f),
     }
@@ -66,5 +99,22 @@ display!(
     }
 );
 
+impl TypeDecorator {
+    /// Checks if the decorator implies an `int` when the type name is omitted,
+    /// like in `unsigned x` or `long y`.
+    pub const fn implies_int(self) -> bool {
+        matches!(
+            self,
+            Self::Modifiers(
+                Modifiers::Long
+                    | Modifiers::LongLong
+                    | Modifiers::Short
+                    | Modifiers::Signed
+                    | Modifiers::Unsigned
+            )
+        )
+    }
+}
+
 from!(Modifiers TypeDecorator);
 from!(Storage TypeDecorator);
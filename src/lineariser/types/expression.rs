@@ -0,0 +1,100 @@
+//! Computes the type of an expression without evaluating it.
+
+use crate::lineariser::const_eval::is_null_constant;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::FunctionBuilder;
+use crate::lineariser::types::Type;
+use crate::lineariser::walk::generic::select_association;
+use crate::lineariser::walk::sizeof::eval_type_operator;
+use crate::parser::api::{Ast, Binary, BinaryOperator, Literal, Ternary, Unary};
+
+impl Type {
+    /// Returns the type of an expression, without evaluating it.
+    ///
+    /// This is used for the operands that are never evaluated, like the
+    /// controlling expression of `_Generic`. Returns `None` if the type can't
+    /// be found, for instance on undeclared variables.
+    pub fn of_expression(ast: &Ast, state: &LState) -> Option<Self> {
+        match ast {
+            Ast::Binary(Binary { op, arg_l, arg_r: member })
+                if matches!(
+                    op.as_value(),
+                    BinaryOperator::StructEnumMemberAccess
+                        | BinaryOperator::StructEnumMemberPointerAccess
+                ) =>
+            {
+                let Ast::Variable(var) = &**member else {
+                    return None;
+                };
+                let base = Self::of_expression(arg_l, state)?;
+                let name = var.as_user_defined_name()?;
+                state
+                    .find_member(&base, *op.as_value(), name)
+                    .ok()
+                    .map(|(_, ty)| ty)
+            }
+            Ast::Binary(Binary { op, arg_r, .. }) if *op.as_value() == BinaryOperator::Comma =>
+                Self::of_expression(arg_r, state).map(Self::decayed),
+            Ast::Binary(Binary { op, arg_l, arg_r })
+                if *op.as_value() == BinaryOperator::ArraySubscript =>
+                Self::of_expression(arg_l, state)?
+                    .subscript(Self::of_expression(arg_r, state)?)
+                    .ok(),
+            Ast::Binary(Binary { op, arg_l, .. }) if op.as_value().is_assignment() =>
+                Some(Self::of_expression(arg_l, state)?.unqualified()),
+            Ast::Binary(Binary { op, arg_l, arg_r }) => Self::binary_operands(
+                *op.as_value(),
+                &Self::of_expression(arg_l, state)?,
+                &Self::of_expression(arg_r, state)?,
+                (is_null_constant(arg_l, state), is_null_constant(arg_r, state)),
+                state.target(),
+            )
+            .ok()
+            .map(|operands| operands.result),
+            Ast::Cast(cast) => Self::from_attributes(&cast.dest_type, state).into_parts().0,
+            Ast::FunctionCall(call) if call.function_body.is_none() =>
+                Self::of_expression(&call.callee, state)?
+                    .decayed()
+                    .pointee()?
+                    .return_type(),
+            Ast::Generic(generic) => {
+                let index = select_association(generic, state).into_parts().0?;
+                Self::of_expression(&generic.associations.get(index)?.value, state)
+            }
+            Ast::Leaf(lit) => Some(Self::from_lit(lit.as_value())),
+            Ast::ParensBlock(parens) => Self::of_expression(parens.as_value(), state),
+            Ast::Ternary(Ternary { success, failure: Some((_, failure)), .. }) =>
+                Self::conditional_result(
+                    &Self::of_expression(success, state)?,
+                    &Self::of_expression(failure, state)?,
+                    (is_null_constant(success, state), is_null_constant(failure, state)),
+                    state.target(),
+                )
+                .ok(),
+            Ast::Unary(Unary { arg, op }) if op.as_value().is_type_operator() =>
+                eval_type_operator(op, arg, state)
+                    .ok()
+                    .map(|number| Self::from_lit(&Literal::Number(number))),
+            Ast::Unary(Unary { arg, op }) =>
+                Self::of_expression(arg, state)?
+                    .apply_unary(op)
+                    .into_parts()
+                    .0,
+            Ast::Variable(var) => {
+                let name = var.as_user_defined_name()?;
+                state.find_declaration(name).map_or_else(
+                    || state.find_function(name).map(FunctionBuilder::ty),
+                    |decl| Some(decl.metadata.ty.clone()),
+                )
+            }
+            Ast::CompoundLiteral(lit) => lit.ty(state).ok(),
+            Ast::BracedBlock(_)
+            | Ast::ControlFlow(_)
+            | Ast::Empty
+            | Ast::FunctionArgsBuild(..)
+            | Ast::FunctionCall(_)
+            | Ast::ListInitialiser(_)
+            | Ast::Ternary(_) => None,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use core::cmp::Ordering;
+
+use crate::Res;
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::IndirectionDecorator;
+use crate::lineariser::types::name::TypeName;
+use crate::parser::api::BasicDataType;
+use crate::utils::{bset, display, repr_vec};
+
+/// Parameters of a function type, like `(int, char *, ...)` in
+/// `int (*)(int, char *, ...)`.
+///
+/// Two signatures are equal if they have the same parameters, whether they are
+/// unprototyped or not.
+#[derive(Debug, Clone)]
+pub struct Signature {
+    /// Types of the parameters, without their qualifiers.
+    params: Vec<Type>,
+    /// Whether the parameter list is empty, like in `int f()`. Since C23, this
+    /// declares a function without parameters, as `int f(void)`, but the type
+    /// is displayed as written.
+    unprototyped: bool,
+    /// Whether the function takes a variable number of arguments after its
+    /// parameters, like `printf`.
+    variadic: bool,
+}
+
+impl Signature {
+    /// Checks if a call with `count` arguments matches the number of
+    /// parameters.
+    pub const fn accepts_count(&self, count: usize) -> bool {
+        count == self.params.len() || (self.variadic && count > self.params.len())
+    }
+
+    /// Builds the signature of a function with the given parameter types.
+    pub fn new<I: IntoIterator<Item = Type>>(params: I, variadic: bool) -> Self {
+        Self {
+            params: params.into_iter().map(|ty| ty.unqualified()).collect(),
+            unprototyped: false,
+            variadic,
+        }
+    }
+
+    /// Returns the types of the parameters.
+    pub fn params(&self) -> &[Type] {
+        &self.params
+    }
+
+    /// Marks the signature as unprototyped if the parameter list was empty,
+    /// like in `int f()`.
+    #[must_use]
+    pub const fn with_unprototyped(mut self, unprototyped: bool) -> Self {
+        self.unprototyped = unprototyped && self.params.is_empty() && !self.variadic;
+        self
+    }
+}
+
+impl PartialEq for Signature {
+    fn eq(&self, other: &Self) -> bool {
+        self.params == other.params && self.variadic == other.variadic
+    }
+}
+
+impl Eq for Signature {}
+
+impl PartialOrd for Signature {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Signature {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.params, self.variadic).cmp(&(&other.params, other.variadic))
+    }
+}
+
+display!(Signature, self, f, {
+    repr_vec(&self.params, ", ").fmt(f)?;
+    match (self.variadic, self.params.is_empty()) {
+        (true, true) => "...".fmt(f),
+        (true, false) => ", ...".fmt(f),
+        (false, true) if self.unprototyped => Ok(()),
+        (false, true) => "void".fmt(f),
+        (false, false) => Ok(()),
+    }
+});
+
+/// Context of an implicit conversion as if by assignment, that decides the
+/// error messages.
+#[derive(Debug, Clone, Copy)]
+pub enum Assignment {
+    /// Argument passed for a parameter of a function.
+    Argument,
+    /// Initial value of a declaration.
+    Initialisation,
+    /// Value returned by a function.
+    Return,
+    /// Assignment operator `=`.
+    Simple,
+}
+
+impl Assignment {
+    /// Returns the error message for a value of incompatible type.
+    fn incompatible(self, dest: &Type, value: &Type) -> String {
+        match self {
+            Self::Argument => format!("Passing {value} to parameter of incompatible type {dest}"),
+            Self::Initialisation =>
+                format!("Initialising {dest} with an expression of incompatible type {value}"),
+            Self::Return =>
+                format!("Returning {value} from a function with incompatible result type {dest}"),
+            Self::Simple => format!("Assigning to {dest} from incompatible type {value}"),
+        }
+    }
+
+    /// Returns the warning message for a pointer to an incompatible type.
+    fn incompatible_pointers(self, dest: &Type, value: &Type) -> String {
+        match self {
+            Self::Argument =>
+                format!("Incompatible pointer types passing {value} to parameter of type {dest}"),
+            Self::Initialisation => format!(
+                "Incompatible pointer types initialising {dest} with an expression of type {value}"
+            ),
+            Self::Return => format!(
+                "Incompatible pointer types returning {value} from a function with result type {dest}"
+            ),
+            Self::Simple => format!("Incompatible pointer types assigning to {dest} from {value}"),
+        }
+    }
+}
+
+/// Kind of a type, that decides which values can be passed for a parameter.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Category<'ty> {
+    /// Integer or floating type.
+    Arithmetic,
+    /// Pointer to the given type.
+    Pointer(Type),
+    /// Struct or union.
+    Record(&'ty TypeName),
+    /// Type that couldn't be found, already reported.
+    Unknown,
+    /// `void`
+    Void,
+}
+
+impl Type {
+    /// Returns the kind of a type, after the conversions of arrays and
+    /// functions to pointers.
+    pub(super) fn category(&self) -> Category<'_> {
+        if *self == Self::empty() {
+            return Category::Unknown;
+        }
+        if let Some(pointee) = self.pointee() {
+            return Category::Pointer(pointee);
+        }
+        match &self.base {
+            TypeName::BasicDataType(BasicDataType::Void) => Category::Void,
+            TypeName::BasicDataType(_) | TypeName::BitInt(_) | TypeName::Enum(..) =>
+                Category::Arithmetic,
+            TypeName::Struct(_) | TypeName::Union(_) => Category::Record(&self.base),
+            TypeName::TypeDef(..) => Category::Unknown,
+        }
+    }
+
+    /// Checks if a value of type `value` can be converted to this type as if
+    /// by assignment, like an argument passed for a parameter of this type.
+    ///
+    /// An integer can only be converted to a pointer if it is a null pointer
+    /// constant, like `0`, and a pointer can only be converted to `bool`.
+    pub fn check_assignment(
+        &self,
+        value: &Self,
+        null: bool,
+        loc: ErrorLocation,
+        context: Assignment,
+    ) -> Res<()> {
+        let dest = self.clone().decayed().fully_resolved();
+        let source = value.clone().decayed().fully_resolved();
+        let incompatible = || Res::from_err(loc.fail(context.incompatible(self, value)));
+        match (dest.category(), source.category()) {
+            (Category::Unknown, _)
+            | (_, Category::Unknown)
+            | (Category::Arithmetic, Category::Arithmetic) => Res::ok(()),
+            (Category::Pointer(_), Category::Arithmetic) if null && source.is_integer() =>
+                Res::ok(()),
+            (Category::Arithmetic, Category::Pointer(_))
+                if dest.base == TypeName::BasicDataType(BasicDataType::Bool) =>
+                Res::ok(()),
+            (Category::Pointer(expected), Category::Pointer(found)) => {
+                let expected_ty = expected.unqualified();
+                let found_ty = found.unqualified();
+                if expected_ty == found_ty
+                    || expected_ty.category() == Category::Void
+                    || found_ty.category() == Category::Void
+                {
+                    Res::ok(())
+                } else {
+                    Res::ok(()).add_err(loc.warn(context.incompatible_pointers(self, value)))
+                }
+            }
+            (Category::Record(expected), Category::Record(found)) if expected == found =>
+                Res::ok(()),
+            (
+                Category::Arithmetic | Category::Pointer(_) | Category::Record(_) | Category::Void,
+                Category::Arithmetic | Category::Pointer(_) | Category::Record(_) | Category::Void,
+            ) => incompatible(),
+        }
+    }
+
+    /// Returns the type of a function with the given signature, that returns
+    /// values of this type.
+    pub fn into_function(mut self, signature: Signature) -> Self {
+        self.indirections
+            .push(bset![IndirectionDecorator::Function(signature)]);
+        self
+    }
+
+    /// Checks if the type is a function.
+    pub fn is_function(&self) -> bool {
+        self.signature().is_some()
+    }
+
+    /// Checks if the type is `void`, ignoring its qualifiers.
+    pub fn is_void(&self) -> bool {
+        self.fully_resolved().category() == Category::Void
+    }
+
+    /// Returns the type of the values returned by a function of this type.
+    pub fn return_type(&self) -> Option<Self> {
+        let mut ty = if self.indirections.len() == 1 {
+            self.fully_resolved()
+        } else {
+            self.clone()
+        };
+        level_signature(ty.indirections.last()?)?;
+        ty.indirections.pop();
+        Some(ty)
+    }
+
+    /// Returns the parameters of the function, if the type is a function.
+    pub fn signature(&self) -> Option<&Signature> {
+        if self.indirections.len() == 1
+            && let TypeName::TypeDef(_, Some(aliased)) = &self.base
+        {
+            aliased.signature()
+        } else if self.indirections.len() == 1 {
+            None
+        } else {
+            level_signature(self.indirections.last()?)
+        }
+    }
+}
+
+/// Returns the parameters of the function if the level of indirection is a
+/// function.
+pub(super) fn level_signature<'dec, I: IntoIterator<Item = &'dec IndirectionDecorator>>(
+    level: I,
+) -> Option<&'dec Signature> {
+    level.into_iter().find_map(|dec| {
+        if let IndirectionDecorator::Function(signature) = dec {
+            Some(signature)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,252 @@
+use crate::lineariser::state::tags::Tags;
+use crate::lineariser::types::bit_int::{bit_int_layout, bit_int_scalar};
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::lineariser::types::name::TypeName;
+use crate::lineariser::types::{COMPLEX, LONG, LONG_LONG, Type, UNSIGNED};
+use crate::parser::api::{BasicDataType, Modifiers, UserDefinedTypes};
+use crate::target::{Layout, Scalar, TargetInfo};
+use crate::utils::bset;
+
+/// Number of bits in a byte.
+const BYTE: u64 = 8;
+
+/// Incremental computation of the layout of a struct or a union.
+///
+/// Members are placed one after the other, each at the first offset that
+/// respects its alignment. Bitfields are packed in the storage unit of their
+/// type, and start a new unit when they don't fit in the current one.
+#[derive(Debug)]
+pub struct RecordLayout {
+    /// Alignment of the record, i.e., the largest alignment of its members.
+    align: u64,
+    /// Number of bits used by the members pushed so far.
+    bits: u64,
+    /// Whether the record is a union: all the members start at offset 0.
+    union: bool,
+}
+
+impl RecordLayout {
+    /// Returns the final layout of the record, padded to its alignment.
+    pub const fn finish(self) -> Layout {
+        Layout { align: self.align, size: align_up(self.bits.div_ceil(BYTE), self.align) }
+    }
+
+    /// Starts the layout of a struct or a union.
+    pub const fn new(kind: UserDefinedTypes) -> Self {
+        Self { align: 1, bits: 0, union: matches!(kind, UserDefinedTypes::Union) }
+    }
+
+    /// Places a member in the record, and returns its offset in bytes.
+    ///
+    /// The `width` is the number of bits of a bitfield. The offset of a
+    /// bitfield is the offset of its storage unit. A member of size 0, like a
+    /// flexible array member, only aligns the next offset.
+    pub fn push(&mut self, member: Layout, width: Option<u64>) -> u64 {
+        self.align = self.align.max(member.align);
+        let unit = member.size.saturating_mul(BYTE).max(1);
+        let start = if self.union {
+            0
+        } else {
+            match width {
+                None => align_up(self.bits, member.align.saturating_mul(BYTE)),
+                Some(bits)
+                    if bits == 0
+                        || self
+                            .bits
+                            .checked_rem(unit)
+                            .unwrap_or_default()
+                            .saturating_add(bits)
+                            > unit =>
+                    align_up(self.bits, unit),
+                Some(_) => self.bits,
+            }
+        };
+        let end = start.saturating_add(width.unwrap_or_else(|| member.size.saturating_mul(BYTE)));
+        self.bits = self.bits.max(end);
+        match width {
+            None => start.checked_div(BYTE).unwrap_or_default(),
+            Some(_) => start
+                .checked_div(unit)
+                .unwrap_or_default()
+                .saturating_mul(member.size),
+        }
+    }
+}
+
+impl Type {
+    /// Returns the scalar of an arithmetic type and whether it is unsigned, or
+    /// `None` if the type isn't a real arithmetic type.
+    pub fn arithmetic_scalar(&self, target: &TargetInfo) -> Option<(Scalar, bool)> {
+        if self.indirections.len() != 1 || self.base_decorations.contains(&COMPLEX) {
+            return None;
+        }
+        let unsigned = self.base_decorations.contains(&UNSIGNED);
+        match &self.base {
+            TypeName::BasicDataType(basic) => match basic {
+                BasicDataType::Bool => Some((Scalar::Bool, true)),
+                BasicDataType::Char => Some((
+                    Scalar::Char,
+                    unsigned
+                        || (!target.char_signed
+                            && !self.base_decorations.contains(&Modifiers::Signed.into())),
+                )),
+                BasicDataType::Double if self.base_decorations.contains(&LONG) =>
+                    Some((Scalar::LongDouble, false)),
+                BasicDataType::Double => Some((Scalar::Double, false)),
+                BasicDataType::Float => Some((Scalar::Float, false)),
+                BasicDataType::Int | BasicDataType::BigInt => Some((self.int_scalar(), unsigned)),
+                BasicDataType::Decimal32
+                | BasicDataType::Decimal64
+                | BasicDataType::Decimal128
+                | BasicDataType::Void => None,
+            },
+            TypeName::BitInt(width) => Some((bit_int_scalar(*width, target), unsigned)),
+            TypeName::Enum(_, scalar) => Some((*scalar, false)),
+            TypeName::TypeDef(_, aliased) => aliased.as_ref()?.arithmetic_scalar(target),
+            TypeName::Struct(_) | TypeName::Union(_) => None,
+        }
+    }
+
+    /// Returns the kind and the name of the struct, union or enum, if the type
+    /// is one.
+    pub fn as_tag(&self) -> Option<(UserDefinedTypes, &str)> {
+        if self.indirections.len() != 1 {
+            return None;
+        }
+        match &self.base {
+            TypeName::Enum(name, _) => Some((UserDefinedTypes::Enum, name)),
+            TypeName::Struct(name) => Some((UserDefinedTypes::Struct, name)),
+            TypeName::TypeDef(_, Some(aliased)) => aliased.as_tag(),
+            TypeName::Union(name) => Some((UserDefinedTypes::Union, name)),
+            TypeName::BasicDataType(_) | TypeName::BitInt(_) | TypeName::TypeDef(_, None) => None,
+        }
+    }
+
+    /// Creates the type of a struct, union or enum.
+    pub fn from_tag(kind: UserDefinedTypes, name: String, tags: &Tags) -> Self {
+        Self::from_base(match kind {
+            UserDefinedTypes::Enum => {
+                let underlying = tags.underlying(&name);
+                TypeName::Enum(name, underlying)
+            }
+            UserDefinedTypes::Struct => TypeName::Struct(name),
+            UserDefinedTypes::Union => TypeName::Union(name),
+        })
+    }
+
+    /// Returns the scalar of an integer, depending on its size modifiers.
+    fn int_scalar(&self) -> Scalar {
+        if self.base_decorations.contains(&LONG_LONG) {
+            Scalar::LongLong
+        } else if self.base_decorations.contains(&LONG) {
+            Scalar::Long
+        } else if self.base_decorations.contains(&Modifiers::Short.into()) {
+            Scalar::Short
+        } else {
+            Scalar::Int
+        }
+    }
+
+    /// Returns the type of a pointer to the current type.
+    pub fn into_pointer(mut self) -> Self {
+        self.indirections.push(bset![]);
+        self
+    }
+
+    /// Checks if an object of this type can't be defined because the type is
+    /// incomplete, like `void`, `int[]` or a struct that is only declared.
+    ///
+    /// Variable-length arrays can be defined, and the unknown typedef names
+    /// are reported when the type is built.
+    pub fn is_incomplete(&self, target: &TargetInfo, tags: &Tags) -> bool {
+        let (lengths, element) = self.split_arrays();
+        lengths
+            .iter()
+            .any(|len| matches!(len, ArrayLength::Incomplete | ArrayLength::Unspecified))
+            || (!matches!(element.fully_resolved().base, TypeName::TypeDef(_, None))
+                && element.layout(target, tags).is_none())
+    }
+
+    /// Checks if the type is an integer, i.e., can be used for bitfields.
+    pub fn is_integer(&self) -> bool {
+        self.indirections.len() == 1
+            && match &self.base {
+                TypeName::BasicDataType(basic) => basic.is_integer(),
+                TypeName::BitInt(_) | TypeName::Enum(..) => true,
+                TypeName::TypeDef(_, Some(aliased)) => aliased.is_integer(),
+                TypeName::Struct(_) | TypeName::TypeDef(_, None) | TypeName::Union(_) => false,
+            }
+    }
+
+    /// Returns the size and alignment of the type, or `None` if the type is
+    /// incomplete.
+    ///
+    /// Arrays of unknown or variable length and functions have no layout.
+    pub fn layout(&self, target: &TargetInfo, tags: &Tags) -> Option<Layout> {
+        if self.is_function() {
+            return None;
+        }
+        if self.is_array() {
+            let (lengths, element) = self.split_arrays();
+            let layout = element.layout(target, tags)?;
+            let mut size = layout.size;
+            for len in lengths {
+                let ArrayLength::Fixed(nb) = len else {
+                    return None;
+                };
+                size = size.saturating_mul(nb);
+            }
+            return Some(Layout { align: layout.align, size });
+        }
+        if self.indirections.len() > 1 {
+            return Some(target.layout(Scalar::Pointer));
+        }
+        let scalar = match &self.base {
+            TypeName::BasicDataType(basic) => match basic {
+                BasicDataType::Bool => Scalar::Bool,
+                BasicDataType::Char => Scalar::Char,
+                BasicDataType::Decimal32 => return Some(Layout::natural(4)),
+                BasicDataType::Decimal64 => return Some(Layout::natural(8)),
+                BasicDataType::Decimal128 => return Some(Layout::natural(16)),
+                BasicDataType::Double if self.base_decorations.contains(&LONG) =>
+                    Scalar::LongDouble,
+                BasicDataType::Double => Scalar::Double,
+                BasicDataType::Float => Scalar::Float,
+                BasicDataType::Int | BasicDataType::BigInt => self.int_scalar(),
+                BasicDataType::Void => return None,
+            },
+            TypeName::BitInt(width) => return Some(bit_int_layout(*width, target)),
+            TypeName::Enum(_, scalar) => *scalar,
+            TypeName::Struct(name) | TypeName::Union(name) => return tags.find(name)?.layout,
+            TypeName::TypeDef(_, aliased) => return aliased.as_ref()?.layout(target, tags),
+        };
+        let layout = target.layout(scalar);
+        Some(if self.base_decorations.contains(&COMPLEX) {
+            Layout { align: layout.align, size: layout.size.saturating_mul(2) }
+        } else {
+            layout
+        })
+    }
+
+    /// Returns the type pointed to, if the type is a pointer.
+    ///
+    /// The typedef name is kept if the pointer was built on top of it, like in
+    /// `T *`.
+    pub fn pointee(&self) -> Option<Self> {
+        let mut ty = if self.indirections.len() > 1 {
+            self.clone()
+        } else {
+            self.resolved()
+        };
+        (ty.indirections.len() > 1).then(|| {
+            ty.indirections.pop();
+            ty
+        })
+    }
+}
+
+/// Rounds `value` up to the next multiple of `align`.
+const fn align_up(value: u64, align: u64) -> u64 {
+    let step = if align == 0 { 1 } else { align };
+    value.div_ceil(step).saturating_mul(step)
+}
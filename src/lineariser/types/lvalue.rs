@@ -0,0 +1,191 @@
+use crate::errors::api::{CompileError, ErrorLocation};
+use crate::lineariser::state::LState;
+use crate::lineariser::types::decorators::IndirectionDecorator;
+use crate::lineariser::types::{CONST, Type};
+use crate::lineariser::walk::generic::select_association;
+use crate::parser::api::{Ast, Binary, BinaryOperator, Literal, Qualifiers, Unary, UnaryOperator};
+use crate::utils::display;
+
+/// Kind of the expression modified by an assignment or an increment, that
+/// decides whether it designates an object that can be modified.
+#[derive(Debug)]
+pub enum Lvalue {
+    /// Member of a struct or union, like `s.x` or `p->x`, and whether the
+    /// struct or union is const-qualified.
+    Member(String, bool),
+    /// Object designated by a dereferenced pointer, like `*p`, by an element
+    /// of an array, like `a[i]`, or by a compound literal.
+    Object,
+    /// Expression that doesn't designate an object, like `x + 1` or `f()`.
+    Rvalue,
+    /// String literal, like `"abc"`, or one of its characters, like
+    /// `"abc"[0]`, that can't be modified.
+    String,
+    /// Variable, like `x`.
+    Variable(String),
+}
+
+impl Lvalue {
+    /// Classifies an expression, without evaluating it.
+    ///
+    /// The parenthesis are ignored, like in `(x) = 3`, a member of an rvalue,
+    /// like `f().x`, is an rvalue, and the enum constants are rvalues.
+    pub fn of(ast: &Ast, state: &LState) -> Self {
+        match ast {
+            Ast::Binary(Binary { op, arg_l, arg_r }) => match op.as_value() {
+                BinaryOperator::ArraySubscript =>
+                    if matches!(Self::of(arg_l, state), Self::String)
+                        || matches!(Self::of(arg_r, state), Self::String)
+                    {
+                        Self::String
+                    } else {
+                        Self::Object
+                    },
+                operator @ (BinaryOperator::StructEnumMemberAccess
+                | BinaryOperator::StructEnumMemberPointerAccess) => {
+                    let Ast::Variable(member) = &**arg_r else {
+                        return Self::Rvalue;
+                    };
+                    let Some(name) = member.as_user_defined_name() else {
+                        return Self::Rvalue;
+                    };
+                    let base_ty = Type::of_expression(arg_l, state);
+                    let read_only = if *operator == BinaryOperator::StructEnumMemberPointerAccess {
+                        base_ty
+                            .and_then(|ty| ty.decayed().pointee())
+                            .is_some_and(|ty| ty.is_const())
+                    } else {
+                        match Self::of(arg_l, state) {
+                            Self::Rvalue | Self::String => return Self::Rvalue,
+                            Self::Member(_, read_only) =>
+                                read_only || base_ty.is_some_and(|ty| ty.is_const()),
+                            Self::Object | Self::Variable(_) =>
+                                base_ty.is_some_and(|ty| ty.is_const()),
+                        }
+                    };
+                    Self::Member(name.to_owned(), read_only)
+                }
+                BinaryOperator::AddAssign
+                | BinaryOperator::Add
+                | BinaryOperator::AndAssign
+                | BinaryOperator::Assign
+                | BinaryOperator::BitwiseAnd
+                | BinaryOperator::BitwiseOr
+                | BinaryOperator::BitwiseXor
+                | BinaryOperator::Comma
+                | BinaryOperator::Different
+                | BinaryOperator::DivAssign
+                | BinaryOperator::Divide
+                | BinaryOperator::Equal
+                | BinaryOperator::Ge
+                | BinaryOperator::Gt
+                | BinaryOperator::Le
+                | BinaryOperator::LogicalAnd
+                | BinaryOperator::LogicalOr
+                | BinaryOperator::Lt
+                | BinaryOperator::ModAssign
+                | BinaryOperator::Modulo
+                | BinaryOperator::MulAssign
+                | BinaryOperator::Multiply
+                | BinaryOperator::OrAssign
+                | BinaryOperator::ShiftLeft
+                | BinaryOperator::ShiftLeftAssign
+                | BinaryOperator::ShiftRight
+                | BinaryOperator::ShiftRightAssign
+                | BinaryOperator::SubAssign
+                | BinaryOperator::Subtract
+                | BinaryOperator::XorAssign => Self::Rvalue,
+            },
+            Ast::CompoundLiteral(_) => Self::Object,
+            Ast::Generic(generic) => select_association(generic, state)
+                .into_parts()
+                .0
+                .and_then(|index| generic.associations.get(index))
+                .map_or(Self::Rvalue, |association| Self::of(&association.value, state)),
+            Ast::Leaf(lit) if matches!(lit.as_value(), Literal::Str(..)) => Self::String,
+            Ast::ParensBlock(parens) => Self::of(parens.as_value(), state),
+            Ast::Unary(Unary { op, arg }) if *op.as_value() == UnaryOperator::Indirection =>
+                if matches!(Self::of(arg, state), Self::String) {
+                    Self::String
+                } else {
+                    Self::Object
+                },
+            Ast::Variable(var) => var
+                .as_user_defined_name()
+                .filter(|name| state.tags().find_constant(name).is_none())
+                .map_or(Self::Rvalue, |name| Self::Variable(name.to_owned())),
+            Ast::BracedBlock(_)
+            | Ast::Cast(_)
+            | Ast::ControlFlow(_)
+            | Ast::Empty
+            | Ast::FunctionArgsBuild(..)
+            | Ast::FunctionCall(_)
+            | Ast::Leaf(_)
+            | Ast::ListInitialiser(_)
+            | Ast::Ternary(_)
+            | Ast::Unary(_) => Self::Rvalue,
+        }
+    }
+}
+
+impl Type {
+    /// Checks if an lvalue of this type can be modified, by an assignment or
+    /// an increment, like `x` in `x = 1` or `x++`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression isn't an lvalue, if it is a string
+    /// literal, an array or a function, or if it is const-qualified, or a
+    /// struct or union with a const-qualified member.
+    pub fn check_modifiable(
+        &self,
+        lvalue: &Lvalue,
+        loc: ErrorLocation,
+        state: &LState,
+    ) -> Result<(), CompileError> {
+        let msg = match lvalue {
+            Lvalue::Rvalue => "Expression is not assignable".to_owned(),
+            Lvalue::String => "String literal is not assignable".to_owned(),
+            Lvalue::Member(..) | Lvalue::Object | Lvalue::Variable(_) if self.is_array() =>
+                format!("Array type {self} is not assignable"),
+            Lvalue::Member(..) | Lvalue::Object | Lvalue::Variable(_) if self.is_function() =>
+                format!("Function type {self} is not assignable"),
+            Lvalue::Member(_, true) =>
+                format!("Cannot assign to {lvalue} of a const-qualified struct or union"),
+            Lvalue::Member(..) | Lvalue::Object | Lvalue::Variable(_) if self.is_const() =>
+                format!("Cannot assign to {lvalue} with const-qualified type {self}"),
+            Lvalue::Member(..) | Lvalue::Object | Lvalue::Variable(_) => {
+                let Some(member) = state.const_member(self) else {
+                    return Ok(());
+                };
+                format!("Cannot assign to {lvalue} with const-qualified member {member}")
+            }
+        };
+        Err(loc.fail(msg))
+    }
+
+    /// Checks if the outermost level of the type is `const` or `constexpr`,
+    /// like `const int` or `int *const`, but not `const int *`.
+    pub fn is_const(&self) -> bool {
+        self.fully_resolved()
+            .indirections
+            .last()
+            .is_some_and(|level| {
+                level.contains(&CONST)
+                    || level.contains(&IndirectionDecorator::Qualifiers(Qualifiers::Constexpr))
+            })
+    }
+}
+
+display!(
+    Lvalue,
+    self,
+    f,
+    match self {
+        Self::Member(name, _) => write!(f, "member {name}"),
+        Self::Object => "object".fmt(f),
+        Self::Rvalue => "expression".fmt(f),
+        Self::String => "string literal".fmt(f),
+        Self::Variable(name) => write!(f, "variable {name}"),
+    }
+);
@@ -1,11 +1,35 @@
 //! Module to define and handle type coherence and storing.
 
+/// Resolution of the typedef names.
+mod alias;
+/// Array types, and their conversion to pointers.
+mod array;
+/// Bit-precise integer types, like `_BitInt(8)`, and their conversions.
+mod bit_int;
+/// Explicit conversions of the casts, like `(char *)p`.
+mod cast;
 /// Computes the new type after an operation.
 mod compat;
+/// Implicit conversions between the arithmetic types, following C23 6.3.1.
+mod conversion;
+/// Abstract declarators of the types, like `(*)(int)` in `int (*)(int)`.
+mod declarator;
 /// Type decorators, like `const`, `short` or `thread_local`.
-mod decorators;
+pub mod decorators;
+/// Type of the expressions that are not evaluated.
+mod expression;
+/// Function types, their parameters and their conversion to pointers.
+pub mod function;
+/// Size and alignment of the types, and layout of the structs and unions.
+pub mod layout;
+/// Lvalues, that designate the objects modified by the assignments, and
+/// their modifiable types.
+pub mod lvalue;
 /// Base of the type, like `struct A`, `custom` or `int`.
 mod name;
+/// Types of the operands and of the results of the operators, after the
+/// implicit conversions.
+pub mod operands;
 /// Parsing state to read one by one the attributes and build a [`Type`] or
 /// [`ReturnType`].
 mod state;
@@ -14,14 +38,15 @@ extern crate alloc;
 use alloc::collections::BTreeSet;
 
 use crate::errors::api::Located;
+use crate::lineariser::state::LState;
 use crate::lineariser::types::decorators::{
-    FunctionAttribute, IndirectionDecorator, TypeDecorator
+    ArrayLength, FunctionAttribute, IndirectionDecorator, TypeDecorator
 };
 use crate::lineariser::types::name::TypeName;
 use crate::lineariser::types::state::TypeParsingState;
 use crate::parser::api::{Attribute, Literal, Modifiers, Qualifiers};
 use crate::utils::{bset, display, repr_vec};
-use crate::{EMPTY, Number, Res};
+use crate::{EMPTY, Encoding, Number, Res};
 
 /// Helper macro to create a type attribute.
 macro_rules! lity {
@@ -40,6 +65,8 @@ const CONST: IndirectionDecorator = IndirectionDecorator::Qualifiers(Qualifiers:
 const LONG: TypeDecorator = TypeDecorator::Modifiers(Modifiers::Long);
 /// Shorthand for the `long long` double keyword.
 const LONG_LONG: TypeDecorator = TypeDecorator::Modifiers(Modifiers::LongLong);
+/// Shorthand for the `short` keyword.
+const SHORT: TypeDecorator = TypeDecorator::Modifiers(Modifiers::Short);
 /// Shorthand for the `unsigned` keyword.
 const UNSIGNED: TypeDecorator = TypeDecorator::Modifiers(Modifiers::Unsigned);
 /// Shorthand for the `_Complex` keyword.
@@ -79,15 +106,15 @@ impl ReturnType {
     }
 
     /// Builds a [`ReturnType`] for a list of attributes.
-    pub fn from_attributes(attrs: &[Located<Attribute>]) -> Res<Self> {
-        let mut state = TypeParsingState::default();
+    pub fn from_attributes(attrs: &[Located<Attribute>], state: &LState) -> Res<Self> {
+        let mut parsing = TypeParsingState::default();
         let mut errors = vec![];
         for attr in attrs {
-            state
-                .add_attribute(attr)
+            parsing
+                .add_attribute(attr, state)
                 .store_errors(&mut |err| errors.push(err));
         }
-        state
+        parsing
             .into_type(
                 attrs
                     .first()
@@ -116,8 +143,11 @@ impl ReturnType {
 ///   information on the type, such as `short`, `const`, `volatile` or `auto`.
 ///
 /// Some decorators can only be applied on the base type name, whereas others
-/// can be applied to each level of indirection.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// can be applied to each level of indirection. A level of indirection is an
+/// array instead of a pointer when it is decorated with
+/// [`IndirectionDecorator::Array`], and a function when it is decorated with
+/// [`IndirectionDecorator::Function`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Type {
     /// Base type of the data, once all pointers are accessed.
     base: TypeName,
@@ -135,31 +165,24 @@ pub struct Type {
 }
 
 impl Type {
-    /// Combines 2 types.
-    #[expect(clippy::unused_self, reason = "todo")]
-    pub const fn combine(&self, _: &Self) -> Self {
-        // TODO: implement
-        Self::empty()
-    }
-
     /// Returns a place holder return type for function defines but wrongly.
     pub const fn empty() -> Self {
         Self {
-            base: TypeName::TypeDef(String::new()),
+            base: TypeName::TypeDef(String::new(), None),
             base_decorations: bset![],
             indirections: vec![],
         }
     }
 
     /// Builds a [`ReturnType`] for a list of attributes.
-    pub fn from_attributes(attrs: &[Located<Attribute>]) -> Res<Self> {
-        ReturnType::from_attributes(attrs).and_then(|ret| {
-            let res = Res::ok(ret.ty);
+    pub fn from_attributes(attrs: &[Located<Attribute>], state: &LState) -> Res<Self> {
+        ReturnType::from_attributes(attrs, state).and_then(|ret| {
+            let mut res = Res::ok(ret.ty);
             for attr in ret.attrs {
-                attr.as_location().fail(format!(
+                res = res.add_err(attr.as_location().fail(format!(
                     "Variable type contains function-only keyword {}",
                     attr.as_value()
-                ));
+                )));
             }
             res
         })
@@ -170,13 +193,37 @@ impl Type {
         Self { base, base_decorations: bset![], indirections: vec![bset![]] }
     }
 
+    /// Builds the type of a code unit of a char or string constant.
+    ///
+    /// `char8_t`, `char16_t` and `char32_t` are `unsigned char`, `unsigned
+    /// short` and `unsigned int`, and `wchar_t` is considered to be an `int`.
+    fn from_encoding(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Plain => lity!(Char, bset![], CONST),
+            Encoding::Utf8 => lity!(Char, bset![UNSIGNED], CONST),
+            Encoding::Utf16 => lity!(Int, bset![UNSIGNED, SHORT], CONST),
+            Encoding::Utf32 => lity!(Int, bset![UNSIGNED], CONST),
+            Encoding::Wide => lity!(Int, bset![], CONST),
+        }
+    }
+
     /// Builds and returns the type of a literal.
+    ///
+    /// A string literal is an array of its code units and of the null
+    /// terminator, like `char[4]` for `"abc"`: it only decays into a pointer
+    /// when its value is used. As in C, its elements are not `const`, even
+    /// though they can't be modified.
     pub fn from_lit(lit: &Literal) -> Self {
         match lit {
-            Literal::Char(_) => lity!(Char, bset![], CONST),
+            Literal::Char(_, encoding) => Self::from_encoding(*encoding),
             Literal::ConstantBool(_) => lity!(Bool, bset![], CONST),
             Literal::Null => lity!(Void, bset![], CONST, CONST),
-            Literal::Str(_) => lity!(Char, bset![], CONST, CONST),
+            Literal::Str(string, encoding) => {
+                let units = u64::try_from(encoding.code_units(string)).unwrap_or(u64::MAX);
+                Self::from_encoding(*encoding)
+                    .unqualified()
+                    .into_array(ArrayLength::Fixed(units.saturating_add(1)))
+            }
             Literal::Number(Number::Int(_)) => lity!(Int, bset![], CONST),
             Literal::Number(Number::Long(_)) => lity!(Int, bset![LONG], CONST),
             Literal::Number(Number::LongLong(_)) => lity!(Int, bset![LONG_LONG], CONST),
@@ -186,6 +233,12 @@ impl Type {
             Literal::Number(Number::UInt(_)) => lity!(Int, bset![UNSIGNED], CONST),
             Literal::Number(Number::ULong(_)) => lity!(Int, bset![UNSIGNED, LONG], CONST),
             Literal::Number(Number::ULongLong(_)) => lity!(Int, bset![UNSIGNED, LONG_LONG], CONST),
+            Literal::Number(Number::BitInt(value)) => Self {
+                indirections: vec![bset![CONST]],
+                ..Self::from_bit_int(value.width(), false)
+            },
+            Literal::Number(Number::UBitInt(value)) =>
+                Self { indirections: vec![bset![CONST]], ..Self::from_bit_int(value.width(), true) },
         }
     }
 }
@@ -214,12 +267,9 @@ display!(Type, self, f, {
         " ".fmt(f)?;
     }
     self.base.fmt(f)?;
-    for ind in self.indirections.iter().skip(1) {
-        " *".fmt(f)?;
-        if !ind.is_empty() {
-            " ".fmt(f)?;
-            repr_vec(ind, " ").fmt(f)?;
-        }
+    let declarator = self.abstract_declarator();
+    if !declarator.is_empty() && !declarator.starts_with('[') {
+        " ".fmt(f)?;
     }
-    Ok(())
+    declarator.fmt(f)
 });
@@ -1,20 +1,27 @@
 use crate::Res;
 use crate::errors::api::ErrorLocation;
+use crate::lineariser::types::Type;
 use crate::parser::api::{BasicDataType, UserDefinedTypes};
+use crate::target::Scalar;
 use crate::utils::{display, from};
 
 /// Actual name of the type segment, stripped of modifiers, qualifiers and what
 /// not.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum TypeName {
     /// The type is a builtin, like `int` or `char`.
     BasicDataType(BasicDataType),
-    /// The type is user-defined with a enum.
-    Enum(String),
+    /// The type is a bit-precise integer `_BitInt(N)`, of width `N`.
+    BitInt(u64),
+    /// The type is user-defined with a enum, whose values are stored as the
+    /// given integer type.
+    Enum(String, Scalar),
     /// The type is user-defined with a struct.
     Struct(String),
     /// The type is user-defined with a typedef.
-    TypeDef(String),
+    ///
+    /// The aliased type is `None` if the typedef wasn't found.
+    TypeDef(String, Option<Box<Type>>),
     /// The type is user-defined with a union.
     Union(String),
 }
@@ -25,11 +32,11 @@ impl TypeName {
         let Some(usr_def) = usr_def_attr else {
             return Res::ok(self);
         };
-        if let Self::TypeDef(name) = self {
+        if let Self::TypeDef(name, _) = self {
             Res::ok(match usr_def {
                 UserDefinedTypes::Struct => Self::Struct(name),
                 UserDefinedTypes::Union => Self::Union(name),
-                UserDefinedTypes::Enum => Self::Enum(name),
+                UserDefinedTypes::Enum => Self::Enum(name, Scalar::Int),
             })
         } else {
             let err = loc.fail(format!("{usr_def} for type {self} isn't allowed"));
@@ -44,10 +51,11 @@ display!(
     f,
     match self {
         TypeName::BasicDataType(key) => key.fmt(f),
-        TypeName::TypeDef(name) => name.fmt(f),
+        TypeName::BitInt(width) => write!(f, "_BitInt({width})"),
+        TypeName::TypeDef(name, _) => name.fmt(f),
         TypeName::Struct(name) => write!(f, "struct {name}"),
         TypeName::Union(name) => write!(f, "union {name}"),
-        TypeName::Enum(name) => write!(f, "enum {name}"),
+        TypeName::Enum(name, _) => write!(f, "enum {name}"),
     }
 );
 
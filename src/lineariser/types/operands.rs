@@ -0,0 +1,230 @@
+use crate::lexer::api::NumberType;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::function::Category;
+use crate::parser::api::BinaryOperator;
+use crate::target::TargetInfo;
+
+/// Types to which the operands of a binary operator are converted, and type
+/// of its result.
+///
+/// # Examples
+///
+/// In `c + 1.5` where `c` is a `char`, both operands are converted to `double`
+/// and the result is a `double`. In `p + c` where `p` is an `int *`, `c` is
+/// promoted to `int` and the result is an `int *`.
+#[derive(Debug)]
+pub struct Operands {
+    /// Type to which the left operand is converted.
+    pub left: Type,
+    /// Type of the result.
+    pub result: Type,
+    /// Type to which the right operand is converted.
+    pub right: Type,
+}
+
+impl Operands {
+    /// Both operands are converted to `ty`, and the result has type `result`.
+    fn both(ty: Type, result: Type) -> Self {
+        Self { left: ty.clone(), result, right: ty }
+    }
+
+    /// Both operands are converted to `ty`, and so is the result.
+    fn same(ty: Type) -> Self {
+        Self::both(ty.clone(), ty)
+    }
+}
+
+impl Type {
+    /// Returns the types of the operands and of the result of a binary
+    /// operator, following the usual arithmetic conversions and the rules of
+    /// the pointer arithmetic.
+    ///
+    /// `nulls` tells if the operands are null pointer constants, which can be
+    /// compared to pointers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the operator can't be applied to the
+    /// operands, like a pointer compared to an integer or a pointer added to a
+    /// pointer.
+    ///
+    /// # Panics
+    ///
+    /// If the operator is an assignment, a subscript or a member access, as
+    /// their types are computed by the caller.
+    #[expect(clippy::match_same_arms, reason = "readability")]
+    pub fn binary_operands(
+        op: BinaryOperator,
+        left: &Self,
+        right: &Self,
+        nulls: (bool, bool),
+        target: &TargetInfo,
+    ) -> Result<Operands, String> {
+        let lhs = left.clone().decayed().unqualified();
+        let rhs = right.clone().decayed().unqualified();
+        let int = Self::from_number_type(NumberType::Int);
+        let invalid = || Err(format!("Invalid operands to binary {op}: {left} and {right}"));
+        let (lcat, rcat) = (lhs.category(), rhs.category());
+        let combined = lhs.combine(&rhs, target);
+        let arithmetic = combined.is_some();
+        if lcat == Category::Unknown
+            || rcat == Category::Unknown
+            || (lcat == Category::Arithmetic && rcat == Category::Arithmetic && !arithmetic)
+        {
+            // unknown, complex or decimal operands
+            return Ok(Operands { left: lhs, result: Self::empty(), right: rhs });
+        }
+        let common = combined.unwrap_or_else(Self::empty);
+        let integers = lhs.is_integer() && rhs.is_integer();
+        match op {
+            BinaryOperator::Comma => Ok(Operands { left: lhs, result: rhs.clone(), right: rhs }),
+            BinaryOperator::LogicalAnd | BinaryOperator::LogicalOr
+                if lhs.is_scalar() && rhs.is_scalar() =>
+                Ok(Operands { left: lhs, result: int, right: rhs }),
+            BinaryOperator::Divide | BinaryOperator::Multiply if arithmetic =>
+                Ok(Operands::same(common)),
+            BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOr
+            | BinaryOperator::BitwiseXor
+            | BinaryOperator::Modulo
+                if integers =>
+                Ok(Operands::same(common)),
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight if integers => {
+                let promoted = lhs.promoted(target);
+                Ok(Operands {
+                    left: promoted.clone(),
+                    result: promoted,
+                    right: rhs.promoted(target),
+                })
+            }
+            BinaryOperator::Add | BinaryOperator::Subtract if arithmetic =>
+                Ok(Operands::same(common)),
+            BinaryOperator::Add | BinaryOperator::Subtract
+                if matches!(lcat, Category::Pointer(_)) && rhs.is_integer() =>
+                Ok(Operands { left: lhs.clone(), result: lhs, right: rhs.promoted(target) }),
+            BinaryOperator::Add if lhs.is_integer() && matches!(rcat, Category::Pointer(_)) =>
+                Ok(Operands { left: lhs.promoted(target), result: rhs.clone(), right: rhs }),
+            BinaryOperator::Subtract
+                if matches!(lcat, Category::Pointer(_)) && matches!(rcat, Category::Pointer(_)) =>
+                Ok(Operands { left: lhs, result: Self::ptrdiff(target), right: rhs }),
+            BinaryOperator::Different
+            | BinaryOperator::Equal
+            | BinaryOperator::Ge
+            | BinaryOperator::Gt
+            | BinaryOperator::Le
+            | BinaryOperator::Lt
+                if lhs.is_scalar() && rhs.is_scalar() =>
+                Self::compared(op, lhs, rhs, common, nulls).ok_or_else(|| {
+                    format!("Comparison between pointer and integer: {left} and {right}")
+                }),
+            BinaryOperator::Add
+            | BinaryOperator::BitwiseAnd
+            | BinaryOperator::BitwiseOr
+            | BinaryOperator::BitwiseXor
+            | BinaryOperator::Different
+            | BinaryOperator::Divide
+            | BinaryOperator::Equal
+            | BinaryOperator::Ge
+            | BinaryOperator::Gt
+            | BinaryOperator::Le
+            | BinaryOperator::LogicalAnd
+            | BinaryOperator::LogicalOr
+            | BinaryOperator::Lt
+            | BinaryOperator::Modulo
+            | BinaryOperator::Multiply
+            | BinaryOperator::ShiftLeft
+            | BinaryOperator::ShiftRight
+            | BinaryOperator::Subtract => invalid(),
+            BinaryOperator::AddAssign
+            | BinaryOperator::AndAssign
+            | BinaryOperator::ArraySubscript
+            | BinaryOperator::Assign
+            | BinaryOperator::DivAssign
+            | BinaryOperator::ModAssign
+            | BinaryOperator::MulAssign
+            | BinaryOperator::OrAssign
+            | BinaryOperator::ShiftLeftAssign
+            | BinaryOperator::ShiftRightAssign
+            | BinaryOperator::StructEnumMemberAccess
+            | BinaryOperator::StructEnumMemberPointerAccess
+            | BinaryOperator::SubAssign
+            | BinaryOperator::XorAssign => unreachable!("computed by the caller"),
+        }
+    }
+
+    /// Returns the types of the operands and of the result of a comparison
+    /// between two scalars, or `None` if a pointer is compared to an integer
+    /// that isn't a null pointer constant.
+    fn compared(
+        op: BinaryOperator,
+        lhs: Self,
+        rhs: Self,
+        common: Self,
+        nulls: (bool, bool),
+    ) -> Option<Operands> {
+        let int = Self::from_number_type(NumberType::Int);
+        let equality = matches!(op, BinaryOperator::Different | BinaryOperator::Equal);
+        match (lhs.category(), rhs.category()) {
+            (Category::Arithmetic, Category::Arithmetic) => Some(Operands::both(common, int)),
+            (Category::Pointer(_), Category::Pointer(_)) =>
+                Some(Operands { left: lhs, result: int, right: rhs }),
+            (Category::Pointer(_), _) if equality && nulls.1 => Some(Operands::both(lhs, int)),
+            (_, Category::Pointer(_)) if equality && nulls.0 => Some(Operands::both(rhs, int)),
+            _ => None,
+        }
+    }
+
+    /// Returns the type of the result of a conditional operator `c ? x : y`,
+    /// to which both `x` and `y` are converted.
+    ///
+    /// `nulls` tells if `x` and `y` are null pointer constants.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the operands have incompatible types.
+    #[expect(clippy::match_same_arms, reason = "readability")]
+    pub fn conditional_result(
+        success: &Self,
+        failure: &Self,
+        nulls: (bool, bool),
+        target: &TargetInfo,
+    ) -> Result<Self, String> {
+        let lhs = success.clone().decayed().unqualified();
+        let rhs = failure.clone().decayed().unqualified();
+        match (lhs.category(), rhs.category()) {
+            (Category::Unknown, _) | (_, Category::Unknown) => Ok(Self::empty()),
+            (Category::Arithmetic, Category::Arithmetic) =>
+                Ok(lhs.combine(&rhs, target).unwrap_or_else(Self::empty)),
+            (Category::Pointer(_), Category::Pointer(pointee))
+                if pointee.unqualified().category() == Category::Void =>
+                Ok(rhs),
+            (Category::Pointer(_), Category::Pointer(_)) => Ok(lhs),
+            (Category::Pointer(_), Category::Arithmetic) if nulls.1 => Ok(lhs),
+            (Category::Arithmetic, Category::Pointer(_)) if nulls.0 => Ok(rhs),
+            (Category::Record(expected), Category::Record(found)) if expected == found => Ok(lhs),
+            (Category::Void, Category::Void) => Ok(lhs),
+            (
+                Category::Arithmetic | Category::Pointer(_) | Category::Record(_) | Category::Void,
+                Category::Arithmetic | Category::Pointer(_) | Category::Record(_) | Category::Void,
+            ) => Err(format!(
+                "Incompatible operand types {success} and {failure} in conditional expression"
+            )),
+        }
+    }
+
+    /// Checks if the type is a scalar type, i.e., an arithmetic type or a
+    /// pointer.
+    pub fn is_scalar(&self) -> bool {
+        matches!(self.category(), Category::Arithmetic | Category::Pointer(_))
+    }
+
+    /// Returns the type of the difference of two pointers, `ptrdiff_t`, which
+    /// is a signed integer as large as a pointer.
+    fn ptrdiff(target: &TargetInfo) -> Self {
+        Self::from_number_type(if target.long.size == target.pointer.size {
+            NumberType::Long
+        } else {
+            NumberType::LongLong
+        })
+    }
+}
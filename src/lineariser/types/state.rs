@@ -5,6 +5,7 @@ use core::mem::take;
 
 use crate::Res;
 use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::state::LState;
 use crate::lineariser::types::decorators::{
     FunctionAttribute, IndirectionDecorator, TypeDecorator
 };
@@ -58,8 +59,10 @@ impl Default for TypeParsingState {
 
 impl TypeParsingState {
     /// Adds an attribute to the current type parsing state.
+    ///
+    /// The typedef names are resolved with the typedefs visible in `state`.
     #[expect(clippy::min_ident_chars, reason = "scoped shorthand")]
-    pub fn add_attribute(&mut self, attr: &Located<Attribute>) -> Res<()> {
+    pub fn add_attribute(&mut self, attr: &Located<Attribute>, state: &LState) -> Res<()> {
         #[cfg(feature = "debug")]
         crate::lgp!("Add attr {attr} to {self:?}");
         use AttributeKeyword as K;
@@ -70,7 +73,9 @@ impl TypeParsingState {
                 self.add_indirection();
                 Res::ok(())
             }
-            Attribute::User(name) => self.add_type(loc.wrap(TypeName::TypeDef(name.to_owned()))),
+            // applied on the declared symbol, not on its type
+            Attribute::Standard(_) => Res::ok(()),
+            Attribute::User(name) => self.add_user_type(name, loc, state),
             Attribute::Keyword(kwd) => match kwd {
                 K::Modifiers(Modifiers::Long) => self.add_long(loc),
                 K::Modifiers(dec) => self.add_ty_dec(loc.wrap(*dec)),
@@ -84,7 +89,7 @@ impl TypeParsingState {
                     S::Noreturn => self.add_fn_attr(loc.wrap(FunctionAttribute::NoReturn)),
                     S::Restrict =>
                         self.add_indirection_dec(loc.wrap(IndirectionDecorator::Restrict)),
-                    S::Alignas | S::Generic | S::Typeof | S::TypeofUnqual => Res::ok(())
+                    S::Alignas | S::Typeof | S::TypeofUnqual => Res::ok(())
                         .add_err(loc.fail(format!("`{special}` keyword not yet supported"))),
                 },
             },
@@ -183,7 +188,7 @@ impl TypeParsingState {
     }
 
     /// Adds the base type to the parsing state.
-    fn add_type(&mut self, base: Located<TypeName>) -> Res<()> {
+    pub fn add_type(&mut self, base: Located<TypeName>) -> Res<()> {
         let loc = base.as_location();
         match take(self) {
             Self::NoBase(base_decorations, indirections, usr_def, attrs) => {
@@ -201,8 +206,10 @@ impl TypeParsingState {
                                         .fail("Decimal can't be complex, only real".to_owned()),
                                 );
                             },
-                        TypeName::TypeDef(_) => todo!(),
-                        TypeName::Enum(_) | TypeName::Struct(_) | TypeName::Union(_) => errors
+                        // Reported with the other modifiers by `add_user_type`,
+                        // and the bit-precise integers are only built for constants.
+                        TypeName::BitInt(_) | TypeName::TypeDef(..) => (),
+                        TypeName::Enum(..) | TypeName::Struct(_) | TypeName::Union(_) => errors
                             .push(loc.into_two_tokens(complex).fail(
                                 "User-defined packed type can't be complex, only real".to_owned(),
                             )),
@@ -255,7 +262,13 @@ impl TypeParsingState {
 
     /// Returns the type represented by the current parsing state.
     pub fn into_type(mut self, loc: ErrorLocation) -> Res<ReturnType> {
+        let implies_int = matches!(&self, Self::NoBase(decs, ..)
+            if decs.iter().any(|dec| dec.as_value().implies_int()));
         match self {
+            Self::NoBase(..) if implies_int => {
+                self.add_type(loc.wrap(TypeName::BasicDataType(BasicDataType::Int)));
+                self.into_type(loc)
+            }
             Self::NoBase(..) => {
                 self.add_type(loc.wrap(TypeName::BasicDataType(BasicDataType::Int)));
                 self.into_type(loc)
@@ -0,0 +1,227 @@
+//! Applies the array declarators of a declaration, like `[10]` in `int a[10]`,
+//! on the type of the declared name.
+
+use crate::Number;
+use crate::errors::api::{CompileError, Located};
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::const_eval::eval_constant;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::{ArrayLength, IndirectionDecorator};
+use crate::lineariser::walk::sizeof::size_type;
+use crate::parser::api::{
+    ArrayDeclarator, ArraySize, Ast, AttributeKeyword, SpecialAttributes, Storage
+};
+
+/// Kind of the declared name, that changes which array declarators are
+/// allowed.
+#[derive(Debug)]
+pub enum ArrayContext<'bbs> {
+    /// Member of a struct or union.
+    Member,
+    /// Parameter of a function, adjusted to a pointer.
+    Parameter,
+    /// Array pointed to by a parameter, like `[3]` in `int (*p)[3]`, that is
+    /// not adjusted.
+    ParameterPointee,
+    /// Type name declared with `typedef`.
+    Typedef,
+    /// Variable, whose length can be computed at runtime in block scope.
+    Variable(&'bbs mut BasicBlocks),
+}
+
+impl ArrayContext<'_> {
+    /// Returns the context of the arrays pointed to by the declared name, like
+    /// `[3]` in `int (*p)[3]`.
+    pub const fn pointee(&mut self) -> ArrayContext<'_> {
+        match self {
+            Self::Member => ArrayContext::Member,
+            Self::Parameter | Self::ParameterPointee => ArrayContext::ParameterPointee,
+            Self::Typedef => ArrayContext::Typedef,
+            Self::Variable(bbs) => ArrayContext::Variable(bbs),
+        }
+    }
+}
+
+/// Applies the array declarators on the type `ty` of a declared name.
+///
+/// The innermost declarator is applied first, so `int a[2][3]` is an array of
+/// 2 arrays of 3 `int`. An array parameter is adjusted to a pointer to its
+/// elements, qualified with the qualifiers of the brackets, like in `int
+/// a[const 10]`.
+pub fn apply_arrays(
+    arrays: Vec<ArrayDeclarator>,
+    ty: Type,
+    mut context: ArrayContext<'_>,
+    state: &mut LState,
+) -> Type {
+    let parameter = matches!(context, ArrayContext::Parameter);
+    let mut array_ty = ty;
+    let mut adjusted = None;
+    for (index, array) in arrays.into_iter().enumerate().rev() {
+        let ArrayDeclarator { location, qualifiers, size } = array;
+        if state.layout(&array_ty).is_none() && !array_ty.has_variable_length() {
+            state
+                .push_error(location.fail(format!("Array has incomplete element type {array_ty}")));
+        }
+        let outermost_parameter = parameter && index == 0;
+        if !qualifiers.is_empty() && !outermost_parameter {
+            state.push_error(location.fail(
+                "Qualifiers and `static` are only allowed in the outermost array declarator of a parameter".to_owned(),
+            ));
+        }
+        let is_static = qualifiers
+            .iter()
+            .any(|qual| *qual.as_value() == AttributeKeyword::Storage(Storage::Static));
+        let length = match size {
+            ArraySize::Expression(ast) => array_length(ast, &mut context, state),
+            ArraySize::Incomplete => {
+                if is_static {
+                    state.push_error(location.fail("`static` requires an array size".to_owned()));
+                }
+                ArrayLength::Incomplete
+            }
+            ArraySize::Unspecified => {
+                if !matches!(context, ArrayContext::Parameter | ArrayContext::ParameterPointee) {
+                    state.push_error(location.fail(
+                        "Variable length array of unspecified size is only allowed in parameters"
+                            .to_owned(),
+                    ));
+                }
+                ArrayLength::Unspecified
+            }
+        };
+        array_ty = array_ty.into_array(length);
+        if outermost_parameter {
+            adjusted = Some(indirection_decorators(&qualifiers, state));
+        }
+    }
+    if let Some(decorators) = adjusted {
+        array_ty.into_adjusted_parameter(decorators)
+    } else {
+        array_ty
+    }
+}
+
+/// Returns the length of an array declared with the given size.
+///
+/// A size that isn't a constant expression is evaluated at runtime for
+/// variables in block scope. It is unspecified in parameters and is an error
+/// elsewhere.
+fn array_length(size: Ast, context: &mut ArrayContext<'_>, state: &mut LState) -> ArrayLength {
+    let loc = size.location();
+    let err = match eval_constant(&size, state) {
+        Ok(number) => {
+            return length_of(&size, &number, "Size of array").map_or_else(
+                |length_err| {
+                    state.push_error(length_err);
+                    ArrayLength::Incomplete
+                },
+                ArrayLength::Fixed,
+            );
+        }
+        Err(err) => err,
+    };
+    match context {
+        ArrayContext::Variable(bbs) if state.is_block_scope() => match size.push_in(bbs, state) {
+            Some(Id::Found(id, ty)) => {
+                if ty != Type::empty() && !ty.is_integer() {
+                    state.push_error(loc.fail(format!("Size of array has non-integer type {ty}")));
+                }
+                // The length is multiplied with the size of the elements by
+                // `sizeof`, so it is stored as a `size_t`.
+                let size_ty = size_type(state);
+                if ty.needs_conversion(&size_ty, false, state.target()) {
+                    ArrayLength::Variable(state.push_element(Value::Convert(id), size_ty))
+                } else {
+                    ArrayLength::Variable(id)
+                }
+            }
+            Some(Id::NotFound) => ArrayLength::Incomplete,
+            None => {
+                state.stat_not_expr(loc, "array size");
+                ArrayLength::Incomplete
+            }
+        },
+        ArrayContext::Parameter | ArrayContext::ParameterPointee => ArrayLength::Unspecified,
+        ArrayContext::Member => {
+            state.push_error(
+                loc.fail("Variable length array can't be a member of a struct or union".to_owned()),
+            );
+            ArrayLength::Incomplete
+        }
+        ArrayContext::Typedef => {
+            state.push_error(err);
+            ArrayLength::Incomplete
+        }
+        ArrayContext::Variable(_) => {
+            state.push_error(
+                loc.fail("Variable length array is not allowed at file scope".to_owned()),
+            );
+            ArrayLength::Incomplete
+        }
+    }
+}
+
+/// Evaluates the size of an array, that must be a non-negative integer
+/// constant expression.
+pub fn constant_length(size: &Ast, state: &LState) -> Result<u64, CompileError> {
+    constant_index(size, "Size of array", state)
+}
+
+/// Evaluates a non-negative integer constant expression, like the size of an
+/// array or the index of an array designator. `what` names the expression in
+/// the errors.
+pub fn constant_index(ast: &Ast, what: &str, state: &LState) -> Result<u64, CompileError> {
+    length_of(ast, &eval_constant(ast, state)?, what)
+}
+
+/// Returns the length or the index whose expression `size` evaluates to
+/// `number`.
+fn length_of(size: &Ast, number: &Number, what: &str) -> Result<u64, CompileError> {
+    let length = match number {
+        Number::Int(value) => u64::try_from(*value).ok(),
+        Number::Long(value) | Number::LongLong(value) => u64::try_from(*value).ok(),
+        Number::UInt(value) => Some(u64::from(*value)),
+        Number::ULong(value) | Number::ULongLong(value) => Some(*value),
+        Number::BitInt(value) | Number::UBitInt(value) => value.to_u64(),
+        Number::Float(_) | Number::Double(_) | Number::LongDouble(_) =>
+            return Err(size
+                .location()
+                .fail(format!("{what} has non-integer type, found {number}"))),
+    };
+    length.ok_or_else(|| {
+        size.location()
+            .fail(format!("{what} must be non-negative, found {number}"))
+    })
+}
+
+/// Converts the qualifiers of an array parameter, like `const` in `int a[const
+/// 10]`, into the qualifiers of the adjusted pointer.
+///
+/// `static` only promises a minimum length, and is dropped.
+fn indirection_decorators(
+    qualifiers: &[Located<AttributeKeyword>],
+    state: &mut LState,
+) -> Vec<IndirectionDecorator> {
+    let mut decorators = vec![];
+    for qualifier in qualifiers {
+        match qualifier.as_value() {
+            AttributeKeyword::Qualifiers(qual) => decorators.push((*qual).into()),
+            AttributeKeyword::SpecialAttributes(SpecialAttributes::Restrict) =>
+                decorators.push(IndirectionDecorator::Restrict),
+            AttributeKeyword::Storage(Storage::Static) => (),
+            kwd @ (AttributeKeyword::BasicDataType(_)
+            | AttributeKeyword::Modifiers(_)
+            | AttributeKeyword::SpecialAttributes(_)
+            | AttributeKeyword::Storage(_)
+            | AttributeKeyword::UserDefinedTypes(_)) => state.push_error(
+                qualifier
+                    .as_location()
+                    .fail(format!("`{kwd}` is not supported in an array declarator")),
+            ),
+        }
+    }
+    decorators
+}
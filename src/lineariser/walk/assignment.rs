@@ -0,0 +1,131 @@
+//! Walks the assignments, like `x = 1` or `x += 1`, and the increments and
+//! decrements, like `x++`, that are lowered into stores.
+
+use crate::Number;
+use crate::errors::api::Located;
+use crate::lineariser::basic_block::Id;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::function::Assignment;
+use crate::lineariser::walk::conversion::{Operand, push_conversion};
+use crate::lineariser::walk::operator::push_operation;
+use crate::parser::api::{BinaryOperator, Literal, UnaryOperator};
+
+/// Pushes an assignment to the lvalue `left`, already checked to be
+/// modifiable, with the implicit conversions of the operands.
+///
+/// A simple assignment, like `x = 1`, stores the right operand converted to
+/// the type of `x`. A compound assignment, like `x += 1`, is lowered into a
+/// load-op-store sequence: the operation is applied on the loaded value of `x`
+/// and its result is converted back and stored.
+///
+/// `assigned` is the local variable modified by the assignment, which gets a
+/// new version.
+pub fn push_assignment(
+    op: Located<BinaryOperator>,
+    left: &Operand,
+    right: &Operand,
+    assigned: Option<usize>,
+    state: &mut LState,
+) -> Id {
+    let assigned_ty = left.ty.unqualified();
+    let value = if let Some(operation) = op.as_value().compound_operation() {
+        let Some(result) = push_operation(op.as_location().wrap(operation), left, right, state)
+        else {
+            return Id::NotFound;
+        };
+        push_conversion(&result, &assigned_ty, state)
+    } else {
+        assigned_ty
+            .check_assignment(&right.ty, right.null, right.loc, Assignment::Simple)
+            .store_errors(&mut |err| state.push_error(err));
+        push_conversion(right, &assigned_ty, state)
+    };
+    let id = push_store(left.id, value, assigned_ty.clone(), assigned, state);
+    Id::Found(id, assigned_ty)
+}
+
+/// Pushes an increment or a decrement of the lvalue `operand`, already
+/// checked to be modifiable, like `x++` or `--x`.
+///
+/// It is lowered into a load-op-store sequence, like `x += 1`, except that
+/// the conversion of the result back to the type of `x` is never reported.
+/// The operand must be an arithmetic type or a pointer.
+/// The value of a prefix operator is the stored value, and the value of a
+/// postfix operator is the value loaded before the store.
+///
+/// `assigned` is the local variable modified by the operator, which gets a
+/// new version.
+pub fn push_increment(
+    op: &Located<UnaryOperator>,
+    operand: &Operand,
+    assigned: Option<usize>,
+    state: &mut LState,
+) -> Id {
+    let (operation, prefix) = match op.as_value() {
+        UnaryOperator::PostfixDecrement => (BinaryOperator::Subtract, false),
+        UnaryOperator::PostfixIncrement => (BinaryOperator::Add, false),
+        UnaryOperator::PrefixDecrement => (BinaryOperator::Subtract, true),
+        UnaryOperator::PrefixIncrement => (BinaryOperator::Add, true),
+        UnaryOperator::AddressOf
+        | UnaryOperator::Alignof
+        | UnaryOperator::BitwiseNot
+        | UnaryOperator::Indirection
+        | UnaryOperator::LogicalNot
+        | UnaryOperator::Minus
+        | UnaryOperator::Plus
+        | UnaryOperator::Sizeof => unreachable!("not an increment or a decrement"),
+    };
+    if !operand.ty.fully_resolved().is_scalar() {
+        let action = if operation == BinaryOperator::Add {
+            "increment"
+        } else {
+            "decrement"
+        };
+        state.push_error(
+            operand
+                .loc
+                .fail(format!("Cannot {action} value of type {}", operand.ty)),
+        );
+        return Id::NotFound;
+    }
+    let lit = Literal::Number(Number::Int(1));
+    let one = Operand {
+        ty: Type::from_lit(&lit),
+        id: state.push_literal(lit),
+        loc: op.as_location(),
+        null: false,
+    };
+    let Some(result) = push_operation(op.as_location().wrap(operation), operand, &one, state)
+    else {
+        return Id::NotFound;
+    };
+    let assigned_ty = operand.ty.unqualified();
+    let value = if result
+        .ty
+        .needs_conversion(&assigned_ty, false, state.target())
+    {
+        state.push_element(Value::Convert(result.id), assigned_ty.clone())
+    } else {
+        result.id
+    };
+    let stored = push_store(operand.id, value, assigned_ty.clone(), assigned, state);
+    Id::Found(if prefix { stored } else { operand.id }, assigned_ty)
+}
+
+/// Pushes the store of `value` into the object `object`, and returns its id,
+/// that is the value of the assignment.
+fn push_store(
+    object: usize,
+    value: usize,
+    ty: Type,
+    assigned: Option<usize>,
+    state: &mut LState,
+) -> usize {
+    let id = state.push_element(Value::Store(object, 0, value), ty);
+    if let Some(decl) = assigned {
+        state.set_version(decl, id);
+    }
+    id
+}
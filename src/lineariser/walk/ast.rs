@@ -1,72 +1,65 @@
 //! Walks a generic ast, updating state and creating symbols and basic blocks.
 
-use crate::lineariser::basic_block::{BasicBlocks, Id, Instruction};
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::basic_block::{BasicBlocks, Id};
 use crate::lineariser::state::LState;
 use crate::lineariser::symbol::Value;
 use crate::lineariser::types::Type;
-use crate::parser::api::{Ast, ControlFlowNode, Unary, VariableName, VariableValue};
+use crate::lineariser::types::lvalue::Lvalue;
+use crate::lineariser::walk::assignment::push_increment;
+use crate::lineariser::walk::conversion::{Operand, push_promotion};
+use crate::lineariser::walk::sizeof::push_type_operator;
+use crate::parser::api::{Ast, Unary, UnaryOperator, VariableName, VariableValue};
 
 impl Ast {
     /// Pushes some content into the basic blocks.
-    #[expect(clippy::missing_panics_doc, reason = "todo: remove unreachable")]
-    pub fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Option<Id> {
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Option<Id> {
         #[cfg(feature = "debug")]
         crate::lgp!(notab: "Pushing ast {self}");
         match self {
-            Self::ControlFlow(ControlFlowNode::Ast(return_ctrl)) => {
-                let value = return_ctrl.into_value();
-                let loc = value.location();
-                value.push_in(bbs, state).map_or_else(
-                    || {
-                        state.stat_not_expr(loc, "return");
-                    },
-                    |ret| {
-                        if let Id::Found(id, _) = ret {
-                            bbs.add(Instruction::Return(id));
-                        }
-                    },
-                );
+            Self::ControlFlow(ctrl) => {
+                ctrl.push_in(bbs, state);
                 None
             }
+            Self::Cast(cast) => Some(cast.push_in(bbs, state)),
+            Self::CompoundLiteral(lit) => Some(lit.push_in(bbs, state)),
             Self::FunctionCall(func) => func.push_in(bbs, state),
+            Self::Generic(generic) => generic.push_in(bbs, state),
             Self::Empty => None,
-            Self::Variable(var) => match var.into_value() {
-                VariableValue::AttributeVariable(attr) => {
-                    attr.push_in(bbs, state);
-                    None
-                }
-                VariableValue::VariableName(loc, VariableName::UserDefined(vname)) =>
-                    #[expect(clippy::option_if_let_else, reason = "clippy bug")]
-                    if let Some(decl) = state.find_declaration(&vname) {
-                        Some(Id::Found(decl.metadata.id, decl.metadata.ty.clone()))
-                    } else {
-                        state.push_error(loc.fail(format!("Use of undeclared variable {vname}")));
-                        Some(Id::NotFound)
-                    },
-                VariableValue::VariableName(loc, VariableName::Keyword(kwd)) => {
-                    state.push_error(
-                        loc.fail(format!(
+            Self::Variable(var) =>
+                match var.into_value() {
+                    VariableValue::AttributeVariable(attr) => {
+                        attr.push_in(bbs, state);
+                        None
+                    }
+                    VariableValue::VariableName(loc, VariableName::UserDefined(vname)) =>
+                        Some(push_variable_name(loc, &vname, state)),
+                    VariableValue::VariableName(loc, VariableName::Keyword(kwd)) => {
+                        state.push_error(loc.fail(format!(
                             "Keyword {kwd} is a function, but no arguments were given"
-                        )),
-                    );
-                    Some(Id::NotFound)
-                }
-            },
+                        )));
+                        Some(Id::NotFound)
+                    }
+                },
             Self::Leaf(lit) => {
-                let ty = Type::from_lit(lit.as_value());
+                let ty = Type::from_lit(lit.as_value()).decayed();
                 Some(Id::Found(state.push_literal(lit.drop_location()), ty))
             }
             Self::BracedBlock(bb) => {
                 state.increment_depth();
-                for elt in bb.elts {
-                    elt.push_in(bbs, state);
-                }
+                push_statements(bb.elts, bbs, state);
                 state.decrement_depth();
                 None
             }
             Self::Binary(bin) => Some(bin.push_in(bbs, state)),
             Self::Ternary(ter) => Some(ter.push_in(bbs, state)),
+            Self::Unary(Unary { arg, op }) if op.as_value().is_type_operator() =>
+                Some(push_type_operator(&op, &arg, state)),
             Self::Unary(Unary { arg, op }) => {
+                let modified = op
+                    .as_value()
+                    .is_assignment()
+                    .then(|| (Lvalue::of(&arg, state), state.assigned_local(&arg)));
                 let loc = if arg.is_empty() {
                     op.as_location()
                 } else {
@@ -75,14 +68,15 @@ impl Ast {
                 match arg.push_in(bbs, state) {
                     Some(Id::NotFound) => Some(Id::NotFound),
                     Some(Id::Found(id, ty)) => {
-                        let result = ty
-                            .apply_unary(&op)
-                            .store_errors(&mut |err| state.push_error(err))
-                            .expect("never none");
-                        Some(Id::Found(
-                            state.push_element(Value::Unary(*op.as_value(), id), result.clone()),
-                            result,
-                        ))
+                        let operand = Operand { id, loc, null: false, ty };
+                        let Some((lvalue, assigned)) = modified else {
+                            return Some(push_unary(&op, &operand, state));
+                        };
+                        if let Err(err) = operand.ty.check_modifiable(&lvalue, loc, state) {
+                            state.push_error(err);
+                            return Some(Id::NotFound);
+                        }
+                        Some(push_increment(&op, &operand, assigned, state))
                     }
                     None => {
                         state.stat_not_expr(loc, "unary");
@@ -90,11 +84,76 @@ impl Ast {
                     }
                 }
             }
-            Self::Cast(_)
-            | Self::FunctionArgsBuild(..)
-            | Self::ListInitialiser(_)
-            | Self::ParensBlock(_)
-            | Self::ControlFlow(_) => todo!("{self:?}"),
+            Self::ParensBlock(parens) => {
+                let (inner, loc) = parens.into_inner();
+                if inner.is_empty() {
+                    state.push_error(loc.fail("Expected expression in parenthesis".to_owned()));
+                    Some(Id::NotFound)
+                } else {
+                    inner.push_in(bbs, state)
+                }
+            }
+            Self::ListInitialiser(_) => {
+                state.push_error(
+                    self.location()
+                        .fail("Braced initialiser not allowed here".to_owned()),
+                );
+                Some(Id::NotFound)
+            }
+            Self::FunctionArgsBuild(..) => todo!("{self:?}"),
         }
     }
 }
+
+/// Pushes the statements of a block into the basic blocks.
+///
+/// The attributes that depend on the surrounding statements, like
+/// `[[fallthrough]]` or `[[nodiscard]]`, are checked here.
+pub fn push_statements(elts: Vec<Ast>, bbs: &mut BasicBlocks, state: &mut LState) {
+    for elt in elts {
+        if !elt.is_empty() {
+            state.check_fallthrough(Some(&elt));
+            state.check_discarded(&elt);
+        }
+        elt.push_in(bbs, state);
+    }
+}
+
+/// Pushes the value of a variable or of a function, from its name.
+fn push_variable_name(loc: ErrorLocation, vname: &str, state: &mut LState) -> Id {
+    if let Some(decl) = state.find_declaration(vname) {
+        let (id, ty) = (decl.metadata.id, decl.metadata.ty.clone());
+        state.refer(loc, id);
+        Id::Found(state.version(id), ty)
+    } else if let Some(func) = state.find_function(vname) {
+        let (id, ty) = (func.id, func.ty());
+        state.refer(loc, id);
+        Id::Found(id, ty)
+    } else if state.find_typedef(vname).is_some() {
+        state.push_error(loc.fail(format!("Unexpected type name {vname}: expected expression")));
+        Id::NotFound
+    } else {
+        state.push_error(loc.fail(format!("Use of undeclared variable {vname}")));
+        Id::NotFound
+    }
+}
+
+/// Pushes a unary operator applied to an operand already pushed into the basic
+/// blocks, after the integer promotions for `~`, `-` and `+`.
+///
+/// The increments and decrements are pushed by [`push_increment`].
+fn push_unary(op: &Located<UnaryOperator>, operand: &Operand, state: &mut LState) -> Id {
+    let (id, ty) = if matches!(
+        op.as_value(),
+        UnaryOperator::BitwiseNot | UnaryOperator::Minus | UnaryOperator::Plus
+    ) {
+        push_promotion(operand, state)
+    } else {
+        (operand.id, operand.ty.clone())
+    };
+    let result = ty
+        .apply_unary(op)
+        .store_errors(&mut |err| state.push_error(err))
+        .expect("never none");
+    Id::Found(state.push_element(Value::Unary(*op.as_value(), id), result.clone()), result)
+}
@@ -0,0 +1,137 @@
+//! Walks a function call, like `f(x)` or `(*table[i])(x)`, checking the
+//! arguments against the parameters of the called function.
+
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::const_eval::is_null_constant;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::function::Assignment;
+use crate::lineariser::walk::conversion::{Operand, push_conversion};
+use crate::parser::api::{Ast, VariableName, VariableValue};
+
+/// Pushes a call to `callee` with the given arguments.
+///
+/// The callee is converted to a pointer, and must point to a function. The
+/// arguments are converted to the types of the parameters, and the extra
+/// arguments of a variadic function get the default argument promotions.
+pub fn push_call(
+    callee: Ast,
+    arguments: Vec<Ast>,
+    variadic: Option<ErrorLocation>,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Id {
+    if let Some(loc) = variadic {
+        state.push_error(loc.fail("Found '...' in the arguments of a function call".to_owned()));
+    }
+    let callee_loc = callee.location();
+    let found = push_callee(callee, bbs, state);
+    let mut args = vec![];
+    let mut has_errors = false;
+    for arg in arguments {
+        let loc = arg.location();
+        let null = is_null_constant(&arg, state);
+        match arg.push_in(bbs, state) {
+            Some(Id::Found(id, ty)) => args.push(Operand { id, loc, null, ty }),
+            Some(Id::NotFound) => has_errors = true,
+            None => {
+                state.stat_not_expr(loc, "function argument");
+                has_errors = true;
+            }
+        }
+    }
+    let Some((callee_id, callee_ty)) = found else {
+        return Id::NotFound;
+    };
+    let Some(function) = callee_ty
+        .clone()
+        .decayed()
+        .pointee()
+        .filter(Type::is_function)
+    else {
+        if callee_ty != Type::empty() {
+            state.push_error(callee_loc.fail(format!(
+                "Called object of type {callee_ty} is not a function or function pointer"
+            )));
+        }
+        return Id::NotFound;
+    };
+    let signature = function.signature().expect("checked above");
+    if has_errors {
+        return Id::NotFound;
+    }
+    if !signature.accepts_count(args.len()) {
+        state.push_error(callee_loc.fail(format!(
+            "Wrong number of arguments in call to function of type {function}: found {}",
+            args.len()
+        )));
+        return Id::NotFound;
+    }
+    for (param, arg) in signature.params().iter().zip(&args) {
+        param
+            .check_assignment(&arg.ty, arg.null, arg.loc, Assignment::Argument)
+            .store_errors(&mut |err| state.push_error(err));
+    }
+    let ret = function.return_type().expect("checked above");
+    let ids = args
+        .iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            let param = signature
+                .params()
+                .get(index)
+                .map_or_else(|| arg.ty.argument_promoted(state.target()), Clone::clone);
+            push_conversion(arg, &param, state)
+        })
+        .collect();
+    Id::Found(state.push_element(Value::Call(callee_id, ids), ret.clone()), ret)
+}
+
+/// Pushes the callee of a function call, and returns its id and its type.
+///
+/// The parenthesis around the callee, like in `(*cb)(x)`, are ignored.
+fn push_callee(callee: Ast, bbs: &mut BasicBlocks, state: &mut LState) -> Option<(usize, Type)> {
+    match callee {
+        Ast::ParensBlock(parens) if !parens.as_value().is_empty() =>
+            push_callee(parens.into_inner().0, bbs, state),
+        Ast::Variable(var)
+            if var.as_user_defined_name().is_some_and(|name| {
+                state.find_declaration(name).is_none() && state.find_function(name).is_none()
+            }) =>
+        {
+            if let VariableValue::VariableName(loc, VariableName::UserDefined(name)) =
+                var.into_value()
+            {
+                state.push_error(loc.fail(format!("Call of undeclared function {name}")));
+            }
+            None
+        }
+        Ast::Binary(_)
+        | Ast::BracedBlock(_)
+        | Ast::Cast(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::ControlFlow(_)
+        | Ast::Empty
+        | Ast::FunctionArgsBuild(..)
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_)
+        | Ast::Leaf(_)
+        | Ast::ListInitialiser(_)
+        | Ast::ParensBlock(_)
+        | Ast::Ternary(_)
+        | Ast::Unary(_)
+        | Ast::Variable(_) => {
+            let loc = callee.location();
+            match callee.push_in(bbs, state) {
+                Some(Id::Found(id, ty)) => Some((id, ty)),
+                Some(Id::NotFound) => None,
+                None => {
+                    state.stat_not_expr(loc, "called function");
+                    None
+                }
+            }
+        }
+    }
+}
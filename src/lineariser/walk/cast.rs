@@ -0,0 +1,51 @@
+//! Walks the casts, like `(char *)p`, that are lowered into explicit
+//! conversions.
+
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::parser::api::Cast;
+
+impl Cast {
+    /// Pushes the conversion of the operand to the type of the cast into the
+    /// [`BasicBlocks`].
+    ///
+    /// No [`Value::Convert`] is pushed if the operand already has the type of
+    /// the cast, and a cast to `void` discards the value of the operand, like
+    /// in `(void)f()`.
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
+        #[cfg(feature = "debug")]
+        crate::lgp!(notab: "Pushing cast {self}");
+        let Self { dest_type, parens_location, value, .. } = self;
+        let dest = Type::from_attributes(&dest_type, state)
+            .store_errors(&mut |err| state.push_error(err))
+            .expect("never none");
+        let loc = if value.is_empty() {
+            parens_location
+        } else {
+            value.location()
+        };
+        let (id, ty) = match value.push_in(bbs, state) {
+            Some(Id::Found(id, ty)) => (id, ty),
+            Some(Id::NotFound) => return Id::NotFound,
+            None => {
+                state.stat_not_expr(loc, "cast");
+                return Id::NotFound;
+            }
+        };
+        let check = dest.check_cast(&ty, parens_location.into_extended(loc));
+        if check
+            .store_errors(&mut |err| state.push_error(err))
+            .is_none()
+        {
+            return Id::NotFound;
+        }
+        let result = dest.unqualified();
+        if dest.is_void() || ty.decayed().unqualified() == result {
+            Id::Found(id, result)
+        } else {
+            Id::Found(state.push_element(Value::Convert(id), result.clone()), result)
+        }
+    }
+}
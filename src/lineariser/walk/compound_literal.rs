@@ -0,0 +1,70 @@
+//! Walks the compound literals, like `(int[]){1, 2}`, that are lowered into
+//! anonymous objects.
+
+use crate::errors::api::CompileError;
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::lineariser::walk::initialiser::length::initialised_length;
+use crate::lineariser::walk::initialiser::push_anonymous;
+use crate::lineariser::walk::sizeof::type_name_type;
+use crate::parser::api::{Attribute, AttributeKeyword, CompoundLiteral, Storage};
+
+impl CompoundLiteral {
+    /// Pushes the anonymous object of the compound literal into the
+    /// [`BasicBlocks`].
+    ///
+    /// The object has static storage duration at file scope or if the type
+    /// name is `static`, like in `(static int[]){1, 2}`, and automatic storage
+    /// duration otherwise. The storage-class specifiers only give the storage
+    /// duration of the object, and aren't part of the type of the compound
+    /// literal: `&(static int){1}` is an `int *`.
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
+        #[cfg(feature = "debug")]
+        crate::lgp!(notab: "Pushing compound literal {self}");
+        match self.ty(state) {
+            Ok(ty) => match push_anonymous(ty, self.value, bbs, state) {
+                Id::Found(id, object_ty) => Id::Found(id, object_ty.without_storage()),
+                Id::NotFound => Id::NotFound,
+            },
+            Err(err) => {
+                state.push_error(err);
+                Id::NotFound
+            }
+        }
+    }
+
+    /// Returns the type of the object of the compound literal.
+    ///
+    /// The only storage-class specifiers allowed in the type name are
+    /// `constexpr`, `static`, `register` and `thread_local`, and the type must
+    /// be complete, or an array of unknown length, like `int[]`. The latter
+    /// gets its length from the initialiser list when it can be computed
+    /// without lowering it.
+    pub(crate) fn ty(&self, state: &LState) -> Result<Type, CompileError> {
+        let (attrs, sizes) = self.type_name.type_name().expect("checked by parser");
+        for attr in &attrs {
+            if let Attribute::Keyword(AttributeKeyword::Storage(
+                storage @ (Storage::Auto | Storage::Extern),
+            )) = attr.as_value()
+            {
+                return Err(attr.as_location().fail(format!(
+                    "Storage-class specifier {storage} is not allowed in a compound literal"
+                )));
+            }
+        }
+        let ty = type_name_type(&attrs, sizes, state)?;
+        let incomplete = ty.array_length() == Some(ArrayLength::Incomplete);
+        if incomplete && let Some(length) = initialised_length(&ty, &self.value, state) {
+            return Ok(ty.into_completed(length));
+        }
+        if !incomplete && state.layout(&ty).is_none() {
+            return Err(self
+                .type_name
+                .as_location()
+                .fail(format!("Compound literal has incomplete type {ty}")));
+        }
+        Ok(ty)
+    }
+}
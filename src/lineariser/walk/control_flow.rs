@@ -0,0 +1,276 @@
+//! Walks the control flow statements, creating the basic blocks and the jumps
+//! between them.
+
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::basic_block::{BasicBlocks, Id, Instruction};
+use crate::lineariser::const_eval::{eval_case_label, is_null_constant};
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::function::Assignment;
+use crate::lineariser::walk::conversion::{Operand, push_conversion};
+use crate::lineariser::walk::loops::{push_do_while, push_for, push_while};
+use crate::parser::api::{
+    Ast, ColonAstKeyword, ControlFlowNode, ParensBlock, ParensBlockKeyword, SemiColonKeyword
+};
+
+impl ControlFlowNode {
+    /// Pushes a control flow statement into the [`BasicBlocks`].
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) {
+        match self {
+            Self::Ast(return_ctrl) => {
+                let (value, keyword) = return_ctrl.into_parts();
+                if value.is_empty() {
+                    push_void_return(keyword, bbs, state);
+                    return;
+                }
+                let loc = value.location();
+                let null = is_null_constant(&value, state);
+                match value.push_in(bbs, state) {
+                    Some(Id::Found(id, ty)) => {
+                        let operand = Operand { id, loc, null, ty };
+                        bbs.add(Instruction::Return(Some(push_returned(&operand, state))));
+                    }
+                    Some(Id::NotFound) => (),
+                    None => state.stat_not_expr(loc, "return"),
+                }
+            }
+            Self::AstColonAst(case) => {
+                let (value, after, keyword) = case.into_parts();
+                if value.is_empty() {
+                    state.push_error(keyword.fail("Missing value after 'case'".to_owned()));
+                }
+                let label = state
+                    .switch_type()
+                    .cloned()
+                    .filter(|_| !value.is_empty())
+                    .and_then(|ty| {
+                        eval_case_label(&value, &ty, state)
+                            .map_err(|err| state.push_error(err))
+                            .ok()
+                    });
+                state.push_case(bbs, label.map(|number| value.location().wrap(number)), keyword);
+                push_statement(after, bbs, state);
+            }
+            Self::ColonAst(ctrl) => {
+                let (located, after) = ctrl.into_parts();
+                let (keyword, loc) = located.into_inner();
+                match keyword {
+                    ColonAstKeyword::Default => state.push_default(bbs, loc),
+                    ColonAstKeyword::Label(name) => state.push_label(bbs, name, loc),
+                }
+                push_statement(after, bbs, state);
+            }
+            Self::ColonIdent(goto) =>
+                if let (Some(label), _) = goto.into_parts() {
+                    state.push_goto(bbs, label);
+                },
+            Self::Condition(ctrl) => {
+                let (condition, success, failure, keyword) = ctrl.into_parts();
+                push_if(condition, *success, failure, keyword, bbs, state);
+            }
+            Self::DoWhile(ctrl) => {
+                let (body, condition, keyword) = ctrl.into_parts();
+                push_do_while(*body, condition, keyword, bbs, state);
+            }
+            Self::IdentBlock(ctrl) => ctrl.push_in(bbs, state),
+            Self::ParensBlock(ctrl) => {
+                let (located, parens, body) = ctrl.into_parts();
+                let (keyword, loc) = located.into_inner();
+                match keyword {
+                    ParensBlockKeyword::For => push_for(parens, *body, loc, bbs, state),
+                    ParensBlockKeyword::Switch => push_switch(parens, *body, loc, bbs, state),
+                    ParensBlockKeyword::While => push_while(parens, *body, loc, bbs, state),
+                }
+            }
+            Self::SemiColon(ctrl) => {
+                let (keyword, loc) = ctrl.into_keyword().into_inner();
+                match keyword {
+                    SemiColonKeyword::Break => state.push_break(bbs, loc),
+                    SemiColonKeyword::Continue => state.push_continue(bbs, loc),
+                }
+            }
+            Self::Typedef(ctrl) => ctrl.push_in(state),
+        }
+    }
+}
+
+/// Returns the terminator that branches to `success` if the condition holds,
+/// and to `failure` otherwise.
+///
+/// A missing condition always holds.
+pub fn branch(condition: Option<usize>, success: usize, failure: usize) -> Instruction {
+    condition.map_or(Instruction::Branch(success), |cond| {
+        Instruction::CondBranch(cond, success, failure)
+    })
+}
+
+/// Pushes the condition of a control flow statement.
+///
+/// # Returns
+///
+/// The id of the condition, if it is valid.
+pub fn push_condition(
+    condition: Option<ParensBlock>,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Option<usize> {
+    if let Some(parens) = condition {
+        // kept in parenthesis to report an empty condition
+        push_expression(Ast::ParensBlock(parens), bbs, state, "condition")
+    } else {
+        state.push_error(keyword.fail("Missing condition".to_owned()));
+        None
+    }
+}
+
+/// Pushes an expression.
+///
+/// # Returns
+///
+/// The id of the expression, if it is valid.
+pub fn push_expression(
+    ast: Ast,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+    scope: &str,
+) -> Option<usize> {
+    let loc = ast.location();
+    match ast.push_in(bbs, state) {
+        Some(Id::Found(id, _)) => Some(id),
+        Some(Id::NotFound) => None,
+        None => {
+            state.stat_not_expr(loc, scope);
+            None
+        }
+    }
+}
+
+/// Pushes the controlling expression of a `switch` statement, that must be an
+/// integer, converted to its promoted type.
+///
+/// # Returns
+///
+/// The id of the promoted value and its type, if it is valid.
+fn push_controlling(
+    condition: Option<ParensBlock>,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Option<(usize, Type)> {
+    let Some(parens) = condition else {
+        state.push_error(keyword.fail("Missing condition".to_owned()));
+        return None;
+    };
+    let (ast, parens_loc) = parens.into_inner();
+    if ast.is_empty() {
+        state.push_error(parens_loc.fail("Expected expression in parenthesis".to_owned()));
+        return None;
+    }
+    let loc = ast.location();
+    match ast.push_in(bbs, state) {
+        Some(Id::Found(id, ty)) if ty.is_integer() => {
+            let promoted = ty.promoted(state.target());
+            let operand = Operand { id, loc, null: false, ty };
+            Some((push_conversion(&operand, &promoted, state), promoted))
+        }
+        Some(Id::Found(_, ty)) => {
+            state.push_error(loc.fail(format!("Switch condition has non-integer type {ty}")));
+            None
+        }
+        Some(Id::NotFound) => None,
+        None => {
+            state.stat_not_expr(loc, "condition");
+            None
+        }
+    }
+}
+
+/// Converts a returned value to the return type of the function, as if by
+/// assignment, and returns the id of the converted value.
+fn push_returned(operand: &Operand, state: &mut LState) -> usize {
+    let Some(ret) = state.return_type().cloned() else {
+        return operand.id;
+    };
+    ret.check_assignment(&operand.ty, operand.null, operand.loc, Assignment::Return)
+        .store_errors(&mut |err| state.push_error(err));
+    push_conversion(operand, &ret, state)
+}
+
+/// Pushes a `return` without value, that is only valid in a function returning
+/// `void`.
+fn push_void_return(keyword: ErrorLocation, bbs: &mut BasicBlocks, state: &mut LState) {
+    if let Some(ret) = state.return_type()
+        && !ret.is_void()
+    {
+        let msg = format!("Missing value after 'return' in a function returning {ret}");
+        state.push_error(keyword.fail(msg));
+    }
+    bbs.add(Instruction::Return(None));
+}
+
+/// Pushes an `if` statement, with its optional `else` block.
+fn push_if(
+    condition: Option<ParensBlock>,
+    success: Ast,
+    failure: Option<Box<Ast>>,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) {
+    let cond = push_condition(condition, keyword, bbs, state);
+    let from = bbs.current();
+    let skip = state.edge(from, false);
+    let success_block = bbs.new_block();
+    success.push_in(bbs, state);
+    let mut edges = state.fallthrough(bbs).into_iter().collect::<Vec<_>>();
+    let failure_block = if let Some(node) = failure {
+        state.restore_versions(&skip);
+        let block = bbs.new_block();
+        node.push_in(bbs, state);
+        edges.extend(state.fallthrough(bbs));
+        Some(block)
+    } else {
+        edges.push(skip);
+        None
+    };
+    let merge = state.join(bbs, &edges, true);
+    bbs.terminate(from, branch(cond, success_block, failure_block.unwrap_or(merge)));
+}
+
+/// Pushes an optional statement.
+pub fn push_statement(ast: Option<Box<Ast>>, bbs: &mut BasicBlocks, state: &mut LState) {
+    if let Some(node) = ast {
+        node.push_in(bbs, state);
+    }
+}
+
+/// Pushes a `switch` statement.
+fn push_switch(
+    parens: Option<ParensBlock>,
+    body: Ast,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) {
+    let controlling = push_controlling(parens, keyword, bbs, state);
+    let (value, ty) = controlling.unzip();
+    let from = state.enter_switch(bbs, ty);
+    body.push_in(bbs, state);
+    state.check_fallthrough(None);
+    let breakable = state.exit_breakable();
+    let cases = breakable.switch.expect("entered a switch");
+    let mut exits = breakable.breaks;
+    exits.extend(state.fallthrough(bbs));
+    if cases.default.is_none() {
+        exits.push(cases.dispatch);
+    }
+    let exit = state.join(bbs, &exits, true);
+    let default = cases.default.unwrap_or(exit);
+    bbs.terminate(
+        from,
+        value.map_or(Instruction::Branch(default), |id| {
+            Instruction::Switch(id, cases.cases, default)
+        }),
+    );
+}
@@ -0,0 +1,53 @@
+//! Pushes the implicit conversions of the operands of the expressions, like
+//! the integer promotions or the usual arithmetic conversions.
+
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::const_eval::is_representable;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+
+/// Operand of an expression, already pushed in the basic blocks.
+#[derive(Debug)]
+pub struct Operand {
+    /// Id of the value of the operand.
+    pub id: usize,
+    /// Location of the operand.
+    pub loc: ErrorLocation,
+    /// Whether the operand is a null pointer constant, like `0`.
+    pub null: bool,
+    /// Type of the operand.
+    pub ty: Type,
+}
+
+/// Converts an operand to the type `to`, and returns the id of the converted
+/// value.
+///
+/// A [`Value::Convert`] is only pushed if the representation of the value
+/// changes, see [`Type::needs_conversion`]. A warning is emitted if the
+/// conversion can change the value, unless the operand is a literal that fits
+/// in `to`, like `1` converted to `unsigned`.
+pub fn push_conversion(operand: &Operand, to: &Type, state: &mut LState) -> usize {
+    if !operand
+        .ty
+        .needs_conversion(to, operand.null, state.target())
+    {
+        return operand.id;
+    }
+    if let Some(msg) = operand.ty.conversion_loss(to, state.target())
+        && !state
+            .find_literal(operand.id)
+            .is_some_and(|literal| is_representable(literal, to, operand.loc, state))
+    {
+        state.push_error(operand.loc.warn(msg));
+    }
+    let ty = to.unqualified();
+    state.push_element(Value::Convert(operand.id), ty)
+}
+
+/// Applies the integer promotions on an operand, and returns the id and the
+/// type of the promoted value.
+pub fn push_promotion(operand: &Operand, state: &mut LState) -> (usize, Type) {
+    let promoted = operand.ty.promoted(state.target());
+    (push_conversion(operand, &promoted, state), promoted)
+}
@@ -0,0 +1,136 @@
+//! Reads the declarators that follow the definition of a struct, union or
+//! enum, like `*a, b = 0` in `struct A { int x; } *a, b = 0;`.
+
+use crate::errors::api::Located;
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::lineariser::walk::arrays::constant_length;
+use crate::parser::api::{
+    Ast, Binary, BinaryOperator, Unary, UnaryOperator, VariableName, VariableValue
+};
+
+/// Returns the name and the type of a declarator, like `*a` or `a[2]`.
+///
+/// The type of the declarator is `ty`, with the indirections and the arrays of
+/// the declarator. The sizes of the arrays must be constant.
+pub fn declarator_name(ast: Ast, ty: Type, state: &mut LState) -> Option<(Located<String>, Type)> {
+    match ast {
+        Ast::Unary(Unary { op, arg }) if *op.as_value() == UnaryOperator::Indirection =>
+            if arg.is_empty() {
+                state.push_error(
+                    op.as_location()
+                        .fail("Expected variable name after '*'".to_owned()),
+                );
+                None
+            } else {
+                declarator_name(*arg, ty.into_pointer(), state)
+            },
+        Ast::Binary(Binary { op, arg_l, arg_r })
+            if *op.as_value() == BinaryOperator::ArraySubscript =>
+        {
+            let length = if arg_r.is_empty() {
+                ArrayLength::Incomplete
+            } else {
+                match constant_length(&arg_r, state) {
+                    Ok(len) => ArrayLength::Fixed(len),
+                    Err(err) => {
+                        state.push_error(err);
+                        ArrayLength::Incomplete
+                    }
+                }
+            };
+            declarator_name(*arg_l, ty.into_array(length), state)
+        }
+        Ast::Variable(var) => match var.into_value() {
+            VariableValue::VariableName(loc, VariableName::UserDefined(name)) =>
+                Some((loc.wrap(name), ty)),
+            VariableValue::VariableName(loc, VariableName::Keyword(_)) => {
+                state.push_error(loc.fail("Expected variable name after type".to_owned()));
+                None
+            }
+            VariableValue::AttributeVariable(attr) => {
+                state.push_error(
+                    attr.location()
+                        .fail("Expected variable name after type, found declaration".to_owned()),
+                );
+                None
+            }
+        },
+        node @ (Ast::Binary(_)
+        | Ast::BracedBlock(_)
+        | Ast::Cast(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::ControlFlow(_)
+        | Ast::Empty
+        | Ast::FunctionArgsBuild(..)
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_)
+        | Ast::Leaf(_)
+        | Ast::ListInitialiser(_)
+        | Ast::ParensBlock(_)
+        | Ast::Ternary(_)
+        | Ast::Unary(_)) => {
+            state.push_error(
+                node.location()
+                    .fail("Expected variable name after type".to_owned()),
+            );
+            None
+        }
+    }
+}
+
+/// Splits the declarators that follow a type definition, like `a, *b = 0`,
+/// into the declarators and their initial values.
+pub fn split_declarators(ast: Ast) -> Vec<(Ast, Option<Ast>)> {
+    let mut declarators = vec![];
+    split_commas(ast, &mut declarators);
+    declarators
+        .into_iter()
+        .map(|declarator| match declarator {
+            Ast::Binary(Binary { op, arg_l, arg_r })
+                if *op.as_value() == BinaryOperator::Assign =>
+                (*arg_l, Some(*arg_r)),
+            node @ (Ast::Binary(_)
+            | Ast::BracedBlock(_)
+            | Ast::Cast(_)
+            | Ast::CompoundLiteral(_)
+            | Ast::ControlFlow(_)
+            | Ast::Empty
+            | Ast::FunctionArgsBuild(..)
+            | Ast::FunctionCall(_)
+            | Ast::Generic(_)
+            | Ast::Leaf(_)
+            | Ast::ListInitialiser(_)
+            | Ast::ParensBlock(_)
+            | Ast::Ternary(_)
+            | Ast::Unary(_)
+            | Ast::Variable(_)) => (node, None),
+        })
+        .collect()
+}
+
+/// Splits a list of expressions separated by commas, like `a, b = 1, c`.
+pub fn split_commas(ast: Ast, elts: &mut Vec<Ast>) {
+    match ast {
+        Ast::Empty => (),
+        Ast::Binary(Binary { op, arg_l, arg_r }) if *op.as_value() == BinaryOperator::Comma => {
+            split_commas(*arg_l, elts);
+            split_commas(*arg_r, elts);
+        }
+        node @ (Ast::Binary(_)
+        | Ast::BracedBlock(_)
+        | Ast::Cast(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::ControlFlow(_)
+        | Ast::FunctionArgsBuild(..)
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_)
+        | Ast::Leaf(_)
+        | Ast::ListInitialiser(_)
+        | Ast::ParensBlock(_)
+        | Ast::Ternary(_)
+        | Ast::Unary(_)
+        | Ast::Variable(_)) => elts.push(node),
+    }
+}
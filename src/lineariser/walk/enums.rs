@@ -0,0 +1,115 @@
+//! Walks the definition of an enum, giving a value to each constant.
+
+use crate::Number;
+use crate::errors::api::{CompileError, Located};
+use crate::lineariser::const_eval::eval_constant;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::walk::declarators::split_commas;
+use crate::parser::api::{
+    Ast, Binary, BinaryOperator, BracedBlock, Literal, VariableName, VariableValue
+};
+use crate::target::Scalar;
+
+/// Evaluates the value of an enum constant, given with `A = value`.
+///
+/// The value must be an integer constant expression.
+fn constant_value(ast: &Ast, state: &LState) -> Result<i64, CompileError> {
+    let number = eval_constant(ast, state)?;
+    match number {
+        Number::Int(value) => Ok(i64::from(value)),
+        Number::Long(value) | Number::LongLong(value) => Ok(value),
+        Number::UInt(value) => Ok(i64::from(value)),
+        Number::ULong(value) | Number::ULongLong(value) =>
+            i64::try_from(value).ok().ok_or_else(|| {
+                ast.location()
+                    .fail(format!("Enumerator value {value} is not representable as a long long"))
+            }),
+        Number::BitInt(_)
+        | Number::UBitInt(_)
+        | Number::Float(_)
+        | Number::Double(_)
+        | Number::LongDouble(_) => Err(ast
+            .location()
+            .fail(format!("Enumerator value must be an integer, found {number}"))),
+    }
+}
+
+/// Defines an enum constant, with its name and value.
+///
+/// The constant is an `int` if the value fits, and a `long long` otherwise.
+fn push_enumerator(name: Located<String>, value: i64, state: &mut LState) {
+    let number = i32::try_from(value).map_or(Number::LongLong(value), Number::Int);
+    state.push_enum_constant(name.as_value().to_owned(), number.clone());
+    let lit = Literal::Number(number);
+    let ty = Type::from_lit(&lit);
+    let id = state.push_literal(lit);
+    state.push_declaration(name, &ty, Value::Variable(id));
+}
+
+/// Defines the constants of an enum, in order.
+///
+/// # Returns
+///
+/// The underlying type of the enum: `int`, or `long long` if a value doesn't
+/// fit in an `int` (C23).
+pub fn push_enumerators(body: BracedBlock, state: &mut LState) -> Scalar {
+    let mut next = 0;
+    let mut underlying = Scalar::Int;
+    let mut constants = vec![];
+    for elt in body.elts {
+        split_commas(elt, &mut constants);
+    }
+    for constant in constants {
+        let (name, value) = match constant {
+            Ast::Binary(Binary { op, arg_l, arg_r })
+                if *op.as_value() == BinaryOperator::Assign && arg_r.is_empty() =>
+            {
+                state.push_error(
+                    op.as_location()
+                        .fail("Missing value of enum constant after '='".to_owned()),
+                );
+                (*arg_l, next)
+            }
+            Ast::Binary(Binary { op, arg_l, arg_r })
+                if *op.as_value() == BinaryOperator::Assign =>
+                match constant_value(&arg_r, state) {
+                    Ok(value) => (*arg_l, value),
+                    Err(err) => {
+                        state.push_error(err);
+                        (*arg_l, next)
+                    }
+                },
+            node @ (Ast::Binary(_)
+            | Ast::BracedBlock(_)
+            | Ast::Cast(_)
+            | Ast::CompoundLiteral(_)
+            | Ast::ControlFlow(_)
+            | Ast::Empty
+            | Ast::FunctionArgsBuild(..)
+            | Ast::FunctionCall(_)
+            | Ast::Generic(_)
+            | Ast::Leaf(_)
+            | Ast::ListInitialiser(_)
+            | Ast::ParensBlock(_)
+            | Ast::Ternary(_)
+            | Ast::Unary(_)
+            | Ast::Variable(_)) => (node, next),
+        };
+        let loc = name.location();
+        if let Ast::Variable(var) = name
+            && let VariableValue::VariableName(_, VariableName::UserDefined(ident)) =
+                var.into_value()
+        {
+            push_enumerator(loc.wrap(ident), value, state);
+        } else {
+            state.push_error(loc.fail("Expected enumerator name".to_owned()));
+        }
+        if i32::try_from(value).is_err() {
+            underlying = Scalar::LongLong;
+        }
+        next = value.saturating_add(1);
+    }
+    underlying
+}
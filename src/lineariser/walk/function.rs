@@ -4,30 +4,41 @@
 use crate::errors::api::Located;
 use crate::lineariser::basic_block::{BasicBlocks, Id};
 use crate::lineariser::state::LState;
-use crate::lineariser::symbol::Value;
-use crate::lineariser::types::{ReturnType, Type};
-use crate::parser::api::{Ast, BracedBlock, FunctionCall, VariableName, VariableValue};
+use crate::lineariser::types::ReturnType;
+use crate::lineariser::walk::call::push_call;
+use crate::lineariser::walk::parameters::read_parameters;
+use crate::lineariser::walk::static_assert::check_static_assert;
+use crate::parser::api::{
+    Ast, Attribute, BracedBlock, FunctionCall, FunctionKeyword, ParameterList, VariableName, VariableValue
+};
 
 impl FunctionCall {
     /// Pushes some content into the [`BasicBlocks`].
-    pub fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Option<Id> {
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Option<Id> {
         #[cfg(feature = "debug")]
         crate::lgp!(notab: "Pushing fn {self}");
-        let Self { mut arguments, function_body, variable, .. } = self;
+        let Self { arguments, callee, function_body, parens_location, variadic } = self;
+        let parameters =
+            ParameterList { location: parens_location, parameters: arguments, variadic };
+        let Ast::Variable(variable) = *callee else {
+            return Some(push_call(*callee, parameters.parameters, variadic, bbs, state));
+        };
         match variable.into_value() {
             VariableValue::AttributeVariable(attr) => {
                 let loc = attr.location();
                 let (var, attrs) = attr.into_single_variable();
-                if let Some(name) = var {
-                    declare_function(
-                        name,
-                        arguments,
-                        ReturnType::from_attributes(&attrs)
-                            .store_errors(&mut |err| state.push_error(err))
-                            .expect("never none"),
-                        function_body,
-                        state,
-                    );
+                if let Some(decl) = var {
+                    debug_assert!(decl.parens.is_none(), "parameters pushed in the declarator");
+                    if let Some(array) = decl.arrays.first() {
+                        state.push_error(array.location.fail(format!(
+                            "Declaration of {} as array of functions",
+                            decl.name.as_value()
+                        )));
+                    }
+                    let ret = ReturnType::from_attributes(&attrs, state)
+                        .store_errors(&mut |err| state.push_error(err))
+                        .expect("never none");
+                    declare_function(decl.name, parameters, ret, &attrs, function_body, state);
                 } else {
                     state.push_error(
                         loc.fail("Found illegal comma in function declaration".to_owned()),
@@ -41,8 +52,9 @@ impl FunctionCall {
                 state.push_error(loc.fail(format!("Missing return type for function {name}")));
                 declare_function(
                     loc.wrap(name),
-                    arguments,
+                    parameters,
                     ReturnType::empty(),
+                    &[],
                     function_body,
                     state,
                 );
@@ -56,101 +68,41 @@ impl FunctionCall {
                 )));
                 None
             }
-            VariableValue::VariableName(varloc, VariableName::UserDefined(name)) => {
-                if let Some(func) = state.find_function(&name) {
-                    let ret = func.ret.clone();
-                    let fid = func.id;
-                    let mut args = vec![];
-                    let mut has_errors = false;
-                    for arg in arguments {
-                        let argloc = arg.location();
-                        match arg.push_in(bbs, state) {
-                            Some(Id::Found(id, _)) => args.push(id),
-                            Some(Id::NotFound) => has_errors = true,
-                            None => {
-                                state.stat_not_expr(argloc, "function argument");
-                                has_errors = true;
-                            }
-                        }
-                    }
-                    if has_errors {
-                        Some(Id::NotFound)
-                    } else {
-                        let ty = ret.into_type();
-                        Some(Id::Found(state.push_element(Value::Call(fid, args), ty.clone()), ty))
-                    }
-                } else {
-                    state.push_error(varloc.fail(format!("Call of undeclared function {name}")));
-                    Some(Id::NotFound)
-                }
+            VariableValue::VariableName(loc, VariableName::UserDefined(name)) => {
+                let function = Ast::Variable(loc.wrap(name).into());
+                Some(push_call(function, parameters.parameters, variadic, bbs, state))
             }
-            VariableValue::VariableName(loc, VariableName::Keyword(kwd)) => {
-                if arguments.len() > 1 {
-                    state.push_error(loc.fail(format!(
-                        "Too many arguments in call to `{kwd}`: expected 1, got {}",
-                        arguments.len()
-                    )));
-                    return None;
-                }
-                let Some(_) = arguments.pop() else {
-                    state.push_error(
-                        loc.fail(format!("Missing argument in call to `{kwd}`: expected 1, got 0")),
-                    );
-                    return None;
-                };
-                state.push_error(loc.fail(format!("Function keuword {kwd} is not yet supported")));
-                Some(Id::NotFound)
+            VariableValue::VariableName(
+                loc,
+                VariableName::Keyword(FunctionKeyword::StaticAssert),
+            ) => {
+                check_static_assert(loc, &parameters.parameters, state);
+                None
             }
         }
     }
 }
 
 /// Declares a function with the given signature.
+///
+/// The standard attributes of the declaration, like `[[nodiscard]]`, are
+/// applied on the declared function.
 fn declare_function(
     name: Located<String>,
-    arguments: Vec<Ast>,
+    parameters: ParameterList,
     ret: ReturnType,
+    fn_attrs: &[Located<Attribute>],
     body: Option<BracedBlock>,
     state: &mut LState,
 ) {
     #[cfg(feature = "debug")]
     crate::lgp!(notab: "Declaring fn {}", name.as_value());
-    let mut args = vec![];
-    for ast in arguments {
-        if let Ast::Variable(arg_var) = ast {
-            match arg_var.into_value() {
-                VariableValue::AttributeVariable(arg_attr) => {
-                    let loc = arg_attr.location();
-                    let (arg, attrs) = arg_attr.into_single_variable();
-                    let ty = Type::from_attributes(&attrs)
-                        .store_errors(&mut |err| state.push_error(err))
-                        .expect("never none");
-                    if let Some(arg_name) = arg {
-                        args.push((arg_name, ty));
-                    } else {
-                        state.push_error(loc.fail("Missing argument name".to_owned()));
-                        args.push((loc.wrap(String::new()), ty));
-                    }
-                }
-                VariableValue::VariableName(loc, arg_name) => {
-                    state.push_error(loc.fail("Missing argument type".to_owned()));
-                    match arg_name {
-                        VariableName::Keyword(_) => {
-                            state.push_error(
-                                loc.fail("Invalid argument name, shadows keyword.".to_owned()),
-                            );
-                            args.push((loc.wrap(String::new()), Type::empty()));
-                        }
-                        VariableName::UserDefined(vname) =>
-                            args.push((loc.wrap(vname), Type::empty())),
-                    }
-                }
-            }
-        } else {
-            let loc = ast.location();
-            state.push_error(loc.fail("Expected argument declaration".to_owned()));
-            args.push((loc.wrap(String::new()), Type::empty()));
-        }
+    let variadic = parameters.variadic.is_some();
+    let unprototyped = parameters.parameters.is_empty();
+    let args = read_parameters(parameters.parameters, parameters.location, state);
+    let fname = name.as_value().to_owned();
+    state.push_function(name, args, ret, variadic, unprototyped, body);
+    if let Some(func) = state.find_function(&fname) {
+        state.apply_attributes(func.id, &fname, fn_attrs, true);
     }
-    state.push_function(name, args, ret, body);
 }
@@ -0,0 +1,73 @@
+//! Walks a `_Generic` selection, pushing the selected association.
+
+use crate::Res;
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::parser::api::Generic;
+
+impl Generic {
+    /// Pushes the value of the selected association into the
+    /// [`BasicBlocks`].
+    ///
+    /// The controlling expression and the other associations are not
+    /// evaluated.
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Option<Id> {
+        #[cfg(feature = "debug")]
+        crate::lgp!(notab: "Pushing generic {self}");
+        let index = select_association(&self, state).store_errors(&mut |err| state.push_error(err));
+        if let Some(association) = index.and_then(|idx| self.associations.into_iter().nth(idx)) {
+            association.value.push_in(bbs, state)
+        } else {
+            Some(Id::NotFound)
+        }
+    }
+}
+
+/// Returns the index of the association selected by the type of the
+/// controlling expression.
+///
+/// The type of the controlling expression is taken after the lvalue and
+/// array-to-pointer conversions, like `char *` for `"abc"`, and the
+/// association with `default` is selected if no type name matches.
+pub fn select_association(generic: &Generic, state: &LState) -> Res<usize> {
+    let Some(ty) =
+        Type::of_expression(&generic.controlling, state).filter(|ty| *ty != Type::empty())
+    else {
+        return Res::from_err(generic.controlling.location().fail(
+            "Failed to find the type of the controlling expression of `_Generic`".to_owned(),
+        ));
+    };
+    let controlling = ty.decayed().unqualified();
+    let mut errors = vec![];
+    let mut found = vec![];
+    let mut selected = None;
+    let mut default = None;
+    for (index, association) in generic.associations.iter().enumerate() {
+        if let Some(type_name) = &association.type_name {
+            if let Some(assoc_ty) =
+                Type::from_attributes(type_name, state).store_errors(&mut |err| errors.push(err))
+            {
+                let resolved = assoc_ty.fully_resolved();
+                if found.contains(&resolved) {
+                    errors.push(association.location.fail(format!(
+                        "Type {assoc_ty} appears in multiple associations of `_Generic`"
+                    )));
+                } else if resolved == controlling {
+                    selected = Some(index);
+                }
+                found.push(resolved);
+            }
+        } else {
+            default = Some(index);
+        }
+    }
+    if let Some(index) = selected.or(default) {
+        Res::from((index, errors))
+    } else {
+        Res::from_err(generic.location.fail(format!(
+            "No association of `_Generic` matches the type {controlling} of the controlling expression"
+        )))
+        .add_errs(errors)
+    }
+}
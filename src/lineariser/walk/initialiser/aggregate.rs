@@ -0,0 +1,114 @@
+//! Describes the subobjects of the arrays, structs and unions, in the order in
+//! which an initialiser list initialises them.
+
+use crate::lineariser::state::LState;
+use crate::lineariser::state::tags::Member;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::parser::api::UserDefinedTypes;
+
+/// Object made of subobjects, that can be initialised with an initialiser
+/// list.
+#[derive(Debug)]
+pub enum Aggregate {
+    /// Array.
+    Array {
+        /// Type of the elements.
+        element: Type,
+        /// Number of elements, or `None` if it isn't known at compile time,
+        /// like in `int a[] = {1, 2}`.
+        length: Option<u64>,
+        /// Size of an element, in bytes.
+        size: u64,
+    },
+    /// Struct, whose members are all initialised in order.
+    Struct(Vec<Member>),
+    /// Union, whose first member only is initialised without designators.
+    Union(Vec<Member>),
+}
+
+impl Aggregate {
+    /// Returns the index of a member by name, in a struct or a union.
+    ///
+    /// If the member is in an anonymous struct or union, returns the index of
+    /// the anonymous member, and `true`.
+    pub fn find(&self, name: &str, state: &LState) -> Option<(u64, bool)> {
+        let members = match self {
+            Self::Array { .. } => return None,
+            Self::Struct(members) | Self::Union(members) => members,
+        };
+        let index = |position: usize| u64::try_from(position).ok();
+        if let Some(position) = members.iter().position(|member| member.name == name) {
+            return Some((index(position)?, false));
+        }
+        let position = members.iter().position(|member| {
+            member.name.is_empty()
+                && Self::of(&member.ty, state)
+                    .is_some_and(|inner| inner.find(name, state).is_some())
+        })?;
+        Some((index(position)?, true))
+    }
+
+    /// Checks if the aggregate is an array.
+    pub const fn is_array(&self) -> bool {
+        matches!(self, Self::Array { .. })
+    }
+
+    /// Returns the name of the kind of aggregate, for the error messages.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Array { .. } => "array",
+            Self::Struct(_) => "struct",
+            Self::Union(_) => "union",
+        }
+    }
+
+    /// Returns the offset in bytes and the type of the subobject at the given
+    /// index.
+    ///
+    /// Returns `None` if the index is past the end of the aggregate.
+    pub fn member(&self, index: u64) -> Option<(u64, Type)> {
+        match self {
+            Self::Array { element, length, size } => length
+                .is_none_or(|len| index < len)
+                .then(|| (index.saturating_mul(*size), element.clone())),
+            Self::Struct(members) | Self::Union(members) => members
+                .get(usize::try_from(index).ok()?)
+                .map(|member| (member.offset, member.ty.clone())),
+        }
+    }
+
+    /// Returns the index of the subobject initialised after the one at the
+    /// given index, by the next element without designator.
+    ///
+    /// Only one member of a union is initialised, so there is none after.
+    pub const fn next(&self, index: u64) -> u64 {
+        match self {
+            Self::Array { .. } | Self::Struct(_) => index.saturating_add(1),
+            Self::Union(_) => u64::MAX,
+        }
+    }
+
+    /// Returns the subobjects of a type, or `None` if the type isn't an array,
+    /// or a complete struct or union.
+    pub fn of(ty: &Type, state: &LState) -> Option<Self> {
+        if let Some(array_length) = ty.array_length() {
+            let element = ty.clone().decayed().pointee()?;
+            let size = state.layout(&element).map_or(0, |layout| layout.size);
+            let length = match array_length {
+                ArrayLength::Fixed(len) => Some(len),
+                ArrayLength::Incomplete | ArrayLength::Unspecified | ArrayLength::Variable(_) =>
+                    None,
+            };
+            return Some(Self::Array { element, length, size });
+        }
+        let (kind, name) = ty.as_tag()?;
+        let tag = state.tags().find(name).filter(|tag| tag.layout.is_some())?;
+        let members = tag.members.clone();
+        match kind {
+            UserDefinedTypes::Struct => Some(Self::Struct(members)),
+            UserDefinedTypes::Union => Some(Self::Union(members)),
+            UserDefinedTypes::Enum => None,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Reads the designations of the elements of the initialiser lists, like
+//! `.p[2] =` in `{.p[2] = 1}`.
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+
+use crate::errors::api::{CompileError, ErrorLocation};
+use crate::parser::api::{Ast, Binary, BinaryOperator};
+
+/// Designator of a subobject in a designation.
+#[derive(Debug)]
+pub enum Designator {
+    /// Element of an array, like `[2]`.
+    Index(Ast, ErrorLocation),
+    /// Member of a struct or a union, like `.x`.
+    Member(String, ErrorLocation),
+}
+
+/// Returns the index of the first designator of an element of an initialiser
+/// list, like `2` in `[2].x = 1`, if it designates an element of an array.
+///
+/// See [`is_designation`].
+pub fn first_index(elt: &Ast) -> Option<&Ast> {
+    let Ast::Binary(Binary { arg_l, .. }) = elt else {
+        return None;
+    };
+    let mut designator = &**arg_l;
+    while let Ast::Binary(Binary { op, arg_l: outer, arg_r }) = designator {
+        if outer.is_empty() {
+            return (*op.as_value() == BinaryOperator::ArraySubscript).then_some(&**arg_r);
+        }
+        designator = outer;
+    }
+    None
+}
+
+/// Checks if an element of an initialiser list starts with a designation,
+/// like `[2] = 1` or `.x = 1`.
+pub fn is_designation(elt: &Ast) -> bool {
+    if let Ast::Binary(Binary { op, arg_l, .. }) = elt {
+        *op.as_value() == BinaryOperator::Assign && is_designator(arg_l)
+    } else {
+        false
+    }
+}
+
+/// Checks if a node is a list of designators, like `.p[2]`.
+///
+/// The designators are parsed as member accesses and subscripts without
+/// left operand.
+fn is_designator(ast: &Ast) -> bool {
+    if let Ast::Binary(Binary { op, arg_l, .. }) = ast {
+        matches!(
+            op.as_value(),
+            BinaryOperator::ArraySubscript | BinaryOperator::StructEnumMemberAccess
+        ) && (arg_l.is_empty() || is_designator(arg_l))
+    } else {
+        false
+    }
+}
+
+/// Pushes the designators of a list of designators, from the outermost, into
+/// `designators`.
+fn push_designators(ast: Ast, designators: &mut VecDeque<Designator>) -> Result<(), CompileError> {
+    let Ast::Binary(Binary { op, arg_l, arg_r }) = ast else {
+        return Ok(());
+    };
+    push_designators(*arg_l, designators)?;
+    if arg_r.is_empty() {
+        let expected = if *op.as_value() == BinaryOperator::ArraySubscript {
+            "index in '[]'"
+        } else {
+            "member name after '.'"
+        };
+        return Err(op
+            .as_location()
+            .fail(format!("Expected {expected} in designator")));
+    }
+    let loc = op.as_location().into_extended(arg_r.location());
+    if *op.as_value() == BinaryOperator::ArraySubscript {
+        designators.push_back(Designator::Index(*arg_r, loc));
+    } else if let Ast::Variable(var) = *arg_r
+        && let Some(name) = var.as_user_defined_name()
+    {
+        designators.push_back(Designator::Member(name.to_owned(), loc));
+    } else {
+        return Err(loc.fail("Expected member name after '.' in designator".to_owned()));
+    }
+    Ok(())
+}
+
+/// Splits an element of an initialiser list that starts with a designation
+/// into its designators, from the outermost, and its value.
+///
+/// See [`is_designation`].
+pub fn split_designation(elt: Ast) -> (Result<VecDeque<Designator>, CompileError>, Ast) {
+    let Ast::Binary(Binary { arg_l, arg_r, .. }) = elt else {
+        unreachable!("checked by caller")
+    };
+    let mut designators = VecDeque::new();
+    (push_designators(*arg_l, &mut designators).map(|()| designators), *arg_r)
+}
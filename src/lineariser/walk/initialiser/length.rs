@@ -0,0 +1,44 @@
+//! Computes the length of the arrays of unknown length from their initialiser
+//! lists, without lowering them, like `2` for `(int[]){1, 2}`.
+
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::walk::arrays::constant_index;
+use crate::lineariser::walk::initialiser::aggregate::Aggregate;
+use crate::lineariser::walk::initialiser::designation::{first_index, is_designation};
+use crate::lineariser::walk::initialiser::is_string_for;
+use crate::parser::api::{Ast, ListInitialiser, Literal};
+
+/// Returns the number of elements of the array of unknown length of type
+/// `ty` initialised by `list`.
+///
+/// Returns `None` if the elements of the array are aggregates, as the length
+/// then depends on the elided braces, or if a designator isn't constant.
+pub fn initialised_length(ty: &Type, list: &ListInitialiser, state: &LState) -> Option<u64> {
+    let Some(Aggregate::Array { element, .. }) = Aggregate::of(ty, state) else {
+        return None;
+    };
+    let mut elts = list.elts.iter().filter(|elt| !elt.is_empty()).peekable();
+    if let Some(Ast::Leaf(literal)) = elts.peek()
+        && is_string_for(ty, elts.peek()?, state)
+        && let Literal::Str(string, encoding) = literal.as_value()
+    {
+        // braced string literal, like in `(char[]){"abc"}`
+        return u64::try_from(encoding.code_units(string))
+            .ok()
+            .map(|units| units.saturating_add(1));
+    }
+    if Aggregate::of(&element, state).is_some() {
+        return None;
+    }
+    let mut index = 0;
+    let mut length = 0;
+    for elt in elts {
+        if is_designation(elt) {
+            index = constant_index(first_index(elt)?, "Array designator index", state).ok()?;
+        }
+        index = index.saturating_add(1);
+        length = length.max(index);
+    }
+    Some(length)
+}
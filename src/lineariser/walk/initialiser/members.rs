@@ -0,0 +1,243 @@
+//! Walks the elements of the initialiser lists, following the designators
+//! and the elided braces to find the subobjects they initialise.
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+
+use crate::errors::api::CompileError;
+use crate::lineariser::types::Type;
+use crate::lineariser::walk::arrays::constant_index;
+use crate::lineariser::walk::initialiser::aggregate::Aggregate;
+use crate::lineariser::walk::initialiser::designation::{
+    Designator, is_designation, split_designation
+};
+use crate::lineariser::walk::initialiser::{Initialiser, is_string_for};
+use crate::parser::api::{Ast, ListInitialiser};
+
+impl Initialiser<'_> {
+    /// Initialises the subobject designated by `designators` in the aggregate
+    /// of type `ty` at `offset`, with the element at the front of `elts`.
+    ///
+    /// As after a subobject initialised without designator, the next elements
+    /// initialise the subobjects that follow the designated one.
+    ///
+    /// # Returns
+    ///
+    /// The index of the subobject that follows the designated one, or `None`
+    /// if a designator is invalid, in which case the element is dropped.
+    fn designate(
+        &mut self,
+        ty: &Type,
+        offset: u64,
+        designators: &mut VecDeque<Designator>,
+        elts: &mut VecDeque<Ast>,
+    ) -> Option<u64> {
+        let designated = self.designated_index(ty, designators);
+        let Some((aggregate, index)) = designated.map_err(|err| self.state.push_error(err)).ok()
+        else {
+            elts.pop_front();
+            return None;
+        };
+        let (relative, member_ty) = aggregate.member(index)?;
+        let member_offset = offset.saturating_add(relative);
+        if designators.is_empty() {
+            self.push_subobject(&member_ty, member_offset, elts);
+        } else {
+            let next = self.designate(&member_ty, member_offset, designators, elts)?;
+            self.push_members(&member_ty, member_offset, elts, false, next);
+        }
+        Some(aggregate.next(index))
+    }
+
+    /// Returns the subobjects of the aggregate of type `ty`, and the index of
+    /// the one designated by the first of the `designators`.
+    ///
+    /// The designator of a member of an anonymous struct or union is kept, to
+    /// be applied on the anonymous member.
+    fn designated_index(
+        &self,
+        ty: &Type,
+        designators: &mut VecDeque<Designator>,
+    ) -> Result<(Aggregate, u64), CompileError> {
+        let designator = designators.pop_front().expect("at least one designator");
+        match (designator, Aggregate::of(ty, self.state)) {
+            (Designator::Index(index, loc), Some(array @ Aggregate::Array { length, .. })) => {
+                let value = constant_index(&index, "Array designator index", self.state)?;
+                match length {
+                    Some(len) if value >= len => Err(loc.fail(format!(
+                        "Array designator index {value} exceeds array bounds {len}"
+                    ))),
+                    Some(_) | None => Ok((array, value)),
+                }
+            }
+            (Designator::Member(name, loc), Some(record)) if !record.is_array() =>
+                match record.find(&name, self.state) {
+                    Some((index, anonymous)) => {
+                        if anonymous {
+                            designators.push_front(Designator::Member(name, loc));
+                        }
+                        Ok((record, index))
+                    }
+                    None => Err(loc.fail(format!("No member named {name} in {ty}"))),
+                },
+            (Designator::Index(_, loc), Some(_)) =>
+                Err(loc.fail(format!("Array designator used on non-array type {ty}"))),
+            (Designator::Member(name, loc), Some(_)) =>
+                Err(loc.fail(format!("Field designator .{name} used on array type {ty}"))),
+            (Designator::Index(_, loc) | Designator::Member(_, loc), None) =>
+                Err(loc.fail(format!("Designator in initialiser of scalar type {ty}"))),
+        }
+    }
+
+    /// Initialises the object of type `ty` at `offset` with a braced
+    /// initialiser list.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements initialised, to compute the length of an array
+    /// of unknown length.
+    pub(super) fn push_list(&mut self, ty: &Type, offset: u64, list: ListInitialiser) -> u64 {
+        let mut elts: VecDeque<Ast> = list
+            .elts
+            .into_iter()
+            .filter(|elt| !elt.is_empty())
+            .collect();
+        if elts
+            .front()
+            .is_some_and(|first| is_string_for(ty, first, self.state))
+        {
+            // braced string literal, like in `char s[] = {"abc"}`
+            let length = self.push_subobject(ty, offset, &mut elts);
+            self.warn_excess(&mut elts, "array");
+            length
+        } else if Aggregate::of(ty, self.state).is_some() {
+            self.push_members(ty, offset, &mut elts, true, 0)
+        } else {
+            match elts.pop_front() {
+                Some(Ast::ListInitialiser(inner)) => {
+                    self.state.push_error(
+                        inner
+                            .location
+                            .warn("Braces around scalar initialiser".to_owned()),
+                    );
+                    self.push_list(ty, offset, inner);
+                }
+                Some(elt) if is_designation(&elt) => {
+                    let err = match split_designation(elt).0 {
+                        Ok(mut designators) => self.designated_index(ty, &mut designators).err(),
+                        Err(err) => Some(err),
+                    };
+                    self.state
+                        .push_error(err.expect("scalars have no subobjects"));
+                }
+                Some(elt) => self.push_value(ty, offset, elt),
+                None => (),
+            }
+            self.warn_excess(&mut elts, "scalar");
+            0
+        }
+    }
+
+    /// Initialises the subobjects of the aggregate of type `ty` at `offset`,
+    /// from the one at index `first`, with the elements of `elts`.
+    ///
+    /// In a braced list, all the elements are used. If the braces are
+    /// elided, this stops after the last subobject, or on a designator,
+    /// which applies to the enclosing braced list.
+    ///
+    /// # Returns
+    ///
+    /// The index following the last initialised subobject, to compute the
+    /// length of an array of unknown length.
+    pub(super) fn push_members(
+        &mut self,
+        ty: &Type,
+        offset: u64,
+        elts: &mut VecDeque<Ast>,
+        braced: bool,
+        first: u64,
+    ) -> u64 {
+        let Some(aggregate) = Aggregate::of(ty, self.state) else {
+            return first;
+        };
+        let mut index = first;
+        let mut length = first;
+        while let Some(elt) = elts.front() {
+            if is_designation(elt) {
+                if !braced {
+                    break;
+                }
+                let (designation, value) = split_designation(elts.pop_front().expect("non empty"));
+                elts.push_front(value);
+                match designation {
+                    Ok(mut designators) =>
+                        if let Some(next) = self.designate(ty, offset, &mut designators, elts) {
+                            index = next;
+                        },
+                    Err(err) => {
+                        self.state.push_error(err);
+                        elts.pop_front();
+                    }
+                }
+            } else if let Some((member_offset, member_ty)) = aggregate.member(index) {
+                self.push_subobject(&member_ty, offset.saturating_add(member_offset), elts);
+                index = aggregate.next(index);
+            } else {
+                if braced {
+                    self.warn_excess(elts, aggregate.kind());
+                }
+                break;
+            }
+            length = length.max(index);
+        }
+        length
+    }
+
+    /// Initialises the subobject of type `ty` at `offset` with the element at
+    /// the front of `elts`.
+    ///
+    /// If the subobject is an aggregate and the element doesn't initialise
+    /// it as a whole, the braces are elided: the element initialises its
+    /// first subobject, and the next elements the following ones.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements initialised, to compute the length of an array
+    /// of unknown length.
+    pub(super) fn push_subobject(
+        &mut self,
+        ty: &Type,
+        offset: u64,
+        elts: &mut VecDeque<Ast>,
+    ) -> u64 {
+        let Some(value) = elts.pop_front() else {
+            return 0;
+        };
+        if let Ast::ListInitialiser(list) = value {
+            self.push_list(ty, offset, list)
+        } else if is_string_for(ty, &value, self.state)
+            && let Ast::Leaf(literal) = value
+        {
+            self.push_string(ty, offset, literal)
+        } else if Aggregate::of(ty, self.state).is_some() && !self.is_whole_record(ty, &value) {
+            elts.push_front(value);
+            self.push_members(ty, offset, elts, false, 0)
+        } else {
+            self.push_value(ty, offset, value);
+            0
+        }
+    }
+
+    /// Reports the elements left after the last subobject of an aggregate of
+    /// the given kind, like `3` in `int a[2] = {1, 2, 3}`, and drops them.
+    fn warn_excess(&mut self, elts: &mut VecDeque<Ast>, kind: &str) {
+        if let Some(extra) = elts.front() {
+            self.state.push_error(
+                extra
+                    .location()
+                    .warn(format!("Excess elements in {kind} initialiser")),
+            );
+            elts.clear();
+        }
+    }
+}
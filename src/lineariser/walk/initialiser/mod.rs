@@ -0,0 +1,277 @@
+//! Walks the initialisers of the declarations, like `{1, [4] = 2}` in `int
+//! a[] = {1, [4] = 2};`, and lowers them into the values of the scalars they
+//! initialise.
+
+/// Subobjects of the arrays, structs and unions.
+mod aggregate;
+/// Designations of the elements of the initialiser lists.
+mod designation;
+/// Lengths of the arrays of unknown length.
+pub mod length;
+/// Elements of the initialiser lists.
+mod members;
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+
+use crate::Number;
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::lineariser::walk::conversion::{Operand, push_conversion};
+use crate::lineariser::walk::initialiser::aggregate::Aggregate;
+use crate::lineariser::walk::variable::push_init_value;
+use crate::parser::api::{Ast, ListInitialiser, Literal};
+
+/// Value of an initialised scalar, or of a subobject initialised as a whole,
+/// like a string literal.
+#[derive(Debug)]
+struct Entry {
+    /// Id of the value.
+    id: usize,
+    /// Offset of the subobject in the initialised object, in bytes.
+    offset: u64,
+    /// Size of the subobject, in bytes.
+    size: u64,
+    /// Type of the subobject.
+    ty: Type,
+}
+
+/// Initialiser list lowered into the initial value of an object.
+struct Lowered {
+    /// Values to store into the object once declared, if it has automatic
+    /// storage.
+    stores: Vec<Entry>,
+    /// Type of the object, completed by the initialiser.
+    ty: Type,
+    /// Initial value of the object.
+    value: Value,
+}
+
+/// Lowering of an initialiser list.
+struct Initialiser<'walk> {
+    /// Basic blocks in which the values are pushed.
+    bbs: &'walk mut BasicBlocks,
+    /// Values of the initialised subobjects.
+    entries: Vec<Entry>,
+    /// State of the lineariser.
+    state: &'walk mut LState,
+}
+
+impl Initialiser<'_> {
+    /// Checks if `value` initialises the struct or union of type `ty` as a
+    /// whole, instead of its first member, because it has the same type.
+    fn is_whole_record(&self, ty: &Type, value: &Ast) -> bool {
+        ty.as_tag().is_some_and(|tag| {
+            Type::of_expression(value, self.state)
+                .is_some_and(|value_ty| value_ty.as_tag() == Some(tag))
+        })
+    }
+
+    /// Initialises the array of type `ty` at `offset` with a string literal.
+    ///
+    /// # Returns
+    ///
+    /// The number of elements initialised, including the null terminator.
+    fn push_string(&mut self, ty: &Type, offset: u64, literal: Located<Literal>) -> u64 {
+        let (value, loc) = literal.into_inner();
+        let units = match &value {
+            Literal::Str(string, encoding) =>
+                u64::try_from(encoding.code_units(string)).unwrap_or(u64::MAX),
+            Literal::Char(..) | Literal::ConstantBool(_) | Literal::Null | Literal::Number(_) =>
+                unreachable!("checked by caller"),
+        };
+        let length = units.saturating_add(1);
+        let full_ty = match ty.array_length() {
+            Some(ArrayLength::Fixed(len)) => {
+                if units > len {
+                    self.state.push_error(loc.warn(format!(
+                        "Initialiser string for array of {len} elements is too long"
+                    )));
+                }
+                ty.clone()
+            }
+            Some(ArrayLength::Incomplete | ArrayLength::Unspecified | ArrayLength::Variable(_))
+            | None => ty.clone().into_completed(length),
+        };
+        let size = self.state.layout(&full_ty).map_or(0, |layout| layout.size);
+        let id = self.state.push_literal(value);
+        self.store(Entry { id, offset, size, ty: full_ty }, loc);
+        length
+    }
+
+    /// Initialises the scalar, or the struct or union as a whole, of type
+    /// `ty` at `offset` with an expression.
+    fn push_value(&mut self, ty: &Type, offset: u64, value: Ast) {
+        let loc = value.location();
+        if let Some(Value::Variable(id)) = push_init_value(value, ty, self.bbs, self.state) {
+            let size = self.state.layout(ty).map_or(0, |layout| layout.size);
+            self.store(Entry { id, offset, size, ty: ty.clone() }, loc);
+        }
+    }
+
+    /// Records the value of a subobject, replacing the values of the
+    /// subobjects it overlaps with, that were initialised before.
+    fn store(&mut self, entry: Entry, loc: ErrorLocation) {
+        let end = |subobject: &Entry| subobject.offset.saturating_add(subobject.size.max(1));
+        let count = self.entries.len();
+        self.entries
+            .retain(|other| end(other) <= entry.offset || end(&entry) <= other.offset);
+        if self.entries.len() != count {
+            self.state.push_error(
+                loc.warn("Initialiser overrides prior initialisation of this subobject".to_owned()),
+            );
+        }
+        self.entries.push(entry);
+    }
+}
+
+/// Checks if `value` is a string literal that initialises the array of type
+/// `ty`, i.e., if its code units have the size of the integer elements of the
+/// array, like `"abc"` for `char[4]` or `u"abc"` for `char16_t[]`.
+fn is_string_for(ty: &Type, value: &Ast, state: &LState) -> bool {
+    let Ast::Leaf(literal) = value else {
+        return false;
+    };
+    let Literal::Str(..) = literal.as_value() else {
+        return false;
+    };
+    let Some(Aggregate::Array { element, size, .. }) = Aggregate::of(ty, state) else {
+        return false;
+    };
+    element.is_integer()
+        && Type::from_lit(literal.as_value())
+            .pointee()
+            .and_then(|unit| state.layout(&unit))
+            .is_some_and(|unit| unit.size == size)
+}
+
+/// Pushes the declaration of a variable of type `ty`, with its initial value.
+///
+/// An initialiser list, or a string literal initialising an array, is lowered
+/// into the values of the scalars it initialises:
+///
+/// - an object with static storage gets a constant [`Value::Aggregate`];
+/// - an object with automatic storage is zero-filled, then each initialised
+///   scalar is stored in it with a [`Value::Store`].
+///
+/// An array of unknown length, like `int a[]`, gets its length from the
+/// initialiser. Any other object defined with an incomplete type, like
+/// `struct S s;` at block scope with `struct S` only declared, is an error.
+///
+/// # Returns
+///
+/// The id of the declared variable, if it was declared.
+pub fn push_initialised(
+    name: Located<String>,
+    ty: Type,
+    value: Option<Ast>,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Option<usize> {
+    if (value.is_some() || state.is_block_scope())
+        && !ty.is_extern()
+        && ty.is_incomplete(state.target(), state.tags())
+        && !(value.is_some() && ty.is_array())
+    {
+        state.push_error(name.as_location().fail(format!(
+            "Variable {} has incomplete type {}",
+            name.as_value(),
+            ty.without_storage()
+        )));
+    }
+    let Some(ast) = value else {
+        return Some(state.push_declaration(name, &ty, Value::DeclaredOnly));
+    };
+    if ast.is_empty() {
+        state.push_error(
+            name.as_location()
+                .fail(format!("Missing initial value of {} after '='", name.as_value())),
+        );
+        return Some(state.push_declaration(name, &ty, Value::DeclaredOnly));
+    }
+    if !matches!(ast, Ast::ListInitialiser(_)) && !is_string_for(&ty, &ast, state) {
+        if ty.is_array() {
+            state.push_error(ast.location().fail(
+                "Array initialiser must be an initialiser list or a string literal".to_owned(),
+            ));
+            return Some(state.push_declaration(name, &ty, Value::DeclaredOnly));
+        }
+        let init_value = push_init_value(ast, &ty, bbs, state)?;
+        return Some(state.push_declaration(name, &ty, init_value));
+    }
+    if let Some(ArrayLength::Variable(_)) = ty.array_length()
+        && !matches!(&ast, Ast::ListInitialiser(list) if list.elts.iter().all(Ast::is_empty))
+    {
+        state.push_error(ast.location().fail(
+            "Variable-length array can only be initialised with an empty initialiser".to_owned(),
+        ));
+        return Some(state.push_declaration(name, &ty, Value::DeclaredOnly));
+    }
+    let Lowered { stores, ty: var_ty, value: init_value } = lower(ty, ast, bbs, state);
+    let var = state.push_declaration(name, &var_ty, init_value);
+    push_stores(var, stores, state);
+    Some(var)
+}
+
+/// Pushes an anonymous object of type `ty`, initialised with an initialiser
+/// list, like the object of a compound literal.
+///
+/// See [`push_initialised`] for the lowering of the initialiser list.
+pub fn push_anonymous(
+    ty: Type,
+    list: ListInitialiser,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Id {
+    let Lowered { stores, ty: var_ty, value } = lower(ty, Ast::ListInitialiser(list), bbs, state);
+    let var = state.push_element(value, var_ty.clone());
+    push_stores(var, stores, state);
+    Id::Found(var, var_ty)
+}
+
+/// Lowers an initialiser list, or a string literal initialising an array,
+/// into the initial value of an object of type `ty`.
+fn lower(ty: Type, ast: Ast, bbs: &mut BasicBlocks, state: &mut LState) -> Lowered {
+    let loc = ast.location();
+    let mut init = Initialiser { bbs, entries: vec![], state };
+    let length = init.push_subobject(&ty, 0, &mut VecDeque::from([ast]));
+    let mut entries = init.entries;
+    entries.sort_by_key(|entry| entry.offset);
+    let var_ty = match ty.array_length() {
+        Some(ArrayLength::Incomplete | ArrayLength::Unspecified) => ty.into_completed(length),
+        Some(ArrayLength::Fixed(_) | ArrayLength::Variable(_)) | None => ty,
+    };
+    if Aggregate::of(&var_ty, state).is_none() {
+        // scalar in braces, like `int x = {1}`
+        let id = entries.first().map_or_else(
+            || {
+                let lit = Literal::Number(Number::Int(0));
+                let lit_ty = Type::from_lit(&lit);
+                let zero = Operand { id: state.push_literal(lit), loc, null: true, ty: lit_ty };
+                push_conversion(&zero, &var_ty, state)
+            },
+            |entry| entry.id,
+        );
+        return Lowered { stores: vec![], ty: var_ty, value: Value::Variable(id) };
+    }
+    if var_ty.has_static_storage() || !state.is_block_scope() {
+        let values = entries
+            .iter()
+            .map(|entry| (entry.offset, entry.id))
+            .collect();
+        return Lowered { stores: vec![], ty: var_ty, value: Value::Aggregate(values) };
+    }
+    Lowered { stores: entries, ty: var_ty, value: Value::Aggregate(vec![]) }
+}
+
+/// Pushes the stores of the initialised scalars into the object `var`, with
+/// automatic storage.
+fn push_stores(var: usize, stores: Vec<Entry>, state: &mut LState) {
+    for Entry { id, offset, ty, .. } in stores {
+        state.push_element(Value::Store(var, offset, id), ty);
+    }
+}
@@ -0,0 +1,139 @@
+//! Walks the loops, creating the basic blocks of their header, body and exit.
+
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::basic_block::{BasicBlocks, Instruction};
+use crate::lineariser::state::LState;
+use crate::lineariser::walk::control_flow::{branch, push_condition, push_expression};
+use crate::parser::api::{Ast, ParensBlock};
+
+/// Splits the header of a `for` loop into the initialisation, the condition
+/// and the increment.
+fn for_clauses(
+    parens: Option<ParensBlock>,
+    keyword: ErrorLocation,
+    state: &mut LState,
+) -> (Ast, Ast, Ast) {
+    if let Some(Ast::BracedBlock(clauses)) = parens.map(|block| block.into_inner().0) {
+        let mut elts = clauses.elts.into_iter();
+        match (elts.next(), elts.next(), elts.next(), elts.next()) {
+            // consecutive `;` are merged by the parser, so only the condition is missing
+            (Some(init), Some(incr), None, None) => return (init, Ast::Empty, incr),
+            (Some(init), Some(cond), Some(incr), None) => return (init, cond, incr),
+            _ => (),
+        }
+    }
+    state.push_error(keyword.fail("Expected 2 ';' in the header of the for loop".to_owned()));
+    (Ast::Empty, Ast::Empty, Ast::Empty)
+}
+
+/// Pushes a `do ... while` loop.
+pub fn push_do_while(
+    body: Ast,
+    condition: Option<ParensBlock>,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) {
+    let entry = state.edge(bbs.current(), true);
+    let header = state.header(bbs, &[entry]);
+    state.enter_loop();
+    body.push_in(bbs, state);
+    let breakable = state.exit_breakable();
+    let mut exits = breakable.breaks;
+    let mut latch = breakable.continues.unwrap_or_default();
+    latch.extend(state.fallthrough(bbs));
+    if latch.is_empty() {
+        push_condition(condition, keyword, bbs, state);
+        state.join(bbs, &exits, true);
+        return;
+    }
+    let from = state.join(bbs, &latch, true);
+    let cond = push_condition(condition, keyword, bbs, state);
+    state.push_back_edge(bbs, &header, from);
+    if cond.is_some() {
+        exits.push(state.edge(from, false));
+    }
+    let exit = state.join(bbs, &exits, true);
+    bbs.terminate(from, branch(cond, header.block, exit));
+}
+
+/// Pushes a `for` loop.
+pub fn push_for(
+    parens: Option<ParensBlock>,
+    body: Ast,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) {
+    state.increment_depth();
+    let (init, condition, increment) = for_clauses(parens, keyword, state);
+    init.push_in(bbs, state);
+    let entry = state.edge(bbs.current(), true);
+    let header = state.header(bbs, &[entry]);
+    let cond = if condition.is_empty() {
+        None
+    } else {
+        push_expression(condition, bbs, state, "condition")
+    };
+    let exit = state.edge(header.block, false);
+    let body_block = push_loop_body(body, bbs, state);
+    let breakable = state.exit_breakable();
+    let mut latch = breakable.continues.unwrap_or_default();
+    latch.extend(state.fallthrough(bbs));
+    if latch.is_empty() {
+        increment.push_in(bbs, state);
+    } else {
+        let from = state.join(bbs, &latch, true);
+        increment.push_in(bbs, state);
+        state.push_back_edge(bbs, &header, from);
+        bbs.terminate(from, Instruction::Branch(header.block));
+    }
+    let mut exits = breakable.breaks;
+    if cond.is_some() {
+        exits.push(exit);
+    }
+    let exit_block = state.join(bbs, &exits, true);
+    bbs.terminate(header.block, branch(cond, body_block, exit_block));
+    state.decrement_depth();
+}
+
+/// Enters a loop and pushes its body into a new basic block.
+///
+/// # Returns
+///
+/// The index of the first basic block of the body.
+fn push_loop_body(body: Ast, bbs: &mut BasicBlocks, state: &mut LState) -> usize {
+    state.enter_loop();
+    let block = bbs.new_block();
+    body.push_in(bbs, state);
+    block
+}
+
+/// Pushes a `while` loop.
+pub fn push_while(
+    parens: Option<ParensBlock>,
+    body: Ast,
+    keyword: ErrorLocation,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) {
+    let entry = state.edge(bbs.current(), true);
+    let header = state.header(bbs, &[entry]);
+    let cond = push_condition(parens, keyword, bbs, state);
+    let exit = state.edge(header.block, false);
+    let body_block = push_loop_body(body, bbs, state);
+    let breakable = state.exit_breakable();
+    let mut latch = breakable.continues.unwrap_or_default();
+    latch.extend(state.fallthrough(bbs));
+    if !latch.is_empty() {
+        let from = state.join(bbs, &latch, true);
+        state.push_back_edge(bbs, &header, from);
+        bbs.terminate(from, Instruction::Branch(header.block));
+    }
+    let mut exits = breakable.breaks;
+    if cond.is_some() {
+        exits.push(exit);
+    }
+    let exit_block = state.join(bbs, &exits, true);
+    bbs.terminate(header.block, branch(cond, body_block, exit_block));
+}
@@ -1,6 +1,23 @@
 //! Defines the walker to populate state and basic blocks on different nodes.
 
+pub mod arrays;
+pub mod assignment;
 pub mod ast;
+pub mod call;
+pub mod cast;
+pub mod compound_literal;
+pub mod control_flow;
+pub mod conversion;
+pub mod declarators;
+pub mod enums;
 pub mod function;
+pub mod generic;
+pub mod initialiser;
+pub mod loops;
 pub mod operator;
+pub mod parameters;
+pub mod records;
+pub mod sizeof;
+pub mod static_assert;
+pub mod typedefs;
 pub mod variable;
@@ -1,14 +1,21 @@
-//! Walks a ternary expression, updating state and creating symbols and basic
-//! blocks.
+//! Walks the binary and ternary expressions, updating state and creating
+//! symbols and basic blocks.
 
+use crate::errors::api::Located;
 use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::const_eval::is_null_constant;
 use crate::lineariser::state::LState;
 use crate::lineariser::symbol::Value;
-use crate::parser::api::{Binary, Ternary};
+use crate::lineariser::types::Type;
+use crate::lineariser::types::lvalue::Lvalue;
+use crate::lineariser::walk::assignment::push_assignment;
+use crate::lineariser::walk::conversion::{Operand, push_conversion};
+use crate::lineariser::walk::records::push_member_access;
+use crate::parser::api::{Ast, Binary, BinaryOperator, Ternary};
 
 impl Binary {
     /// Pushes some content into the [`BasicBlocks`].
-    pub fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
         #[cfg(feature = "debug")]
         crate::lgp!(notab: "Pushing binary {self}");
         let Self { arg_l, arg_r, op } = self;
@@ -21,7 +28,21 @@ impl Binary {
             );
             return Id::NotFound;
         }
+        if matches!(
+            op.as_value(),
+            BinaryOperator::StructEnumMemberAccess | BinaryOperator::StructEnumMemberPointerAccess
+        ) {
+            return push_member_access(*arg_l, *arg_r, op, bbs, state);
+        }
+        if *op.as_value() == BinaryOperator::Comma {
+            return push_comma(*arg_l, *arg_r, bbs, state);
+        }
         let loc_r = arg_r.location();
+        let nulls = (is_null_constant(&arg_l, state), is_null_constant(&arg_r, state));
+        let modified = op
+            .as_value()
+            .is_assignment()
+            .then(|| (Lvalue::of(&arg_l, state), state.assigned_local(&arg_l)));
         match (arg_l.push_in(bbs, state), arg_r.push_in(bbs, state)) {
             (Some(Id::NotFound), _) | (_, Some(Id::NotFound)) => Id::NotFound,
             res @ ((None, _) | (_, None)) => {
@@ -34,11 +55,34 @@ impl Binary {
                 Id::NotFound
             }
             (Some(Id::Found(id_l, ty_l)), Some(Id::Found(id_r, ty_r))) => {
-                let ty = ty_l.combine(&ty_r);
-                Id::Found(
-                    state.push_element(Value::Binary(op.drop_location(), id_l, id_r), ty.clone()),
-                    ty,
-                )
+                if *op.as_value() == BinaryOperator::ArraySubscript {
+                    return match ty_l.subscript(ty_r) {
+                        Ok(ty) => Id::Found(
+                            state.push_element(
+                                Value::Binary(op.drop_location(), id_l, id_r),
+                                ty.clone(),
+                            ),
+                            ty,
+                        ),
+                        Err(msg) => {
+                            state.push_error(loc_l.into_extended(loc_r).fail(msg));
+                            Id::NotFound
+                        }
+                    };
+                }
+                let left = Operand { id: id_l, loc: loc_l, null: nulls.0, ty: ty_l };
+                let right = Operand { id: id_r, loc: loc_r, null: nulls.1, ty: ty_r };
+                let Some((lvalue, assigned)) = modified else {
+                    return push_operation(op, &left, &right, state)
+                        .map_or(Id::NotFound, |result| Id::Found(result.id, result.ty));
+                };
+                match left.ty.check_modifiable(&lvalue, loc_l, state) {
+                    Ok(()) => push_assignment(op, &left, &right, assigned, state),
+                    Err(err) => {
+                        state.push_error(err);
+                        Id::NotFound
+                    }
+                }
             }
         }
     }
@@ -46,19 +90,24 @@ impl Binary {
 
 impl Ternary {
     /// Pushes some content into the [`BasicBlocks`].
-    pub fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
         #[cfg(feature = "debug")]
         crate::lgp!(notab: "Pushing ternary {self}");
         match self {
-            Self { condition, success, failure: None, .. } => {
+            Self { failure: None, .. } => {
                 state.push_error(
-                    condition
-                        .location()
-                        .into_extended(success.location())
+                    self.location()
                         .fail("Missing ':' after ternary operator".to_owned()),
                 );
                 Id::NotFound
             }
+            _ if self.success.is_empty() => {
+                state.push_error(
+                    self.location()
+                        .fail("Missing node between '?' and ':' in ternary operator".to_owned()),
+                );
+                Id::NotFound
+            }
             Self { condition, failure: Some((loc, failure)), .. } if failure.is_empty() => {
                 state.push_error(
                     condition
@@ -72,6 +121,7 @@ impl Ternary {
                 let loc_cond = condition.location();
                 let loc_succ = success.location();
                 let loc_fail = failure.location();
+                let nulls = (is_null_constant(&success, state), is_null_constant(&failure, state));
                 match (
                     condition.push_in(bbs, state),
                     success.push_in(bbs, state),
@@ -93,18 +143,79 @@ impl Ternary {
                     | (_, Some(Id::NotFound), _)
                     | (_, _, Some(Id::NotFound)) => Id::NotFound,
                     (
-                        Some(Id::Found(node_c, ty_c)),
+                        Some(Id::Found(node_c, _)),
                         Some(Id::Found(node_s, ty_s)),
                         Some(Id::Found(node_f, ty_f)),
                     ) => {
-                        let ty = ty_c.combine(&ty_s).combine(&ty_f);
-                        Id::Found(
-                            state.push_element(Value::Ternary(node_c, node_s, node_f), ty.clone()),
-                            ty,
-                        )
+                        let ty = match Type::conditional_result(&ty_s, &ty_f, nulls, state.target())
+                        {
+                            Ok(ty) => ty,
+                            Err(msg) => {
+                                state.push_error(loc_succ.into_extended(loc_fail).fail(msg));
+                                return Id::NotFound;
+                            }
+                        };
+                        let succ = Operand { id: node_s, loc: loc_succ, null: nulls.0, ty: ty_s };
+                        let fail = Operand { id: node_f, loc: loc_fail, null: nulls.1, ty: ty_f };
+                        let value = Value::Ternary(
+                            node_c,
+                            push_conversion(&succ, &ty, state),
+                            push_conversion(&fail, &ty, state),
+                        );
+                        Id::Found(state.push_element(value, ty.clone()), ty)
                     }
                 }
             }
         }
     }
 }
+
+/// Pushes the comma operator, like `i++, j--`.
+///
+/// The left operand is only evaluated for its side effects, and the value of
+/// the expression is the value of the right operand, which isn't an lvalue.
+fn push_comma(arg_l: Ast, arg_r: Ast, bbs: &mut BasicBlocks, state: &mut LState) -> Id {
+    let (loc_l, loc_r) = (arg_l.location(), arg_r.location());
+    if arg_l.push_in(bbs, state).is_none() {
+        state.stat_not_expr(loc_l, "comma lhs");
+    }
+    match arg_r.push_in(bbs, state) {
+        Some(Id::Found(id, ty)) => Id::Found(id, ty.decayed().unqualified()),
+        Some(Id::NotFound) => Id::NotFound,
+        None => {
+            state.stat_not_expr(loc_r, "comma rhs");
+            Id::NotFound
+        }
+    }
+}
+
+/// Pushes a binary operation on two operands, with the implicit conversions of
+/// the operands, and returns its result.
+///
+/// It is also the operation of the compound assignments and of the
+/// increments, applied on the loaded value of the lvalue.
+pub fn push_operation(
+    op: Located<BinaryOperator>,
+    left: &Operand,
+    right: &Operand,
+    state: &mut LState,
+) -> Option<Operand> {
+    let operation = *op.as_value();
+    let types = match Type::binary_operands(
+        operation,
+        &left.ty,
+        &right.ty,
+        (left.null, right.null),
+        state.target(),
+    ) {
+        Ok(types) => types,
+        Err(msg) => {
+            state.push_error(op.as_location().fail(msg));
+            return None;
+        }
+    };
+    let value_l = push_conversion(left, &types.left, state);
+    let value_r = push_conversion(right, &types.right, state);
+    let id = state.push_element(Value::Binary(operation, value_l, value_r), types.result.clone());
+    Some(Operand { id, loc: left.loc.into_extended(right.loc), null: false, ty: types.result })
+}
@@ -0,0 +1,177 @@
+//! Reads the parameter lists of the functions, and applies the declarators in
+//! parenthesis, like `(*cb)(int)` in `int (*cb)(int)`.
+
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::function::Signature;
+use crate::lineariser::walk::arrays::{ArrayContext, apply_arrays};
+use crate::parser::api::{
+    ArrayDeclarator, Ast, FunctionCall, ParameterList, ParensDeclarator, VariableName, VariableValue
+};
+
+/// Applies the declarators of a declared name on its type `ty`.
+///
+/// The array declarators or the parameter list that follow the parenthesis
+/// are applied first, then the pointers inside the parenthesis, and then the
+/// array declarators inside the parenthesis: `int (*p[2])[3]` is an array of 2
+/// pointers to arrays of 3 `int`.
+pub fn apply_declarator(
+    arrays: Vec<ArrayDeclarator>,
+    parens: Option<ParensDeclarator>,
+    ty: Type,
+    mut context: ArrayContext<'_>,
+    state: &mut LState,
+) -> Type {
+    let Some(ParensDeclarator { arrays: outer, parameters, pointers, .. }) = parens else {
+        return apply_arrays(arrays, ty, context, state);
+    };
+    let inner = if let Some(list) = parameters {
+        if ty.is_array() {
+            state.push_error(
+                list.location
+                    .fail(format!("Function can't return array type {ty}")),
+            );
+        } else if ty.is_function() {
+            state.push_error(
+                list.location
+                    .fail(format!("Function can't return function type {ty}")),
+            );
+        }
+        ty.into_function(read_signature(list, state))
+    } else {
+        apply_arrays(outer, ty, context.pointee(), state)
+    };
+    let pointer = inner
+        .with_pointers(&pointers, state)
+        .store_errors(&mut |err| state.push_error(err))
+        .expect("never none");
+    apply_arrays(arrays, pointer, context, state)
+}
+
+/// Reads a parameter, like `int *x`, `int cb(int)` or `char`.
+///
+/// Returns its name, empty for unnamed parameters, and its type, adjusted to a
+/// pointer if it is an array or a function.
+fn read_parameter(ast: Ast, state: &mut LState) -> (Located<String>, Type) {
+    match ast {
+        Ast::Variable(var) => match var.into_value() {
+            VariableValue::AttributeVariable(attr) => {
+                let loc = attr.location();
+                let unnamed = attr.declarations.is_empty();
+                let (decl, attrs) = attr.into_single_variable();
+                let ty = Type::from_attributes(&attrs, state)
+                    .store_errors(&mut |err| state.push_error(err))
+                    .expect("never none");
+                if let Some(param) = decl {
+                    let param_ty = apply_declarator(
+                        param.arrays,
+                        param.parens,
+                        ty,
+                        ArrayContext::Parameter,
+                        state,
+                    );
+                    (param.name, param_ty.decayed())
+                } else {
+                    if !unnamed {
+                        state.push_error(loc.fail("Expected parameter declaration".to_owned()));
+                    }
+                    (loc.wrap(String::new()), ty.decayed())
+                }
+            }
+            VariableValue::VariableName(loc, name) => {
+                state.push_error(loc.fail("Missing argument type".to_owned()));
+                match name {
+                    VariableName::Keyword(_) => {
+                        state.push_error(
+                            loc.fail("Invalid argument name, shadows keyword.".to_owned()),
+                        );
+                        (loc.wrap(String::new()), Type::empty())
+                    }
+                    VariableName::UserDefined(vname) => (loc.wrap(vname), Type::empty()),
+                }
+            }
+        },
+        Ast::FunctionCall(FunctionCall {
+            arguments,
+            callee,
+            function_body: None,
+            parens_location,
+            variadic,
+        }) if matches!(*callee, Ast::Variable(_)) => {
+            let (name, ret) = read_parameter(*callee, state);
+            let unprototyped = arguments.is_empty();
+            let params = read_parameters(arguments, parens_location, state);
+            let signature =
+                Signature::new(params.into_iter().map(|(_, ty)| ty), variadic.is_some())
+                    .with_unprototyped(unprototyped);
+            (name, ret.into_function(signature).decayed())
+        }
+        Ast::Binary(_)
+        | Ast::BracedBlock(_)
+        | Ast::Cast(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::ControlFlow(_)
+        | Ast::Empty
+        | Ast::FunctionArgsBuild(..)
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_)
+        | Ast::Leaf(_)
+        | Ast::ListInitialiser(_)
+        | Ast::ParensBlock(_)
+        | Ast::Ternary(_)
+        | Ast::Unary(_) => {
+            let loc = ast.location();
+            state.push_error(loc.fail("Expected argument declaration".to_owned()));
+            (loc.wrap(String::new()), Type::empty())
+        }
+    }
+}
+
+/// Reads the parameters of a function, with their names and their adjusted
+/// types.
+///
+/// A single unnamed `void` parameter, like in `int f(void)`, means that the
+/// function has no parameters. The empty parameters, like in `int f(, int)`,
+/// are reported at `location`, the location of the parenthesis.
+pub fn read_parameters(
+    parameters: Vec<Ast>,
+    location: ErrorLocation,
+    state: &mut LState,
+) -> Vec<(Located<String>, Type)> {
+    let params = parameters
+        .into_iter()
+        .map(|param| {
+            if param.is_empty() {
+                state.push_error(location.fail("Expected parameter declaration".to_owned()));
+                (location.wrap(String::new()), Type::empty())
+            } else {
+                read_parameter(param, state)
+            }
+        })
+        .collect::<Vec<_>>();
+    if let [(name, ty)] = params.as_slice()
+        && name.as_value().is_empty()
+        && ty.is_void()
+    {
+        return vec![];
+    }
+    for (name, ty) in &params {
+        if ty.is_void() {
+            state.push_error(
+                name.as_location()
+                    .fail("Parameter can't have type void".to_owned()),
+            );
+        }
+    }
+    params
+}
+
+/// Reads the signature of a function declarator, like `(int, char *)` in
+/// `int (*cb)(int, char *)`.
+pub fn read_signature(list: ParameterList, state: &mut LState) -> Signature {
+    let unprototyped = list.parameters.is_empty();
+    let params = read_parameters(list.parameters, list.location, state);
+    Signature::new(params.into_iter().map(|(_, ty)| ty), list.variadic.is_some())
+        .with_unprototyped(unprototyped)
+}
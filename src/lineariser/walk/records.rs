@@ -0,0 +1,280 @@
+//! Walks the definitions of structs, unions and enums, the variables declared
+//! with them, and the accesses to their members.
+
+use crate::Number;
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::lineariser::walk::arrays::ArrayContext;
+use crate::lineariser::walk::declarators::{declarator_name, split_declarators};
+use crate::lineariser::walk::enums::push_enumerators;
+use crate::lineariser::walk::initialiser::push_initialised;
+use crate::lineariser::walk::parameters::apply_declarator;
+use crate::lineariser::walk::static_assert::check_static_assert;
+use crate::parser::api::{
+    Ast, BinaryOperator, BracedBlock, ControlFlowNode, Declaration, DeclarationValue, FunctionKeyword, IdentBlockCtrl, IdentBlockKeyword, UserDefinedTypes, Variable, VariableName, VariableValue
+};
+use crate::target::Layout;
+
+/// Member of a struct or union being defined, with its type, its layout and
+/// its width if it is a bitfield.
+type MemberDecl = (Located<String>, Type, Layout, Option<u64>);
+
+impl IdentBlockCtrl {
+    /// Defines the user-defined type.
+    ///
+    /// # Returns
+    ///
+    /// The defined type, the declarators that follow the definition, and
+    /// the location of the keyword if the type is anonymous.
+    pub(crate) fn define(self, state: &mut LState) -> (Type, Ast, Option<ErrorLocation>) {
+        let (keyword, ident, attrs, block, declarators) = self.into_parts();
+        let (kwd, loc) = keyword.into_inner();
+        let kind = match kwd {
+            IdentBlockKeyword::Enum => UserDefinedTypes::Enum,
+            IdentBlockKeyword::Struct => UserDefinedTypes::Struct,
+            IdentBlockKeyword::Union => UserDefinedTypes::Union,
+        };
+        let anonymous = ident.is_none().then_some(loc);
+        let name = ident.map_or_else(|| state.anonymous_tag(), Located::drop_location);
+        let ty = match block {
+            None => state.declare_tag(kind, name, loc),
+            Some(body) if kind == UserDefinedTypes::Enum => {
+                let underlying = push_enumerators(body, state);
+                state.define_enum(name, underlying, loc)
+            }
+            Some(body) => {
+                let members = record_members(body, state)
+                    .into_iter()
+                    .map(|(member, ty, layout, width)| (member.drop_location(), ty, layout, width))
+                    .collect();
+                state.define_tag(kind, name, members, loc)
+            }
+        };
+        if let Some((_, tag)) = ty.as_tag() {
+            state.apply_tag_attributes(kind, tag, &attrs);
+        }
+        (ty, *declarators, anonymous)
+    }
+
+    /// Pushes a type definition, and the variables declared with it.
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) {
+        let (ty, declarators, _) = self.define(state);
+        for (declarator, value) in split_declarators(declarators) {
+            let Some((name, var_ty)) = declarator_name(declarator, ty.clone(), state) else {
+                continue;
+            };
+            push_initialised(name, var_ty, value, bbs, state);
+        }
+    }
+}
+
+/// Pushes a member into the list of members of the record being defined.
+///
+/// A flexible array member, like `int data[]`, takes no space, and must be the
+/// last member.
+fn push_member(
+    members: &mut Vec<MemberDecl>,
+    name: Located<String>,
+    ty: Type,
+    width: Option<u64>,
+    state: &mut LState,
+) {
+    let loc = name.as_location();
+    let member = name.as_value();
+    if !member.is_empty() && members.iter().any(|(other, ..)| other.as_value() == member) {
+        state.push_error(loc.fail(format!("Duplicate member {member}")));
+        return;
+    }
+    if let Some((previous, previous_ty, ..)) = members.last()
+        && previous_ty.array_length() == Some(ArrayLength::Incomplete)
+    {
+        state.push_error(previous.as_location().fail(format!(
+            "Flexible array member {} is not at the end of the struct",
+            previous.as_value()
+        )));
+    }
+    let flexible = (ty.array_length() == Some(ArrayLength::Incomplete))
+        .then(|| state.layout(&ty.split_arrays().1))
+        .flatten()
+        .map(|element| Layout { align: element.align, size: 0 });
+    let Some(layout) = state.layout(&ty).or(flexible) else {
+        state.push_error(loc.fail(format!("Field {member} has incomplete type {ty}")));
+        return;
+    };
+    if let Some(bits) = width {
+        let max = layout.size.saturating_mul(8);
+        if !ty.is_integer() {
+            state.push_error(loc.fail(format!("Bitfield {member} has non-integer type {ty}")));
+            return;
+        }
+        if bits == 0 {
+            state.push_error(loc.fail(format!("Named bitfield {member} has zero width")));
+            return;
+        }
+        if bits > max {
+            state.push_error(loc.fail(format!(
+                "Width of bitfield {member} ({bits} bits) exceeds the width of its type ({max} bits)"
+            )));
+            return;
+        }
+    }
+    members.push((name, ty, layout, width));
+}
+
+/// Pushes an access to a member of a struct or union, with `.` or `->`.
+pub fn push_member_access(
+    base: Ast,
+    member: Ast,
+    op: Located<BinaryOperator>,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Id {
+    let loc_base = base.location();
+    let loc = loc_base.into_extended(member.location());
+    let name = if let Ast::Variable(var) = member
+        && let VariableValue::VariableName(_, VariableName::UserDefined(name)) = var.into_value()
+    {
+        Some(name)
+    } else {
+        None
+    };
+    let Some(member_name) = name else {
+        state.push_error(loc.fail(format!("Expected member name after {op}")));
+        return Id::NotFound;
+    };
+    match base.push_in(bbs, state) {
+        Some(Id::NotFound) => Id::NotFound,
+        Some(Id::Found(id, ty)) => match state.find_member(&ty, *op.as_value(), &member_name) {
+            Ok((offset, member_ty)) => {
+                let value = Value::Member(op.drop_location(), id, member_name, offset);
+                Id::Found(state.push_element(value, member_ty.clone()), member_ty)
+            }
+            Err(msg) => {
+                state.push_error(loc.fail(msg));
+                Id::NotFound
+            }
+        },
+        None => {
+            state.stat_not_expr(loc_base, "member access");
+            Id::NotFound
+        }
+    }
+}
+
+/// Reads the members of a struct or union definition.
+fn record_members(body: BracedBlock, state: &mut LState) -> Vec<MemberDecl> {
+    let mut members = vec![];
+    for elt in body.elts {
+        match elt {
+            Ast::Empty => (),
+            Ast::Variable(var) => match var.into_value() {
+                VariableValue::AttributeVariable(attr) => {
+                    let ty = Type::from_attributes(&attr.attrs, state)
+                        .store_errors(&mut |err| state.push_error(err))
+                        .expect("never none");
+                    for decl in attr.declarations.into_iter().flatten() {
+                        let Declaration { arrays, name, parens, value } = decl;
+                        let width = match value {
+                            DeclarationValue::None => None,
+                            DeclarationValue::Bitfield(nb) => {
+                                let (number, loc) = nb.into_inner();
+                                let Some(bits) = number.as_ref().and_then(bitfield_width) else {
+                                    state.push_error(loc.fail(format!(
+                                        "Width of bitfield {} must be a positive integer",
+                                        name.as_value()
+                                    )));
+                                    continue;
+                                };
+                                Some(bits)
+                            }
+                            DeclarationValue::Value(ast) => {
+                                let loc = if ast.is_empty() {
+                                    name.as_location()
+                                } else {
+                                    ast.location()
+                                };
+                                state.push_error(loc.fail(format!(
+                                    "Member {} can't have an initial value",
+                                    name.as_value()
+                                )));
+                                None
+                            }
+                        };
+                        let member_ty = apply_declarator(
+                            arrays,
+                            parens,
+                            ty.clone(),
+                            ArrayContext::Member,
+                            state,
+                        );
+                        push_member(&mut members, name, member_ty, width, state);
+                    }
+                }
+                VariableValue::VariableName(loc, _) =>
+                    state.push_error(loc.fail("Expected member declaration".to_owned())),
+            },
+            Ast::ControlFlow(ControlFlowNode::IdentBlock(ctrl)) => {
+                let (ty, declarators, anonymous) = ctrl.define(state);
+                if declarators.is_empty() {
+                    if let Some(loc) = anonymous
+                        && ty
+                            .as_tag()
+                            .is_some_and(|(kind, _)| kind != UserDefinedTypes::Enum)
+                    {
+                        push_member(&mut members, loc.wrap(String::new()), ty, None, state);
+                    }
+                    continue;
+                }
+                for (declarator, value) in split_declarators(declarators) {
+                    if let Some(ast) = value {
+                        state.push_error(
+                            ast.location()
+                                .fail("Member can't have an initial value".to_owned()),
+                        );
+                    }
+                    if let Some((name, member_ty)) = declarator_name(declarator, ty.clone(), state)
+                    {
+                        push_member(&mut members, name, member_ty, None, state);
+                    }
+                }
+            }
+            Ast::FunctionCall(call)
+                if call.as_variable().and_then(Variable::as_function_keyword)
+                    == Some(&FunctionKeyword::StaticAssert) =>
+                check_static_assert(call.callee.location(), &call.arguments, state),
+            node @ (Ast::Binary(_)
+            | Ast::BracedBlock(_)
+            | Ast::Cast(_)
+            | Ast::CompoundLiteral(_)
+            | Ast::ControlFlow(_)
+            | Ast::FunctionArgsBuild(..)
+            | Ast::FunctionCall(_)
+            | Ast::Generic(_)
+            | Ast::Leaf(_)
+            | Ast::ListInitialiser(_)
+            | Ast::ParensBlock(_)
+            | Ast::Ternary(_)
+            | Ast::Unary(_)) => state.push_error(
+                node.location()
+                    .fail("Expected member declaration".to_owned()),
+            ),
+        }
+    }
+    members
+}
+
+/// Returns the width of a bitfield, if it is a non-negative integer.
+fn bitfield_width(number: &Number) -> Option<u64> {
+    match number {
+        Number::Int(value) => u64::try_from(*value).ok(),
+        Number::Long(value) | Number::LongLong(value) => u64::try_from(*value).ok(),
+        Number::UInt(value) => Some(u64::from(*value)),
+        Number::ULong(value) | Number::ULongLong(value) => Some(*value),
+        Number::BitInt(value) | Number::UBitInt(value) => value.to_u64(),
+        Number::Float(_) | Number::Double(_) | Number::LongDouble(_) => None,
+    }
+}
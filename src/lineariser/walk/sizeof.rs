@@ -0,0 +1,172 @@
+//! Computes the `sizeof` and `alignof` operators, from the type of their
+//! operand.
+
+use crate::Number;
+use crate::errors::api::{CompileError, Located};
+use crate::lineariser::basic_block::Id;
+use crate::lineariser::state::LState;
+use crate::lineariser::symbol::Value;
+use crate::lineariser::types::Type;
+use crate::lineariser::types::decorators::ArrayLength;
+use crate::lineariser::walk::arrays::constant_length;
+use crate::parser::api::{Ast, Attribute, BinaryOperator, Literal, UnaryOperator};
+
+/// Returns the value of `sizeof` or `alignof` on the given operand.
+///
+/// The operand is either a type name in parenthesis, like `sizeof(int)`, or an
+/// expression, like `sizeof x`, that is not evaluated. The result is of type
+/// `size_t`, that is `unsigned long` or `unsigned long long`, depending on the
+/// size of the pointers on the target.
+///
+/// The alignment of an array is the alignment of its elements, but the size
+/// of a variable length array is only known at runtime. Functions have neither
+/// a size nor an alignment.
+pub fn eval_type_operator(
+    op: &Located<UnaryOperator>,
+    arg: &Ast,
+    state: &LState,
+) -> Result<Number, CompileError> {
+    let operand = operand_type(op, arg, state)?;
+    let alignof = *op.as_value() == UnaryOperator::Alignof;
+    let ty = if alignof {
+        operand.split_arrays().1
+    } else if operand.has_variable_length() {
+        return Err(arg.location().fail(format!(
+            "Expected constant expression, found `{op}` of variable length array of type {operand}"
+        )));
+    } else {
+        operand
+    };
+    if ty.is_function() {
+        return Err(arg
+            .location()
+            .fail(format!("Invalid application of `{op}` to a function type")));
+    }
+    let Some(layout) = state.layout(&ty) else {
+        return Err(arg
+            .location()
+            .fail(format!("Invalid application of `{op}` to incomplete type {ty}")));
+    };
+    Ok(size_number(if alignof { layout.align } else { layout.size }, state))
+}
+
+/// Returns the type of the operand of `sizeof` or `alignof`.
+///
+/// A lone identifier in parenthesis, like `sizeof(x)`, is a type name if it
+/// names a typedef, and an expression otherwise. The sizes of the arrays of a
+/// type name, like `10` in `sizeof(int[10])`, must be constant.
+fn operand_type(
+    op: &Located<UnaryOperator>,
+    arg: &Ast,
+    state: &LState,
+) -> Result<Type, CompileError> {
+    if arg.is_empty() || matches!(arg, Ast::ParensBlock(parens) if parens.as_value().is_empty()) {
+        return Err(op.as_location().fail(format!("Missing operand of `{op}`")));
+    }
+    if let Ast::ParensBlock(parens) = arg
+        && let Some((attrs, sizes)) = parens.type_name()
+        && !matches!(attrs.as_slice(), [attr] if matches!(attr.as_value(),
+            Attribute::User(name) if state.find_typedef(name).is_none()))
+    {
+        return type_name_type(&attrs, sizes, state);
+    }
+    Type::of_expression(arg, state).ok_or_else(|| {
+        arg.location()
+            .fail(format!("Failed to compute the type of the operand of `{op}`"))
+    })
+}
+
+/// Returns the type of a type name, from its attributes and the sizes of its
+/// array declarators, as returned by
+/// [`ParensBlock::type_name`](crate::parser::api::ParensBlock::type_name).
+///
+/// The sizes of the arrays must be constant.
+pub fn type_name_type(
+    attrs: &[Located<Attribute>],
+    sizes: Vec<&Ast>,
+    state: &LState,
+) -> Result<Type, CompileError> {
+    let (ty, errors) = Type::from_attributes(attrs, state).into_parts();
+    if let Some(err) = errors.into_iter().next() {
+        return Err(err);
+    }
+    let mut array_ty = ty.expect("never none");
+    for size in sizes {
+        let length = if size.is_empty() {
+            ArrayLength::Incomplete
+        } else {
+            ArrayLength::Fixed(constant_length(size, state)?)
+        };
+        array_ty = array_ty.into_array(length);
+    }
+    Ok(array_ty)
+}
+
+/// Returns a value of type `size_t`.
+const fn size_number(value: u64, state: &LState) -> Number {
+    let target = state.target();
+    if target.long.size >= target.pointer.size {
+        Number::ULong(value)
+    } else {
+        Number::ULongLong(value)
+    }
+}
+
+/// Returns the type `size_t` of the sizes.
+pub fn size_type(state: &LState) -> Type {
+    Type::from_lit(&Literal::Number(size_number(0, state))).unqualified()
+}
+
+/// Pushes the value of `sizeof` or `alignof`.
+///
+/// It is a constant, except for the size of variable length arrays, that is
+/// computed at runtime from the lengths of the array.
+pub fn push_type_operator(op: &Located<UnaryOperator>, arg: &Ast, state: &mut LState) -> Id {
+    if *op.as_value() == UnaryOperator::Sizeof
+        && let Ok(ty) = operand_type(op, arg, state)
+        && ty.has_variable_length()
+    {
+        return push_variable_size(op, arg, &ty, state);
+    }
+    match eval_type_operator(op, arg, state) {
+        Ok(number) => {
+            let literal = Literal::Number(number);
+            let ty = Type::from_lit(&literal);
+            Id::Found(state.push_literal(literal), ty)
+        }
+        Err(err) => {
+            state.push_error(err);
+            Id::NotFound
+        }
+    }
+}
+
+/// Pushes the size of a variable length array, as the product of the size of
+/// its elements and of its lengths.
+fn push_variable_size(op: &Located<UnaryOperator>, arg: &Ast, ty: &Type, state: &mut LState) -> Id {
+    let (lengths, element) = ty.split_arrays();
+    let mut lengths_ids = vec![];
+    let mut size = state.layout(&element).map(|layout| layout.size);
+    for len in lengths {
+        match len {
+            ArrayLength::Fixed(nb) => size = size.map(|value| value.saturating_mul(nb)),
+            ArrayLength::Variable(id) => lengths_ids.push(id),
+            ArrayLength::Incomplete | ArrayLength::Unspecified => size = None,
+        }
+    }
+    let Some(constant) = size else {
+        state.push_error(
+            arg.location()
+                .fail(format!("Invalid application of `{op}` to incomplete type {ty}")),
+        );
+        return Id::NotFound;
+    };
+    let literal = Literal::Number(size_number(constant, state));
+    let size_ty = Type::from_lit(&literal);
+    let mut id = state.push_literal(literal);
+    for len_id in lengths_ids {
+        let product = Value::Binary(BinaryOperator::Multiply, id, len_id);
+        id = state.push_element(product, size_ty.unqualified());
+    }
+    Id::Found(id, size_ty.unqualified())
+}
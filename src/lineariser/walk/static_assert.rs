@@ -0,0 +1,72 @@
+//! Checks the static assertions, declared with `static_assert`.
+
+use crate::Number;
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::const_eval::eval_constant;
+use crate::lineariser::state::LState;
+use crate::parser::api::{Ast, Literal};
+
+/// Evaluates the condition of a `static_assert`, and reports an error if it is
+/// false.
+///
+/// The message is optional since C23, so the arguments are either
+/// `(condition, "message")` or `(condition)`.
+pub fn check_static_assert(loc: ErrorLocation, arguments: &[Ast], state: &mut LState) {
+    if arguments.iter().any(Ast::is_empty) {
+        state.push_error(loc.fail("Found empty argument in `static_assert`".to_owned()));
+        return;
+    }
+    let (condition, message) = match arguments {
+        [condition] => (condition, None),
+        [condition, message] => (condition, Some(message)),
+        [] | [_, _, _, ..] => {
+            state.push_error(loc.fail(format!(
+                "Expected 1 or 2 arguments in `static_assert`, got {}",
+                arguments.len()
+            )));
+            return;
+        }
+    };
+    let text = if let Some(msg) = message {
+        if let Ast::Leaf(lit) = msg
+            && let Literal::Str(text, _) = lit.as_value()
+        {
+            Some(text)
+        } else {
+            state.push_error(msg.location().fail(format!(
+                "Expected string literal as message of `static_assert`, found {msg}"
+            )));
+            return;
+        }
+    } else {
+        None
+    };
+    let holds = match eval_constant(condition, state) {
+        Ok(Number::Int(value)) => value != 0i32,
+        Ok(Number::Long(value) | Number::LongLong(value)) => value != 0i64,
+        Ok(Number::UInt(value)) => value != 0u32,
+        Ok(Number::ULong(value) | Number::ULongLong(value)) => value != 0u64,
+        Ok(
+            number @ (Number::BitInt(_)
+            | Number::UBitInt(_)
+            | Number::Float(_)
+            | Number::Double(_)
+            | Number::LongDouble(_)),
+        ) => {
+            state.push_error(condition.location().fail(format!(
+                "Condition of `static_assert` must be an integer constant expression, found {number}"
+            )));
+            return;
+        }
+        Err(err) => {
+            state.push_error(err);
+            return;
+        }
+    };
+    if !holds {
+        state.push_error(condition.location().fail(text.map_or_else(
+            || format!("Static assertion failed: {condition}"),
+            |msg| format!("Static assertion failed: {msg}"),
+        )));
+    }
+}
@@ -0,0 +1,75 @@
+//! Walks a `typedef`, declaring the new type names.
+
+use crate::errors::api::ErrorLocation;
+use crate::lineariser::state::LState;
+use crate::lineariser::types::Type;
+use crate::lineariser::walk::arrays::ArrayContext;
+use crate::lineariser::walk::parameters::apply_declarator;
+use crate::parser::api::{
+    Ast, ControlFlowNode, Declaration, DeclarationValue, TypedefContent, TypedefCtrl, VariableValue
+};
+
+impl TypedefCtrl {
+    /// Declares the type names of the typedef in the current scope.
+    pub(crate) fn push_in(self, state: &mut LState) {
+        let (content, keyword) = self.into_parts();
+        match content {
+            TypedefContent::Type(var) => match var.into_value() {
+                VariableValue::AttributeVariable(attr) => {
+                    let loc = attr.location();
+                    let ty = Type::from_attributes(&attr.attrs, state)
+                        .store_errors(&mut |err| state.push_error(err))
+                        .expect("never none");
+                    let mut named = false;
+                    for decl in attr.declarations.into_iter().flatten() {
+                        named = true;
+                        let Declaration { arrays, name, parens, value } = decl;
+                        match value {
+                            DeclarationValue::None => {
+                                let alias = apply_declarator(
+                                    arrays,
+                                    parens,
+                                    ty.clone(),
+                                    ArrayContext::Typedef,
+                                    state,
+                                );
+                                state.push_typedef(name, &alias);
+                            }
+                            DeclarationValue::Value(ast) => state.push_error(ast.location().fail(
+                                format!("Typedef {} can't have an initial value", name.as_value()),
+                            )),
+                            DeclarationValue::Bitfield(nb) => state.push_error(
+                                nb.as_location()
+                                    .fail("Bitfield only works in structs or unions".to_owned()),
+                            ),
+                        }
+                    }
+                    if !named {
+                        missing_name(keyword.into_extended(loc), state);
+                    }
+                }
+                VariableValue::VariableName(loc, name) =>
+                    state.push_error(loc.fail(format!("Missing type for typedef name {name}"))),
+            },
+            TypedefContent::Definition(ctrl, name) => {
+                let ControlFlowNode::IdentBlock(block) = *ctrl else {
+                    let loc = Ast::ControlFlow(*ctrl).location();
+                    state.push_error(loc.fail("Expected type after typedef".to_owned()));
+                    return;
+                };
+                let (ty, ..) = block.define(state);
+                if let Some(alias) = name {
+                    state.push_typedef(alias, &ty);
+                } else {
+                    missing_name(keyword, state);
+                }
+            }
+            TypedefContent::None => missing_name(keyword, state),
+        }
+    }
+}
+
+/// Reports a typedef without a type name to declare.
+fn missing_name(loc: ErrorLocation, state: &mut LState) {
+    state.push_error(loc.warn("Typedef requires a name".to_owned()));
+}
@@ -2,54 +2,133 @@
 //! creating symbols and basic blocks.
 
 use crate::lineariser::basic_block::{BasicBlocks, Id};
+use crate::lineariser::const_eval::is_null_constant;
 use crate::lineariser::state::LState;
 use crate::lineariser::symbol::Value;
 use crate::lineariser::types::Type;
-use crate::parser::api::{Ast, AttributeVariable, Declaration, DeclarationValue};
+use crate::lineariser::types::function::Assignment;
+use crate::lineariser::walk::arrays::ArrayContext;
+use crate::lineariser::walk::conversion::{Operand, push_conversion};
+use crate::lineariser::walk::initialiser::push_initialised;
+use crate::lineariser::walk::parameters::apply_declarator;
+use crate::parser::api::{
+    Ast, Attribute, AttributeKeyword, AttributeVariable, Declaration, DeclarationValue
+};
 
 impl AttributeVariable {
     /// Pushes some content into the [`BasicBlocks`].
-    pub fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) {
+    pub(crate) fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState) {
         #[cfg(feature = "debug")]
         crate::lgp!(notab: "Pushing attr var {self}");
-        let ty = Type::from_attributes(&self.attrs)
+        if let [keyword, attrs @ ..] = self.attrs.as_slice()
+            && let Attribute::Keyword(AttributeKeyword::UserDefinedTypes(kind)) = keyword.as_value()
+            && attrs
+                .iter()
+                .all(|attr| matches!(attr.as_value(), Attribute::Standard(_)))
+            && let [Some(decl)] = self.declarations.as_slice()
+            && decl.is_name_only()
+        {
+            // Forward declaration: `struct A;`
+            let loc = keyword.as_location().into_extended(decl.name.as_location());
+            let name = decl.name.as_value();
+            state.declare_tag(*kind, name.to_owned(), loc);
+            state.apply_tag_attributes(*kind, name, attrs);
+            return;
+        }
+        if self.declarations.is_empty()
+            && self
+                .attrs
+                .iter()
+                .all(|attr| matches!(attr.as_value(), Attribute::Standard(_)))
+        {
+            // Attribute statement: `[[fallthrough]];`
+            state.push_attribute_statement(&self.attrs);
+            return;
+        }
+        let ty = Type::from_attributes(&self.attrs, state)
             .store_errors(&mut |err| state.push_error(err))
             .expect("never none");
         for decl in self.declarations.into_iter().flatten() {
-            decl.push_in(bbs, state, &ty);
+            let name = decl.name.as_value().to_owned();
+            if let Some(id) = decl.push_in(bbs, state, &ty) {
+                state.apply_attributes(id, &name, &self.attrs, false);
+            }
         }
     }
 }
 
 impl Declaration {
     /// Pushes some content into the [`BasicBlocks`].
-    pub fn push_in(self, bbs: &mut BasicBlocks, state: &mut LState, ty: &Type) {
+    ///
+    /// # Returns
+    ///
+    /// The id of the declared variable, if it was declared.
+    pub(crate) fn push_in(
+        self,
+        bbs: &mut BasicBlocks,
+        state: &mut LState,
+        ty: &Type,
+    ) -> Option<usize> {
         #[cfg(feature = "debug")]
         crate::lgp!(notab: "Pushing decl {self} with type {ty:?}");
-        let (name, value) = self.into_name_value();
-        let init_value = match value {
-            DeclarationValue::None => Value::DeclaredOnly,
-            DeclarationValue::Value(Ast::Leaf(lit)) =>
-                Value::Variable(state.push_literal(lit.drop_location())),
-            DeclarationValue::Value(ast) => {
-                let loc = ast.location();
-                match ast.push_in(bbs, state) {
-                    Some(Id::Found(id, _)) => Value::Variable(id),
-                    Some(Id::NotFound) => Value::DeclaredOnly,
-                    None => {
-                        state.stat_not_expr(loc, "declaration value");
-                        return;
-                    }
-                }
-            }
+        let Self { arrays, name, parens, value } = self;
+        let var_ty =
+            apply_declarator(arrays, parens, ty.clone(), ArrayContext::Variable(bbs), state);
+        if var_ty.is_function() {
+            state.push_error(name.as_location().fail(format!(
+                "Declaration of function {} with type {var_ty} is not supported: use a function declarator",
+                name.as_value()
+            )));
+            return None;
+        }
+        let init = match value {
+            DeclarationValue::None => None,
+            DeclarationValue::Value(ast) => Some(ast),
             DeclarationValue::Bitfield(nb) => {
                 state.push_error(
                     nb.as_location()
                         .fail("Bitfield only works in structs or unions".to_owned()),
                 );
-                return;
+                return None;
             }
         };
-        state.push_declaration(name, ty, init_value);
+        push_initialised(name, var_ty, init, bbs, state)
+    }
+}
+
+/// Pushes the initial value of a declaration of type `ty`, converted to `ty` as
+/// if by assignment.
+///
+/// # Returns
+///
+/// The value of the declared variable, or `None` if the initial value is a
+/// statement.
+pub fn push_init_value(
+    ast: Ast,
+    ty: &Type,
+    bbs: &mut BasicBlocks,
+    state: &mut LState,
+) -> Option<Value> {
+    let loc = ast.location();
+    let null = is_null_constant(&ast, state);
+    let (id, value_ty) = if let Ast::Leaf(lit) = ast {
+        let value_ty = Type::from_lit(lit.as_value()).decayed();
+        (state.push_literal(lit.drop_location()), value_ty)
+    } else {
+        match ast.push_in(bbs, state) {
+            Some(Id::Found(id, value_ty)) => (id, value_ty),
+            Some(Id::NotFound) => return Some(Value::DeclaredOnly),
+            None => {
+                state.stat_not_expr(loc, "declaration value");
+                return None;
+            }
+        }
+    };
+    if ty.is_array() {
+        return Some(Value::Variable(id));
     }
+    ty.check_assignment(&value_ty, null, loc, Assignment::Initialisation)
+        .store_errors(&mut |err| state.push_error(err));
+    let operand = Operand { id, loc, null, ty: value_ty };
+    Some(Value::Variable(push_conversion(&operand, ty, state)))
 }
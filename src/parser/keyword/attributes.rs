@@ -6,7 +6,7 @@ use crate::errors::api::Located;
 use crate::lexer::api::Keyword;
 use crate::parser::modifiers::push::Push as _;
 use crate::parser::operators::api::{Binary, Ternary, Unary};
-use crate::parser::symbols::api::{BracedBlock, ListInitialiser};
+use crate::parser::symbols::api::{BracedBlock, CompoundLiteral, ListInitialiser};
 use crate::parser::tree::Ast;
 use crate::parser::variable::Variable;
 use crate::utils::display;
@@ -67,7 +67,7 @@ define_attribute_keywords!(
     Storage: Auto ThreadLocal Extern Static Register =>,
     Qualifiers: Const Constexpr Volatile Default=>,
     UserDefinedTypes: Struct Union Enum =>,
-    SpecialAttributes: Atomic Alignas Inline Restrict Generic Noreturn Typeof TypeofUnqual =>,
+    SpecialAttributes: Atomic Alignas Inline Restrict Noreturn Typeof TypeofUnqual =>,
 );
 
 impl From<Located<AttributeKeyword>> for Ast {
@@ -84,7 +84,7 @@ impl PushInNode for Located<AttributeKeyword> {
             Ast::Empty => *node = Ast::from(self),
             Ast::Cast(_) => return Err("Attribute found after a cast, but not allowed".to_owned()),
             Ast::Variable(var) => var.push_keyword(self)?,
-            Ast::ParensBlock(_) | Ast::Leaf(_) => {
+            Ast::Generic(_) | Ast::ParensBlock(_) | Ast::Leaf(_) => {
                 return Err(format!(
                     "invalid attribute. Attribute keywords can only be applied to variables, but found {node}"
                 ));
@@ -99,13 +99,21 @@ impl PushInNode for Located<AttributeKeyword> {
             Ast::ControlFlow(_) => {
                 return Err("Attribute found after full control flow, but not allowed.".to_owned());
             }
-            Ast::FunctionCall(_) | Ast::ListInitialiser(ListInitialiser { full: true, .. }) => {
+            Ast::FunctionCall(_)
+            | Ast::ListInitialiser(ListInitialiser { full: true, .. })
+            | Ast::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: true, .. },
+                ..
+            }) => {
                 return Err(format!(
                     "Attribute {self} can only be placed before variables, but was found after {node}. Did you forget a ';' ?"
                 ));
             }
             Ast::FunctionArgsBuild(elts, ..)
             | Ast::ListInitialiser(ListInitialiser { elts, .. })
+            | Ast::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { elts, .. }, ..
+            })
             | Ast::BracedBlock(BracedBlock { elts, .. }) => match elts.last_mut() {
                 Some(last) => return self.push_in_node(last),
                 None => elts.push(Ast::from(self)),
@@ -42,6 +42,7 @@ macro_rules! derive_method{
 
 /// Node representation of a control flow.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ControlFlowNode {
     /// Keyword expects a node: `return 3+4`
     Ast(ReturnCtrl),
@@ -188,9 +189,11 @@ pub fn switch_wanting_block(current: &Ast) -> bool {
         | Ast::Cast(_)
         | Ast::Ternary(_)
         | Ast::Variable(_)
+        | Ast::Generic(_)
         | Ast::ParensBlock(_)
         | Ast::FunctionCall(_)
         | Ast::ListInitialiser(_)
+        | Ast::CompoundLiteral(_)
         | Ast::FunctionArgsBuild(..)
         | Ast::BracedBlock(BracedBlock { full: true, .. }) => false,
         Ast::ControlFlow(ctrl) =>
@@ -213,9 +216,11 @@ pub fn try_push_semicolon_control(current: &mut Ast) -> bool {
         | Ast::Binary(_)
         | Ast::Ternary(_)
         | Ast::Variable(_)
+        | Ast::Generic(_)
         | Ast::ParensBlock(_)
         | Ast::FunctionCall(_)
         | Ast::ListInitialiser(_)
+        | Ast::CompoundLiteral(_)
         | Ast::FunctionArgsBuild(..) => false,
         Ast::ControlFlow(ctrl) => ctrl.push_semicolon(),
         Ast::BracedBlock(BracedBlock { elts, full, .. }) =>
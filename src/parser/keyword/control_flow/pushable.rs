@@ -42,8 +42,10 @@ impl PushInNode for PushableKeyword {
             | Ast::Binary(_)
             | Ast::Ternary(_)
             | Ast::Variable(_)
+            | Ast::Generic(_)
             | Ast::ParensBlock(_)
             | Ast::ListInitialiser(_)
+            | Ast::CompoundLiteral(_)
             | Ast::FunctionArgsBuild(..)
             | Ast::FunctionCall(_) =>
                 unreachable!("found a control flow: pushing {self} in {node}"),
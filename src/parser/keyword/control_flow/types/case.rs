@@ -3,6 +3,7 @@
 use core::fmt;
 
 use crate::errors::api::ErrorLocation;
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::keyword::control_flow::types::repr_colon_option;
 use crate::parser::modifiers::push::Push;
@@ -24,6 +25,15 @@ pub struct AstColonAstCtrl {
     keyword_location: ErrorLocation,
 }
 
+impl AstColonAstCtrl {
+    /// Returns the value of the case, the [`Ast`] after the colon and the
+    /// location of the keyword.
+    #[must_use]
+    pub fn into_parts(self) -> (Box<Ast>, Option<Box<Ast>>, ErrorLocation) {
+        (self.before, self.after, self.keyword_location)
+    }
+}
+
 impl ControlFlow for AstColonAstCtrl {
     type Keyword = ErrorLocation;
 
@@ -48,11 +58,16 @@ impl ControlFlow for AstColonAstCtrl {
     }
 
     fn location(&self) -> ErrorLocation {
-        self.after
+        let last = self
+            .after
             .as_ref()
-            .map_or(&self.before, |last| last)
-            .location()
-            .into_extended(self.keyword_location)
+            .filter(|after| !after.is_empty())
+            .unwrap_or(&self.before);
+        if last.is_empty() {
+            self.keyword_location
+        } else {
+            last.location().into_extended(self.keyword_location)
+        }
     }
 
     fn push_colon(&mut self) -> bool {
@@ -119,3 +134,21 @@ display!(
         repr_fullness(self.full)
     )
 );
+
+impl Walk for AstColonAstCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.before);
+        if let Some(after) = &self.after {
+            visitor.visit_ast(after);
+        }
+        visitor.visit_location(self.keyword_location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.before);
+        if let Some(after) = &mut self.after {
+            visitor.visit_ast_mut(after);
+        }
+        visitor.visit_location_mut(&mut self.keyword_location);
+    }
+}
@@ -3,6 +3,7 @@
 use core::fmt;
 
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::node::ControlFlowNode;
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::keyword::control_flow::types::repr_colon_option;
@@ -35,6 +36,19 @@ impl ColonAstCtrl {
             keyword: name.transfer(ColonAstKeyword::Label),
         }))
     }
+
+    /// Returns the keyword (`default` or the label) and the [`Ast`] after the
+    /// colon.
+    #[must_use]
+    pub fn into_parts(self) -> (Located<ColonAstKeyword>, Option<Box<Ast>>) {
+        (self.keyword, self.after)
+    }
+
+    /// Checks if this is a `default` label, and not a user-defined label.
+    #[must_use]
+    pub const fn is_default(&self) -> bool {
+        matches!(self.keyword.as_value(), ColonAstKeyword::Default)
+    }
 }
 
 impl ControlFlow for ColonAstCtrl {
@@ -69,10 +83,13 @@ impl ControlFlow for ColonAstCtrl {
     }
 
     fn location(&self) -> ErrorLocation {
-        self.after.as_ref().map_or_else(
-            || self.keyword.as_location(),
-            |after| self.keyword.as_location().into_extended(after.location()),
-        )
+        self.after
+            .as_ref()
+            .filter(|after| !after.is_empty())
+            .map_or_else(
+                || self.keyword.as_location(),
+                |after| self.keyword.as_location().into_extended(after.location()),
+            )
     }
 
     fn push_colon(&mut self) -> bool {
@@ -93,7 +110,15 @@ impl ControlFlow for ColonAstCtrl {
                 true
             } else if *self.keyword.as_value() == ColonAstKeyword::Default {
                 // continue to push until closing brace
-                ast.brace();
+                if ast.is_empty() {
+                    **ast = Ast::BracedBlock(BracedBlock {
+                        elts: vec![Ast::Empty],
+                        full: false,
+                        location: self.keyword.as_location(),
+                    });
+                } else {
+                    ast.brace();
+                }
                 true
             } else {
                 // fill because a semicolon means end of block
@@ -154,3 +179,19 @@ pub enum ColonAstKeyword {
     /// labels (identifiers)
     Label(String),
 }
+
+impl Walk for ColonAstCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        if let Some(after) = &self.after {
+            visitor.visit_ast(after);
+        }
+        visitor.visit_location(self.keyword.as_location());
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(after) = &mut self.after {
+            visitor.visit_ast_mut(after);
+        }
+        visitor.visit_location_mut(self.keyword.as_location_mut());
+    }
+}
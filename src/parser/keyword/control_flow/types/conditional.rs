@@ -3,6 +3,7 @@
 use core::fmt;
 
 use crate::errors::api::ErrorLocation;
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::node::{ControlFlowNode, try_push_semicolon_control};
 use crate::parser::keyword::control_flow::pushable::PushableKeyword;
 use crate::parser::keyword::control_flow::traits::ControlFlow;
@@ -32,8 +33,15 @@ pub struct ConditionCtrl {
 }
 
 impl ConditionCtrl {
+    /// Returns the condition, the success block and the failure block of the
+    /// control flow, with the location of the `if` keyword.
+    #[must_use]
+    pub fn into_parts(self) -> (Option<ParensBlock>, Box<Ast>, Option<Box<Ast>>, ErrorLocation) {
+        (self.condition, self.success, self.failure, self.keyword_location)
+    }
+
     /// Checks if the control flow is waiting for an `else` keyword
-    pub const fn no_else(&self) -> bool {
+    pub(crate) const fn no_else(&self) -> bool {
         self.condition.is_some() && self.failure.is_none() && !self.full_f
     }
 
@@ -177,13 +185,13 @@ impl Push for ConditionCtrl {
                 self.success.push_block_as_leaf(ast)?;
             }
             Ok(())
-        } else if self.condition.is_none()
-            && let Ast::ParensBlock(parens) = ast
-        {
+        } else if self.condition.is_some() {
+            Err(format!("Found {ast} after a complete conditional."))
+        } else if let Ast::ParensBlock(parens) = ast {
             self.condition = Some(parens);
             Ok(())
         } else {
-            unreachable!("Tried to push to complete conditional")
+            Err(format!("Expected a condition in parenthesis after 'if', but found {ast}."))
         }
     }
 
@@ -222,3 +230,27 @@ display!(
         if self.full_f { "" } else { ".\u{b2}." },
     )
 );
+
+impl Walk for ConditionCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        if let Some(condition) = &self.condition {
+            visitor.visit_parens_block(condition);
+        }
+        visitor.visit_ast(&self.success);
+        if let Some(failure) = &self.failure {
+            visitor.visit_ast(failure);
+        }
+        visitor.visit_location(self.keyword_location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(condition) = &mut self.condition {
+            visitor.visit_parens_block_mut(condition);
+        }
+        visitor.visit_ast_mut(&mut self.success);
+        if let Some(failure) = &mut self.failure {
+            visitor.visit_ast_mut(failure);
+        }
+        visitor.visit_location_mut(&mut self.keyword_location);
+    }
+}
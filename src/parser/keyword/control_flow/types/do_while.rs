@@ -3,6 +3,7 @@
 use core::fmt;
 
 use crate::errors::api::ErrorLocation;
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::node::{ControlFlowNode, try_push_semicolon_control};
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::modifiers::push::Push;
@@ -29,6 +30,15 @@ pub struct DoWhileCtrl {
     while_found: Option<ErrorLocation>,
 }
 
+impl DoWhileCtrl {
+    /// Returns the loop block and the looping condition, with the location of
+    /// the `do` keyword.
+    #[must_use]
+    pub fn into_parts(self) -> (Box<Ast>, Option<ParensBlock>, ErrorLocation) {
+        (self.loop_block, self.condition, self.keyword_location)
+    }
+}
+
 impl ControlFlow for DoWhileCtrl {
     type Keyword = ErrorLocation;
 
@@ -51,10 +61,18 @@ impl ControlFlow for DoWhileCtrl {
     }
 
     fn location(&self) -> ErrorLocation {
-        self.while_found
-            .as_ref()
-            .map_or_else(|| self.loop_block.location(), Clone::clone)
-            .into_extended(self.keyword_location)
+        self.while_found.map_or_else(
+            || {
+                if self.loop_block.is_empty() {
+                    self.keyword_location
+                } else {
+                    self.loop_block
+                        .location()
+                        .into_extended(self.keyword_location)
+                }
+            },
+            |while_location| while_location.into_extended(self.keyword_location),
+        )
     }
 
     fn push_colon(&mut self) -> bool {
@@ -135,3 +153,29 @@ display!(
         }
     )
 );
+
+impl Walk for DoWhileCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.loop_block);
+        if let Some(condition) = &self.condition {
+            visitor.visit_parens_block(condition);
+        }
+        for location in self.while_found.iter().chain([&self.keyword_location]) {
+            visitor.visit_location(*location);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.loop_block);
+        if let Some(condition) = &mut self.condition {
+            visitor.visit_parens_block_mut(condition);
+        }
+        for location in self
+            .while_found
+            .iter_mut()
+            .chain([&mut self.keyword_location])
+        {
+            visitor.visit_location_mut(location);
+        }
+    }
+}
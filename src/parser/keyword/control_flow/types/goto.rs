@@ -3,6 +3,7 @@
 use core::fmt;
 
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::modifiers::push::Push;
 use crate::parser::operators::api::OperatorConversions;
@@ -18,6 +19,14 @@ pub struct ColonIdentCtrl {
     label: Option<Located<String>>,
 }
 
+impl ColonIdentCtrl {
+    /// Returns the label to jump to, with the location of the `goto` keyword.
+    #[must_use]
+    pub fn into_parts(self) -> (Option<Located<String>>, ErrorLocation) {
+        (self.label, self.keyword_location)
+    }
+}
+
 impl ControlFlow for ColonIdentCtrl {
     type Keyword = ErrorLocation;
 
@@ -80,3 +89,19 @@ impl Push for ColonIdentCtrl {
 }
 
 display!(ColonIdentCtrl, self, f, write!(f, "<goto {}>", repr_option(&self.label)));
+
+impl Walk for ColonIdentCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        if let Some(label) = &self.label {
+            visitor.visit_location(label.as_location());
+        }
+        visitor.visit_location(self.keyword_location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(label) = &mut self.label {
+            visitor.visit_location_mut(label.as_location_mut());
+        }
+        visitor.visit_location_mut(&mut self.keyword_location);
+    }
+}
@@ -3,45 +3,95 @@
 use core::fmt;
 
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::attributes::UserDefinedTypes;
 use crate::parser::keyword::control_flow::node::ControlFlowNode;
 use crate::parser::keyword::control_flow::traits::ControlFlow;
+use crate::parser::literal::Attribute;
 use crate::parser::modifiers::push::Push;
 use crate::parser::operators::api::OperatorConversions;
 use crate::parser::symbols::api::BracedBlock;
 use crate::parser::tree::Ast;
-use crate::utils::{display, repr_option};
+use crate::parser::visit::{walk_attributes, walk_attributes_mut};
+use crate::utils::{display, repr_fullness, repr_option, repr_vec};
+
+/// Keyword, name, attributes, definition block and declarators of a
+/// user-defined type.
+pub type IdentBlockParts = (
+    Located<IdentBlockKeyword>,
+    Option<Located<String>>,
+    Vec<Located<Attribute>>,
+    Option<BracedBlock>,
+    Box<Ast>,
+);
 
 /// Keyword expects an identifier and a braced block: `struct Blob {}`
 #[derive(Debug)]
 pub struct IdentBlockCtrl {
+    /// Attributes of the type, like `[[deprecated]]` in
+    /// `struct [[deprecated]] A {}`.
+    attrs: Vec<Located<Attribute>>,
     /// User defined type definition
     block: Option<BracedBlock>,
+    /// Variables declared with the type, after the definition.
+    ///
+    /// # Examples
+    ///
+    /// `a, *b` in `struct A { int x; } a, *b;`
+    declarators: Box<Ast>,
+    /// Marks the end of the declaration, when the semicolon was found.
+    full: bool,
     /// User defined type name
     ident: Option<Located<String>>,
     /// User defined type type
     keyword: Located<IdentBlockKeyword>,
 }
 
+impl IdentBlockCtrl {
+    /// Returns the keyword, the name, the attributes, the definition block and
+    /// the declarators of the user-defined type.
+    #[must_use]
+    pub fn into_parts(self) -> IdentBlockParts {
+        (self.keyword, self.ident, self.attrs, self.block, self.declarators)
+    }
+
+    /// Checks if the definition is finished, and the declarators are now
+    /// expected.
+    const fn wants_declarators(&self) -> bool {
+        self.block.is_some() && !self.full
+    }
+}
+
 impl ControlFlow for IdentBlockCtrl {
     type Keyword = Located<IdentBlockKeyword>;
 
     fn as_ast(&self) -> Option<&Ast> {
-        None
+        self.wants_declarators().then(|| self.declarators.as_ref())
     }
 
     fn as_ast_mut(&mut self) -> Option<&mut Ast> {
-        None
+        self.wants_declarators().then(|| self.declarators.as_mut())
     }
 
-    fn fill(&mut self) {}
+    fn fill(&mut self) {
+        if self.block.is_some() {
+            self.full = true;
+        }
+    }
 
     fn from_keyword(keyword: Self::Keyword) -> Self {
-        Self { keyword, ident: None, block: None }
+        Self {
+            attrs: vec![],
+            block: None,
+            declarators: Ast::empty_box(),
+            full: false,
+            ident: None,
+            keyword,
+        }
     }
 
     fn is_full(&self) -> bool {
-        self.block.is_some()
+        self.full
     }
 
     fn location(&self) -> ErrorLocation {
@@ -56,7 +106,14 @@ impl ControlFlow for IdentBlockCtrl {
                     },
                 )
             },
-            |block| block.location.into_extended(self.keyword.as_location()),
+            |block| {
+                let loc = block.location.into_extended(self.keyword.as_location());
+                if self.declarators.is_empty() {
+                    loc
+                } else {
+                    loc.into_extended(self.declarators.location())
+                }
+            },
         )
     }
 
@@ -65,7 +122,12 @@ impl ControlFlow for IdentBlockCtrl {
     }
 
     fn push_semicolon(&mut self) -> bool {
-        false
+        if self.wants_declarators() {
+            self.full = true;
+            true
+        } else {
+            false
+        }
     }
 }
 
@@ -75,7 +137,7 @@ impl Push for IdentBlockCtrl {
         crate::errors::api::Print::push_leaf(&ast, self, "user-defined type");
         debug_assert!(!self.is_full(), "");
         match (&mut self.ident, &mut self.block, ast) {
-            (_, Some(_), node) => unreachable!("Tried to push {node} on full control flow."),
+            (_, Some(_), node) => return self.declarators.push_block_as_leaf(node),
             (_, None, Ast::BracedBlock(braced)) => self.block = Some(braced),
             (None, None, Ast::Variable(var)) => {
                 self.ident = Some(var.into_user_defined_name()?);
@@ -94,10 +156,12 @@ impl Push for IdentBlockCtrl {
                 | Ast::Unary(_)
                 | Ast::Binary(_)
                 | Ast::Ternary(_)
+                | Ast::Generic(_)
                 | Ast::ParensBlock(_)
                 | Ast::ControlFlow(_)
                 | Ast::FunctionCall(_)
                 | Ast::ListInitialiser(_)
+                | Ast::CompoundLiteral(_)
                 | Ast::FunctionArgsBuild(..)),
             ) => {
                 return Err(format!(
@@ -115,15 +179,15 @@ impl Push for IdentBlockCtrl {
         #[cfg(feature = "debug")]
         crate::errors::api::Print::push_op(&op, self, "user-defined type");
         debug_assert!(!self.is_full(), "");
-        if let Some(BracedBlock { elts, full: false, .. }) = &mut self.block {
+        if self.wants_declarators() {
+            self.declarators.push_op(op)
+        } else if let Some(BracedBlock { elts, full: false, .. }) = &mut self.block {
             if let Some(last) = elts.last_mut() {
                 last.push_op(op)
             } else {
                 elts.push(op.try_to_node()?);
                 Ok(())
             }
-        } else if self.block.is_some() {
-            Err("Failed to push operator to full control flow".to_owned())
         } else {
             Err("Failed to push operator: missing identifier".to_owned())
         }
@@ -136,19 +200,26 @@ display!(
     f,
     write!(
         f,
-        "<{} {} {}>",
-        match self.keyword.as_value() {
-            IdentBlockKeyword::Enum => "enum",
-            IdentBlockKeyword::Struct => "struct",
-            IdentBlockKeyword::Union => "union",
+        "<{} {}{} {}{}{}>",
+        self.keyword.as_value(),
+        if self.attrs.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", repr_vec(&self.attrs, " "))
         },
         repr_option(&self.ident),
-        repr_option(&self.block)
+        repr_option(&self.block),
+        if self.declarators.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", self.declarators)
+        },
+        repr_fullness(self.full || self.block.is_none())
     )
 );
 
 /// C control flow keywords that have the [`IdentBlockCtrl`] structure.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum IdentBlockKeyword {
     /// `enum A { }`
     Enum,
@@ -158,6 +229,18 @@ pub enum IdentBlockKeyword {
     Union,
 }
 
+display!(
+    IdentBlockKeyword,
+    self,
+    f,
+    match self {
+        Self::Enum => "enum",
+        Self::Struct => "struct",
+        Self::Union => "union",
+    }
+    .fmt(f)
+);
+
 impl Located<UserDefinedTypes> {
     /// Tries to convert an attribute keyword to a control flow
     ///
@@ -169,6 +252,7 @@ impl Located<UserDefinedTypes> {
     pub fn into_control_flow(
         self,
         ident: Option<Located<String>>,
+        attrs: Vec<Located<Attribute>>,
         block: Option<BracedBlock>,
     ) -> ControlFlowNode {
         let keyword = match self.as_value() {
@@ -177,9 +261,38 @@ impl Located<UserDefinedTypes> {
             UserDefinedTypes::Enum => IdentBlockKeyword::Enum,
         };
         ControlFlowNode::IdentBlock(IdentBlockCtrl {
+            attrs,
             block,
+            declarators: Ast::empty_box(),
+            full: false,
             ident,
             keyword: self.transfer(|_| keyword),
         })
     }
 }
+
+impl Walk for IdentBlockCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        if let Some(block) = &self.block {
+            visitor.visit_braced_block(block);
+        }
+        visitor.visit_ast(&self.declarators);
+        if let Some(ident) = &self.ident {
+            visitor.visit_location(ident.as_location());
+        }
+        walk_attributes(&self.attrs, visitor);
+        visitor.visit_location(self.keyword.as_location());
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(block) = &mut self.block {
+            visitor.visit_braced_block_mut(block);
+        }
+        visitor.visit_ast_mut(&mut self.declarators);
+        if let Some(ident) = &mut self.ident {
+            visitor.visit_location_mut(ident.as_location_mut());
+        }
+        walk_attributes_mut(&mut self.attrs, visitor);
+        visitor.visit_location_mut(self.keyword.as_location_mut());
+    }
+}
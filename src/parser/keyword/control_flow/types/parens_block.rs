@@ -4,6 +4,7 @@
 use core::fmt;
 
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::node::try_push_semicolon_control;
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::modifiers::push::Push;
@@ -26,6 +27,15 @@ pub struct ParensBlockCtrl {
     parens: Option<ParensBlock>,
 }
 
+impl ParensBlockCtrl {
+    /// Returns the keyword, the parenthesised expression and the block of the
+    /// control flow.
+    #[must_use]
+    pub fn into_parts(self) -> (Located<ParensBlockKeyword>, Option<ParensBlock>, Box<Ast>) {
+        (self.keyword, self.parens, self.block)
+    }
+}
+
 impl ControlFlow for ParensBlockCtrl {
     type Keyword = Located<ParensBlockKeyword>;
 
@@ -67,9 +77,13 @@ impl ControlFlow for ParensBlockCtrl {
     }
 
     fn location(&self) -> ErrorLocation {
-        self.block
-            .location()
-            .into_extended(self.keyword.as_location())
+        if self.block.is_empty() {
+            self.keyword.as_location()
+        } else {
+            self.block
+                .location()
+                .into_extended(self.keyword.as_location())
+        }
     }
 
     fn push_colon(&mut self) -> bool {
@@ -173,3 +187,21 @@ display!(
         Self::While => "while".fmt(f),
     }
 );
+
+impl Walk for ParensBlockCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        if let Some(parens) = &self.parens {
+            visitor.visit_parens_block(parens);
+        }
+        visitor.visit_ast(&self.block);
+        visitor.visit_location(self.keyword.as_location());
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        if let Some(parens) = &mut self.parens {
+            visitor.visit_parens_block_mut(parens);
+        }
+        visitor.visit_ast_mut(&mut self.block);
+        visitor.visit_location_mut(self.keyword.as_location_mut());
+    }
+}
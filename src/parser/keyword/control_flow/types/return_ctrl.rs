@@ -3,6 +3,7 @@
 use core::fmt;
 
 use crate::errors::api::ErrorLocation;
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::node::try_push_semicolon_control;
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::modifiers::push::Push;
@@ -23,9 +24,11 @@ pub struct ReturnCtrl {
 }
 
 impl ReturnCtrl {
-    /// Returns the value returned by the control flow.
-    pub fn into_value(self) -> Box<Ast> {
-        self.value
+    /// Returns the value returned by the control flow, with the location of
+    /// the `return` keyword.
+    #[must_use]
+    pub fn into_parts(self) -> (Box<Ast>, ErrorLocation) {
+        (self.value, self.return_location)
     }
 }
 
@@ -53,7 +56,11 @@ impl ControlFlow for ReturnCtrl {
     }
 
     fn location(&self) -> ErrorLocation {
-        self.value.location().into_extended(self.return_location)
+        if self.value.is_empty() {
+            self.return_location
+        } else {
+            self.value.location().into_extended(self.return_location)
+        }
     }
 
     fn push_colon(&mut self) -> bool {
@@ -101,3 +108,15 @@ display!(
     f,
     write!(f, "<return {}{}>", self.value, repr_fullness(self.full))
 );
+
+impl Walk for ReturnCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.value);
+        visitor.visit_location(self.return_location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.value);
+        visitor.visit_location_mut(&mut self.return_location);
+    }
+}
@@ -4,6 +4,7 @@
 use core::fmt;
 
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::modifiers::push::Push;
 use crate::parser::operators::api::OperatorConversions;
@@ -14,6 +15,14 @@ use crate::utils::display;
 #[derive(Debug)]
 pub struct SemiColonCtrl(Located<SemiColonKeyword>);
 
+impl SemiColonCtrl {
+    /// Returns the keyword of the control flow.
+    #[must_use]
+    pub const fn into_keyword(self) -> Located<SemiColonKeyword> {
+        self.0
+    }
+}
+
 impl ControlFlow for SemiColonCtrl {
     type Keyword = Located<SemiColonKeyword>;
 
@@ -85,3 +94,13 @@ pub enum SemiColonKeyword {
     /// `continue;`
     Continue,
 }
+
+impl Walk for SemiColonCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_location(self.0.as_location());
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_location_mut(self.0.as_location_mut());
+    }
+}
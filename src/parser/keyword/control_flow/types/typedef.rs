@@ -4,6 +4,7 @@ use core::fmt;
 
 use crate::EMPTY;
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::node::ControlFlowNode;
 use crate::parser::keyword::control_flow::traits::ControlFlow;
 use crate::parser::modifiers::push::Push;
@@ -15,7 +16,7 @@ use crate::utils::{display, repr_fullness, repr_option};
 
 /// Content of the typedef, i.e., what it aliases and to what.
 #[derive(Debug, Default)]
-enum TypedefContent {
+pub enum TypedefContent {
     /// Typedef in a type definition
     ///
     /// # Examples
@@ -42,6 +43,34 @@ enum TypedefContent {
 #[derive(Debug)]
 pub struct TypedefCtrl(TypedefContent, ErrorLocation);
 
+impl TypedefCtrl {
+    /// Returns the aliased type with its name, if the typedef doesn't define a
+    /// new type, like `int A` in `typedef int A[3];`.
+    pub(crate) const fn as_type(&self) -> Option<&Variable> {
+        if let TypedefContent::Type(var) = &self.0 {
+            Some(var)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the aliased type with its name, if the typedef doesn't define a
+    /// new type, like `int A` in `typedef int A[3];`.
+    pub(crate) const fn as_type_mut(&mut self) -> Option<&mut Variable> {
+        if let TypedefContent::Type(var) = &mut self.0 {
+            Some(var)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the content of the typedef and the location of the keyword.
+    #[must_use]
+    pub fn into_parts(self) -> (TypedefContent, ErrorLocation) {
+        (self.0, self.1)
+    }
+}
+
 impl ControlFlow for TypedefCtrl {
     type Keyword = ErrorLocation;
 
@@ -146,9 +175,11 @@ impl Push for TypedefCtrl {
             match &mut self.0 {
                 TypedefContent::Definition(ctrl, None) => ctrl.push_block_as_leaf(ast),
                 TypedefContent::Type(var) =>
-                    if let Some((user_type, name)) = var.as_partial_typedef() {
-                        let mut ctrl = user_type.into_control_flow(name, None);
+                    if let Some((user_type, name, attrs)) = var.as_partial_typedef() {
+                        let mut ctrl = user_type.into_control_flow(name, attrs, None);
                         ctrl.push_block_as_leaf(ast)?;
+                        // The typedef reads the names itself.
+                        ctrl.fill();
                         self.0 = TypedefContent::Definition(Box::new(ctrl), None);
                         Ok(())
                     } else {
@@ -194,3 +225,33 @@ display!(
         repr_fullness(self.is_full())
     )
 );
+
+impl Walk for TypedefCtrl {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        match &self.0 {
+            TypedefContent::Definition(ctrl, name) => {
+                visitor.visit_control_flow(ctrl);
+                if let Some(located) = name {
+                    visitor.visit_location(located.as_location());
+                }
+            }
+            TypedefContent::None => (),
+            TypedefContent::Type(var) => visitor.visit_variable(var),
+        }
+        visitor.visit_location(self.1);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        match &mut self.0 {
+            TypedefContent::Definition(ctrl, name) => {
+                visitor.visit_control_flow_mut(ctrl);
+                if let Some(located) = name {
+                    visitor.visit_location_mut(located.as_location_mut());
+                }
+            }
+            TypedefContent::None => (),
+            TypedefContent::Type(var) => visitor.visit_variable_mut(var),
+        }
+        visitor.visit_location_mut(&mut self.1);
+    }
+}
@@ -10,16 +10,6 @@ use crate::utils::display;
 /// List of existing function keywords
 #[derive(Debug, PartialEq, Eq)]
 pub enum FunctionKeyword {
-    /// Alignof
-    ///
-    /// Returns the alignment, in bytes, of the input
-    Alignof,
-    /// Sizeof
-    ///
-    /// Yields the size in bytes of the object representation of the argument
-    /// (the argument is of type type).
-    // TODO: works without parents
-    Sizeof,
     /// Static assert
     ///
     /// The constant expression is evaluated at compile time and compared to
@@ -40,8 +30,6 @@ display!(
     self,
     f,
     match self {
-        Self::Alignof => "alignof".fmt(f),
-        Self::Sizeof => "sizeof".fmt(f),
         Self::StaticAssert => "static_assert".fmt(f),
     }
 );
@@ -0,0 +1,248 @@
+//! Implements the `_Generic` selection expressions, like `_Generic(x, int: a,
+//! default: b)`.
+
+extern crate alloc;
+use alloc::vec::IntoIter;
+
+use crate::errors::api::{CompileError, ErrorLocation, Located, Res};
+use crate::lexer::api::{Keyword, Symbol, Token, TokenValue};
+use crate::parser::api::{Visit, VisitMut, Walk};
+use crate::parser::literal::Attribute;
+use crate::parser::modifiers::push::Push as _;
+use crate::parser::parse_content::{ParseAction, parse_block};
+use crate::parser::state::ParsingState;
+use crate::parser::symbols::blocks::attributes::parse_arguments;
+use crate::parser::tree::api::Ast;
+use crate::parser::variable::api::PureType as _;
+use crate::parser::visit::{walk_attributes, walk_attributes_mut};
+use crate::utils::{display, repr_vec};
+
+/// `_Generic` selection expression
+///
+/// The value of the expression is the value of the association whose type
+/// name matches the type of the controlling expression, which is not
+/// evaluated.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Generic {
+    /// Associations between a type name and an expression.
+    pub associations: Vec<GenericAssociation>,
+    /// Expression whose type selects the association.
+    pub controlling: Box<Ast>,
+    /// Location of the entire expression, from `_Generic` to the closing `)`.
+    pub location: ErrorLocation,
+}
+
+display!(Generic, self, f, {
+    write!(f, "_Generic({}", self.controlling)?;
+    for association in &self.associations {
+        write!(f, ", {association}")?;
+    }
+    ")".fmt(f)
+});
+
+impl Walk for Generic {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.controlling);
+        for association in &self.associations {
+            visitor.visit_ast(&association.value);
+            if let Some(type_name) = &association.type_name {
+                walk_attributes(type_name, visitor);
+            }
+            visitor.visit_location(association.location);
+        }
+        visitor.visit_location(self.location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.controlling);
+        for association in &mut self.associations {
+            visitor.visit_ast_mut(&mut association.value);
+            if let Some(type_name) = &mut association.type_name {
+                walk_attributes_mut(type_name, visitor);
+            }
+            visitor.visit_location_mut(&mut association.location);
+        }
+        visitor.visit_location_mut(&mut self.location);
+    }
+}
+
+/// Association of a `_Generic` selection, like `int: a` or `default: b`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct GenericAssociation {
+    /// Location of the association, from the type name to the expression.
+    pub location: ErrorLocation,
+    /// Type name of the association, or `None` for `default`.
+    pub type_name: Option<Vec<Located<Attribute>>>,
+    /// Expression selected when the type name matches.
+    pub value: Ast,
+}
+
+display!(GenericAssociation, self, f, {
+    if let Some(type_name) = &self.type_name {
+        write!(f, "{}: {}", repr_vec(type_name, " "), self.value)
+    } else {
+        write!(f, "default: {}", self.value)
+    }
+});
+
+/// Handler for `_Generic`
+///
+/// Parses the whole selection, until the closing `)`, and pushes it into the
+/// current node.
+pub fn handle_generic(
+    current: &mut Ast,
+    tokens: &mut IntoIter<Token>,
+    location: ErrorLocation,
+) -> Res<ParseAction> {
+    if !tokens
+        .as_slice()
+        .first()
+        .is_some_and(|token| *token.as_value() == TokenValue::Symbol(Symbol::ParenthesisOpen))
+    {
+        return location
+            .crash("Expected '(' after `_Generic`.".to_owned())
+            .into_res();
+    }
+    let parens = tokens.next().expect("checked above").as_location();
+    let (content, closing) = parse_arguments(tokens, parens, "associations of `_Generic`")?;
+    let mut errors = vec![];
+    let mut groups = split_commas(content, parens).into_iter();
+    let (controlling_tokens, controlling_location) = groups.next().expect("at least one group");
+    let controlling = parse_expression(controlling_tokens, controlling_location, &mut errors);
+    let mut associations = vec![];
+    for (group, group_location) in groups {
+        match parse_association(group, group_location, &mut errors) {
+            Ok(association) => associations.push(association),
+            Err(err) => errors.push(err),
+        }
+    }
+    let generic_location = location.into_extended(closing);
+    if associations.is_empty() {
+        errors.push(
+            generic_location.crash("Expected at least one association in `_Generic`.".to_owned()),
+        );
+    }
+    if let Some(second) = associations
+        .iter()
+        .filter(|association| association.type_name.is_none())
+        .nth(1)
+    {
+        errors.push(
+            second
+                .location
+                .fail("Found multiple `default` associations in `_Generic`.".to_owned()),
+        );
+    }
+    let generic =
+        Generic { associations, controlling: controlling.into_box(), location: generic_location };
+    if let Err(err) = current.push_block_as_leaf(Ast::Generic(generic)) {
+        errors.push(location.crash(err));
+    }
+    Res::from((ParseAction::Continue, errors))
+}
+
+/// Parses one association, like `int: a` or `default: b`.
+fn parse_association(
+    mut tokens: Vec<Token>,
+    location: ErrorLocation,
+    errors: &mut Vec<CompileError>,
+) -> Result<GenericAssociation, CompileError> {
+    let extent = tokens_location(&tokens).unwrap_or(location);
+    let Some(colon) = top_level_positions(&tokens, Symbol::Colon)
+        .into_iter()
+        .next()
+    else {
+        return Err(extent.crash(
+            "Expected '<type>: <expression>' in `_Generic` association, but no ':' was found."
+                .to_owned(),
+        ));
+    };
+    let mut value_tokens = tokens.split_off(colon);
+    let colon_location = value_tokens.remove(0).as_location();
+    let value = parse_expression(value_tokens, colon_location, errors);
+    let type_name = if let [token] = tokens.as_slice()
+        && *token.as_value() == TokenValue::Keyword(Keyword::Default)
+    {
+        None
+    } else {
+        let type_location = tokens_location(&tokens).unwrap_or(colon_location);
+        let mut node = parse_expression(tokens, type_location, errors);
+        if let Ast::Variable(var) = &mut node
+            && var.is_pure_type()
+        {
+            var.take_pure_type()
+        } else {
+            return Err(type_location.crash(format!(
+                "Expected type name in `_Generic` association, but found {node}."
+            )));
+        }
+    };
+    Ok(GenericAssociation { location: extent, type_name, value })
+}
+
+/// Parses a list of tokens into an expression.
+///
+/// An empty list of tokens is an error at the given location.
+fn parse_expression(
+    tokens: Vec<Token>,
+    location: ErrorLocation,
+    errors: &mut Vec<CompileError>,
+) -> Ast {
+    if tokens.is_empty() {
+        errors.push(location.crash("Expected expression in `_Generic`.".to_owned()));
+    }
+    let mut node = Ast::Empty;
+    parse_block(&mut tokens.into_iter(), &mut ParsingState::default(), &mut node)
+        .store_errors(&mut |err| errors.push(err));
+    node.fill();
+    node
+}
+
+/// Splits the content of the parenthesis at the commas that are not nested in
+/// a block.
+///
+/// Each group is returned with the location of the delimiter before it.
+fn split_commas(mut tokens: Vec<Token>, parens: ErrorLocation) -> Vec<(Vec<Token>, ErrorLocation)> {
+    let mut groups = vec![];
+    for position in top_level_positions(&tokens, Symbol::Comma)
+        .into_iter()
+        .rev()
+    {
+        let mut group = tokens.split_off(position);
+        let comma = group.remove(0).as_location();
+        groups.push((group, comma));
+    }
+    groups.push((tokens, parens));
+    groups.reverse();
+    groups
+}
+
+/// Returns the location that spans all the tokens, if there are any.
+fn tokens_location(tokens: &[Token]) -> Option<ErrorLocation> {
+    let first = tokens.first()?.as_location();
+    Some(first.into_extended(tokens.last()?.as_location()))
+}
+
+/// Returns the positions of the given symbol in the tokens, when it is not
+/// nested in parenthesis, brackets or braces.
+fn top_level_positions(tokens: &[Token], symbol: Symbol) -> Vec<usize> {
+    let mut depth = 0u32;
+    let mut positions = vec![];
+    for (position, token) in tokens.iter().enumerate() {
+        if let TokenValue::Symbol(found) = token.as_value() {
+            if matches!(found, Symbol::ParenthesisOpen | Symbol::BracketOpen | Symbol::BraceOpen) {
+                depth = depth.saturating_add(1);
+            } else if matches!(
+                found,
+                Symbol::ParenthesisClose | Symbol::BracketClose | Symbol::BraceClose
+            ) {
+                depth = depth.saturating_sub(1);
+            } else if *found == symbol && depth == 0 {
+                positions.push(position);
+            }
+        }
+    }
+    positions
+}
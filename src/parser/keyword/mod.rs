@@ -4,15 +4,17 @@
 pub mod attributes;
 pub mod control_flow;
 pub mod functions;
+pub mod generic;
 pub mod sort;
 
 use control_flow::pushable::PushableKeyword;
-use sort::{Context, KeywordParsing, PushInNode as _};
+use sort::{Context, KeywordParsing, PushInNode};
 
 use super::parse_content::ParseAction;
 use super::state::ParsingState;
 use crate::errors::api::{ErrorLocation, Res};
 use crate::lexer::api::Keyword;
+use crate::parser::modifiers::push::Push as _;
 use crate::parser::symbols::api::BracedBlock;
 use crate::parser::tree::Ast;
 use crate::parser::tree::api::AstPushContext;
@@ -33,6 +35,13 @@ pub fn handle_keyword(
     };
     let parsed_keyword: KeywordParsing =
         KeywordParsing::try_from((keyword, ctx)).map_err(|msg| keyword_location.crash(msg))?;
+    if let KeywordParsing::Operator(op) = parsed_keyword {
+        // pushed like the operator symbols, to be found after a cast
+        current
+            .push_op(keyword_location.wrap(op))
+            .map_err(|msg| keyword_location.crash(msg))?;
+        return Res::ok(ParseAction::Continue);
+    }
     let ast_push_ctx = match parsed_keyword {
         KeywordParsing::Attr(_) => AstPushContext::UserVariable,
         KeywordParsing::Pushable(PushableKeyword::Else) => AstPushContext::Else,
@@ -41,34 +50,55 @@ pub fn handle_keyword(
         | KeywordParsing::Func(_)
         | KeywordParsing::Null
         | KeywordParsing::True => AstPushContext::None,
+        KeywordParsing::Operator(_) => unreachable!("pushed above"),
+    };
+    push_in_statement(
+        keyword_location.wrap(parsed_keyword),
+        current,
+        ast_push_ctx,
+        keyword_location,
+        "keyword",
+    )
+}
+
+/// Pushes a keyword or an attribute into an [`Ast`].
+///
+/// If the [`Ast`] can't receive it, the keyword or attribute starts a new
+/// statement in the current block.
+pub fn push_in_statement<T: PushInNode>(
+    value: T,
+    current: &mut Ast,
+    ast_push_ctx: AstPushContext,
+    location: ErrorLocation,
+    kind: &str,
+) -> Res<ParseAction> {
+    let invalid = || {
+        location
+            .crash(format!("Invalid {kind} in current context. Perhaps a missing ';'"))
+            .into_res()
     };
-    let located_keyword = keyword_location.wrap(parsed_keyword);
     if current.can_push_leaf_with_ctx(ast_push_ctx) {
-        located_keyword
+        value
             .push_in_node(current)
-            .map_err(|msg| keyword_location.crash(msg))?;
+            .map_err(|msg| location.crash(msg))?;
     } else if let Ast::BracedBlock(BracedBlock { elts, full: false, .. }) = current {
         match elts.last_mut() {
             Some(last) if last.is_empty() => {
-                located_keyword
+                value
                     .push_in_node(last)
-                    .map_err(|msg| keyword_location.crash(msg))?;
+                    .map_err(|msg| location.crash(msg))?;
             }
             Some(Ast::BracedBlock(_) | Ast::ControlFlow(_)) | None => {
                 let mut new = Ast::Empty;
-                located_keyword
+                value
                     .push_in_node(&mut new)
-                    .map_err(|msg| keyword_location.crash(msg))?;
+                    .map_err(|msg| location.crash(msg))?;
                 elts.push(new);
             }
-            Some(_) => {
-                return keyword_location
-                    .crash("Invalid keyword in current context. Perhaps a missing ';'".to_owned())
-                    .into_res();
-            }
+            Some(_) => return invalid(),
         }
     } else {
-        unreachable!("trying to push {:?} in {current}", located_keyword.into_inner().0)
+        return invalid();
     }
     Res::ok(ParseAction::Continue)
 }
@@ -10,6 +10,7 @@ use crate::errors::api::Located;
 use crate::lexer::api::Keyword;
 use crate::parser::literal::Literal;
 use crate::parser::modifiers::push::Push as _;
+use crate::parser::operators::api::UnaryOperator;
 use crate::parser::symbols::api::BracedBlock;
 use crate::parser::tree::Ast;
 
@@ -74,9 +75,11 @@ impl From<&Ast> for Context {
             | Ast::Binary(_)
             | Ast::Ternary(_)
             | Ast::Variable(_)
+            | Ast::Generic(_)
             | Ast::ParensBlock(_)
             | Ast::FunctionCall(_)
             | Ast::ListInitialiser(_)
+            | Ast::CompoundLiteral(_)
             | Ast::BracedBlock(BracedBlock { full: true, .. }) => Self::default(),
             Ast::FunctionArgsBuild(elts, ..)
             | Ast::BracedBlock(BracedBlock { elts, full: false, .. }) =>
@@ -103,10 +106,12 @@ pub enum KeywordParsing {
     CtrlFlow(CtrlFlow),
     /// Boolean constant `false`
     False,
-    /// Function keyword: `sizeof`, `static_assert`, ...
+    /// Function keyword: `static_assert`
     Func(Func),
     /// `NULL` constant
     Null,
+    /// Operator keyword: `sizeof` and `alignof`
+    Operator(UnaryOperator),
     /// Keywords that need to be pushed in an existing control flow block
     Pushable(PushableKeyword),
     /// Boolean constant `true`
@@ -121,6 +126,7 @@ impl PushInNode for Located<KeywordParsing> {
             KeywordParsing::Attr(attr) => loc.wrap(attr).push_in_node(node),
             KeywordParsing::CtrlFlow(ctrl) => loc.wrap(ctrl).push_in_node(node),
             KeywordParsing::Null => node.push_block_as_leaf(Ast::Leaf(loc.wrap(Literal::Null))),
+            KeywordParsing::Operator(_) => unreachable!("pushed as an operator"),
             KeywordParsing::True =>
                 node.push_block_as_leaf(Ast::Leaf(loc.wrap(Literal::ConstantBool(true)))),
             KeywordParsing::False =>
@@ -138,9 +144,10 @@ impl TryFrom<(Keyword, Context)> for KeywordParsing {
             Keyword::True => Self::True,
             Keyword::False => Self::False,
             Keyword::Nullptr => Self::Null,
+            // operators
+            Keyword::Sizeof => Self::Operator(UnaryOperator::Sizeof),
+            Keyword::Alignof => Self::Operator(UnaryOperator::Alignof),
             // functions
-            Keyword::Sizeof => Self::Func(Func::Sizeof),
-            Keyword::Alignof => Self::Func(Func::Alignof),
             Keyword::StaticAssert => Self::Func(Func::StaticAssert),
             // pushable
             Keyword::Case => Self::CtrlFlow(CtrlFlow::Case),
@@ -157,6 +164,8 @@ impl TryFrom<(Keyword, Context)> for KeywordParsing {
             // conditionals
             Keyword::If => Self::CtrlFlow(CtrlFlow::If),
             Keyword::Else => return Err("Found nomad `else` without `if`.".to_owned()),
+            // selections
+            Keyword::Generic => unreachable!("`_Generic` is parsed with its associations"),
             // user-defined types
             Keyword::Enum if context == Context::Typedef => Self::CtrlFlow(CtrlFlow::Enum),
             Keyword::Union if context == Context::Typedef => Self::CtrlFlow(CtrlFlow::Union),
@@ -187,7 +196,6 @@ impl TryFrom<(Keyword, Context)> for KeywordParsing {
             Keyword::Restrict => Self::Attr(Attr::from(UnsortedAttr::Restrict)),
             Keyword::Volatile => Self::Attr(Attr::from(UnsortedAttr::Volatile)),
             Keyword::Complex => Self::Attr(Attr::from(UnsortedAttr::Complex)),
-            Keyword::Generic => Self::Attr(Attr::from(UnsortedAttr::Generic)),
             Keyword::Noreturn => Self::Attr(Attr::from(UnsortedAttr::Noreturn)),
             Keyword::Constexpr => Self::Attr(Attr::from(UnsortedAttr::Constexpr)),
             Keyword::Decimal64 => Self::Attr(Attr::from(UnsortedAttr::Decimal64)),
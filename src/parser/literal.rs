@@ -4,16 +4,20 @@ use core::hash::{Hash, Hasher};
 use core::mem::discriminant;
 
 use crate::Number;
+use crate::lexer::api::{Encoding, Token, TokenValue};
 use crate::parser::keyword::attributes::AttributeKeyword;
 use crate::utils::display;
 
 /// Attribute of a variable
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum Attribute {
     /// Represents the `*` attribute
     Indirection,
     /// Keyword attribute, like `const` or `int`
     Keyword(AttributeKeyword),
+    /// Standard attribute, like `[[nodiscard]]` or `[[gnu::pure]]`
+    Standard(StandardAttribute),
     /// User-defined attribute, like a user defined type
     User(String),
 }
@@ -25,23 +29,80 @@ display!(
     match self {
         Self::Indirection => '*'.fmt(f),
         Self::Keyword(keyword) => keyword.fmt(f),
+        Self::Standard(attr) => attr.fmt(f),
         Self::User(val) => val.fmt(f),
     }
 );
 
+/// Attribute written in an attribute specifier, like `[[deprecated("msg")]]`.
+///
+/// The leading and trailing double underscores of the names are removed, as
+/// `[[__nodiscard__]]` is the same attribute as `[[nodiscard]]`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct StandardAttribute {
+    /// Tokens between the parenthesis that follow the name, if any.
+    ///
+    /// The parenthesis, brackets and braces of the tokens are balanced.
+    pub arguments: Option<Vec<Token>>,
+    /// Name of the attribute, like `deprecated`
+    pub name: String,
+    /// Vendor prefix of the attribute, like `gnu` in `[[gnu::pure]]`
+    pub prefix: Option<String>,
+}
+
+impl StandardAttribute {
+    /// Returns the string constant given as argument, like the message of
+    /// `[[deprecated("msg")]]`.
+    #[must_use]
+    pub fn as_message(&self) -> Option<&str> {
+        if let Some([token]) = self.arguments.as_deref()
+            && let TokenValue::Str(msg, _) = token.as_value()
+        {
+            Some(msg)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if this is the standard attribute of the given name, without
+    /// vendor prefix.
+    #[must_use]
+    pub fn is(&self, name: &str) -> bool {
+        self.prefix.is_none() && self.name == name
+    }
+}
+
+display!(StandardAttribute, self, f, {
+    "[[".fmt(f)?;
+    if let Some(prefix) = &self.prefix {
+        write!(f, "{prefix}::")?;
+    }
+    self.name.fmt(f)?;
+    if let Some(arguments) = &self.arguments {
+        let args = arguments
+            .iter()
+            .map(|token| token.as_value().spelling())
+            .collect::<Vec<_>>();
+        write!(f, "({})", args.join(" "))?;
+    }
+    "]]".fmt(f)
+});
+
 /// Literal
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum Literal {
-    /// Char
-    Char(char),
+    /// Char, with the encoding of its prefix
+    Char(char, Encoding),
     /// Boolean constant: `true` or `false`
     ConstantBool(bool),
     /// `NULL` constant
     Null,
     /// Number constant
     Number(Number),
-    /// String constant
-    Str(String),
+    /// String constant, with the encoding of its prefix
+    Str(String, Encoding),
 }
 
 impl Eq for Literal {}
@@ -58,8 +119,8 @@ display!(
     f,
     match self {
         Self::Null => "NULL".fmt(f),
-        Self::Char(val) => write!(f, "'{val}'"),
-        Self::Str(val) => write!(f, "\"{val}\""),
+        Self::Char(val, encoding) => write!(f, "{encoding}'{val}'"),
+        Self::Str(val, encoding) => write!(f, "{encoding}\"{val}\""),
         Self::Number(val) => val.fmt(f),
         Self::ConstantBool(val) => val.fmt(f),
     }
@@ -9,18 +9,45 @@ pub mod api {
 
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
-    pub use super::literal::{Attribute, Literal};
+    pub use super::literal::{Attribute, Literal, StandardAttribute};
     pub use super::operators::api::{Binary, BinaryOperator, Ternary, Unary, UnaryOperator};
     pub use super::parse_content::parse;
-    pub use super::symbols::api::{BracedBlock, FunctionCall};
+    pub use super::reparse::reparse;
+    pub use super::symbols::api::{
+        BracedBlock, Cast, CompoundLiteral, FunctionCall, ListInitialiser, ParensBlock
+    };
     pub use super::tree::Ast;
+    pub use super::variable::Variable;
     pub use super::variable::api::{
-        AttributeVariable, Declaration, DeclarationValue, VariableName, VariableValue
+        ArrayDeclarator, ArraySize, AttributeVariable, Declaration, DeclarationValue, ParameterList, ParensDeclarator, VariableName, VariableValue
     };
+    pub use super::visit::Walk;
+    pub use super::visit::visitor::Visit;
+    pub use super::visit::visitor_mut::VisitMut;
     pub use crate::parser::keyword::attributes::{
         AttributeKeyword, BasicDataType, Modifiers, Qualifiers, SpecialAttributes, Storage, UserDefinedTypes
     };
     pub use crate::parser::keyword::control_flow::node::ControlFlowNode;
+    pub use crate::parser::keyword::control_flow::types::case::AstColonAstCtrl;
+    pub use crate::parser::keyword::control_flow::types::colon_ast::{
+        ColonAstCtrl, ColonAstKeyword
+    };
+    pub use crate::parser::keyword::control_flow::types::conditional::ConditionCtrl;
+    pub use crate::parser::keyword::control_flow::types::do_while::DoWhileCtrl;
+    pub use crate::parser::keyword::control_flow::types::goto::ColonIdentCtrl;
+    pub use crate::parser::keyword::control_flow::types::ident_block::{
+        IdentBlockCtrl, IdentBlockKeyword
+    };
+    pub use crate::parser::keyword::control_flow::types::parens_block::{
+        ParensBlockCtrl, ParensBlockKeyword
+    };
+    pub use crate::parser::keyword::control_flow::types::return_ctrl::ReturnCtrl;
+    pub use crate::parser::keyword::control_flow::types::semi_colon::{
+        SemiColonCtrl, SemiColonKeyword
+    };
+    pub use crate::parser::keyword::control_flow::types::typedef::{TypedefContent, TypedefCtrl};
+    pub use crate::parser::keyword::functions::FunctionKeyword;
+    pub use crate::parser::keyword::generic::{Generic, GenericAssociation};
 }
 
 mod keyword;
@@ -28,7 +55,9 @@ mod literal;
 mod modifiers;
 mod operators;
 mod parse_content;
+mod reparse;
 mod state;
 mod symbols;
 mod tree;
 mod variable;
+mod visit;
@@ -1,13 +1,17 @@
 //! Module that modifies [`FunctionCall`] within an existing node.
 
 use core::convert::Infallible;
+use core::mem::take;
 use core::ops::{ControlFlow, FromResidual, Residual, Try};
 
-use crate::errors::api::ErrorLocation;
+use crate::parser::api::{ControlFlowNode, ParameterList};
 use crate::parser::keyword::control_flow::traits::ControlFlow as _;
-use crate::parser::operators::api::{Binary, Ternary, Unary};
-use crate::parser::symbols::api::{BracedBlock, Cast, FunctionCall, ListInitialiser};
+use crate::parser::operators::api::{Binary, BinaryOperator, Ternary, Unary};
+use crate::parser::symbols::api::{
+    BracedBlock, Cast, CompoundLiteral, FunctionCall, ListInitialiser
+};
 use crate::parser::tree::Ast;
+use crate::parser::variable::api::PureType as _;
 
 /// Result of call to `[MakeFunction::can_make_function]`, indicated whether a
 /// function can be created from a `(` and a pending variable, and if so, at
@@ -24,6 +28,10 @@ pub enum CanMakeFnRes {
     ///
     /// No pending variable was found.
     None,
+    /// No function can be made, even from an enclosing variable.
+    ///
+    /// The parenthesis is an operand, like in `int x = -(1)`.
+    Operand,
     /// An error occurred whilst incrementing the depth.
     ///
     /// This means that the user has more than 2**32 nested variables.
@@ -41,6 +49,7 @@ impl CanMakeFnRes {
                 None => Self::TooDeep,
             },
             Self::None => Self::CanMakeFn(0),
+            Self::Operand => Self::Operand,
             Self::TooDeep => Self::TooDeep,
         }
     }
@@ -89,21 +98,63 @@ pub trait MakeFunction {
 
     /// Makes a function out of the variable found in
     /// [`Self::can_make_function`].
-    fn make_function(&mut self, depth: u32, arguments: Vec<Ast>, parens_location: ErrorLocation);
+    ///
+    /// If the variable is a declaration waiting for its declarator, like in
+    /// `int (*cb)(int)`, the parenthesis are pushed in the declaration instead.
+    fn make_function(&mut self, depth: u32, arguments: ParameterList) -> Result<(), String>;
+}
+
+impl Ast {
+    /// Checks if the node is an expression that designates a function as a
+    /// whole, like `(*cb)`, `s.cb` or `table[i]`, instead of a node whose last
+    /// operand is to be called.
+    fn is_callee(&self) -> bool {
+        match self {
+            Self::Binary(Binary { op, arg_r, .. }) =>
+                matches!(
+                    op.as_value(),
+                    BinaryOperator::ArraySubscript
+                        | BinaryOperator::StructEnumMemberAccess
+                        | BinaryOperator::StructEnumMemberPointerAccess
+                ) && !arg_r.is_empty(),
+            Self::FunctionCall(call) => call.function_body.is_none(),
+            Self::Generic(_) => true,
+            Self::ParensBlock(parens) => !parens.is_pure_type(),
+            Self::BracedBlock(_)
+            | Self::Cast(_)
+            | Self::CompoundLiteral(_)
+            | Self::ControlFlow(_)
+            | Self::Empty
+            | Self::FunctionArgsBuild(..)
+            | Self::Leaf(_)
+            | Self::ListInitialiser(_)
+            | Self::Ternary(_)
+            | Self::Unary(_)
+            | Self::Variable(_) => false,
+        }
+    }
 }
 
 impl MakeFunction for Ast {
     fn can_make_function(&self) -> CanMakeFnRes {
         #[cfg(feature = "debug")]
         crate::lgp!("Can make function out of {self}");
+        if self.is_callee() {
+            return CanMakeFnRes::CanMakeFn(0);
+        }
         match self {
             Self::Variable(variable) => variable.can_make_function().increment_or_default(),
-            Self::Empty
-            | Self::Leaf(_)
+            Self::Empty => CanMakeFnRes::Operand,
+            Self::Leaf(_)
+            | Self::Generic(_)
             | Self::ParensBlock(_)
             | Self::BracedBlock(BracedBlock { full: true, .. })
             | Self::FunctionCall(_)
-            | Self::ListInitialiser(ListInitialiser { full: true, .. }) => CanMakeFnRes::None,
+            | Self::ListInitialiser(ListInitialiser { full: true, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: true, .. },
+                ..
+            }) => CanMakeFnRes::None,
             Self::Cast(Cast { value: child, .. })
             | Self::Unary(Unary { arg: child, .. })
             | Self::Binary(Binary { arg_r: child, .. })
@@ -111,50 +162,79 @@ impl MakeFunction for Ast {
                 Ternary { failure: Some((_, child)), .. }
                 | Ternary { failure: None, success: child, .. },
             ) => child.can_make_function(),
-            Self::FunctionArgsBuild(vec, ..)
-            | Self::ListInitialiser(ListInitialiser { elts: vec, .. })
-            | Self::BracedBlock(BracedBlock { elts: vec, .. }) => vec.last()?.can_make_function(),
+            // first element of a list, like `(1)` in `{(1)}`
+            Self::ListInitialiser(ListInitialiser { elts: vec, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { elts: vec, .. },
+                ..
+            }) => vec
+                .last()
+                .map_or(CanMakeFnRes::Operand, Self::can_make_function),
+            Self::FunctionArgsBuild(vec, ..) | Self::BracedBlock(BracedBlock { elts: vec, .. }) =>
+                vec.last()?.can_make_function(),
+            Self::ControlFlow(ControlFlowNode::Typedef(typedef)) if typedef.as_type().is_some() =>
+                CanMakeFnRes::CanMakeFn(0),
             Self::ControlFlow(ctrl) => ctrl.as_ast()?.can_make_function(),
         }
     }
 
-    fn make_function(&mut self, depth: u32, arguments: Vec<Self>, parens_location: ErrorLocation) {
+    fn make_function(&mut self, depth: u32, arguments: ParameterList) -> Result<(), String> {
         #[cfg(feature = "debug")]
         crate::lgp!("Making function out of {self}");
+        if depth == 0 && self.is_callee() {
+            *self = Self::FunctionCall(FunctionCall::new(take(self), arguments));
+            return Ok(());
+        }
         match self {
-            Self::Variable(var) => match depth.checked_sub(1) {
-                Some(new_depth) => var.make_function(new_depth, arguments, parens_location),
-                None =>
-                    *self = Self::FunctionCall(FunctionCall {
-                        arguments,
-                        variable: var.take(),
-                        function_body: None,
-                        parens_location,
-                    }),
-            },
+            Self::Variable(var) => {
+                if let Some(new_depth) = depth.checked_sub(1) {
+                    return var.make_function(new_depth, arguments);
+                }
+                if let Some(attr_var) = var.as_attribute_variable_mut()
+                    && attr_var.expects_parens_declarator()
+                {
+                    let ParameterList { location, parameters, variadic } = arguments;
+                    return attr_var.push_parens_declarator(parameters, variadic, location);
+                }
+                *self = Self::FunctionCall(FunctionCall::new(take(self), arguments));
+                Ok(())
+            }
             Self::Empty
             | Self::Leaf(_)
+            | Self::Generic(_)
             | Self::ParensBlock(_)
             | Self::BracedBlock(BracedBlock { full: true, .. })
             | Self::FunctionCall(_)
-            | Self::ListInitialiser(ListInitialiser { full: true, .. }) =>
-                unreachable!("can_make_function checked"),
+            | Self::ListInitialiser(ListInitialiser { full: true, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: true, .. },
+                ..
+            }) => unreachable!("can_make_function checked"),
             Self::Cast(Cast { value: child, .. })
             | Self::Unary(Unary { arg: child, .. })
             | Self::Binary(Binary { arg_r: child, .. })
             | Self::Ternary(
                 Ternary { failure: Some((_, child)), .. } | Ternary { success: child, .. },
-            ) => child.make_function(depth, arguments, parens_location),
+            ) => child.make_function(depth, arguments),
             Self::FunctionArgsBuild(vec, ..)
             | Self::ListInitialiser(ListInitialiser { elts: vec, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { elts: vec, .. },
+                ..
+            })
             | Self::BracedBlock(BracedBlock { elts: vec, .. }) => vec
                 .last_mut()
                 .expect("can_make_function checked")
-                .make_function(depth, arguments, parens_location),
+                .make_function(depth, arguments),
+            Self::ControlFlow(ControlFlowNode::Typedef(typedef)) if typedef.as_type().is_some() =>
+                typedef
+                    .as_type_mut()
+                    .expect("checked by guard")
+                    .push_typedef_parameters(arguments),
             Self::ControlFlow(ctrl) => ctrl
                 .as_ast_mut()
                 .expect("can_make_function_checked")
-                .make_function(depth, arguments, parens_location),
+                .make_function(depth, arguments),
         }
     }
 }
@@ -1,10 +1,11 @@
 //! Module that modifies [`ListInitialiser`] within an existing node.
 
 use crate::errors::api::ErrorLocation;
+use crate::parser::keyword::control_flow::traits::ControlFlow as _;
 use crate::parser::operators::api::{Binary, BinaryOperator, Ternary, Unary};
-use crate::parser::symbols::api::{BracedBlock, Cast, ListInitialiser};
+use crate::parser::symbols::api::{BracedBlock, Cast, CompoundLiteral, ListInitialiser};
 use crate::parser::tree::Ast;
-use crate::parser::variable::api::PureType as _;
+use crate::parser::variable::api::AttributeVariable;
 
 /// Applies a closure to the current [`ListInitialiser`].
 ///
@@ -21,7 +22,11 @@ where
     #[cfg(feature = "debug")]
     crate::lgp!("Searching for list initialiser in {ast}");
     match ast {
-        Ast::ListInitialiser(ListInitialiser { elts, full: full @ false, location }) => {
+        Ast::ListInitialiser(ListInitialiser { elts, full: full @ false, location })
+        | Ast::CompoundLiteral(CompoundLiteral {
+            value: ListInitialiser { elts, full: full @ false, location },
+            ..
+        }) => {
             if let Some(last) = elts.last_mut()
                 && let res @ Some(_) = apply_to_last_list_initialiser(last, visitor)
             {
@@ -37,14 +42,21 @@ where
             } else {
                 apply_to_last_list_initialiser(&mut cast.value, visitor)
             },
+        Ast::Variable(var) if !var.is_full() => apply_to_last_list_initialiser(
+            var.as_attribute_variable_mut()?.last_value_mut()?,
+            visitor,
+        ),
+        // declarators after a definition, like `struct A {int x;} a = {1}`
+        Ast::ControlFlow(ctrl) => apply_to_last_list_initialiser(ctrl.as_ast_mut()?, visitor),
         Ast::Empty
         | Ast::Leaf(_)
         | Ast::Variable(_)
-        | Ast::ControlFlow(_)
+        | Ast::Generic(_)
         | Ast::ParensBlock(_)
         | Ast::FunctionCall(_)
         | Ast::BracedBlock(BracedBlock { full: true, .. })
-        | Ast::ListInitialiser(ListInitialiser { full: true, .. }) => None,
+        | Ast::ListInitialiser(ListInitialiser { full: true, .. })
+        | Ast::CompoundLiteral(_) => None,
         Ast::Unary(Unary { arg, .. })
         | Ast::Binary(Binary { arg_r: arg, .. })
         | Ast::Ternary(Ternary { failure: Some((_, arg)), .. } | Ternary { success: arg, .. }) =>
@@ -57,6 +69,12 @@ where
     }
 }
 
+/// Checks if the last node of an [`Ast`] is inside an initialiser list that
+/// isn't closed yet, like in `x + (int[]){1`.
+pub fn is_in_list_initialiser(ast: &mut Ast) -> bool {
+    apply_to_last_list_initialiser(ast, &|_, _, _| ()).is_some()
+}
+
 /// Checks if a `{` is meant as a [`ListInitialiser`] or as a [`BracedBlock`].
 ///
 /// # Returns
@@ -71,20 +89,34 @@ pub fn can_push_list_initialiser(ast: &mut Ast) -> Result<bool, String> {
     crate::lgp!("Can push list initialiser in {ast}");
     match ast {
         Ast::Empty => Ok(true),
+        Ast::Variable(var) if !var.is_full() => var
+            .as_attribute_variable_mut()
+            .and_then(AttributeVariable::last_value_mut)
+            .map_or(Ok(false), can_push_list_initialiser),
+        // an empty control flow expects a block, like `if (x) {`
+        Ast::ControlFlow(ctrl) => ctrl
+            .as_ast_mut()
+            .filter(|node| !node.is_empty())
+            .map_or(Ok(false), can_push_list_initialiser),
         Ast::Leaf(_)
         | Ast::Variable(_)
-        | Ast::ControlFlow(_)
         | Ast::BracedBlock(BracedBlock { full: true, .. })
         | Ast::ListInitialiser(ListInitialiser { full: true, .. })
-        | Ast::FunctionCall(_) => Ok(false),
-        Ast::ParensBlock(parens) => Ok(parens.is_pure_type()),
+        | Ast::CompoundLiteral(CompoundLiteral {
+            value: ListInitialiser { full: true, .. }, ..
+        })
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_) => Ok(false),
+        Ast::ParensBlock(parens) => Ok(parens.type_name().is_some()),
         Ast::Binary(Binary { op, arg_r, .. })
             if (*arg_r).is_empty()
                 && matches!(op.as_value(), BinaryOperator::Assign | BinaryOperator::Comma) =>
             Ok(true),
         Ast::ListInitialiser(ListInitialiser { full: false, elts: vec, .. })
-            if vec.last().is_none_or(Ast::is_empty) =>
-            Ok(true),
+        | Ast::CompoundLiteral(CompoundLiteral {
+            value: ListInitialiser { full: false, elts: vec, .. },
+            ..
+        }) if vec.last().is_none_or(Ast::is_empty) => Ok(true),
         Ast::BracedBlock(BracedBlock { elts, .. }) if elts.last().is_none_or(Ast::is_empty) =>
             Ok(false),
         Ast::Cast(Cast { full, value, .. }) =>
@@ -103,7 +135,10 @@ pub fn can_push_list_initialiser(ast: &mut Ast) -> Result<bool, String> {
             can_push_list_initialiser(arg),
         Ast::FunctionArgsBuild(vec, ..)
         | Ast::BracedBlock(BracedBlock { elts: vec, full: false, .. })
-        | Ast::ListInitialiser(ListInitialiser { elts: vec, full: false, .. }) =>
-            vec.last_mut().map_or(Ok(false), can_push_list_initialiser),
+        | Ast::ListInitialiser(ListInitialiser { elts: vec, full: false, .. })
+        | Ast::CompoundLiteral(CompoundLiteral {
+            value: ListInitialiser { elts: vec, full: false, .. },
+            ..
+        }) => vec.last_mut().map_or(Ok(false), can_push_list_initialiser),
     }
 }
@@ -30,10 +30,12 @@ fn has_attributes(current: &Ast) -> bool {
         | Ast::Cast(_)
         | Ast::Leaf(_)
         | Ast::BracedBlock(_)
+        | Ast::Generic(_)
         | Ast::ParensBlock(_)
         | Ast::ControlFlow(_)
         | Ast::FunctionCall(_)
         | Ast::ListInitialiser(_)
+        | Ast::CompoundLiteral(_)
         | Ast::FunctionArgsBuild(..) => false,
         Ast::Binary(Binary { arg_l, arg_r, .. }) => has_attributes(arg_l) || has_attributes(arg_r),
         Ast::Ternary(Ternary { condition, failure, success }) =>
@@ -96,7 +98,7 @@ fn make_lhs_aux(current: &mut Ast, push_indirection: Option<ErrorLocation>) -> R
                 *current = mem::take(arg);
                 Ok(())
             } else {
-                unreachable!("operator not rejected but illegal")
+                make_error(&op.as_value().to_string())
             },
         Ast::Binary(Binary { op, arg_l, arg_r }) =>
             if let Some(loc) = op.as_star() {
@@ -113,20 +115,20 @@ fn make_lhs_aux(current: &mut Ast, push_indirection: Option<ErrorLocation>) -> R
             } else if op.is_array_subscript() {
                 make_lhs_aux(arg_l, push_indirection)
             } else {
-                unreachable!("operator not rejected but illegal")
+                make_error(&op.as_value().to_string())
             },
         Ast::Empty
         | Ast::Cast(_)
         | Ast::Leaf(_)
         | Ast::BracedBlock(_)
+        | Ast::Generic(_)
         | Ast::ParensBlock(_)
         | Ast::ControlFlow(_)
         | Ast::FunctionCall(_)
         | Ast::ListInitialiser(_)
-        | Ast::FunctionArgsBuild(..) => unreachable!("lhs check returned false"),
-        Ast::Ternary(_) => {
-            unreachable!("operator not rejected but illegal")
-        }
+        | Ast::CompoundLiteral(_)
+        | Ast::FunctionArgsBuild(..)
+        | Ast::Ternary(_) => make_error(&current.to_string()),
     }
 }
 
@@ -149,9 +151,11 @@ pub fn try_apply_comma_to_variable(current: &mut Ast) -> Result<bool, String> {
         | Ast::Unary(_)
         | Ast::Binary(_)
         | Ast::Ternary(_)
+        | Ast::Generic(_)
         | Ast::ParensBlock(_)
         | Ast::FunctionCall(_)
         | Ast::ListInitialiser(_)
+        | Ast::CompoundLiteral(_)
         | Ast::FunctionArgsBuild(..)
         | Ast::BracedBlock(BracedBlock { full: true, .. }) => Ok(false),
     }
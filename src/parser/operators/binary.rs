@@ -36,6 +36,16 @@ macro_rules! define_binary_operator {
                 *self.as_value() == BinaryOperator::Assign
             }
 
+            fn has_unary_form(&self) -> bool {
+                matches!(
+                    self.as_value(),
+                    BinaryOperator::Add
+                        | BinaryOperator::BitwiseAnd
+                        | BinaryOperator::Multiply
+                        | BinaryOperator::Subtract
+                )
+            }
+
             fn as_star(&self) -> Option<ErrorLocation> {
                 if *self.as_value() == BinaryOperator::Multiply {
                     Some(self.as_location())
@@ -64,6 +74,7 @@ macro_rules! define_binary_operator {
 
 /// Binary node of the [`Ast`]
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Binary {
     /// Operator
     pub op: Located<BinaryOperator>,
@@ -73,11 +84,75 @@ pub struct Binary {
     pub arg_r: Box<Ast>,
 }
 
+impl BinaryOperator {
+    /// Returns the operation applied by a compound assignment, like `+` for
+    /// `+=`, or `None` if the operator isn't a compound assignment.
+    pub const fn compound_operation(self) -> Option<Self> {
+        match self {
+            Self::AddAssign => Some(Self::Add),
+            Self::AndAssign => Some(Self::BitwiseAnd),
+            Self::DivAssign => Some(Self::Divide),
+            Self::ModAssign => Some(Self::Modulo),
+            Self::MulAssign => Some(Self::Multiply),
+            Self::OrAssign => Some(Self::BitwiseOr),
+            Self::ShiftLeftAssign => Some(Self::ShiftLeft),
+            Self::ShiftRightAssign => Some(Self::ShiftRight),
+            Self::SubAssign => Some(Self::Subtract),
+            Self::XorAssign => Some(Self::BitwiseXor),
+            Self::Add
+            | Self::ArraySubscript
+            | Self::Assign
+            | Self::BitwiseAnd
+            | Self::BitwiseOr
+            | Self::BitwiseXor
+            | Self::Comma
+            | Self::Different
+            | Self::Divide
+            | Self::Equal
+            | Self::Ge
+            | Self::Gt
+            | Self::Le
+            | Self::LogicalAnd
+            | Self::LogicalOr
+            | Self::Lt
+            | Self::Modulo
+            | Self::Multiply
+            | Self::ShiftLeft
+            | Self::ShiftRight
+            | Self::StructEnumMemberAccess
+            | Self::StructEnumMemberPointerAccess
+            | Self::Subtract => None,
+        }
+    }
+
+    /// Checks if the operator assigns a new value to its left operand.
+    pub const fn is_assignment(self) -> bool {
+        matches!(
+            self,
+            Self::Assign
+                | Self::AddAssign
+                | Self::SubAssign
+                | Self::MulAssign
+                | Self::DivAssign
+                | Self::ModAssign
+                | Self::ShiftLeftAssign
+                | Self::ShiftRightAssign
+                | Self::AndAssign
+                | Self::XorAssign
+                | Self::OrAssign
+        )
+    }
+}
+
 display!(
     Binary,
     self,
     f,
-    if self.op.is_array_subscript() {
+    if self.arg_l.is_empty() && *self.op.as_value() == BinaryOperator::StructEnumMemberAccess {
+        write!(f, "(.{})", self.arg_r)
+    } else if self.arg_l.is_empty() && self.op.is_array_subscript() {
+        write!(f, "([{}])", self.arg_r)
+    } else if self.op.is_array_subscript() {
         if self.arg_r.is_empty() {
             write!(f, "({}[])", self.arg_l)
         } else {
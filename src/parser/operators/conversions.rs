@@ -7,11 +7,26 @@ use super::ternary::TernaryOperator;
 use crate::errors::api::Located;
 use crate::parser::modifiers::make_lhs::make_lhs;
 use crate::parser::operators::api::{
-    Binary, BinaryOperator, Operator, Ternary, Unary, UnaryOperator
+    Associativity, Binary, BinaryOperator, Operator, Ternary, Unary, UnaryOperator
 };
 use crate::parser::tree::api::Ast;
 
 impl OperatorConversions for Located<BinaryOperator> {
+    fn try_to_designator(self) -> Result<Ast, String> {
+        if matches!(
+            self.as_value(),
+            BinaryOperator::ArraySubscript | BinaryOperator::StructEnumMemberAccess
+        ) {
+            Ok(Ast::Binary(Binary {
+                op: self,
+                arg_l: Ast::empty_box(),
+                arg_r: Ast::empty_box(),
+            }))
+        } else {
+            self.try_to_node()
+        }
+    }
+
     fn try_to_node(self) -> Result<Ast, String> {
         Err(format!("Tried to call binary operator {self} on without a left argument."))
     }
@@ -53,6 +68,13 @@ where
         Ok(())
     }
 
+    /// Makes a node from an operator at the beginning of an element of a list
+    /// initialiser, where `[` and `.` start a designator, like `[2]` or `.x`
+    /// in `{[2] = 1, .x = 2}`.
+    fn try_to_designator(self) -> Result<Ast, String> {
+        self.try_to_node()
+    }
+
     /// Makes a node from an operator, without any argument.
     fn try_to_node(self) -> Result<Ast, String>;
     /// Makes a node from an operator, with an argument to be pushed as its
@@ -76,6 +98,9 @@ impl OperatorConversions for TernaryOperator {
 
 impl OperatorConversions for Located<UnaryOperator> {
     fn try_to_node(self) -> Result<Ast, String> {
+        if self.associativity() == Associativity::LeftToRight {
+            return Err(format!("Tried to call postfix operator {self} without an argument."));
+        }
         Ok(Ast::Unary(Unary { op: self, arg: Ast::empty_box() }))
     }
 
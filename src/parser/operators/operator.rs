@@ -38,6 +38,12 @@ pub trait Operator: fmt::Debug {
     /// Get associativity of an operator.
     fn associativity(&self) -> Associativity;
 
+    /// Checks if a binary operator shares its symbol with a unary operator,
+    /// like `*` for multiplication and indirection.
+    fn has_unary_form(&self) -> bool {
+        false
+    }
+
     /// Checks if an operator is  `[]`
     fn is_array_subscript(&self) -> bool {
         false
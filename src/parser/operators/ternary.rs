@@ -10,6 +10,7 @@ use crate::utils::display;
 ///
 /// The structure is `<condition> ? <success> : <failure>.`
 #[derive(Debug, Default)]
+#[non_exhaustive]
 pub struct Ternary {
     /// Condition [`Ast`] (before `?`)
     pub condition: Box<Ast>,
@@ -23,11 +24,18 @@ pub struct Ternary {
 
 impl Ternary {
     /// Computes the location of the ternary expression.
+    #[must_use]
     pub fn location(&self) -> ErrorLocation {
         self.condition
             .location()
             .into_extended(self.failure.as_ref().map_or_else(
-                || self.success.location(),
+                || {
+                    if self.success.is_empty() {
+                        self.condition.location()
+                    } else {
+                        self.success.location()
+                    }
+                },
                 |failure| {
                     if failure.1.is_empty() {
                         failure.0
@@ -7,6 +7,7 @@ use crate::utils::display;
 
 /// Unary operator node
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Unary {
     /// Argument
     pub arg: Box<Ast>,
@@ -17,6 +18,8 @@ pub struct Unary {
 display!(Unary, self, f, {
     if self.op.associativity() == Associativity::LeftToRight {
         write!(f, "({}{})", self.arg, self.op)
+    } else if self.op.as_value().is_type_operator() {
+        write!(f, "({} {})", self.op, self.arg)
     } else {
         write!(f, "({}{})", self.op, self.arg)
     }
@@ -27,6 +30,8 @@ display!(Unary, self, f, {
 pub enum UnaryOperator {
     /// Address-of (`&`)
     AddressOf,
+    /// `alignof` (in `alignof(int)`)
+    Alignof,
     /// `~`
     BitwiseNot,
     /// Dereference (`*`)
@@ -45,6 +50,27 @@ pub enum UnaryOperator {
     PrefixDecrement,
     /// `++` (in `++x`)
     PrefixIncrement,
+    /// `sizeof` (in `sizeof x` or `sizeof(int)`)
+    Sizeof,
+}
+
+impl UnaryOperator {
+    /// Checks if the operator assigns a new value to its operand.
+    pub const fn is_assignment(self) -> bool {
+        matches!(
+            self,
+            Self::PostfixDecrement
+                | Self::PostfixIncrement
+                | Self::PrefixDecrement
+                | Self::PrefixIncrement
+        )
+    }
+
+    /// Checks if the operator computes a property of the type of its operand,
+    /// without evaluating it, like `sizeof`.
+    pub const fn is_type_operator(self) -> bool {
+        matches!(self, Self::Alignof | Self::Sizeof)
+    }
 }
 
 impl Operator for Located<UnaryOperator> {
@@ -63,7 +89,9 @@ impl Operator for Located<UnaryOperator> {
             | UnaryOperator::BitwiseNot
             | UnaryOperator::LogicalNot
             | UnaryOperator::Indirection
-            | UnaryOperator::AddressOf => Associativity::RightToLeft,
+            | UnaryOperator::AddressOf
+            | UnaryOperator::Alignof
+            | UnaryOperator::Sizeof => Associativity::RightToLeft,
         }
     }
 
@@ -77,7 +105,9 @@ impl Operator for Located<UnaryOperator> {
             | UnaryOperator::BitwiseNot
             | UnaryOperator::LogicalNot
             | UnaryOperator::Indirection
-            | UnaryOperator::AddressOf => 2,
+            | UnaryOperator::AddressOf
+            | UnaryOperator::Alignof
+            | UnaryOperator::Sizeof => 2,
         }
     }
 }
@@ -95,6 +125,8 @@ display!(UnaryOperator, self, f, {
             Self::LogicalNot => "!",
             Self::Indirection => "*",
             Self::AddressOf => "&",
+            Self::Alignof => "alignof",
+            Self::Sizeof => "sizeof",
         }
     )
 });
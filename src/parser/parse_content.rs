@@ -3,6 +3,7 @@
 extern crate alloc;
 use alloc::vec::IntoIter;
 
+use super::keyword::generic::handle_generic;
 use super::keyword::handle_keyword;
 use super::literal::Literal;
 use super::modifiers::push::Push as _;
@@ -12,7 +13,7 @@ use super::symbols::handle_symbol;
 use super::tree::api::Ast;
 use super::variable::Variable;
 use crate::errors::api::{ErrorLocation, Res};
-use crate::lexer::api::{Token, TokenValue};
+use crate::lexer::api::{Keyword, Token, TokenValue};
 
 /// Indicates whether the current block should continue parsing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -44,15 +45,22 @@ pub fn parse_block(
         println!("\x1b[36m{:20} on {current}\x1b[0m", format!("{token}"),);
         let (value, location) = token.into_value_location();
         let res = match value {
-            TokenValue::Char(ch) =>
-                handle_literal(current, Ast::Leaf(location.wrap(Literal::Char(ch))), location),
+            TokenValue::Char(ch, encoding) => handle_literal(
+                current,
+                Ast::Leaf(location.wrap(Literal::Char(ch, encoding))),
+                location,
+            ),
             TokenValue::Ident(val) =>
                 handle_literal(current, Ast::Variable(Variable::from(location.wrap(val))), location),
             TokenValue::Number(nb) =>
                 handle_literal(current, Ast::Leaf(location.wrap(Literal::Number(nb))), location),
-            TokenValue::Str(val) =>
-                handle_literal(current, Ast::Leaf(location.wrap(Literal::Str(val))), location),
+            TokenValue::Str(val, encoding) => handle_literal(
+                current,
+                Ast::Leaf(location.wrap(Literal::Str(val, encoding))),
+                location,
+            ),
             TokenValue::Symbol(symbol) => handle_symbol(symbol, current, p_state, tokens, location),
+            TokenValue::Keyword(Keyword::Generic) => handle_generic(current, tokens, location),
             TokenValue::Keyword(keyword) => handle_keyword(keyword, current, p_state, location),
         };
         let has_failures = res.has_failures();
@@ -69,8 +77,14 @@ pub fn parse_block(
 /// This function manages the blocks with successive calls and checks.
 #[must_use]
 pub fn parse(tokens: Vec<Token>) -> Res<BracedBlock> {
+    parse_after(tokens, BracedBlock::default())
+}
+
+/// Parses a list of tokens as the continuation of the top-level elements of
+/// `block`.
+pub fn parse_after(tokens: Vec<Token>, block: BracedBlock) -> Res<BracedBlock> {
     let mut tokens_iter = tokens.into_iter();
-    let mut ast = Ast::BracedBlock(BracedBlock::default());
+    let mut ast = Ast::BracedBlock(block);
     let mut p_state = ParsingState::default();
     let res = parse_block(&mut tokens_iter, &mut p_state, &mut ast);
     let Ast::BracedBlock(bb) = ast else {
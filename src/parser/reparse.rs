@@ -0,0 +1,260 @@
+//! Module to re-parse a file after an edit, only parsing the top-level
+//! elements affected by the edit.
+//!
+//! The top-level elements are parsed independently: when the parser starts an
+//! element, it only looks at the previous one. The elements that only contain
+//! tokens before or after the re-lexed ones are thus kept, and the locations of
+//! the ones after the edit are moved with a [`ShiftLocations`] visitor.
+
+use super::parse_content::{parse, parse_after};
+use super::symbols::api::BracedBlock;
+use super::tree::api::Ast;
+use crate::errors::api::{ErrorLocation, Res};
+use crate::lexer::api::{Position, Shift, Symbol, Token, TokenChange, TokenValue};
+use crate::parser::api::{ControlFlowNode, Visit, VisitMut};
+
+/// Visitor that finds the first position of a node.
+///
+/// The nodes of a file that failed to parse can be incomplete, so their
+/// locations can't be computed from their children.
+struct FirstPosition(Option<Position>);
+
+impl Visit for FirstPosition {
+    fn visit_location(&mut self, location: ErrorLocation) {
+        if let Some(start) = location.start()
+            && self.0.is_none_or(|first| start < first)
+        {
+            self.0 = Some(start);
+        }
+    }
+}
+
+/// Visitor that moves the locations of the elements kept after an edit to
+/// their positions in the new content.
+struct ShiftLocations(Shift);
+
+impl VisitMut for ShiftLocations {
+    fn visit_location_mut(&mut self, location: &mut ErrorLocation) {
+        *location = self.0.location(*location);
+    }
+}
+
+/// Returns the position of the first located token of an element, if not
+/// empty.
+///
+/// The tokens of an attribute specifier before the name of its first
+/// attribute, like `[[`, have no location, see [`find_token`].
+fn element_start(elt: &Ast) -> Option<Position> {
+    let mut visitor = FirstPosition(None);
+    visitor.visit_ast(elt);
+    visitor.0
+}
+
+/// Returns the index of the first token of the element whose first located
+/// token starts at `pos`, if found.
+///
+/// The brackets and commas just before the located token belong to the same
+/// element, as the previous element ends with a `;` or a `}`. The token is not
+/// found if several tokens start at `pos`, like a `}` followed by a `\` and a
+/// newline, which is located at the start of the next line.
+fn find_token(tokens: &[Token], offset: usize, pos: Position) -> Option<usize> {
+    let mut idx = offset.saturating_add(
+        tokens
+            .get(offset..)?
+            .partition_point(|tok| tok.as_location().start().is_some_and(|start| start < pos)),
+    );
+    let starts_at_pos = |token: &Token| token.as_location().start() == Some(pos);
+    if !tokens.get(idx).is_some_and(starts_at_pos)
+        || tokens.get(idx.saturating_add(1)).is_some_and(starts_at_pos)
+    {
+        return None;
+    }
+    while let Some(previous) = idx.checked_sub(1).filter(|previous| *previous >= offset)
+        && tokens.get(previous).is_some_and(|token| {
+            matches!(
+                token.as_value(),
+                TokenValue::Symbol(Symbol::BracketOpen | Symbol::BracketClose | Symbol::Comma)
+            )
+        })
+    {
+        idx = previous;
+    }
+    Some(idx)
+}
+
+/// Finds the top-level element that contains the token before the re-lexed
+/// ones.
+///
+/// This token is before the edit, so it has the same position before and after
+/// the edit, unlike the first re-lexed token.
+///
+/// # Returns
+///
+/// The index of the element and the index of its first token, or [`None`] if
+/// it wasn't found.
+fn first_element(elts: &[Ast], tokens: &[Token], change: &TokenChange) -> Option<(usize, usize)> {
+    if change.start() == 0 {
+        return Some((0, 0));
+    }
+    let pos = tokens
+        .get(change.start().checked_sub(1)?)?
+        .as_location()
+        .start()?;
+    let (first, start) = elts
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, elt)| element_start(elt).map(|start| (idx, start)))
+        .take_while(|(_, start)| *start <= pos)
+        .last()?;
+    find_token(tokens, 0, start).map(|token| (first, token))
+}
+
+/// Checks if an element is a label, like `case 1:` or `default:`.
+///
+/// The `case` and `default` labels don't fill the empty element left by the
+/// previous element, but are pushed after it.
+const fn is_case_label(elt: &Ast) -> bool {
+    matches!(
+        elt,
+        Ast::ControlFlow(ControlFlowNode::AstColonAst(_) | ControlFlowNode::ColonAst(_))
+    )
+}
+
+/// Finds the top-level element from which the file is re-parsed.
+///
+/// The parser only looks at the last top-level element when it starts a new
+/// one, but it can't be known from the AST whether an empty element was
+/// waiting for the element that contains the edit. The element before it is
+/// thus re-parsed too, from an empty block: its tokens are before the edit and
+/// it is a new element, so it is parsed in the same way as before.
+///
+/// # Returns
+///
+/// The index of the element, the index of its first token and whether the
+/// parser starts in an empty element, or [`None`] if it wasn't found.
+fn start_element(
+    elts: &[Ast],
+    tokens: &[Token],
+    change: &TokenChange,
+) -> Option<(usize, usize, bool)> {
+    let (first, first_token) = first_element(elts, tokens, change)?;
+    let Some(previous) = first.checked_sub(1) else {
+        return Some((first, first_token, false));
+    };
+    let previous_elt = elts.get(previous)?;
+    if previous_elt.is_empty() {
+        return Some((previous, first_token, true));
+    }
+    let previous_token = find_token(tokens, 0, element_start(previous_elt)?)?;
+    Some((previous, previous_token, false))
+}
+
+/// Finds the first top-level element that can be kept after the edit.
+///
+/// The kept elements must start after the re-lexed tokens, and after the line
+/// of the end of the edit, so that their locations are only moved by whole
+/// lines.
+///
+/// # Returns
+///
+/// The index of the element and the index of its first token, or [`None`] if
+/// the file must be parsed until the end.
+fn kept_element(
+    elts: &[Ast],
+    first: usize,
+    tokens: &[Token],
+    change: &TokenChange,
+) -> Option<(usize, usize)> {
+    let resume = change.resume()?;
+    let shift = change.shift();
+    let (elt, start) = elts
+        .iter()
+        .enumerate()
+        .skip(first.saturating_add(1))
+        .find_map(|(idx, elt)| {
+            element_start(elt)
+                .filter(|start| {
+                    *start >= resume && (shift.is_identity() || start.0 > shift.old_line())
+                })
+                .map(|start| (idx, start))
+        })?;
+    find_token(tokens, change.end(), shift.apply(start)).map(|token| (elt, token))
+}
+
+/// Updates the AST of a file after an edit, only re-parsing the top-level
+/// elements that contain the re-lexed tokens.
+///
+/// `ast` must be the AST of the file before the edit, `tokens` the tokens
+/// updated with [`relex`](crate::relex), and `change` the value returned by
+/// [`relex`](crate::relex).
+///
+/// # Returns
+///
+/// The updated AST. Only the errors found in the re-parsed elements are
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::{TargetInfo, TextEdit, lex, parse, relex, reparse};
+///
+/// let mut content = "int x = 1;\nint y = 2;".to_owned();
+/// let mut tokens = lex(&content, 0).unwrap_or_display(&[]).unwrap();
+/// let ast = parse(tokens.clone()).unwrap_or_display(&[]).unwrap();
+/// let edit = TextEdit::new(8..9, "3".to_owned());
+/// let change = relex(&mut content, &mut tokens, &edit, 0, &TargetInfo::default())
+///     .unwrap_or_display(&[])
+///     .unwrap();
+/// let new_ast = reparse(ast, &tokens, &change)
+///     .unwrap_or_display(&[])
+///     .unwrap();
+/// assert!(format!("{new_ast}") == format!("{}", parse(tokens).unwrap_or_display(&[]).unwrap()));
+/// ```
+pub fn reparse(mut ast: BracedBlock, tokens: &[Token], change: &TokenChange) -> Res<BracedBlock> {
+    let Some((start, start_token, waiting)) = start_element(&ast.elts, tokens, change) else {
+        return parse(tokens.to_vec());
+    };
+    let initial_block = || BracedBlock {
+        elts: if waiting { vec![Ast::Empty] } else { vec![] },
+        ..BracedBlock::default()
+    };
+    if let Some((kept, kept_token)) =
+        kept_element(&ast.elts, start.saturating_add(1), tokens, change)
+    {
+        let res = parse_after(
+            tokens
+                .get(start_token..kept_token)
+                .unwrap_or_default()
+                .to_vec(),
+            initial_block(),
+        );
+        if !res.has_failures() {
+            let mut errors = vec![];
+            if let Some(mut parsed) = res.store_errors(&mut |err| errors.push(err))
+                && parsed.elts.last().is_some_and(Ast::is_empty)
+                && let Some(previous) = ast.elts.get(kept.saturating_sub(1))
+                && (previous.is_empty() || !ast.elts.get(kept).is_some_and(is_case_label))
+            {
+                if !previous.is_empty() {
+                    parsed.elts.pop();
+                }
+                let moved = start.saturating_add(parsed.elts.len());
+                ast.elts.splice(start..kept, parsed.elts);
+                if change.shift().moves_lines() {
+                    let mut visitor = ShiftLocations(change.shift());
+                    for elt in ast.elts.get_mut(moved..).unwrap_or_default() {
+                        visitor.visit_ast_mut(elt);
+                    }
+                }
+                return Res::from((ast, errors));
+            }
+        }
+    }
+    parse_after(tokens.get(start_token..).unwrap_or_default().to_vec(), initial_block()).map(
+        |parsed| {
+            ast.elts.truncate(start);
+            ast.elts.extend(parsed.elts);
+            ast
+        },
+    )
+}
@@ -0,0 +1,237 @@
+//! Handler for the attribute specifiers, like `[[nodiscard]]` or
+//! `[[gnu::aligned(8)]]`.
+
+extern crate alloc;
+use alloc::vec::IntoIter;
+
+use crate::Res;
+use crate::errors::api::{CompileError, ErrorLocation, Located};
+use crate::lexer::api::{Symbol, Token, TokenValue};
+use crate::parser::keyword::control_flow::traits::ControlFlow as _;
+use crate::parser::keyword::push_in_statement;
+use crate::parser::keyword::sort::PushInNode;
+use crate::parser::literal::StandardAttribute;
+use crate::parser::modifiers::push::Push as _;
+use crate::parser::parse_content::ParseAction;
+use crate::parser::symbols::api::{BracedBlock, CompoundLiteral, ListInitialiser};
+use crate::parser::tree::api::{Ast, AstPushContext};
+use crate::parser::variable::Variable;
+
+/// Opening and closing delimiters that must be balanced in the arguments of
+/// an attribute.
+const DELIMITERS: [(Symbol, Symbol); 3] = [
+    (Symbol::ParenthesisOpen, Symbol::ParenthesisClose),
+    (Symbol::BracketOpen, Symbol::BracketClose),
+    (Symbol::BraceOpen, Symbol::BraceClose),
+];
+
+impl PushInNode for Located<StandardAttribute> {
+    fn push_in_node(self, node: &mut Ast) -> Result<(), String> {
+        #[cfg(feature = "debug")]
+        crate::errors::api::Print::push_in_node(&self, "std attribute", node);
+        match node {
+            Ast::Empty => *node = Ast::Variable(Variable::from(self)),
+            Ast::Variable(var) => var.push_standard_attr(self)?,
+            Ast::ControlFlow(ctrl) if !ctrl.is_full() =>
+                ctrl.push_block_as_leaf(Ast::Variable(Variable::from(self)))?,
+            Ast::FunctionArgsBuild(elts, ..)
+            | Ast::ListInitialiser(ListInitialiser { elts, full: false, .. })
+            | Ast::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { elts, full: false, .. },
+                ..
+            })
+            | Ast::BracedBlock(BracedBlock { elts, full: false, .. }) => match elts.last_mut() {
+                Some(last) => return self.push_in_node(last),
+                None => elts.push(Ast::Variable(Variable::from(self))),
+            },
+            Ast::Binary(_)
+            | Ast::BracedBlock(_)
+            | Ast::Cast(_)
+            | Ast::CompoundLiteral(_)
+            | Ast::ControlFlow(_)
+            | Ast::FunctionCall(_)
+            | Ast::Generic(_)
+            | Ast::Leaf(_)
+            | Ast::ListInitialiser(_)
+            | Ast::ParensBlock(_)
+            | Ast::Ternary(_)
+            | Ast::Unary(_) => {
+                return Err(format!(
+                    "Attribute {self} can only be placed before a declaration or a statement, but was found after {node}."
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the name of an attribute, or of its prefix.
+///
+/// Keywords are valid names, like `const` in `[[gnu::const]]`, and the
+/// surrounding double underscores are removed.
+fn attribute_name(value: TokenValue, location: ErrorLocation) -> Result<String, CompileError> {
+    let name = match value {
+        TokenValue::Ident(name) => name,
+        TokenValue::Keyword(keyword) => keyword.to_string(),
+        TokenValue::Char(..)
+        | TokenValue::Number(_)
+        | TokenValue::Str(..)
+        | TokenValue::Symbol(_) =>
+            return Err(location.crash(format!("Expected attribute name, found {value}."))),
+    };
+    Ok(
+        if let Some(inner) = name
+            .strip_prefix("__")
+            .and_then(|rest| rest.strip_suffix("__"))
+            && !inner.is_empty()
+        {
+            inner.to_owned()
+        } else {
+            name
+        },
+    )
+}
+
+/// Handler for `[[`
+///
+/// Parses the attributes of the specifier and pushes them into the current
+/// node.
+pub fn handle_attribute_specifier(
+    current: &mut Ast,
+    tokens: &mut IntoIter<Token>,
+    location: ErrorLocation,
+) -> Res<ParseAction> {
+    let attrs = parse_attributes(tokens, location)?;
+    for attr in attrs {
+        push_in_statement(attr, current, AstPushContext::UserVariable, location, "attribute")?;
+    }
+    Res::ok(ParseAction::Continue)
+}
+
+/// Checks if the `[` that was just found opens an attribute specifier, i.e.,
+/// is followed by another `[`.
+pub fn is_attribute_specifier(tokens: &IntoIter<Token>) -> bool {
+    next_is(tokens, Symbol::BracketOpen)
+}
+
+/// Checks if the next token is the given symbol.
+fn next_is(tokens: &IntoIter<Token>, symbol: Symbol) -> bool {
+    tokens
+        .as_slice()
+        .first()
+        .is_some_and(|token| *token.as_value() == TokenValue::Symbol(symbol))
+}
+
+/// Parses the arguments of an attribute, until the closing `)`.
+///
+/// The opening `(` is already consumed. The parenthesis, brackets and braces
+/// of the arguments must be balanced. `kind` describes the arguments in the
+/// error messages.
+pub fn parse_arguments(
+    tokens: &mut IntoIter<Token>,
+    location: ErrorLocation,
+    kind: &str,
+) -> Result<(Vec<Token>, ErrorLocation), CompileError> {
+    let mut arguments = vec![];
+    let mut closing = vec![];
+    for token in tokens.by_ref() {
+        if let TokenValue::Symbol(symbol) = token.as_value() {
+            if let Some((_, close)) = DELIMITERS.iter().find(|(open, _)| open == symbol) {
+                closing.push(*close);
+            } else if DELIMITERS.iter().any(|(_, close)| close == symbol) {
+                match closing.pop() {
+                    None if *symbol == Symbol::ParenthesisClose =>
+                        return Ok((arguments, token.as_location())),
+                    Some(expected) if expected == *symbol => (),
+                    Some(_) | None => {
+                        return Err(token
+                            .as_location()
+                            .crash(format!("Mismatched delimiter in the {kind}.")));
+                    }
+                }
+            }
+        }
+        arguments.push(token);
+    }
+    Err(location.crash(
+        "Mismatched '(': reached end of block. Perhaps you forgot a closing ')'?".to_owned(),
+    ))
+}
+
+/// Parses one attribute, like `gnu::aligned(8)`, whose first name was
+/// already consumed.
+fn parse_attribute(
+    tokens: &mut IntoIter<Token>,
+    first: TokenValue,
+    location: ErrorLocation,
+) -> Result<Located<StandardAttribute>, CompileError> {
+    let mut attr_location = location;
+    let mut prefix = None;
+    let mut name = attribute_name(first, location)?;
+    if next_is(tokens, Symbol::Colon) {
+        let colon = tokens.next().expect("checked above").as_location();
+        if !next_is(tokens, Symbol::Colon) {
+            return Err(colon.crash("Expected '::' after attribute prefix, found ':'.".to_owned()));
+        }
+        tokens.next();
+        let Some((value, name_location)) = tokens.next().map(Token::into_value_location) else {
+            return Err(colon.crash("Expected attribute name after '::'.".to_owned()));
+        };
+        prefix = Some(name);
+        name = attribute_name(value, name_location)?;
+        attr_location.extend(name_location);
+    }
+    let mut arguments = None;
+    if next_is(tokens, Symbol::ParenthesisOpen) {
+        let parens = tokens.next().expect("checked above").as_location();
+        let (args, closing) = parse_arguments(tokens, parens, "arguments of the attribute")?;
+        arguments = Some(args);
+        attr_location.extend(closing);
+    }
+    Ok(attr_location.wrap(StandardAttribute { arguments, name, prefix }))
+}
+
+/// Parses the attributes of an attribute specifier, until the closing `]]`.
+///
+/// The opening `[` is already consumed, and the second one is the next token.
+/// Attributes are separated by commas, and can be empty, like in `[[, a]]`.
+fn parse_attributes(
+    tokens: &mut IntoIter<Token>,
+    location: ErrorLocation,
+) -> Result<Vec<Located<StandardAttribute>>, CompileError> {
+    tokens.next();
+    let mut attrs = vec![];
+    let mut expecting_attr = true;
+    while let Some(token) = tokens.next() {
+        let (value, token_location) = token.into_value_location();
+        match value {
+            TokenValue::Symbol(Symbol::Comma) => expecting_attr = true,
+            TokenValue::Symbol(Symbol::BracketClose) if next_is(tokens, Symbol::BracketClose) => {
+                tokens.next();
+                return Ok(attrs);
+            }
+            TokenValue::Symbol(Symbol::BracketClose) =>
+                return Err(token_location.crash(
+                    "Expected ']]' to close the attribute specifier, found a single ']'."
+                        .to_owned(),
+                )),
+            TokenValue::Ident(_) | TokenValue::Keyword(_) if expecting_attr => {
+                attrs.push(parse_attribute(tokens, value, token_location)?);
+                expecting_attr = false;
+            }
+            TokenValue::Ident(_) | TokenValue::Keyword(_) =>
+                return Err(token_location.crash("Expected ',' or ']]' after attribute.".to_owned())),
+            TokenValue::Char(..)
+            | TokenValue::Number(_)
+            | TokenValue::Str(..)
+            | TokenValue::Symbol(_) => {
+                return Err(
+                    token_location.crash(format!("Expected attribute name, found {value}."))
+                );
+            }
+        }
+    }
+    Err(location.crash(
+        "Mismatched '[[': reached end of block. Perhaps you forgot a closing ']]'?".to_owned(),
+    ))
+}
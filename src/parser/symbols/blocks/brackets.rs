@@ -0,0 +1,148 @@
+//! Handler for the brackets, that are either array subscripts, like `a[1]`, or
+//! array declarators, like `[10]` in `int a[10]`.
+
+extern crate alloc;
+use alloc::vec::IntoIter;
+
+use crate::Res;
+use crate::errors::api::{ErrorLocation, Located};
+use crate::lexer::api::{Keyword, Token, TokenValue};
+use crate::parser::api::{
+    ArrayDeclarator, ArraySize, ControlFlowNode, Declaration, UnaryOperator, Variable
+};
+use crate::parser::keyword::attributes::AttributeKeyword;
+use crate::parser::keyword::control_flow::traits::ControlFlow as _;
+use crate::parser::keyword::sort::{Context, KeywordParsing};
+use crate::parser::modifiers::push::Push as _;
+use crate::parser::operators::api::{BinaryOperator, Unary};
+use crate::parser::parse_content::parse_block;
+use crate::parser::state::{BlockType, ParsingState};
+use crate::parser::symbols::api::BracedBlock;
+use crate::parser::tree::api::Ast;
+
+/// Returns the declaration whose name was just read, like `a` in `int a`, to
+/// apply the next array declarator on it.
+fn declaration_before_bracket(current: &mut Ast) -> Option<&mut Declaration> {
+    match current {
+        Ast::Variable(var) => last_declaration(var),
+        Ast::BracedBlock(BracedBlock { elts, full: false, .. })
+        | Ast::FunctionArgsBuild(elts, ..) => declaration_before_bracket(elts.last_mut()?),
+        Ast::ControlFlow(ControlFlowNode::Typedef(typedef)) =>
+            last_declaration(typedef.as_type_mut()?),
+        Ast::ControlFlow(ctrl) => declaration_before_bracket(ctrl.as_ast_mut()?),
+        Ast::Binary(_)
+        | Ast::BracedBlock(_)
+        | Ast::Cast(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::Empty
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_)
+        | Ast::Leaf(_)
+        | Ast::ListInitialiser(_)
+        | Ast::ParensBlock(_)
+        | Ast::Ternary(_)
+        | Ast::Unary(_) => None,
+    }
+}
+
+/// Handler for `[`
+///
+/// The brackets are an array declarator if they follow the name of a
+/// declaration, and an array subscript otherwise.
+pub fn handle_bracket_open(
+    current: &mut Ast,
+    tokens: &mut IntoIter<Token>,
+    p_state: &mut ParsingState,
+    location: ErrorLocation,
+) -> Res<()> {
+    let is_declarator = declaration_before_bracket(current).is_some();
+    let qualifiers = if is_declarator {
+        take_array_qualifiers(tokens)
+    } else {
+        vec![]
+    };
+    let mut bracket_node = Ast::Empty;
+    p_state.push_ctrl_flow(false);
+    let res = parse_block(tokens, p_state, &mut bracket_node);
+    if res.has_failures() {
+        return res;
+    }
+    if p_state.pop_ctrl_flow().is_none() {
+        return res.add_err(BlockType::Bracket.mismatched_err_end(location));
+    }
+    let Some(start_location) = p_state.pop_and_compare_block(&BlockType::Bracket) else {
+        return res.add_err(BlockType::Bracket.mismatched_err_end(location));
+    };
+    let op_location = start_location.into_extended(location);
+    if let Some(decl) = declaration_before_bracket(current) {
+        let size = match bracket_node {
+            Ast::Empty => ArraySize::Incomplete,
+            Ast::Unary(Unary { op, arg })
+                if *op.as_value() == UnaryOperator::Indirection && arg.is_empty() =>
+                ArraySize::Unspecified,
+            node @ (Ast::Binary(_)
+            | Ast::BracedBlock(_)
+            | Ast::Cast(_)
+            | Ast::CompoundLiteral(_)
+            | Ast::ControlFlow(_)
+            | Ast::FunctionArgsBuild(..)
+            | Ast::FunctionCall(_)
+            | Ast::Generic(_)
+            | Ast::Leaf(_)
+            | Ast::ListInitialiser(_)
+            | Ast::ParensBlock(_)
+            | Ast::Ternary(_)
+            | Ast::Unary(_)
+            | Ast::Variable(_)) => ArraySize::Expression(node),
+        };
+        if let Err(err) =
+            decl.push_array(ArrayDeclarator { location: op_location, qualifiers, size })
+        {
+            res.add_err(location.crash(err))
+        } else {
+            res
+        }
+    } else if let Err(err) = current.push_op(op_location.wrap(BinaryOperator::ArraySubscript)) {
+        res.add_err(location.crash(err))
+    } else if let Err(err) = current.push_block_as_leaf(bracket_node) {
+        res.add_err(location.crash(err))
+    } else {
+        res
+    }
+}
+
+/// Returns the last declaration of a variable, if it is only made of its name
+/// and array declarators.
+fn last_declaration(var: &mut Variable) -> Option<&mut Declaration> {
+    if var.is_full() {
+        return None;
+    }
+    var.as_attribute_variable_mut()?
+        .declarations
+        .last_mut()?
+        .as_mut()
+        .filter(|decl| decl.value.is_none())
+}
+
+/// Reads the `static` keyword and the type qualifiers at the beginning of an
+/// array declarator, like in `int a[static const 10]`.
+fn take_array_qualifiers(tokens: &mut IntoIter<Token>) -> Vec<Located<AttributeKeyword>> {
+    let mut qualifiers = vec![];
+    while let Some(token) = tokens.as_slice().first()
+        && let TokenValue::Keyword(keyword) = token.as_value()
+        && matches!(
+            keyword,
+            Keyword::Atomic
+                | Keyword::Const
+                | Keyword::Restrict
+                | Keyword::Static
+                | Keyword::Volatile
+        )
+        && let Ok(KeywordParsing::Attr(qualifier)) =
+            KeywordParsing::try_from((*keyword, Context::None))
+    {
+        qualifiers.push(token.as_location().wrap(qualifier));
+        tokens.next();
+    }
+    qualifiers
+}
@@ -0,0 +1,52 @@
+//! Defines the compound literals, like `(struct point){.x = 1}`.
+
+use core::mem::take;
+
+use crate::errors::api::ErrorLocation;
+use crate::parser::symbols::api::{ListInitialiser, ParensBlock};
+use crate::parser::tree::api::Ast;
+use crate::utils::display;
+
+/// Compound literal: an unnamed object, with a type name in parenthesis and
+/// an initialiser list, like `(int[]){1, 2}`.
+///
+/// The type name can have storage-class specifiers, like in `(static
+/// int){1}`. It is kept in its [`ParensBlock`], as for `sizeof`, see
+/// [`ParensBlock::type_name`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct CompoundLiteral {
+    /// Type name, in parenthesis.
+    pub type_name: ParensBlock,
+    /// Initial value of the object.
+    ///
+    /// The compound literal is full once the closing `}` of this list was
+    /// found.
+    pub value: ListInitialiser,
+}
+
+impl CompoundLiteral {
+    /// Makes a compound literal from a type name in parenthesis and a new
+    /// node, if the node is an initialiser list and the parenthesis contain a
+    /// type name.
+    pub(crate) fn from_parens(parens: &mut ParensBlock, new: &mut Ast) -> Option<Ast> {
+        if !matches!(new, Ast::ListInitialiser(_)) || parens.type_name().is_none() {
+            return None;
+        }
+        let Ast::ListInitialiser(list) = take(new) else {
+            unreachable!("checked by caller")
+        };
+        Some(Ast::CompoundLiteral(Self { type_name: take(parens), value: list }))
+    }
+
+    /// Returns the location of the compound literal, from the opening
+    /// parenthesis to the closing brace.
+    #[must_use]
+    pub fn location(&self) -> ErrorLocation {
+        self.type_name
+            .as_location()
+            .into_extended(self.value.location)
+    }
+}
+
+display!(CompoundLiteral, self, f, write!(f, "({}{})", self.type_name, self.value));
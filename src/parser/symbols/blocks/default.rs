@@ -2,6 +2,7 @@
 
 use crate::BracedBlock;
 use crate::errors::api::ErrorLocation;
+use crate::parser::api::ParameterList;
 use crate::parser::tree::api::Ast;
 use crate::parser::variable::Variable;
 use crate::utils::{display, repr_vec};
@@ -11,53 +12,87 @@ use crate::utils::{display, repr_vec};
 /// This node represents function calls, function declarations and function
 /// definitions.
 ///
-/// In the case of function calls, the variable should be a line name without
-/// attribute, and the body should be empty.
+/// In the case of function calls, the callee is an expression that designates
+/// the function, like a name without attribute or `(*table[i])`, and the body
+/// should be empty.
 ///
-/// In the case of function declarations, the variable should be a variable
+/// In the case of function declarations, the callee should be a variable
 /// declaration with attribute, and the
 /// body should be empty.
 ///
-/// In the case of function definitions, the variable should be a variable
+/// In the case of function definitions, the callee should be a variable
 /// declaration with attribute, and the body should be a [`BracedBlock`].
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct FunctionCall {
     /// arguments passed to the function
     pub arguments: Vec<Ast>,
+    /// Function called, or name of the function and all its attributes (return
+    /// type) for declarations.
+    pub callee: Box<Ast>,
     /// body of the function if it is a definition
     pub function_body: Option<BracedBlock>,
     /// Location of the entire parenthis block containing the arguments.
     pub parens_location: ErrorLocation,
-    /// name of the function, and all its attributes (return type)
-    pub variable: Variable,
+    /// Location of the ellipsis `...` ending the parameter list of a variadic
+    /// function, like in `int printf(const char *, ...)`.
+    pub variadic: Option<ErrorLocation>,
 }
 
 impl FunctionCall {
+    /// Returns the callee if it is a variable, i.e., a function name or the
+    /// declaration of a function.
+    #[must_use]
+    pub const fn as_variable(&self) -> Option<&Variable> {
+        if let Ast::Variable(var) = &*self.callee {
+            Some(var)
+        } else {
+            None
+        }
+    }
+
     /// Returns the location of the entire function call.
+    #[must_use]
     pub fn location(&self) -> ErrorLocation {
-        self.variable.location().into_extended(
+        self.callee.location().into_extended(
             self.function_body
                 .as_ref()
                 .map_or_else(|| self.parens_location, |body| body.location),
         )
     }
+
+    /// Builds a call of `callee` with the given arguments, or the declaration
+    /// of a function if `callee` is a declaration.
+    pub(crate) fn new(callee: Ast, arguments: ParameterList) -> Self {
+        Self {
+            arguments: arguments.parameters,
+            callee: callee.into_box(),
+            function_body: None,
+            parens_location: arguments.location,
+            variadic: arguments.variadic,
+        }
+    }
 }
 
-display!(
-    FunctionCall,
-    self,
-    f,
+display!(FunctionCall, self, f, {
+    write!(f, "({}\u{b0}({}", self.callee, repr_vec(&self.arguments, ", "))?;
+    match (self.variadic, self.arguments.is_empty()) {
+        (Some(_), true) => "...".fmt(f)?,
+        (Some(_), false) => ", ...".fmt(f)?,
+        (None, _) => (),
+    }
     if let Some(body) = &self.function_body {
-        write!(f, "({}\u{b0}({}){body})", self.variable, repr_vec(&self.arguments, ", "))
+        write!(f, "){body})")
     } else {
-        write!(f, "({}\u{b0}({}))", self.variable, repr_vec(&self.arguments, ", "))
+        "))".fmt(f)
     }
-);
+});
 
 /// List initialiser
 ///
 /// Node to represent list initialisers, such as `{1, 2, 3, [6]=12}`.
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct ListInitialiser {
     /// elements of the list
     pub elts: Vec<Ast>,
@@ -1,7 +1,10 @@
 //! Handles the different block symbols, i.e., opening and closing braced,
 //! parenthesis and brackets.
 
+pub mod attributes;
 pub mod braced_blocks;
+pub mod brackets;
+pub mod compound_literal;
 pub mod default;
 pub mod parens;
 pub mod recursion;
@@ -6,19 +6,22 @@ use core::mem::take;
 use core::{fmt, mem};
 
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::literal::Attribute;
-use crate::parser::operators::api::OperatorConversions;
+use crate::parser::operators::api::{
+    Associativity, Binary, BinaryOperator, OperatorConversions, Unary, UnaryOperator
+};
+use crate::parser::symbols::api::CompoundLiteral;
 use crate::parser::tree::api::Ast;
 use crate::parser::variable::api::PureType;
 use crate::utils::{display, repr_fullness, repr_vec};
 
-/// Cast and Compound Literals
+/// Cast, like `(int)x`
 ///
-/// Cast and compound literals do not differ on the syntax (if you ignore that
-/// a cast take an expression and a compound literal takes a
-/// [`ListInitialiser`](crate::parser::symbols::api::ListInitialiser)), only on
-/// the implementation.
+/// A type name in parenthesis followed by an initialiser list is a
+/// [`CompoundLiteral`] instead.
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Cast {
     /// Type to cast to
     pub dest_type: Vec<Located<Attribute>>,
@@ -49,16 +52,11 @@ impl Cast {
         ) {
             None
         } else if matches!(new, Ast::ListInitialiser(_)) {
-            parens.take_pure_type().map(|dest_type| {
-                Ast::Cast(Self {
-                    dest_type,
-                    full: false,
-                    value: mem::take(new).into_box(),
-                    parens_location: take(&mut parens.1),
-                })
-            })
+            CompoundLiteral::from_parens(parens, new)
         } else {
-            let full = matches!(new, Ast::Cast(_) | Ast::ListInitialiser(_) | Ast::ParensBlock(_));
+            // a type name, like `(int)` in `(unsigned)(int)x`, still expects its operand
+            let full = matches!(new, Ast::Cast(_))
+                || matches!(new, Ast::ParensBlock(value) if !value.is_pure_type());
             parens.take_pure_type().map(|dest_type| {
                 Ast::Cast(Self {
                     dest_type,
@@ -71,7 +69,7 @@ impl Cast {
     }
 
     /// See [`Operator::precedence`](crate::parser::operators::api::Operator::precedence)
-    pub const fn precedence() -> u32 {
+    pub(crate) const fn precedence() -> u32 {
         2
     }
 }
@@ -101,16 +99,19 @@ pub struct ParensBlock(Box<Ast>, ErrorLocation);
 
 impl ParensBlock {
     /// Returns the location of the parens block.
+    #[must_use]
     pub const fn as_location(&self) -> ErrorLocation {
         self.1
     }
 
     /// Returns the value of the parens block.
+    #[must_use]
     pub const fn as_value(&self) -> &Ast {
         &self.0
     }
 
     /// Return the [`Ast`] inside the parenthesis.
+    #[must_use]
     pub fn into_inner(self) -> (Ast, ErrorLocation) {
         (*self.0, self.1)
     }
@@ -122,7 +123,7 @@ impl ParensBlock {
     /// ```ignore
     /// assert!(ParensBlock::make_parens_ast(Ast::Empty) == Ast::ParensBlock(Ast::empty_box()));
     /// ```
-    pub fn make_parens_ast(node: Ast, location: ErrorLocation) -> Ast {
+    pub(crate) fn make_parens_ast(node: Ast, location: ErrorLocation) -> Ast {
         Ast::ParensBlock(Self(node.into_box(), location))
     }
 
@@ -145,16 +146,33 @@ impl ParensBlock {
     /// it must also fail only if parens is a *pure type* (see
     /// [`Variable::take_pure_type`](crate::parser::variable::Variable)), for
     /// instance not to miss that (a+b)*c is meant as a
-    /// [`BinaryOperator`](crate::parser::operators::api::BinaryOperator)! Hence
+    /// [`BinaryOperator`]! Hence
     /// the usage of [`ParensBlock::is_pure_type`] before
     /// [`ParensBlock::take_pure_type`].
-    pub fn take_ast_with_op<T>(&mut self, op: T) -> Result<Ast, String>
+    ///
+    /// A binary operator without a unary form, like `=` in `(x) = 3`, or a
+    /// postfix operator, like `++` in `(x)++`, can't start the operand of a
+    /// cast, so the parenthesis are its operand. A postfix operator after a
+    /// type, like in `(int)++x`, fails in
+    /// [`OperatorConversions::try_to_node`] for the prefix operator to be
+    /// tried.
+    pub(crate) fn take_ast_with_op<T>(&mut self, op: T) -> Result<Ast, String>
     where
         T: OperatorConversions + fmt::Display,
     {
         #[cfg(feature = "debug")]
         crate::errors::api::Print::push_op(&op, self, "parens");
-        if self.is_pure_type() {
+        let postfix = !op.is_binary() && op.associativity() == Associativity::LeftToRight;
+        let identifier =
+            matches!(&*self.0, Ast::Variable(var) if var.as_user_defined_name().is_some());
+        if !self.is_pure_type()
+            || (op.is_binary() && !op.has_unary_form())
+            || (postfix && identifier)
+        {
+            let mut ast = Ast::ParensBlock(mem::take(self));
+            op.try_push_op_as_root(&mut ast)?;
+            Ok(ast)
+        } else {
             let node_op = op.try_to_node()?;
             Ok(Ast::Cast(Cast {
                 dest_type: self.take_pure_type().expect("just checked if possible"),
@@ -162,22 +180,52 @@ impl ParensBlock {
                 value: node_op.into_box(),
                 parens_location: take(&mut self.1),
             }))
-        } else {
-            let mut ast = Ast::ParensBlock(mem::take(self));
-            op.try_push_op_as_root(&mut ast)?;
-            Ok(ast)
         }
     }
+
+    /// Returns the type name inside the parenthesis, if it is a *pure type*,
+    /// like `(int *)` in `sizeof(int *)`.
+    ///
+    /// A lone identifier, like `(T)`, or a pointer to it, like `(T *)`, is also
+    /// returned, as it may be a typedef name. The sizes of the array
+    /// declarators, like `10` in `sizeof(int[10])`, are returned from the
+    /// innermost to the outermost.
+    #[must_use]
+    pub fn type_name(&self) -> Option<(Vec<Located<Attribute>>, Vec<&Ast>)> {
+        let mut sizes = vec![];
+        let mut node = &*self.0;
+        while let Ast::Binary(Binary { op, arg_l, arg_r }) = node
+            && *op.as_value() == BinaryOperator::ArraySubscript
+        {
+            sizes.push(&**arg_r);
+            node = arg_l;
+        }
+        if let Some(attrs) = typedef_pointer(node) {
+            return Some((attrs, sizes));
+        }
+        let Ast::Variable(var) = node else {
+            return None;
+        };
+        if let Some(name) = var.as_user_defined_name() {
+            return Some((vec![var.location().wrap(Attribute::User(name.to_owned()))], sizes));
+        }
+        let attr_var = var
+            .as_attribute_variable()
+            .filter(|attr_var| attr_var.is_pure_type())?;
+        let mut attrs = attr_var.attrs.clone();
+        if let Some(Some(decl)) = attr_var.declarations.last() {
+            attrs.push(decl.name.clone().transfer(Attribute::User));
+        }
+        Some((attrs, sizes))
+    }
 }
 
 impl PureType for ParensBlock {
     fn is_pure_type(&self) -> bool {
-        if let Ast::Variable(var) = &*self.0
-            && var.is_pure_type()
-        {
-            true
+        if let Ast::Variable(var) = &*self.0 {
+            var.is_pure_type()
         } else {
-            false
+            typedef_pointer(&self.0).is_some()
         }
     }
 
@@ -185,9 +233,51 @@ impl PureType for ParensBlock {
         if let Ast::Variable(var) = &mut *self.0 {
             var.take_pure_type()
         } else {
-            None
+            let attrs = typedef_pointer(&self.0)?;
+            *self.0 = Ast::Empty;
+            Some(attrs)
         }
     }
 }
 
 display!(ParensBlock, self, f, write!(f, "({})", self.0));
+
+impl Walk for ParensBlock {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.0);
+        visitor.visit_location(self.1);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.0);
+        visitor.visit_location_mut(&mut self.1);
+    }
+}
+
+/// Returns the type name of a pointer to a typedef name, like `T **`, that is
+/// parsed as a multiplication with a missing right operand.
+fn typedef_pointer(node: &Ast) -> Option<Vec<Located<Attribute>>> {
+    let Ast::Binary(Binary { op, arg_l, arg_r }) = node else {
+        return None;
+    };
+    if *op.as_value() != BinaryOperator::Multiply {
+        return None;
+    }
+    let mut attrs = if let Ast::Variable(var) = &**arg_l {
+        vec![
+            var.location()
+                .wrap(Attribute::User(var.as_user_defined_name()?.to_owned())),
+        ]
+    } else {
+        typedef_pointer(arg_l)?
+    };
+    attrs.push(op.as_location().wrap(Attribute::Indirection));
+    let mut pointee = &**arg_r;
+    while let Ast::Unary(Unary { op: star, arg }) = pointee
+        && *star.as_value() == UnaryOperator::Indirection
+    {
+        attrs.push(star.as_location().wrap(Attribute::Indirection));
+        pointee = arg;
+    }
+    pointee.is_empty().then_some(attrs)
+}
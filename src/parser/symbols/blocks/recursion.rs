@@ -4,12 +4,15 @@ extern crate alloc;
 use alloc::vec::IntoIter;
 use core::mem;
 
+use super::attributes::{handle_attribute_specifier, is_attribute_specifier};
 use super::braced_blocks::BracedBlock;
+use super::brackets::handle_bracket_open;
 use super::default::ListInitialiser;
 use super::parens::ParensBlock;
 use crate::Res;
 use crate::errors::api::ErrorLocation;
 use crate::lexer::api::Token;
+use crate::parser::api::ParameterList;
 use crate::parser::keyword::control_flow::node::{
     switch_wanting_block, try_push_semicolon_control
 };
@@ -18,7 +21,6 @@ use crate::parser::modifiers::list_initialiser::{
     apply_to_last_list_initialiser, can_push_list_initialiser
 };
 use crate::parser::modifiers::push::Push as _;
-use crate::parser::operators::api::BinaryOperator;
 use crate::parser::parse_content::{ParseAction, parse_block};
 use crate::parser::state::{BlockType, ParsingState};
 use crate::parser::tree::api::Ast;
@@ -55,7 +57,7 @@ pub fn blocks_handler(
     match block_state {
         // semi-colon
         TodoBlock::SemiColon => {
-            handle_semicolon(current);
+            handle_semicolon(current, location);
             Res::ok(ParseAction::Continue)
         }
         // parenthesis
@@ -69,27 +71,10 @@ pub fn blocks_handler(
             p_state.push_closing_block(BlockType::Bracket, location);
             Res::ok(ParseAction::Stop)
         }
-        TodoBlock::OpenBracket => {
-            let mut bracket_node = Ast::Empty;
-            p_state.push_ctrl_flow(false);
-            let res = parse_block(tokens, p_state, &mut bracket_node);
-            let has_failures = res.has_failures();
-            if has_failures {
-                res
-            } else if p_state.pop_ctrl_flow().is_none() {
-                res.add_err(BlockType::Bracket.mismatched_err_end(location))
-            } else if let Some(start_location) = p_state.pop_and_compare_block(&BlockType::Bracket) {
-                let op_location = start_location.into_extended(location);
-                if let Err(err) = current.push_op(op_location.wrap(BinaryOperator::ArraySubscript)) {
-                    res.add_err(location.crash(err))
-                } else if let Err(err) = current.push_block_as_leaf(bracket_node) {
-                    res.add_err(location.crash(err))
-                }
-                else {res}
-            } else {
-                res.add_err(BlockType::Bracket.mismatched_err_end(location))
-            }.map(|()| ParseAction::Continue)
-        }
+        TodoBlock::OpenBracket if is_attribute_specifier(tokens) =>
+            handle_attribute_specifier(current, tokens, location),
+        TodoBlock::OpenBracket =>
+            handle_bracket_open(current, tokens, p_state, location).map(|()| ParseAction::Continue),
         // brace
         TodoBlock::CloseBraceBlock
             if apply_to_last_list_initialiser(current, &|_, full, start| {*full = true; *start = start.into_extended(location); }).is_none() =>
@@ -159,7 +144,7 @@ fn handle_parenthesis_open(
     match current.can_make_function() {
         CanMakeFnRes::CanMakeFn(variable_depth) =>
             make_function(current, p_state, tokens, location, variable_depth),
-        CanMakeFnRes::None =>
+        CanMakeFnRes::None | CanMakeFnRes::Operand =>
             handle_non_function_parenthesis_open(current, p_state, tokens, location),
         CanMakeFnRes::TooDeep => Res::from_err(
             location.crash("Code to complex: AST to deep to fit depth in 32 bits.".to_owned()),
@@ -177,7 +162,7 @@ fn make_function(
     location: ErrorLocation,
     variable_depth: u32,
 ) -> Res<()> {
-    let mut arguments_node = Ast::FunctionArgsBuild(vec![Ast::Empty], location, location);
+    let mut arguments_node = Ast::FunctionArgsBuild(vec![Ast::Empty], location, location, None);
     p_state.push_ctrl_flow(false);
     let mut res = parse_block(tokens, p_state, &mut arguments_node);
     let has_failures = res.has_failures();
@@ -188,13 +173,19 @@ fn make_function(
         return res.add_err(BlockType::Parenthesis.mismatched_err_end(location));
     }
     if let Some(closing_loc) = p_state.pop_and_compare_block(&BlockType::Parenthesis) {
-        if let Ast::FunctionArgsBuild(vec, ..) = &mut arguments_node {
+        if let Ast::FunctionArgsBuild(vec, .., ellipsis_loc) = &mut arguments_node {
+            let variadic = *ellipsis_loc;
+            if let Some(ellipsis) = variadic
+                && vec.last().is_some_and(|last| !last.is_empty())
+            {
+                return res.add_err(ellipsis.crash("Expected ')' after '...'".to_owned()));
+            }
             let args = if let Some(last) = vec.last()
                 && last.is_empty()
             {
                 vec.pop();
                 let args = mem::take(vec);
-                if !args.is_empty() {
+                if !args.is_empty() && variadic.is_none() {
                     res = res.add_err(
                         arguments_node.location().suggest(
                             "Found extra comma in function argument list. Please remove the comma."
@@ -206,10 +197,15 @@ fn make_function(
             } else {
                 mem::take(vec)
             };
-            current.make_function(variable_depth, args, location.into_extended(closing_loc));
+            let parens_location = location.into_extended(closing_loc);
+            let arguments = ParameterList { location: parens_location, parameters: args, variadic };
+            if let Err(err) = current.make_function(variable_depth, arguments) {
+                return res.add_err(parens_location.crash(err));
+            }
             res
         } else {
-            unreachable!("a function args build cannot be dismissed as root");
+            // the arguments were made a block by a `;`
+            res.add_err(location.crash("Found ';' in a list of arguments.".to_owned()))
         }
     } else {
         res.add_err(BlockType::Parenthesis.mismatched_err_end(location))
@@ -248,7 +244,10 @@ fn handle_non_function_parenthesis_open(
 /// Handler for `;`
 ///
 /// Pushes a new empty node if needed.
-fn handle_semicolon(current: &mut Ast) {
+///
+/// An empty node is kept as the first element, so that the empty clauses of
+/// a `for` loop header are not lost.
+fn handle_semicolon(current: &mut Ast, location: ErrorLocation) {
     if try_push_semicolon_control(current) {
         return;
     }
@@ -263,7 +262,13 @@ fn handle_semicolon(current: &mut Ast) {
         } else {
             elts.push(Ast::Empty);
         }
-    } else if !current.is_empty() {
+    } else if current.is_empty() {
+        *current = Ast::BracedBlock(BracedBlock {
+            elts: vec![Ast::Empty, Ast::Empty],
+            full: false,
+            location,
+        });
+    } else {
         current.brace();
     }
 }
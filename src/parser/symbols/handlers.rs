@@ -1,6 +1,7 @@
 //! Handlers to be called when a symbol can represent by multiple operator.
 
 use super::blocks::braced_blocks::BracedBlock;
+use super::blocks::compound_literal::CompoundLiteral;
 use super::blocks::default::ListInitialiser;
 use crate::errors::api::{ErrorLocation, Located};
 use crate::parser::keyword::control_flow::traits::ControlFlow as _;
@@ -31,10 +32,6 @@ impl Ast {
     ///
     /// This method finds a ternary operator, and changes its reading state to
     /// failure.
-    ///
-    /// # Panics
-    ///
-    /// On unreachable events.
     pub(crate) fn handle_colon(&mut self, colon_location: ErrorLocation) -> Result<(), String> {
         #[cfg(feature = "debug")]
         crate::lgp!("Pushing colon in {self}");
@@ -52,9 +49,14 @@ impl Ast {
             }
             Self::Empty
             | Self::Leaf(_)
+            | Self::Generic(_)
             | Self::ParensBlock(_)
             | Self::FunctionCall(_)
             | Self::ListInitialiser(ListInitialiser { full: true, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: true, .. },
+                ..
+            })
             | Self::BracedBlock(BracedBlock { full: true, .. }) =>
                 Err("Ternary symbol mismatched: found a ':' symbol without '?'.".to_owned()),
             Self::Unary(Unary { arg, .. })
@@ -62,11 +64,15 @@ impl Ast {
             | Self::Ternary(Ternary { failure: Some((_, arg)), .. }) =>
                 arg.handle_colon(colon_location),
             Self::ListInitialiser(ListInitialiser { full: false, elts: vec, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: false, elts: vec, .. },
+                ..
+            })
             | Self::BracedBlock(BracedBlock { elts: vec, full: false, .. })
-            | Self::FunctionArgsBuild(vec, ..) => vec
-                .last_mut()
-                .expect("Created with one elt")
-                .handle_colon(colon_location),
+            | Self::FunctionArgsBuild(vec, ..) => vec.last_mut().map_or_else(
+                || Err("Ternary symbol mismatched: found a ':' symbol without '?'.".to_owned()),
+                |last| last.handle_colon(colon_location),
+            ),
             Self::ControlFlow(ctrl) =>
                 if ctrl.push_colon() {
                     Ok(())
@@ -85,13 +91,21 @@ impl Ast {
     }
 
     /// Handler to push a comma into an [`Self`]
+    ///
+    /// A comma inside a list initialiser that isn't closed yet, like in
+    /// `f((int[]){1, 2})`, separates the elements of the list, and not the
+    /// arguments of the function.
     pub fn handle_comma(&mut self, location: ErrorLocation) -> Result<(), String> {
-        if let Self::FunctionArgsBuild(vec, _, loc) = self {
+        if apply_to_last_list_initialiser(self, &|vec, _, _| vec.push(Self::Empty)).is_some() {
+            return Ok(());
+        }
+        if let Self::FunctionArgsBuild(vec, _, loc, variadic) = self {
+            if variadic.is_some() {
+                return Err("Expected ')' after '...', found ','".to_owned());
+            }
             vec.push(Self::Empty);
             *loc = location;
-        } else if apply_to_last_list_initialiser(self, &|vec, _, _| vec.push(Self::Empty)).is_none()
-            && !try_apply_comma_to_variable(self)?
-        {
+        } else if !try_apply_comma_to_variable(self)? {
             self.push_op(location.wrap(BinaryOperator::Comma))?;
         }
         Ok(())
@@ -107,4 +121,21 @@ impl Ast {
         self.push_op(first)
             .map_or_else(|_| self.push_op(second), |()| Ok(()))
     }
+
+    /// Handler to push an ellipsis `...` into an [`Self`].
+    ///
+    /// The ellipsis is only valid as the last element of a parameter list,
+    /// like in `int printf(const char *, ...)`.
+    pub fn handle_ellipsis(&mut self, location: ErrorLocation) -> Result<(), String> {
+        if let Self::FunctionArgsBuild(vec, _, _, variadic @ None) = self
+            && vec.last().is_some_and(Self::is_empty)
+        {
+            *variadic = Some(location);
+            Ok(())
+        } else if let Self::FunctionArgsBuild(..) = self {
+            Err("Found misplaced '...': expected ',' before it".to_owned())
+        } else {
+            Err("Found '...' outside of a parameter list".to_owned())
+        }
+    }
 }
@@ -10,11 +10,12 @@ pub mod api {
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
     pub use super::blocks::braced_blocks::BracedBlock;
+    pub use super::blocks::compound_literal::CompoundLiteral;
     pub use super::blocks::default::{FunctionCall, ListInitialiser};
     pub use super::blocks::parens::{Cast, ParensBlock};
 }
 
-mod blocks;
+pub mod blocks;
 mod handlers;
 mod sort_symbols;
 
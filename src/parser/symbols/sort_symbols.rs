@@ -33,6 +33,9 @@ enum SymbolParsing {
     Colon,
     /// Comma symbol
     Comma,
+    /// Symbol that is only valid in a preprocessor directive, like `#` or
+    /// `##`, and that is thus not valid after preprocessing.
+    Directive(&'static str),
     /// There are 2 [`UnaryOperator`] that exist with that symbol.
     ///
     /// Try the first one, and if it is not allowed, try the second.
@@ -42,6 +45,8 @@ enum SymbolParsing {
     /// `++` can be a [`UnaryOperator::PrefixIncrement`] or a
     /// [`UnaryOperator::PostfixIncrement`].
     DoubleUnary(UnaryOperator, UnaryOperator),
+    /// Ellipsis, ending a variadic parameter list.
+    Ellipsis,
     /// Interrogation mark
     Interrogation,
     /// The symbol exists only for one operator, a [`BinaryOperator`].
@@ -54,8 +59,8 @@ impl From<Symbol> for SymbolParsing {
     fn from(value: Symbol) -> Self {
         match value {
             // invalid
-            Symbol::Hash | Symbol::Concat =>
-                unreachable!("# should have been removed by preprocessor"),
+            Symbol::Hash => Self::Directive("#"),
+            Symbol::Concat => Self::Directive("##"),
             // mirror unary
             Symbol::BitwiseNot => Self::UniqueUnary(UnaryOperator::BitwiseNot),
             Symbol::LogicalNot => Self::UniqueUnary(UnaryOperator::LogicalNot),
@@ -111,6 +116,7 @@ impl From<Symbol> for SymbolParsing {
             // special
             Symbol::Colon => Self::Colon,
             Symbol::Comma => Self::Comma,
+            Symbol::Ellipsis => Self::Ellipsis,
             Symbol::Interrogation => Self::Interrogation,
         }
     }
@@ -141,6 +147,11 @@ pub fn handle_one_symbol(
         SymbolParsing::Interrogation => current.push_op(TernaryOperator)?, /* ternary only because trigraphis are ignored, and colon is handled in the main function in mod.rs */
         SymbolParsing::Colon => current.handle_colon(loc)?,
         SymbolParsing::Comma => current.handle_comma(loc)?,
+        SymbolParsing::Directive(directive) =>
+            return Err(format!(
+                "Stray '{directive}' in program: directives must start at the beginning of a line."
+            )),
+        SymbolParsing::Ellipsis => current.handle_ellipsis(loc)?,
     }
     Ok(None)
 }
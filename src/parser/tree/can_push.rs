@@ -2,7 +2,74 @@
 
 use super::Ast;
 use crate::errors::api::Located;
+use crate::parser::keyword::control_flow::node::ControlFlowNode;
+use crate::parser::keyword::control_flow::traits::ControlFlow as _;
 use crate::parser::literal::Attribute;
+use crate::parser::operators::api::{Binary, Ternary, Unary};
+use crate::parser::symbols::api::{BracedBlock, Cast, CompoundLiteral, ListInitialiser};
+use crate::parser::variable::api::{AttributeVariable, PureType as _};
+
+impl Ast {
+    /// Wrapper for [`CanPush`] with additional
+    /// context
+    #[must_use]
+    pub(crate) fn can_push_leaf_with_ctx(&self, ctx: AstPushContext) -> bool {
+        #[cfg(feature = "debug")]
+        crate::lgp!("Can push leaf in {self} with ctx {ctx:?}");
+        match self {
+            Self::Empty
+            | Self::Cast(Cast { full: true, .. })
+            | Self::Ternary(Ternary { failure: None, .. }) => true,
+            Self::Variable(var) =>
+                ctx.is_user_variable()
+                    || var.can_push_leaf()
+                    || var
+                        .as_attribute_variable()
+                        .and_then(AttributeVariable::last_value)
+                        .is_some_and(|value| value.can_push_leaf_with_ctx(ctx)),
+            // a type in parenthesis is a cast, like `(int)` in `x = (int)1`, or a
+            // compound literal, like `(int[])` in `x = (int[]){1}`
+            Self::ParensBlock(parens) => match ctx {
+                AstPushContext::Else => false,
+                AstPushContext::ListInitialiser => parens.type_name().is_some(),
+                AstPushContext::Any | AstPushContext::None | AstPushContext::UserVariable =>
+                    parens.is_pure_type(),
+            },
+            Self::Leaf(_) | Self::FunctionCall(_) | Self::Generic(_) => false,
+            Self::Cast(Cast { full: false, value: arg, .. })
+            | Self::Unary(Unary { arg, .. })
+            | Self::Binary(Binary { arg_r: arg, .. })
+            | Self::Ternary(Ternary { failure: Some((_, arg)), .. }) =>
+                arg.can_push_leaf_with_ctx(ctx),
+            Self::FunctionArgsBuild(vec, ..) => vec
+                .last()
+                .is_none_or(|child| child.can_push_leaf_with_ctx(ctx)),
+            Self::BracedBlock(BracedBlock { elts: vec, full, .. })
+            | Self::ListInitialiser(ListInitialiser { full, elts: vec, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full, elts: vec, .. },
+                ..
+            }) =>
+                !*full
+                    && vec
+                        .last()
+                        .is_none_or(|child| child.can_push_leaf_with_ctx(ctx)),
+            // Full not complete because: `if (0) 1; else 2;`
+            Self::ControlFlow(ctrl) if ctx.is_else() => {
+                if let ControlFlowNode::Condition(cond) = ctrl
+                    && cond.no_else()
+                {
+                    true
+                } else {
+                    ctrl.as_ast()
+                        .is_some_and(|ast| ast.can_push_leaf_with_ctx(ctx))
+                }
+            }
+            // Complete not full because: `if (0) 1; 2;`
+            Self::ControlFlow(ctrl) => !ctrl.is_complete(),
+        }
+    }
+}
 
 impl CanPush for Ast {
     fn can_push_leaf(&self) -> bool {
@@ -19,6 +86,9 @@ pub enum AstPushContext {
     Any,
     /// Trying to see if an `else` block ca be added
     Else,
+    /// Trying to push an initialiser list, that can follow a type name in
+    /// parenthesis, like in `(int[]){1}`
+    ListInitialiser,
     /// Nothing particular
     #[default]
     None,
@@ -48,7 +118,7 @@ impl AstPushContext {
 /// # Examples
 ///
 /// When pushing an `else` keyword into an
-/// [`ControlFlowNode`](crate::parser::keyword::control_flow::node::ControlFlowNode),
+/// [`ControlFlowNode`],
 /// the latter is pushable iff the control flow is complete (not
 /// necessary full)! But when pushing a literal into a
 /// control flow, the latter is not pushable iff the control flow is full (not
@@ -7,15 +7,16 @@
 use core::mem::take;
 
 use super::Ast;
-use super::can_push::{AstPushContext, CanPush as _};
+use super::can_push::AstPushContext;
 use crate::errors::api::{ErrorLocation, Located};
-use crate::parser::keyword::control_flow::node::ControlFlowNode;
 use crate::parser::keyword::control_flow::traits::ControlFlow as _;
 use crate::parser::literal::Attribute;
 use crate::parser::modifiers::push::Push as _;
 use crate::parser::operators::api::{Binary, Ternary, Unary};
-use crate::parser::symbols::api::{BracedBlock, Cast, FunctionCall, ListInitialiser};
-use crate::parser::variable::api::{PureType as _, VariableConversion as _};
+use crate::parser::symbols::api::{
+    BracedBlock, Cast, CompoundLiteral, FunctionCall, ListInitialiser
+};
+use crate::parser::variable::api::VariableConversion as _;
 
 impl Ast {
     /// Makes a pushable braced block out of a node.
@@ -28,49 +29,6 @@ impl Ast {
         });
     }
 
-    /// Wrapper for [`CanPush`](super::can_push::CanPush) with additional
-    /// context
-    #[must_use]
-    pub(crate) fn can_push_leaf_with_ctx(&self, ctx: AstPushContext) -> bool {
-        #[cfg(feature = "debug")]
-        crate::lgp!("Can push leaf in {self} with ctx {ctx:?}");
-        match self {
-            Self::Empty
-            | Self::Cast(Cast { full: true, .. })
-            | Self::Ternary(Ternary { failure: None, .. }) => true,
-            Self::Variable(var) => ctx.is_user_variable() || var.can_push_leaf(),
-            Self::ParensBlock(parens) => parens.is_pure_type() && ctx.is_user_variable(),
-            Self::Leaf(_) | Self::FunctionCall(_) => false,
-            Self::Cast(Cast { full: false, value: arg, .. })
-            | Self::Unary(Unary { arg, .. })
-            | Self::Binary(Binary { arg_r: arg, .. })
-            | Self::Ternary(Ternary { failure: Some((_, arg)), .. }) =>
-                arg.can_push_leaf_with_ctx(ctx),
-            Self::FunctionArgsBuild(vec, ..) => vec
-                .last()
-                .is_none_or(|child| child.can_push_leaf_with_ctx(ctx)),
-            Self::BracedBlock(BracedBlock { elts: vec, full, .. })
-            | Self::ListInitialiser(ListInitialiser { full, elts: vec, .. }) =>
-                !*full
-                    && vec
-                        .last()
-                        .is_none_or(|child| child.can_push_leaf_with_ctx(ctx)),
-            // Full not complete because: `if (0) 1; else 2;`
-            Self::ControlFlow(ctrl) if ctx.is_else() => {
-                if let ControlFlowNode::Condition(cond) = ctrl
-                    && cond.no_else()
-                {
-                    true
-                } else {
-                    ctrl.as_ast()
-                        .is_some_and(|ast| ast.can_push_leaf_with_ctx(ctx))
-                }
-            }
-            // Complete not full because: `if (0) 1; 2;`
-            Self::ControlFlow(ctrl) => !ctrl.is_complete(),
-        }
-    }
-
     /// Creates an empty [`Ast`] inside a [`Box`] to initialise nodes
     #[must_use]
     pub fn empty_box() -> Box<Self> {
@@ -90,12 +48,16 @@ impl Ast {
             Self::ControlFlow(ctrl) => ctrl.fill(),
             Self::Cast(Cast { full, .. })
             | Self::BracedBlock(BracedBlock { full, .. })
-            | Self::ListInitialiser(ListInitialiser { full, .. }) => *full = true,
+            | Self::ListInitialiser(ListInitialiser { full, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full, .. }, ..
+            }) => *full = true,
             Self::Variable(var) => var.fill(),
             Self::FunctionCall(_)
             | Self::FunctionArgsBuild(..)
             | Self::Empty
             | Self::Leaf(_)
+            | Self::Generic(_)
             | Self::ParensBlock(_) => (),
         }
     }
@@ -131,16 +93,21 @@ impl Ast {
             Self::Binary(Binary { arg_l, arg_r, .. }) =>
                 arg_l.is_finished_expr() && arg_r.is_finished_expr(),
             Self::Cast(Cast { value, .. }) => value.is_finished_expr(),
-            Self::FunctionCall(FunctionCall { variable, function_body, arguments, .. }) =>
-                variable.is_finished_expr()
+            Self::FunctionCall(FunctionCall { callee, function_body, arguments, .. }) =>
+                callee.is_finished_expr()
                     && function_body.is_none()
                     && arguments.iter().all(Self::is_finished_expr),
-            Self::Leaf(_) | Self::ListInitialiser(_) => true,
+            Self::CompoundLiteral(_)
+            | Self::Generic(_)
+            | Self::Leaf(_)
+            | Self::ListInitialiser(_) => true,
             Self::ParensBlock(parens) => parens.as_value().is_finished_expr(),
             Self::Ternary(Ternary { failure, .. }) => failure
                 .as_ref()
                 .is_some_and(|(_, node)| node.is_finished_expr()),
-            Self::Unary(Unary { arg, .. }) => arg.is_finished_expr(),
+            Self::Unary(Unary { arg, op }) =>
+                arg.is_finished_expr()
+                    || (op.as_value().is_type_operator() && matches!(**arg, Self::ParensBlock(_))),
             Self::Variable(var) => var.is_finished_expr(),
             Self::Empty
             | Self::BracedBlock(_)
@@ -163,20 +130,22 @@ impl Ast {
                 },
             Self::BracedBlock(bb) => bb.location,
             Self::Cast(Cast { parens_location, value, .. }) =>
-                value.location().into_extended(*parens_location),
+                if value.is_empty() {
+                    *parens_location
+                } else {
+                    value.location().into_extended(*parens_location)
+                },
+            Self::CompoundLiteral(lit) => lit.location(),
             Self::ControlFlow(ctrl) => ctrl.location(),
             Self::Empty => unreachable!("this is nonsensical"),
-            Self::FunctionArgsBuild(args, start_location, comma_location) => start_location
-                .into_extended(
-                    if let Some(last) = args.last()
-                        && !last.is_empty()
-                    {
-                        last.location()
-                    } else {
-                        *comma_location
-                    },
-                ),
+            Self::FunctionArgsBuild(args, start_location, comma_location, variadic) =>
+                start_location.into_extended(variadic.unwrap_or_else(|| {
+                    args.last()
+                        .filter(|last| !last.is_empty())
+                        .map_or(*comma_location, Self::location)
+                })),
             Self::FunctionCall(func) => func.location(),
+            Self::Generic(generic) => generic.location,
             Self::Leaf(lit) => lit.as_location(),
             Self::ListInitialiser(list) => list.location,
             Self::ParensBlock(parens) => parens.as_location(),
@@ -203,6 +172,8 @@ impl Ast {
         if let Some(last) = vec.last_mut() {
             let ctx = if matches!(node, Self::Variable(_)) {
                 AstPushContext::UserVariable
+            } else if matches!(node, Self::ListInitialiser(_)) {
+                AstPushContext::ListInitialiser
             } else {
                 AstPushContext::None
             };
@@ -243,10 +214,13 @@ impl Ast {
                     {
                         ctrl.push_block_as_leaf(Self::BracedBlock(braced_block))?;
                     } else if let Self::Variable(var) = last_mut
-                        && let Some((keyword, name)) = var.as_partial_typedef()
+                        && let Some((keyword, name, attrs)) = var.as_partial_typedef()
                     {
-                        *last_mut =
-                            Self::ControlFlow(keyword.into_control_flow(name, Some(braced_block)));
+                        *last_mut = Self::ControlFlow(keyword.into_control_flow(
+                            name,
+                            attrs,
+                            Some(braced_block),
+                        ));
                     } else if let Self::FunctionCall(FunctionCall {
                         function_body: body @ None,
                         ..
@@ -272,14 +246,17 @@ impl Ast {
             | Self::Binary(_)
             | Self::Ternary(_)
             | Self::Variable(_)
+            | Self::Generic(_)
             | Self::ParensBlock(_)
             | Self::BracedBlock(_)
             | Self::ControlFlow(_)
             | Self::FunctionCall(_)
             | Self::ListInitialiser(_)
-            | Self::FunctionArgsBuild(..) => {
-                unreachable!("Trying to push block {braced_block} in {self}")
-            }
+            | Self::CompoundLiteral(_)
+            | Self::FunctionArgsBuild(..) =>
+                return Err(format!(
+                    "Found block {braced_block} after {self}. Perhaps a missing ';'"
+                )),
         }
         Ok(())
     }
@@ -15,9 +15,12 @@ mod default;
 mod push;
 
 use super::keyword::control_flow::node::ControlFlowNode;
+use super::keyword::generic::Generic;
 use super::literal::Literal;
 use super::operators::api::{Binary, Ternary, Unary};
-use super::symbols::api::{BracedBlock, Cast, FunctionCall, ListInitialiser, ParensBlock};
+use super::symbols::api::{
+    BracedBlock, Cast, CompoundLiteral, FunctionCall, ListInitialiser, ParensBlock
+};
 use super::variable::Variable;
 use crate::EMPTY;
 use crate::errors::api::{ErrorLocation, Located};
@@ -39,6 +42,8 @@ pub enum Ast {
     BracedBlock(BracedBlock),
     /// Cast
     Cast(Cast),
+    /// Compound literal: `(int[]){1, 2}`
+    CompoundLiteral(CompoundLiteral),
     /// Control Flow blocks
     ControlFlow(ControlFlowNode),
     /// Empty AST
@@ -46,10 +51,14 @@ pub enum Ast {
     Empty,
     /// Function arguments: `(x+y, !g(z), (a, !b)++, )`
     ///
-    /// First location is the opening parenthesis, last is the last comma.
-    FunctionArgsBuild(Vec<Self>, ErrorLocation, ErrorLocation),
+    /// First location is the opening parenthesis, second is the last comma, and
+    /// the last is the location of the ellipsis `...` ending a variadic
+    /// parameter list.
+    FunctionArgsBuild(Vec<Self>, ErrorLocation, ErrorLocation, Option<ErrorLocation>),
     /// Function call, declaration or definition.
     FunctionCall(FunctionCall),
+    /// Generic selection: `_Generic(x, int: a, default: b)`
+    Generic(Generic),
     /// Literal (constants, variables, etc.)
     Leaf(Located<Literal>),
     /// List initialiser: `{1, 2, 3, [6]=7}`
@@ -71,12 +80,14 @@ display!(
     match self {
         Self::Empty => EMPTY.fmt(f),
         Self::Cast(cast) => cast.fmt(f),
+        Self::CompoundLiteral(literal) => literal.fmt(f),
         Self::Unary(val) => val.fmt(f),
         Self::Leaf(val) => val.fmt(f),
         Self::Binary(val) => val.fmt(f),
         Self::Ternary(val) => val.fmt(f),
         Self::Variable(var) => var.fmt(f),
         Self::FunctionCall(val) => val.fmt(f),
+        Self::Generic(val) => val.fmt(f),
         Self::BracedBlock(block) => block.fmt(f),
         Self::ParensBlock(parens) => parens.fmt(f),
         Self::ControlFlow(ctrl) => ctrl.fmt(f),
@@ -2,21 +2,21 @@
 
 use core::cmp::Ordering;
 use core::fmt;
-use core::mem::take;
 
 use super::Ast;
 use super::can_push::PushAttribute;
 use crate::errors::api::Located;
 use crate::parser::keyword::control_flow::traits::ControlFlow as _;
 use crate::parser::literal::Attribute;
+use crate::parser::modifiers::list_initialiser::is_in_list_initialiser;
 use crate::parser::modifiers::push::Push;
 use crate::parser::operators::api::{
     Associativity, Binary, Operator as _, OperatorConversions, Ternary, TernaryOperator, Unary
 };
-use crate::parser::symbols::api::{BracedBlock, Cast, ListInitialiser};
+use crate::parser::symbols::api::{BracedBlock, Cast, CompoundLiteral, ListInitialiser};
 
 impl Push for Ast {
-    fn push_block_as_leaf(&mut self, ast: Self) -> Result<(), String> {
+    fn push_block_as_leaf(&mut self, mut ast: Self) -> Result<(), String> {
         #[cfg(feature = "debug")]
         crate::errors::api::Print::push_leaf(&ast, self, "ast");
         match self {
@@ -32,24 +32,21 @@ impl Push for Ast {
             }
             // previous is incomplete variable: waiting for variable name
             Self::Variable(var) => var.push_block_as_leaf(ast),
+            // type name: cast or compound literal
             Self::ParensBlock(old) => {
-                let (parens, parens_location) = take(old).into_inner();
-                if let Some(ty) = parens.into_type() {
-                    *self = Self::Cast(Cast {
-                        dest_type: ty,
-                        full: false,
-                        value: ast.into_box(),
-                        parens_location,
-                    });
-                    Ok(())
-                } else {
-                    Err(successive_literal_error("Parenthesis group", old, ast))
-                }
+                *self = Cast::parens_node_into_cast(old, &mut ast)
+                    .ok_or_else(|| successive_literal_error("Parenthesis group", &*old, &ast))?;
+                Ok(())
             }
             Self::Leaf(old) => Err(successive_literal_error("Literal", old, ast)),
             Self::FunctionCall(_) => Err(successive_literal_error("Function call", self, ast)),
+            Self::Generic(_) => Err(successive_literal_error("Generic selection", self, ast)),
             Self::ListInitialiser(ListInitialiser { full: true, .. }) =>
                 Err(successive_literal_error("List initialiser", self, ast)),
+            Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: true, .. },
+                ..
+            }) => Err(successive_literal_error("Compound literal", self, ast)),
             Self::Unary(Unary { arg, .. })
             | Self::Binary(Binary { arg_r: arg, .. })
             | Self::Ternary(
@@ -57,6 +54,10 @@ impl Push for Ast {
             ) => arg.push_block_as_leaf(ast),
             Self::FunctionArgsBuild(vec, ..)
             | Self::ListInitialiser(ListInitialiser { elts: vec, full: false, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { elts: vec, full: false, .. },
+                ..
+            })
             | Self::BracedBlock(BracedBlock { elts: vec, full: false, .. }) =>
                 (Self::push_block_as_leaf_in_vec(vec, ast)?).map_or(Ok(()), |err_node| {
                     Err(successive_literal_error("block", self, err_node))
@@ -81,12 +82,21 @@ impl Push for Ast {
     {
         #[cfg(feature = "debug")]
         crate::errors::api::Print::push_op(&op, self, "ast");
+        // operator inside an initialiser list, like `=` in `x + (int[]){[1] = 2}`
+        if let Self::Cast(Cast { value: arg, .. })
+        | Self::Unary(Unary { arg, .. })
+        | Self::Binary(Binary { arg_r: arg, .. })
+        | Self::Ternary(Ternary { failure: Some((_, arg)), .. }) = self
+            && is_in_list_initialiser(arg)
+        {
+            return arg.push_op(op);
+        }
         match self {
             Self::Empty => op.try_convert_and_erase_node(self),
             Self::Variable(var) => {
                 if !var.is_full()
-                    && !op.is_array_subscript()
                     && let Some(attr) = var.as_attribute_variable_mut()
+                    && (!op.is_array_subscript() || attr.has_value())
                 {
                     attr.push_op(op)
                 } else {
@@ -106,8 +116,12 @@ impl Push for Ast {
             // self is a non-modifiable block: Op -> Self
             Self::Leaf(_)
             | Self::FunctionCall(_)
-            | Self::ListInitialiser(ListInitialiser { full: true, .. }) =>
-                op.try_push_op_as_root(self),
+            | Self::Generic(_)
+            | Self::ListInitialiser(ListInitialiser { full: true, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { full: true, .. },
+                ..
+            }) => op.try_push_op_as_root(self),
             // full block: make space: Self = [Self, Empty]
             Self::BracedBlock(BracedBlock { full: true, .. }) => {
                 self.brace();
@@ -115,8 +129,7 @@ impl Push for Ast {
             }
             // pushable list: self.last.push_op(op)
             Self::FunctionArgsBuild(vec, ..)
-            | Self::BracedBlock(BracedBlock { elts: vec, full: false, .. })
-            | Self::ListInitialiser(ListInitialiser { elts: vec, full: false, .. }) => {
+            | Self::BracedBlock(BracedBlock { elts: vec, full: false, .. }) => {
                 if let Some(last) = vec.last_mut() {
                     last.push_op(op)
                 } else {
@@ -124,6 +137,20 @@ impl Push for Ast {
                     Ok(())
                 }
             }
+            // new element of a list: can start with a designator, like `[2]` in `{[2] = 1}`
+            Self::ListInitialiser(ListInitialiser { elts: vec, full: false, .. })
+            | Self::CompoundLiteral(CompoundLiteral {
+                value: ListInitialiser { elts: vec, full: false, .. },
+                ..
+            }) => push_op_in_list(vec, op),
+            // `sizeof(int)` is complete: the parenthesis can't start a cast
+            Self::Unary(Unary { op: old_op, arg })
+                if old_op.as_value().is_type_operator()
+                    && matches!(**arg, Self::ParensBlock(_)) =>
+                match old_op.precedence().cmp(&op.precedence()) {
+                    Ordering::Less => op.try_push_op_as_root(self),
+                    Ordering::Greater | Ordering::Equal => op.try_push_op_as_root(arg),
+                },
             Self::Unary(Unary { op: old_op, arg }) => {
                 match old_op.precedence().cmp(&op.precedence()) {
                     Ordering::Less =>
@@ -131,7 +158,10 @@ impl Push for Ast {
                             op.try_push_op_as_root(self)
                         } else if op.is_binary() {
                             // * in ~*a shouldn't be considered a binary op
-                            Err(String::new())
+                            Err(format!(
+                                "Found binary operator after '{}', but its operand isn't an expression.",
+                                old_op.as_value()
+                            ))
                         } else {
                             arg.push_op(op)
                         },
@@ -189,13 +219,38 @@ impl PushAttribute for Ast {
             Self::Cast(_) => make_error("Casts"),
             Self::FunctionArgsBuild(..) => make_error("Functions arguments"),
             Self::FunctionCall(_) => make_error("Functions"),
+            Self::Generic(_) => make_error("Generic selections"),
             Self::ListInitialiser(_) => make_error("List initialisers"),
+            Self::CompoundLiteral(_) => make_error("Compound literals"),
             Self::BracedBlock(_) => make_error("Blocks"),
             Self::ControlFlow(_) => make_error("Control flow keywords"),
         }
     }
 }
 
+/// Pushes an operator into the elements of an initialiser list.
+///
+/// The operator is pushed into the last element, unless it is empty, in which
+/// case the operator starts a designator, like `[2]` in `{[2] = 1}`.
+fn push_op_in_list<T>(vec: &mut Vec<Ast>, op: T) -> Result<(), String>
+where
+    T: OperatorConversions + fmt::Display,
+{
+    if let Some(last) = vec.last_mut()
+        && !last.is_empty()
+    {
+        last.push_op(op)
+    } else {
+        let node = op.try_to_designator()?;
+        if let Some(last) = vec.last_mut() {
+            *last = node;
+        } else {
+            vec.push(node);
+        }
+        Ok(())
+    }
+}
+
 /// Makes an error [`String`] for consecutive literals.
 ///
 /// If two consecutive literals are found, the [`crate::parser`] fails, and this
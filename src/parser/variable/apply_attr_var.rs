@@ -4,25 +4,33 @@ use core::fmt;
 use core::mem::take;
 
 use crate::Ast;
-use crate::errors::api::{ErrorLocation, Located};
+use crate::errors::api::Located;
 use crate::parser::api::{
-    Attribute, AttributeKeyword, AttributeVariable, Declaration, DeclarationValue, Literal, UserDefinedTypes
+    Attribute, AttributeKeyword, AttributeVariable, Declaration, DeclarationValue, Literal, ParameterList
 };
 use crate::parser::modifiers::functions::{CanMakeFnRes, MakeFunction};
 use crate::parser::modifiers::push::Push;
 use crate::parser::operators::api::OperatorConversions;
 use crate::parser::tree::api::CanPush;
-use crate::parser::variable::api::{PureType, VariableConversion};
+use crate::parser::variable::api::{PartialTypedef, PureType, VariableConversion};
 
 impl MakeFunction for AttributeVariable {
     fn can_make_function(&self) -> CanMakeFnRes {
         match self.declarations.last()? {
-            Some(declaration) => declaration.value.as_ref()?.can_make_function(),
+            Some(declaration) => match declaration.value.as_ref()?.can_make_function() {
+                // The declared variable is already initialised: the
+                // parenthesis belongs to the initial value, like in
+                // `long x = (long)(y + 1)`.
+                CanMakeFnRes::None => CanMakeFnRes::Operand,
+                res @ (CanMakeFnRes::CanMakeFn(_)
+                | CanMakeFnRes::Operand
+                | CanMakeFnRes::TooDeep) => res,
+            },
             None => CanMakeFnRes::None,
         }
     }
 
-    fn make_function(&mut self, depth: u32, arguments: Vec<Ast>, parens_location: ErrorLocation) {
+    fn make_function(&mut self, depth: u32, arguments: ParameterList) -> Result<(), String> {
         match self
             .declarations
             .last_mut()
@@ -32,7 +40,7 @@ impl MakeFunction for AttributeVariable {
                 .value
                 .as_mut()
                 .expect("checked with can_make_function")
-                .make_function(depth, arguments, parens_location),
+                .make_function(depth, arguments),
             None => unreachable!(),
         }
     }
@@ -95,7 +103,9 @@ impl Push for AttributeVariable {
                 DeclarationValue::Bitfield(_) =>
                     Err("Found operator after bitfield specifier but this is not allowed".into()),
                 DeclarationValue::None =>
-                    if let Some(loc) = op.as_star() {
+                    if let Some(loc) = op.as_star()
+                        && last.is_name_only()
+                    {
                         if self.declarations.len() == 1 {
                             self.attrs.push(
                                 self.declarations
@@ -131,34 +141,37 @@ impl Push for AttributeVariable {
 }
 
 impl VariableConversion for AttributeVariable {
-    fn as_partial_typedef(
-        &mut self,
-    ) -> Option<(Located<UserDefinedTypes>, Option<Located<String>>)> {
-        if self.attrs.len() == 1
-            && let Some(last) = self.attrs.last()
-            && let Attribute::Keyword(AttributeKeyword::UserDefinedTypes(user_type)) =
-                last.as_value()
+    fn as_partial_typedef(&mut self) -> Option<PartialTypedef> {
+        let (first, standard) = self.attrs.split_first()?;
+        let Attribute::Keyword(AttributeKeyword::UserDefinedTypes(user_type)) = first.as_value()
+        else {
+            return None;
+        };
+        if !standard
+            .iter()
+            .all(|attr| matches!(attr.as_value(), Attribute::Standard(_)))
         {
-            if self.declarations.is_empty() {
-                Some((last.as_ref().transfer(|_| *user_type), None))
-            } else if self.declarations.len() == 1
-                && let Some(Some(decl)) = self.declarations.last_mut()
-                && decl.value.is_none()
-            {
-                Some((last.as_ref().transfer(|_| *user_type), Some(take(&mut decl.name))))
-            } else {
-                None
-            }
-        } else {
-            None
+            return None;
         }
+        let keyword = first.as_ref().transfer(|_| *user_type);
+        let name = if self.declarations.is_empty() {
+            None
+        } else if self.declarations.len() == 1
+            && let Some(Some(decl)) = self.declarations.last_mut()
+            && decl.is_name_only()
+        {
+            Some(take(&mut decl.name))
+        } else {
+            return None;
+        };
+        Some((keyword, name, self.attrs.split_off(1)))
     }
 
     fn into_attrs(self) -> Result<Vec<Located<Attribute>>, String> {
         let mut mutable = self;
         if mutable.declarations.len() == 1
             && let Some(Some(last)) = mutable.declarations.pop()
-            && last.value.is_none()
+            && last.is_name_only()
         {
             mutable.attrs.push(last.name.transfer(Attribute::User));
         } else if !mutable.declarations.is_empty() {
@@ -179,12 +192,12 @@ impl PureType for AttributeVariable {
     fn is_pure_type(&self) -> bool {
         self.declarations
             .last()
-            .is_none_or(|opt| opt.as_ref().is_none_or(|decl| decl.value.is_none()))
+            .is_none_or(|opt| opt.as_ref().is_none_or(Declaration::is_name_only))
     }
 
     fn take_pure_type(&mut self) -> Option<Vec<Located<Attribute>>> {
         self.is_pure_type().then(|| {
-            if let Some(Some(Declaration { name, value })) = self.declarations.last_mut() {
+            if let Some(Some(Declaration { name, value, .. })) = self.declarations.last_mut() {
                 debug_assert!(value.is_none(), "checked with is_pure_type");
                 self.attrs.push(take(name).transfer(Attribute::User));
             }
@@ -1,12 +1,11 @@
 //! Traits applied to attribute variables for building it.
 
-use crate::Ast;
-use crate::errors::api::{ErrorLocation, Located};
-use crate::parser::api::{Attribute, UserDefinedTypes};
+use crate::errors::api::Located;
+use crate::parser::api::{Attribute, ParameterList};
 use crate::parser::modifiers::functions::{CanMakeFnRes, MakeFunction};
 use crate::parser::tree::api::{CanPush, PushAttribute};
 use crate::parser::variable::Variable;
-use crate::parser::variable::api::{PureType, VariableConversion};
+use crate::parser::variable::api::{PartialTypedef, PureType, VariableConversion};
 
 impl MakeFunction for Variable {
     fn can_make_function(&self) -> CanMakeFnRes {
@@ -17,8 +16,8 @@ impl MakeFunction for Variable {
         }
     }
 
-    fn make_function(&mut self, depth: u32, arguments: Vec<Ast>, parens_location: ErrorLocation) {
-        self.value.make_function(depth, arguments, parens_location);
+    fn make_function(&mut self, depth: u32, arguments: ParameterList) -> Result<(), String> {
+        self.value.make_function(depth, arguments)
     }
 }
 
@@ -52,9 +51,7 @@ impl PushAttribute for Variable {
 }
 
 impl VariableConversion for Variable {
-    fn as_partial_typedef(
-        &mut self,
-    ) -> Option<(Located<UserDefinedTypes>, Option<Located<String>>)> {
+    fn as_partial_typedef(&mut self) -> Option<PartialTypedef> {
         if self.full {
             None
         } else {
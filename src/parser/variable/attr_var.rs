@@ -20,6 +20,7 @@ use crate::utils::{display, repr_option_vec, repr_vec};
 /// const * int * volatile x = 3, y = 2;
 /// ```
 #[derive(Debug, Default)]
+#[non_exhaustive]
 pub struct AttributeVariable {
     /// attributes of the variable
     pub attrs: Vec<Located<Attribute>>,
@@ -28,26 +29,45 @@ pub struct AttributeVariable {
 }
 
 impl AttributeVariable {
-    /// Returns the unique variable in this attribute declaration, if there is
-    /// one and only one.
-    pub fn into_single_variable(mut self) -> (Option<Located<String>>, Vec<Located<Attribute>>) {
+    /// Checks if the last declaration has a value, like `int x = a`, in
+    /// which an array subscript must be pushed.
+    pub(crate) fn has_value(&self) -> bool {
+        self.declarations.last().is_some_and(|decl| {
+            decl.as_ref()
+                .is_some_and(|last| last.value.as_ref().is_some())
+        })
+    }
+
+    /// Returns the unique declaration in this attribute declaration, if there
+    /// is one and only one, and it has no value.
+    pub(crate) fn into_single_variable(mut self) -> (Option<Declaration>, Vec<Located<Attribute>>) {
         if let Some(Some(decl)) = self.declarations.pop()
             && self.declarations.is_empty()
-            && let (name, value) = decl.into_name_value()
-            && value.is_none()
+            && decl.value.is_none()
         {
-            (Some(name), self.attrs)
+            (Some(decl), self.attrs)
         } else {
             (None, self.attrs)
         }
     }
 
     /// Takes the attributes from inside self it is a type;
-    pub fn into_type(self) -> Option<Vec<Located<Attribute>>> {
+    pub(crate) fn into_type(self) -> Option<Vec<Located<Attribute>>> {
         self.declarations.is_empty().then_some(self.attrs)
     }
 
+    /// Returns the value of the last declaration, like `a` in `int x = a`.
+    pub(crate) fn last_value(&self) -> Option<&Ast> {
+        self.declarations.last()?.as_ref()?.value.as_ref()
+    }
+
+    /// Returns the value of the last declaration, like `a` in `int x = a`.
+    pub(crate) fn last_value_mut(&mut self) -> Option<&mut Ast> {
+        self.declarations.last_mut()?.as_mut()?.value.as_mut()
+    }
+
     /// Builds and returns the location of the attribute variable.
+    #[must_use]
     pub fn location(&self) -> ErrorLocation {
         let start = self.attrs.first().map_or_else(
             || {
@@ -75,10 +95,23 @@ impl AttributeVariable {
     }
 
     /// Adds an attribute to the variable
+    ///
+    /// A standard attribute, like `[[maybe_unused]]`, can also follow the
+    /// name of a declaration.
     pub fn push_attr(&mut self, attr: Located<Attribute>) -> Result<(), String> {
-        if self.declarations.len() <= 1 {
+        if let Attribute::Standard(_) = attr.as_value() {
+            if self
+                .declarations
+                .last()
+                .and_then(Option::as_ref)
+                .is_some_and(|decl| !decl.value.is_none())
+            {
+                return Err("Unexpected attribute: found after the value of a variable".to_owned());
+            }
+            self.attrs.push(attr);
+        } else if self.declarations.len() <= 1 {
             if let Some(Some(last)) = self.declarations.pop() {
-                if last.value.is_none() {
+                if last.is_name_only() {
                     self.attrs.push(last.name.transfer(Attribute::User));
                 } else {
                     return Err("Unexpected attribute: not in variable type".to_owned());
@@ -111,7 +144,7 @@ impl AttributeVariable {
     }
 
     /// Pushes a name into an [`AttributeVariable`]
-    pub fn push_name(&mut self, name: Located<String>) -> Result<(), String> {
+    pub(crate) fn push_name(&mut self, name: Located<String>) -> Result<(), String> {
         if self.declarations.is_empty() {
             self.declarations.push(Some(Declaration::from(name)));
             Ok(())
@@ -119,6 +152,10 @@ impl AttributeVariable {
             let last = self.declarations.last_mut().expect("len = 1");
             if let Some(decl) = last {
                 match &mut decl.value {
+                    DeclarationValue::None if !decl.arrays.is_empty() =>
+                        Err("Found unexpected identifier after array declarator".into()),
+                    DeclarationValue::None if decl.parens.is_some() =>
+                        Err("Found unexpected identifier after declarator in parenthesis".into()),
                     DeclarationValue::None => {
                         self.attrs
                             .push(take(&mut decl.name).transfer(Attribute::User));
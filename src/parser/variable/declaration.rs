@@ -3,52 +3,141 @@
 
 use crate::Number;
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::keyword::attributes::AttributeKeyword;
 use crate::parser::tree::api::Ast;
-use crate::utils::{display, repr_option};
+use crate::parser::variable::api::ParensDeclarator;
+use crate::utils::{display, repr_option, repr_vec};
+
+/// Array declarator, following the name of a declaration
+///
+/// # Examples
+///
+/// `[10]` in `int a[10]`, or `[static const 3]` in `void f(int a[static const
+/// 3])`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ArrayDeclarator {
+    /// Location of the brackets.
+    pub location: ErrorLocation,
+    /// `static` and type qualifiers, only valid in function parameters.
+    pub qualifiers: Vec<Located<AttributeKeyword>>,
+    /// Length of the array.
+    pub size: ArraySize,
+}
+
+display!(ArrayDeclarator, self, f, {
+    '['.fmt(f)?;
+    repr_vec(&self.qualifiers, " ").fmt(f)?;
+    match &self.size {
+        ArraySize::Expression(size) if self.qualifiers.is_empty() => size.fmt(f)?,
+        ArraySize::Expression(size) => write!(f, " {size}")?,
+        ArraySize::Incomplete => (),
+        ArraySize::Unspecified => '*'.fmt(f)?,
+    }
+    ']'.fmt(f)
+});
+
+/// Length of an array, written between its brackets.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ArraySize {
+    /// Length given by an expression, like `[10]` or `[n + 1]`.
+    Expression(Ast),
+    /// No length, like `[]`: the array is incomplete.
+    Incomplete,
+    /// Variable length of unspecified size, like `[*]`, only valid in function
+    /// prototypes.
+    Unspecified,
+}
 
 /// Declaration of one variable
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct Declaration {
+    /// Array declarators following the name, like `[2][3]` in `int a[2][3]`.
+    pub arrays: Vec<ArrayDeclarator>,
     /// Name of the variable.
     pub name: Located<String>,
+    /// Declarator in parenthesis around the name, like `(*cb)(int)` in
+    /// `int (*cb)(int)`.
+    ///
+    /// The array declarators inside the parenthesis, like `[3]` in
+    /// `int (*a[3])(int)`, are in [`Self::arrays`].
+    pub parens: Option<ParensDeclarator>,
     /// Expression to define the value of the variable.
     pub value: DeclarationValue,
 }
 
 impl Declaration {
     /// Returns name and value of the declaration.
+    #[must_use]
     pub fn into_name_value(self) -> (Located<String>, DeclarationValue) {
         (self.name, self.value)
     }
 
+    /// Checks if the declaration only contains its name, without array
+    /// declarators, declarator in parenthesis or value.
+    #[must_use]
+    pub const fn is_name_only(&self) -> bool {
+        self.arrays.is_empty() && self.parens.is_none() && self.value.is_none()
+    }
+
     /// Returns the whole location of the declaration, from variable name to
     /// value.
+    #[must_use]
     pub fn location(&self) -> ErrorLocation {
+        let declarator = self.parens.as_ref().map_or_else(
+            || {
+                self.arrays.last().map_or_else(
+                    || self.name.as_location(),
+                    |array| array.location.into_extended(self.name.as_location()),
+                )
+            },
+            ParensDeclarator::location,
+        );
         match &self.value {
             DeclarationValue::Bitfield(size) => size.as_location(),
-            DeclarationValue::None => self.name.as_location(),
-            DeclarationValue::Value(ast) => ast.location().into_extended(self.name.as_location()),
+            DeclarationValue::None | DeclarationValue::Value(Ast::Empty) => declarator,
+            DeclarationValue::Value(ast) => ast.location().into_extended(declarator),
+        }
+    }
+
+    /// Pushes an array declarator, after the name or after the declarator in
+    /// parenthesis, like `[3]` in `int (*p)[3]`.
+    pub(crate) fn push_array(&mut self, array: ArrayDeclarator) -> Result<(), String> {
+        match &mut self.parens {
+            Some(ParensDeclarator { parameters: Some(_), .. }) =>
+                Err("Found function returning an array, but this is not allowed.".to_owned()),
+            Some(parens) => {
+                parens.arrays.push(array);
+                Ok(())
+            }
+            None => {
+                self.arrays.push(array);
+                Ok(())
+            }
         }
     }
 }
 
 impl From<Located<String>> for Declaration {
     fn from(name: Located<String>) -> Self {
-        Self { name, value: DeclarationValue::None }
+        Self { arrays: vec![], name, parens: None, value: DeclarationValue::None }
     }
 }
 
-display!(
-    Declaration,
-    self,
-    f,
+display!(Declaration, self, f, {
+    let mut declarator = format!("{}{}", self.name, repr_vec(&self.arrays, ""));
+    if let Some(parens) = &self.parens {
+        declarator = parens.around(&declarator);
+    }
     match &self.value {
-        DeclarationValue::Value(value) => write!(f, "({} = {})", self.name, value),
-        DeclarationValue::None => self.name.fmt(f),
+        DeclarationValue::Value(value) => write!(f, "({declarator} = {value})"),
+        DeclarationValue::None => declarator.fmt(f),
         DeclarationValue::Bitfield(size) =>
             write!(f, "({}:{})", self.name, repr_option(size.as_value())),
     }
-);
+});
 
 /// Value of the declaration
 ///
@@ -13,9 +13,10 @@ pub mod api {
     #![allow(clippy::pub_use, reason = "expose simple API")]
 
     pub use super::attr_var::AttributeVariable;
-    pub use super::declaration::{Declaration, DeclarationValue};
+    pub use super::declaration::{ArrayDeclarator, ArraySize, Declaration, DeclarationValue};
     pub use super::name::VariableName;
-    pub use super::traits::{PureType, VariableConversion};
+    pub use super::parens_declarator::{ParameterList, ParensDeclarator};
+    pub use super::traits::{PartialTypedef, PureType, VariableConversion};
     pub use super::value::VariableValue;
 }
 
@@ -24,6 +25,7 @@ mod apply_var;
 mod attr_var;
 mod declaration;
 mod name;
+mod parens_declarator;
 mod traits;
 mod value;
 
@@ -36,10 +38,11 @@ use value::VariableValue;
 
 use super::keyword::attributes::AttributeKeyword;
 use super::keyword::functions::FunctionKeyword;
-use super::literal::Attribute;
+use super::literal::{Attribute, StandardAttribute};
 use super::modifiers::push::Push as _;
 use super::tree::api::Ast;
 use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{Visit, VisitMut, Walk};
 use crate::parser::keyword::control_flow::types::colon_ast::ColonAstCtrl;
 use crate::utils::display;
 
@@ -53,6 +56,17 @@ pub struct Variable {
 }
 
 impl Variable {
+    /// Returns the variable as an attribute value if it is not a lone variable
+    /// name.
+    #[must_use]
+    pub const fn as_attribute_variable(&self) -> Option<&AttributeVariable> {
+        if let VariableValue::AttributeVariable(attribute_variable) = &self.value {
+            Some(attribute_variable)
+        } else {
+            None
+        }
+    }
+
     /// Returns the variable as an attribute value if it is not a lone variable
     /// name.
     pub const fn as_attribute_variable_mut(&mut self) -> Option<&mut AttributeVariable> {
@@ -63,6 +77,27 @@ impl Variable {
         }
     }
 
+    /// Returns the keyword if the variable is a lone function keyword.
+    #[must_use]
+    pub const fn as_function_keyword(&self) -> Option<&FunctionKeyword> {
+        if let VariableValue::VariableName(_, VariableName::Keyword(keyword)) = &self.value {
+            Some(keyword)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the variable if it is a lone user-defined variable
+    /// name.
+    #[must_use]
+    pub fn as_user_defined_name(&self) -> Option<&str> {
+        if let VariableValue::VariableName(_, VariableName::UserDefined(name)) = &self.value {
+            Some(name)
+        } else {
+            None
+        }
+    }
+
     /// Merges a [`Variable`] with another [`Variable`] and returns the result.
     pub fn extend(&mut self, other: Self) -> Result<(), String> {
         if self.full {
@@ -83,12 +118,12 @@ impl Variable {
     }
 
     /// Checks if the variable contains attributes
-    pub const fn has_empty_attrs(&self) -> bool {
+    pub(crate) const fn has_empty_attrs(&self) -> bool {
         self.value.has_empty_attrs()
     }
 
     /// Takes the attributes from inside self it is a type;
-    pub fn into_type(self) -> Option<Vec<Located<Attribute>>> {
+    pub(crate) fn into_type(self) -> Option<Vec<Located<Attribute>>> {
         match self.value {
             VariableValue::AttributeVariable(attr) => attr.into_type(),
             VariableValue::VariableName(..) => None,
@@ -101,26 +136,28 @@ impl Variable {
     }
 
     /// Returns the value of the variable.
-    pub fn into_value(self) -> VariableValue {
+    pub(crate) fn into_value(self) -> VariableValue {
         self.value
     }
 
     /// Checks if the variable is a user defined variable
+    #[must_use]
     pub const fn is_declaration(&self) -> bool {
         matches!(self.value, VariableValue::AttributeVariable(_))
     }
 
     /// Checks if an [`Ast`] is valid as a whole and represents an expression.
-    pub const fn is_finished_expr(&self) -> bool {
+    pub(crate) const fn is_finished_expr(&self) -> bool {
         matches!(self.value, VariableValue::VariableName(_, VariableName::UserDefined(_)))
     }
 
     /// Checks if the variable is full
-    pub const fn is_full(&self) -> bool {
+    pub(crate) const fn is_full(&self) -> bool {
         self.full
     }
 
     /// Returns the location of the entire variable.
+    #[must_use]
     pub fn location(&self) -> ErrorLocation {
         match &self.value {
             VariableValue::AttributeVariable(attr) => attr.location(),
@@ -148,9 +185,8 @@ impl Variable {
         } else {
             match &mut self.value {
                 VariableValue::AttributeVariable(decl) => decl.push_block_as_leaf(ast),
-                VariableValue::VariableName(_, name) => {
-                    unreachable!("tried to push block {ast} on non-declaration variable {name}")
-                }
+                VariableValue::VariableName(_, name) =>
+                    Err(format!("Found {ast} after variable {name}. Perhaps a missing ';'")),
             }
         }
     }
@@ -180,9 +216,9 @@ impl Variable {
         self.push_attr(keyword.transfer(Attribute::Keyword))
     }
 
-    /// Takes the value of `self` and puts a placeholder in its place.
-    pub fn take(&mut self) -> Self {
-        Self { full: self.full, value: self.value.take() }
+    /// Adds a standard attribute, like `[[nodiscard]]`, to the variable
+    pub fn push_standard_attr(&mut self, attr: Located<StandardAttribute>) -> Result<(), String> {
+        self.push_attr(attr.transfer(Attribute::Standard))
     }
 
     /// Tries transforming the [`Self`] into a user defined variable name.
@@ -210,6 +246,18 @@ impl From<Located<FunctionKeyword>> for Variable {
     }
 }
 
+impl From<Located<StandardAttribute>> for Variable {
+    fn from(value: Located<StandardAttribute>) -> Self {
+        Self {
+            full: false,
+            value: VariableValue::AttributeVariable(AttributeVariable {
+                attrs: vec![value.transfer(Attribute::Standard)],
+                declarations: vec![],
+            }),
+        }
+    }
+}
+
 impl From<Located<String>> for Variable {
     fn from(value: Located<String>) -> Self {
         let (inner, loc) = value.into_inner();
@@ -220,6 +268,28 @@ impl From<Located<String>> for Variable {
     }
 }
 
+impl Walk for Variable {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        match &self.value {
+            VariableValue::AttributeVariable(var) => visitor.visit_attribute_variable(var),
+            VariableValue::VariableName(location, name) => {
+                visitor.visit_variable_name(name, *location);
+                visitor.visit_location(*location);
+            }
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        match &mut self.value {
+            VariableValue::AttributeVariable(var) => visitor.visit_attribute_variable_mut(var),
+            VariableValue::VariableName(location, name) => {
+                visitor.visit_variable_name_mut(name, *location);
+                visitor.visit_location_mut(location);
+            }
+        }
+    }
+}
+
 display!(Variable, self, f, self.value.fmt(f));
 
 /// Makes an error for values found after a [`FunctionKeyword`].
@@ -7,6 +7,7 @@ use crate::utils::display;
 
 /// Variable name
 #[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum VariableName {
     /// Function keyword, like `sizeof` or `alignof`
     Keyword(FunctionKeyword),
@@ -20,7 +21,7 @@ impl VariableName {
     /// # Panics
     ///
     /// If called on a [`FunctionKeyword`]
-    pub fn into_attr(self) -> Attribute {
+    pub(crate) fn into_attr(self) -> Attribute {
         match self {
             Self::UserDefined(name) => Attribute::User(name),
             Self::Keyword(_) => unreachable!("called on invalid attribute"),
@@ -0,0 +1,293 @@
+//! Module implementation for the declarators in parenthesis, like `(*cb)` in
+//! `int (*cb)(int, char *)`.
+
+use core::mem::take;
+
+use super::Variable;
+use super::attr_var::AttributeVariable;
+use super::name::VariableName;
+use super::value::VariableValue;
+use crate::errors::api::{ErrorLocation, Located};
+use crate::parser::api::{
+    ArrayDeclarator, ArraySize, Attribute, AttributeKeyword, Declaration, DeclarationValue, SpecialAttributes
+};
+use crate::parser::operators::api::{Binary, BinaryOperator, Unary, UnaryOperator};
+use crate::parser::tree::api::Ast;
+use crate::utils::{display, repr_vec};
+
+/// Parameter list of a function declarator
+///
+/// # Examples
+///
+/// `(int, char *)` in `int (*cb)(int, char *)`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ParameterList {
+    /// Location of the parenthesis.
+    pub location: ErrorLocation,
+    /// Declarations of the parameters.
+    pub parameters: Vec<Ast>,
+    /// Location of the ellipsis `...` ending the list of a variadic function.
+    pub variadic: Option<ErrorLocation>,
+}
+
+display!(ParameterList, self, f, {
+    write!(f, "({}", repr_vec(&self.parameters, ", "))?;
+    match (self.variadic, self.parameters.is_empty()) {
+        (Some(_), true) => "...".fmt(f)?,
+        (Some(_), false) => ", ...".fmt(f)?,
+        (None, _) => (),
+    }
+    ')'.fmt(f)
+});
+
+/// Declarator in parenthesis, around the name of a declaration
+///
+/// The parenthesis change the order in which the declarators apply: the
+/// pointers inside them apply after the array and function declarators that
+/// follow them.
+///
+/// # Examples
+///
+/// `(*cb)(int)` in `int (*cb)(int)`, or `(*p)[3]` in `int (*p)[3]`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ParensDeclarator {
+    /// Array declarators following the parenthesis, like `[3]` in
+    /// `int (*p)[3]`.
+    pub arrays: Vec<ArrayDeclarator>,
+    /// Location of the parenthesis.
+    pub location: ErrorLocation,
+    /// Parameters following the parenthesis, if the declarator is a function.
+    pub parameters: Option<ParameterList>,
+    /// Pointers in the parenthesis, with their qualifiers, like `* const` in
+    /// `int (* const cb)(int)`.
+    pub pointers: Vec<Located<Attribute>>,
+}
+
+impl ParensDeclarator {
+    /// Returns the representation of the declarator around the given `inner`
+    /// declarator, like `(*cb)(int)` around `cb`.
+    pub(super) fn around(&self, inner: &str) -> String {
+        let mut repr = "(".to_owned();
+        for pointer in &self.pointers {
+            if matches!(pointer.as_value(), Attribute::Indirection) {
+                repr.push('*');
+            } else {
+                repr.push_str(&pointer.to_string());
+                repr.push(' ');
+            }
+        }
+        repr.push_str(inner);
+        repr.push(')');
+        repr.push_str(&repr_vec(&self.arrays, ""));
+        if let Some(parameters) = &self.parameters {
+            repr.push_str(&parameters.to_string());
+        }
+        repr
+    }
+
+    /// Returns the location of the whole declarator, from the opening
+    /// parenthesis to the last parameter list or array declarator.
+    #[must_use]
+    pub fn location(&self) -> ErrorLocation {
+        let end = self.parameters.as_ref().map_or_else(
+            || {
+                self.arrays
+                    .last()
+                    .map_or(self.location, |array| array.location)
+            },
+            |parameters| parameters.location,
+        );
+        self.location.into_extended(end)
+    }
+}
+
+impl AttributeVariable {
+    /// Checks if a parenthesis block is a declarator or the parameters of a
+    /// declarator in parenthesis, and not the parameters of a function.
+    ///
+    /// This is the case right after the type, like in `int (*cb)`, and after
+    /// a declarator in parenthesis, like in `int (*cb)(int)`.
+    pub(crate) fn expects_parens_declarator(&self) -> bool {
+        match self.declarations.last() {
+            None | Some(None) => !self.attrs.last().is_some_and(|attr| {
+                matches!(
+                    attr.as_value(),
+                    Attribute::Keyword(AttributeKeyword::SpecialAttributes(
+                        SpecialAttributes::Alignas
+                            | SpecialAttributes::Atomic
+                            | SpecialAttributes::Typeof
+                            | SpecialAttributes::TypeofUnqual
+                    ))
+                )
+            }),
+            Some(Some(decl)) => decl.parens.is_some() && decl.value.is_none(),
+        }
+    }
+
+    /// Pushes a parenthesis block, as a declarator or as the parameters of a
+    /// declarator in parenthesis.
+    ///
+    /// See [`Self::expects_parens_declarator`].
+    pub(crate) fn push_parens_declarator(
+        &mut self,
+        mut content: Vec<Ast>,
+        variadic: Option<ErrorLocation>,
+        location: ErrorLocation,
+    ) -> Result<(), String> {
+        let last = self.declarations.last_mut();
+        if let Some(Some(decl)) = last {
+            let parens = decl.parens.as_mut().expect("checked by caller");
+            if parens.parameters.is_some() {
+                return Err("Found function returning a function, but this is not allowed.".into());
+            }
+            if !parens.arrays.is_empty() {
+                return Err("Found array of functions, but this is not allowed.".into());
+            }
+            parens.parameters = Some(ParameterList { location, parameters: content, variadic });
+            return Ok(());
+        }
+        let inner = content
+            .pop()
+            .filter(|_| content.is_empty() && variadic.is_none())
+            .ok_or("Expected exactly one declarator in parenthesis.")?;
+        let mut pointers = vec![];
+        let (name, arrays) = split_inner_declarator(inner, &mut pointers)?;
+        let parens = ParensDeclarator { arrays: vec![], location, parameters: None, pointers };
+        let decl =
+            Declaration { arrays, name, parens: Some(parens), value: DeclarationValue::None };
+        if let Some(pending @ None) = self.declarations.last_mut() {
+            *pending = Some(decl);
+        } else {
+            self.declarations.push(Some(decl));
+        }
+        Ok(())
+    }
+
+    /// Pushes a parenthesis block in a typedef, as a declarator, like
+    /// `(*handler)` in `typedef void (*handler)(int);`, or as the parameters of
+    /// a function type, like `(int)` in the same typedef or in `typedef int
+    /// F(int);`.
+    pub(crate) fn push_typedef_parameters(
+        &mut self,
+        arguments: ParameterList,
+    ) -> Result<(), String> {
+        if self.expects_parens_declarator() {
+            let ParameterList { location, parameters, variadic } = arguments;
+            return self.push_parens_declarator(parameters, variadic, location);
+        }
+        match self.declarations.last_mut() {
+            Some(Some(decl)) if decl.is_name_only() => {
+                decl.parens = Some(ParensDeclarator {
+                    arrays: vec![],
+                    location: decl.name.as_location(),
+                    parameters: Some(arguments),
+                    pointers: vec![],
+                });
+                Ok(())
+            }
+            Some(_) | None => Err("Expected a name before the parameters in typedef.".to_owned()),
+        }
+    }
+}
+
+impl Variable {
+    /// Pushes a parenthesis block in a typedef.
+    ///
+    /// A lone name before it is the aliased type, like `handler` in `typedef
+    /// handler (*table)[4];`.
+    ///
+    /// See [`AttributeVariable::push_typedef_parameters`].
+    pub(crate) fn push_typedef_parameters(
+        &mut self,
+        arguments: ParameterList,
+    ) -> Result<(), String> {
+        if let VariableValue::VariableName(loc, VariableName::UserDefined(name)) = &mut self.value {
+            let attr = loc.wrap(Attribute::User(take(name)));
+            self.value = VariableValue::AttributeVariable(AttributeVariable {
+                attrs: vec![attr],
+                declarations: vec![],
+            });
+        }
+        self.as_attribute_variable_mut()
+            .ok_or("Expected type before parenthesis in typedef.")?
+            .push_typedef_parameters(arguments)
+    }
+}
+
+/// Splits the content of the parenthesis of a declarator into its name and
+/// its array declarators, and pushes its pointers and their qualifiers into
+/// `pointers`.
+fn split_inner_declarator(
+    inner: Ast,
+    pointers: &mut Vec<Located<Attribute>>,
+) -> Result<(Located<String>, Vec<ArrayDeclarator>), String> {
+    match inner {
+        Ast::Unary(Unary { op, arg }) if *op.as_value() == UnaryOperator::Indirection => {
+            pointers.push(op.as_location().wrap(Attribute::Indirection));
+            split_inner_declarator(*arg, pointers)
+        }
+        Ast::Binary(Binary { op, arg_l, arg_r })
+            if *op.as_value() == BinaryOperator::ArraySubscript =>
+        {
+            let (name, mut arrays) = split_inner_declarator(*arg_l, pointers)?;
+            let size = if arg_r.is_empty() {
+                ArraySize::Incomplete
+            } else {
+                ArraySize::Expression(*arg_r)
+            };
+            arrays.push(ArrayDeclarator { location: op.as_location(), qualifiers: vec![], size });
+            Ok((name, arrays))
+        }
+        Ast::Variable(Variable { value, .. }) => match value {
+            VariableValue::VariableName(loc, VariableName::UserDefined(name)) =>
+                Ok((loc.wrap(name), vec![])),
+            VariableValue::AttributeVariable(AttributeVariable { attrs, mut declarations }) => {
+                if let Some(attr) = attrs.iter().find(|attr| {
+                    !matches!(
+                        attr.as_value(),
+                        Attribute::Indirection
+                            | Attribute::Keyword(
+                                AttributeKeyword::Qualifiers(_)
+                                    | AttributeKeyword::SpecialAttributes(
+                                        SpecialAttributes::Restrict
+                                    )
+                            )
+                    )
+                }) {
+                    return Err(format!(
+                        "Expected pointer or qualifier in declarator, but found {attr}."
+                    ));
+                }
+                pointers.extend(attrs);
+                match declarations.pop() {
+                    Some(Some(decl))
+                        if declarations.is_empty()
+                            && decl.parens.is_none()
+                            && decl.value.is_none() =>
+                        Ok((decl.name, decl.arrays)),
+                    Some(_) | None => Err("Expected a name in declarator in parenthesis.".into()),
+                }
+            }
+            VariableValue::VariableName(_, VariableName::Keyword(kwd)) =>
+                Err(format!("Expected a name in declarator in parenthesis, but found {kwd}.")),
+        },
+        Ast::Binary(_)
+        | Ast::BracedBlock(_)
+        | Ast::Cast(_)
+        | Ast::CompoundLiteral(_)
+        | Ast::ControlFlow(_)
+        | Ast::Empty
+        | Ast::FunctionArgsBuild(..)
+        | Ast::FunctionCall(_)
+        | Ast::Generic(_)
+        | Ast::Leaf(_)
+        | Ast::ListInitialiser(_)
+        | Ast::ParensBlock(_)
+        | Ast::Ternary(_)
+        | Ast::Unary(_) => Err(format!(
+            "Expected a name with pointers and arrays in declarator in parenthesis, but found {inner}."
+        )),
+    }
+}
@@ -5,6 +5,12 @@ use crate::errors::api::Located;
 use crate::parser::keyword::attributes::UserDefinedTypes;
 use crate::parser::literal::Attribute;
 
+/// Keyword, name and attributes of a user-defined type that turns out to be
+/// defined, like `struct`, `S` and `[[deprecated]]` in
+/// `struct [[deprecated]] S {}`.
+pub type PartialTypedef =
+    (Located<UserDefinedTypes>, Option<Located<String>>, Vec<Located<Attribute>>);
+
 /// Methods for *pure type*
 ///
 /// A *pure type* is a variable declaration that has a type but no variable
@@ -38,9 +44,7 @@ pub trait VariableConversion {
     /// `struct Name` is parsed as a variable attributes `struct` and `Name` and
     /// is waiting for the variable name. But if the next token is block, like
     /// in `struct Name {}`, it is meant as a control flow to define the type.
-    fn as_partial_typedef(
-        &mut self,
-    ) -> Option<(Located<UserDefinedTypes>, Option<Located<String>>)>;
+    fn as_partial_typedef(&mut self) -> Option<PartialTypedef>;
     /// Transforms a [`Variable`](super::Variable) into [`Attribute`]
     fn into_attrs(self) -> Result<Vec<Located<Attribute>>, String>;
     /// Tries to push a comma into a variable
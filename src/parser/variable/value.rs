@@ -5,10 +5,9 @@ use core::mem::{self, take};
 use super::after_keyword_err;
 use super::declaration::Declaration;
 use super::name::VariableName;
-use super::traits::{PureType, VariableConversion};
+use super::traits::{PartialTypedef, PureType, VariableConversion};
 use crate::errors::api::{ErrorLocation, Located};
-use crate::parser::api::AttributeVariable;
-use crate::parser::keyword::attributes::UserDefinedTypes;
+use crate::parser::api::{AttributeVariable, ParameterList};
 use crate::parser::literal::Attribute;
 use crate::parser::modifiers::functions::{CanMakeFnRes, MakeFunction};
 use crate::parser::modifiers::push::Push as _;
@@ -110,11 +109,6 @@ impl VariableValue {
         Ok(())
     }
 
-    /// Takes the value of `self` and puts a placeholder in its place.
-    pub fn take(&mut self) -> Self {
-        mem::replace(self, Self::AttributeVariable(AttributeVariable::default()))
-    }
-
     /// Tries transforming the [`Self`] into a user defined variable name.
     pub fn take_user_defined(&mut self) -> Option<Located<String>> {
         match self {
@@ -133,9 +127,9 @@ impl MakeFunction for VariableValue {
         }
     }
 
-    fn make_function(&mut self, depth: u32, arguments: Vec<Ast>, parens_location: ErrorLocation) {
+    fn make_function(&mut self, depth: u32, arguments: ParameterList) -> Result<(), String> {
         match self {
-            Self::AttributeVariable(var) => var.make_function(depth, arguments, parens_location),
+            Self::AttributeVariable(var) => var.make_function(depth, arguments),
             Self::VariableName(..) => unreachable!(),
         }
     }
@@ -198,9 +192,7 @@ impl PushAttribute for VariableValue {
 }
 
 impl VariableConversion for VariableValue {
-    fn as_partial_typedef(
-        &mut self,
-    ) -> Option<(Located<UserDefinedTypes>, Option<Located<String>>)> {
+    fn as_partial_typedef(&mut self) -> Option<PartialTypedef> {
         match self {
             Self::AttributeVariable(var) => var.as_partial_typedef(),
             Self::VariableName(..) => None,
@@ -0,0 +1,57 @@
+//! Module to traverse the Abstract Syntax Tree.
+//!
+//! The [`Visit`] and [`VisitMut`] traits have a method for every node type of
+//! the [`Ast`](crate::parser::tree::Ast). By default, these methods visit the
+//! children of the node with [`Walk`]. A visitor overrides the methods of the
+//! nodes it is interested in, and calls [`Walk::walk`] (or
+//! [`Walk::walk_mut`]) to continue the traversal inside these nodes.
+
+pub mod visitor;
+pub mod visitor_mut;
+mod walk;
+
+use visitor::Visit;
+use visitor_mut::VisitMut;
+
+use crate::errors::api::Located;
+use crate::parser::api::Attribute;
+
+/// Node of the [`Ast`](crate::parser::tree::Ast) that can be traversed by the
+/// visitors.
+pub trait Walk {
+    /// Visits the children of the node with a [`Visit`]or.
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V);
+
+    /// Visits the children of the node with a [`VisitMut`]or, that can modify
+    /// them.
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+/// Visits the locations of the attributes of a type, and of the tokens of the
+/// arguments of the standard attributes.
+pub fn walk_attributes<V: Visit + ?Sized>(attrs: &[Located<Attribute>], visitor: &mut V) {
+    for attr in attrs {
+        if let Attribute::Standard(standard) = attr.as_value() {
+            for token in standard.arguments.iter().flatten() {
+                visitor.visit_location(token.as_location());
+            }
+        }
+        visitor.visit_location(attr.as_location());
+    }
+}
+
+/// Visits the locations of the attributes of a type, and of the tokens of the
+/// arguments of the standard attributes, with a [`VisitMut`]or.
+pub fn walk_attributes_mut<V: VisitMut + ?Sized>(
+    attrs: &mut [Located<Attribute>],
+    visitor: &mut V,
+) {
+    for attr in attrs {
+        if let Attribute::Standard(standard) = attr.as_value_mut() {
+            for token in standard.arguments.iter_mut().flatten() {
+                visitor.visit_location_mut(token.as_location_mut());
+            }
+        }
+        visitor.visit_location_mut(attr.as_location_mut());
+    }
+}
@@ -0,0 +1,198 @@
+//! Defines the [`Visit`] trait, to traverse an immutable [`Ast`].
+
+use crate::errors::api::ErrorLocation;
+use crate::parser::api::{
+    AttributeVariable, Binary, BracedBlock, ControlFlowNode, Declaration, FunctionCall, Generic, Literal, ParensBlock, Ternary, Unary, VariableName, Walk as _
+};
+use crate::parser::keyword::control_flow::types::case::AstColonAstCtrl;
+use crate::parser::keyword::control_flow::types::colon_ast::ColonAstCtrl;
+use crate::parser::keyword::control_flow::types::conditional::ConditionCtrl;
+use crate::parser::keyword::control_flow::types::do_while::DoWhileCtrl;
+use crate::parser::keyword::control_flow::types::goto::ColonIdentCtrl;
+use crate::parser::keyword::control_flow::types::ident_block::IdentBlockCtrl;
+use crate::parser::keyword::control_flow::types::parens_block::ParensBlockCtrl;
+use crate::parser::keyword::control_flow::types::return_ctrl::ReturnCtrl;
+use crate::parser::keyword::control_flow::types::semi_colon::SemiColonCtrl;
+use crate::parser::keyword::control_flow::types::typedef::TypedefCtrl;
+use crate::parser::symbols::api::{Cast, CompoundLiteral, ListInitialiser};
+use crate::parser::tree::Ast;
+use crate::parser::variable::Variable;
+
+/// Visitor of an immutable [`Ast`].
+///
+/// Each method visits the children of its node by default. Override the
+/// methods of the nodes of interest, and call [`Walk::walk`](crate::Walk::walk)
+/// inside them to visit the children too.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::*;
+///
+/// #[derive(Default)]
+/// struct CallCounter(usize);
+///
+/// impl Visit for CallCounter {
+///     fn visit_function_call(&mut self, node: &FunctionCall) {
+///         if node.function_body.is_none() {
+///             self.0 += 1;
+///         }
+///         node.walk(self);
+///     }
+/// }
+///
+/// let content = "int main() { f(g(1)); if (x) return h(); }";
+/// let ast = parse(lex(content, 0).unwrap_or_display(&[]).unwrap())
+///     .unwrap_or_display(&[])
+///     .unwrap();
+/// let mut counter = CallCounter::default();
+/// counter.visit_braced_block(&ast);
+/// assert_eq!(counter.0, 3);
+/// ```
+pub trait Visit {
+    /// Visits any node.
+    fn visit_ast(&mut self, node: &Ast) {
+        node.walk(self);
+    }
+
+    /// Visits a `case` control flow: `case 2: x++`.
+    fn visit_ast_colon_ast_ctrl(&mut self, node: &AstColonAstCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits the declarations of variables: `const int x = 1, *y`.
+    fn visit_attribute_variable(&mut self, node: &AttributeVariable) {
+        node.walk(self);
+    }
+
+    /// Visits a binary operator: `x + y`.
+    fn visit_binary(&mut self, node: &Binary) {
+        node.walk(self);
+    }
+
+    /// Visits a braced block: `{ x = 1; y = 2; }`, or the whole file.
+    fn visit_braced_block(&mut self, node: &BracedBlock) {
+        node.walk(self);
+    }
+
+    /// Visits a cast: `(int)x`.
+    fn visit_cast(&mut self, node: &Cast) {
+        node.walk(self);
+    }
+
+    /// Visits a `default` or a label control flow: `label: x++`.
+    fn visit_colon_ast_ctrl(&mut self, node: &ColonAstCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a `goto` control flow: `goto label`.
+    ///
+    /// This node has no children, only locations.
+    fn visit_colon_ident_ctrl(&mut self, node: &ColonIdentCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a compound literal: `(int[]){1, 2}`.
+    fn visit_compound_literal(&mut self, node: &CompoundLiteral) {
+        node.walk(self);
+    }
+
+    /// Visits an `if` control flow, with its `else` block.
+    fn visit_condition_ctrl(&mut self, node: &ConditionCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits any control flow.
+    fn visit_control_flow(&mut self, node: &ControlFlowNode) {
+        node.walk(self);
+    }
+
+    /// Visits the declaration of one variable: `*y = 2` in `int x, *y = 2`.
+    fn visit_declaration(&mut self, node: &Declaration) {
+        node.walk(self);
+    }
+
+    /// Visits a `do`-`while` control flow.
+    fn visit_do_while_ctrl(&mut self, node: &DoWhileCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a function call, declaration or definition.
+    fn visit_function_call(&mut self, node: &FunctionCall) {
+        node.walk(self);
+    }
+
+    /// Visits a `_Generic` selection expression.
+    fn visit_generic(&mut self, node: &Generic) {
+        node.walk(self);
+    }
+
+    /// Visits a `struct`, `union` or `enum` control flow.
+    fn visit_ident_block_ctrl(&mut self, node: &IdentBlockCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a list initialiser: `{1, 2, 3}`.
+    fn visit_list_initialiser(&mut self, node: &ListInitialiser) {
+        node.walk(self);
+    }
+
+    /// Visits a literal: `1`, `'c'`, `"str"`, etc.
+    ///
+    /// This node has no children.
+    fn visit_literal(&mut self, _node: &Literal, _location: ErrorLocation) {}
+
+    /// Visits the location of a node, or of a part of a node like an operator
+    /// or a keyword.
+    ///
+    /// The locations of a node are visited after its children.
+    fn visit_location(&mut self, _location: ErrorLocation) {}
+
+    /// Visits an expression in parenthesis: `(x + y)`.
+    fn visit_parens_block(&mut self, node: &ParensBlock) {
+        node.walk(self);
+    }
+
+    /// Visits a `for`, `while` or `switch` control flow.
+    fn visit_parens_block_ctrl(&mut self, node: &ParensBlockCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a `return` control flow.
+    fn visit_return_ctrl(&mut self, node: &ReturnCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a `break` or `continue` control flow.
+    ///
+    /// This node has no children, only a location.
+    fn visit_semi_colon_ctrl(&mut self, node: &SemiColonCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a ternary operator: `x ? y : z`.
+    fn visit_ternary(&mut self, node: &Ternary) {
+        node.walk(self);
+    }
+
+    /// Visits a `typedef` control flow.
+    fn visit_typedef_ctrl(&mut self, node: &TypedefCtrl) {
+        node.walk(self);
+    }
+
+    /// Visits a unary operator: `!x`.
+    fn visit_unary(&mut self, node: &Unary) {
+        node.walk(self);
+    }
+
+    /// Visits a variable, that is either a name or declarations.
+    fn visit_variable(&mut self, node: &Variable) {
+        node.walk(self);
+    }
+
+    /// Visits the name of a variable, that is not a declaration: `x` in `x +
+    /// 1`.
+    ///
+    /// This node has no children.
+    fn visit_variable_name(&mut self, _node: &VariableName, _location: ErrorLocation) {}
+}
@@ -0,0 +1,174 @@
+//! Defines the [`VisitMut`] trait, to traverse and modify an [`Ast`].
+
+use crate::errors::api::ErrorLocation;
+use crate::parser::api::{
+    AttributeVariable, Binary, BracedBlock, ControlFlowNode, Declaration, FunctionCall, Generic, Literal, ParensBlock, Ternary, Unary, VariableName, Walk as _
+};
+use crate::parser::keyword::control_flow::types::case::AstColonAstCtrl;
+use crate::parser::keyword::control_flow::types::colon_ast::ColonAstCtrl;
+use crate::parser::keyword::control_flow::types::conditional::ConditionCtrl;
+use crate::parser::keyword::control_flow::types::do_while::DoWhileCtrl;
+use crate::parser::keyword::control_flow::types::goto::ColonIdentCtrl;
+use crate::parser::keyword::control_flow::types::ident_block::IdentBlockCtrl;
+use crate::parser::keyword::control_flow::types::parens_block::ParensBlockCtrl;
+use crate::parser::keyword::control_flow::types::return_ctrl::ReturnCtrl;
+use crate::parser::keyword::control_flow::types::semi_colon::SemiColonCtrl;
+use crate::parser::keyword::control_flow::types::typedef::TypedefCtrl;
+use crate::parser::symbols::api::{Cast, CompoundLiteral, ListInitialiser};
+use crate::parser::tree::Ast;
+use crate::parser::variable::Variable;
+
+/// Visitor of a mutable [`Ast`], that can modify the nodes.
+///
+/// Each method visits the children of its node by default. Override the
+/// methods of the nodes of interest, and call
+/// [`Walk::walk_mut`](crate::Walk::walk_mut) inside them to visit the children
+/// too. See [`Visit`](crate::Visit) for an example.
+pub trait VisitMut {
+    /// Visits a `case` control flow: `case 2: x++`.
+    fn visit_ast_colon_ast_ctrl_mut(&mut self, node: &mut AstColonAstCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits any node.
+    fn visit_ast_mut(&mut self, node: &mut Ast) {
+        node.walk_mut(self);
+    }
+
+    /// Visits the declarations of variables: `const int x = 1, *y`.
+    fn visit_attribute_variable_mut(&mut self, node: &mut AttributeVariable) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a binary operator: `x + y`.
+    fn visit_binary_mut(&mut self, node: &mut Binary) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a braced block: `{ x = 1; y = 2; }`, or the whole file.
+    fn visit_braced_block_mut(&mut self, node: &mut BracedBlock) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a cast: `(int)x`.
+    fn visit_cast_mut(&mut self, node: &mut Cast) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `default` or a label control flow: `label: x++`.
+    fn visit_colon_ast_ctrl_mut(&mut self, node: &mut ColonAstCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `goto` control flow: `goto label`.
+    ///
+    /// This node has no children, only locations.
+    fn visit_colon_ident_ctrl_mut(&mut self, node: &mut ColonIdentCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a compound literal: `(int[]){1, 2}`.
+    fn visit_compound_literal_mut(&mut self, node: &mut CompoundLiteral) {
+        node.walk_mut(self);
+    }
+
+    /// Visits an `if` control flow, with its `else` block.
+    fn visit_condition_ctrl_mut(&mut self, node: &mut ConditionCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits any control flow.
+    fn visit_control_flow_mut(&mut self, node: &mut ControlFlowNode) {
+        node.walk_mut(self);
+    }
+
+    /// Visits the declaration of one variable: `*y = 2` in `int x, *y = 2`.
+    fn visit_declaration_mut(&mut self, node: &mut Declaration) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `do`-`while` control flow.
+    fn visit_do_while_ctrl_mut(&mut self, node: &mut DoWhileCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a function call, declaration or definition.
+    fn visit_function_call_mut(&mut self, node: &mut FunctionCall) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `_Generic` selection expression.
+    fn visit_generic_mut(&mut self, node: &mut Generic) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `struct`, `union` or `enum` control flow.
+    fn visit_ident_block_ctrl_mut(&mut self, node: &mut IdentBlockCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a list initialiser: `{1, 2, 3}`.
+    fn visit_list_initialiser_mut(&mut self, node: &mut ListInitialiser) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a literal: `1`, `'c'`, `"str"`, etc.
+    ///
+    /// This node has no children.
+    fn visit_literal_mut(&mut self, _node: &mut Literal, _location: ErrorLocation) {}
+
+    /// Visits the location of a node, or of a part of a node like an operator
+    /// or a keyword.
+    ///
+    /// The locations of a node are visited after its children. They can be
+    /// modified to move the nodes, for instance after an edit of the file.
+    fn visit_location_mut(&mut self, _location: &mut ErrorLocation) {}
+
+    /// Visits a `for`, `while` or `switch` control flow.
+    fn visit_parens_block_ctrl_mut(&mut self, node: &mut ParensBlockCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits an expression in parenthesis: `(x + y)`.
+    fn visit_parens_block_mut(&mut self, node: &mut ParensBlock) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `return` control flow.
+    fn visit_return_ctrl_mut(&mut self, node: &mut ReturnCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `break` or `continue` control flow.
+    ///
+    /// This node has no children, only a location.
+    fn visit_semi_colon_ctrl_mut(&mut self, node: &mut SemiColonCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a ternary operator: `x ? y : z`.
+    fn visit_ternary_mut(&mut self, node: &mut Ternary) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a `typedef` control flow.
+    fn visit_typedef_ctrl_mut(&mut self, node: &mut TypedefCtrl) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a unary operator: `!x`.
+    fn visit_unary_mut(&mut self, node: &mut Unary) {
+        node.walk_mut(self);
+    }
+
+    /// Visits a variable, that is either a name or declarations.
+    fn visit_variable_mut(&mut self, node: &mut Variable) {
+        node.walk_mut(self);
+    }
+
+    /// Visits the name of a variable, that is not a declaration: `x` in `x +
+    /// 1`.
+    ///
+    /// This node has no children.
+    fn visit_variable_name_mut(&mut self, _node: &mut VariableName, _location: ErrorLocation) {}
+}
@@ -0,0 +1,93 @@
+//! Implements [`Walk`] for the declarations, whose declarators contain the
+//! locations of many tokens.
+
+use crate::parser::api::{ArraySize, Declaration, DeclarationValue, Visit, VisitMut, Walk};
+use crate::parser::visit::{walk_attributes, walk_attributes_mut};
+
+impl Walk for Declaration {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        let parens = self.parens.as_ref();
+        if let Some(declarator) = parens {
+            walk_attributes(&declarator.pointers, visitor);
+        }
+        for array in self
+            .arrays
+            .iter()
+            .chain(parens.iter().flat_map(|declarator| &declarator.arrays))
+        {
+            if let ArraySize::Expression(size) = &array.size {
+                visitor.visit_ast(size);
+            }
+            for qualifier in &array.qualifiers {
+                visitor.visit_location(qualifier.as_location());
+            }
+            visitor.visit_location(array.location);
+        }
+        if let Some(list) = parens.and_then(|declarator| declarator.parameters.as_ref()) {
+            for parameter in &list.parameters {
+                visitor.visit_ast(parameter);
+            }
+            for location in list.variadic.iter().chain([&list.location]) {
+                visitor.visit_location(*location);
+            }
+        }
+        match &self.value {
+            DeclarationValue::Bitfield(width) => visitor.visit_location(width.as_location()),
+            DeclarationValue::None => (),
+            DeclarationValue::Value(value) => visitor.visit_ast(value),
+        }
+        for location in parens
+            .map(|declarator| declarator.location)
+            .into_iter()
+            .chain([self.name.as_location()])
+        {
+            visitor.visit_location(location);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        let parens = self.parens.as_mut();
+        let (parens_arrays, parameters, parens_locations) =
+            parens.map_or((None, None, None), |declarator| {
+                walk_attributes_mut(&mut declarator.pointers, visitor);
+                (
+                    Some(&mut declarator.arrays),
+                    declarator.parameters.as_mut(),
+                    Some(&mut declarator.location),
+                )
+            });
+        for array in self
+            .arrays
+            .iter_mut()
+            .chain(parens_arrays.into_iter().flatten())
+        {
+            if let ArraySize::Expression(size) = &mut array.size {
+                visitor.visit_ast_mut(size);
+            }
+            for qualifier in &mut array.qualifiers {
+                visitor.visit_location_mut(qualifier.as_location_mut());
+            }
+            visitor.visit_location_mut(&mut array.location);
+        }
+        if let Some(list) = parameters {
+            for parameter in &mut list.parameters {
+                visitor.visit_ast_mut(parameter);
+            }
+            for location in list.variadic.iter_mut().chain([&mut list.location]) {
+                visitor.visit_location_mut(location);
+            }
+        }
+        match &mut self.value {
+            DeclarationValue::Bitfield(width) =>
+                visitor.visit_location_mut(width.as_location_mut()),
+            DeclarationValue::None => (),
+            DeclarationValue::Value(value) => visitor.visit_ast_mut(value),
+        }
+        for location in parens_locations
+            .into_iter()
+            .chain([self.name.as_location_mut()])
+        {
+            visitor.visit_location_mut(location);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Implements [`Walk`] for the nodes whose children are public.
+//!
+//! The nodes with private fields implement [`Walk`] in their own modules.
+
+mod declaration;
+
+use crate::parser::api::{
+    AttributeVariable, Binary, BracedBlock, ControlFlowNode, FunctionCall, Ternary, Unary, Visit, VisitMut, Walk
+};
+use crate::parser::symbols::api::{Cast, CompoundLiteral, ListInitialiser};
+use crate::parser::tree::Ast;
+use crate::parser::visit::{walk_attributes, walk_attributes_mut};
+
+impl Walk for Ast {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Binary(binary) => visitor.visit_binary(binary),
+            Self::BracedBlock(block) => visitor.visit_braced_block(block),
+            Self::Cast(cast) => visitor.visit_cast(cast),
+            Self::CompoundLiteral(lit) => visitor.visit_compound_literal(lit),
+            Self::ControlFlow(ctrl) => visitor.visit_control_flow(ctrl),
+            Self::Empty => (),
+            Self::FunctionArgsBuild(args, parens, last, variadic) => {
+                for arg in args {
+                    visitor.visit_ast(arg);
+                }
+                for location in [parens, last].into_iter().chain(variadic) {
+                    visitor.visit_location(*location);
+                }
+            }
+            Self::FunctionCall(call) => visitor.visit_function_call(call),
+            Self::Generic(generic) => visitor.visit_generic(generic),
+            Self::Leaf(leaf) => {
+                visitor.visit_literal(leaf.as_value(), leaf.as_location());
+                visitor.visit_location(leaf.as_location());
+            }
+            Self::ListInitialiser(list) => visitor.visit_list_initialiser(list),
+            Self::ParensBlock(parens) => visitor.visit_parens_block(parens),
+            Self::Ternary(ternary) => visitor.visit_ternary(ternary),
+            Self::Unary(unary) => visitor.visit_unary(unary),
+            Self::Variable(var) => visitor.visit_variable(var),
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            Self::Binary(binary) => visitor.visit_binary_mut(binary),
+            Self::BracedBlock(block) => visitor.visit_braced_block_mut(block),
+            Self::Cast(cast) => visitor.visit_cast_mut(cast),
+            Self::CompoundLiteral(lit) => visitor.visit_compound_literal_mut(lit),
+            Self::ControlFlow(ctrl) => visitor.visit_control_flow_mut(ctrl),
+            Self::Empty => (),
+            Self::FunctionArgsBuild(args, parens, last, variadic) => {
+                for arg in args {
+                    visitor.visit_ast_mut(arg);
+                }
+                for location in [parens, last].into_iter().chain(variadic) {
+                    visitor.visit_location_mut(location);
+                }
+            }
+            Self::FunctionCall(call) => visitor.visit_function_call_mut(call),
+            Self::Generic(generic) => visitor.visit_generic_mut(generic),
+            Self::Leaf(leaf) => {
+                let location = leaf.as_location();
+                visitor.visit_literal_mut(leaf.as_value_mut(), location);
+                visitor.visit_location_mut(leaf.as_location_mut());
+            }
+            Self::ListInitialiser(list) => visitor.visit_list_initialiser_mut(list),
+            Self::ParensBlock(parens) => visitor.visit_parens_block_mut(parens),
+            Self::Ternary(ternary) => visitor.visit_ternary_mut(ternary),
+            Self::Unary(unary) => visitor.visit_unary_mut(unary),
+            Self::Variable(var) => visitor.visit_variable_mut(var),
+        }
+    }
+}
+
+impl Walk for AttributeVariable {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        for declaration in self.declarations.iter().flatten() {
+            visitor.visit_declaration(declaration);
+        }
+        walk_attributes(&self.attrs, visitor);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        for declaration in self.declarations.iter_mut().flatten() {
+            visitor.visit_declaration_mut(declaration);
+        }
+        walk_attributes_mut(&mut self.attrs, visitor);
+    }
+}
+
+impl Walk for Binary {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.arg_l);
+        visitor.visit_ast(&self.arg_r);
+        visitor.visit_location(self.op.as_location());
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.arg_l);
+        visitor.visit_ast_mut(&mut self.arg_r);
+        visitor.visit_location_mut(self.op.as_location_mut());
+    }
+}
+
+impl Walk for BracedBlock {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        for elt in &self.elts {
+            visitor.visit_ast(elt);
+        }
+        visitor.visit_location(self.location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        for elt in &mut self.elts {
+            visitor.visit_ast_mut(elt);
+        }
+        visitor.visit_location_mut(&mut self.location);
+    }
+}
+
+impl Walk for Cast {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.value);
+        walk_attributes(&self.dest_type, visitor);
+        visitor.visit_location(self.parens_location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.value);
+        walk_attributes_mut(&mut self.dest_type, visitor);
+        visitor.visit_location_mut(&mut self.parens_location);
+    }
+}
+
+impl Walk for CompoundLiteral {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_parens_block(&self.type_name);
+        visitor.visit_list_initialiser(&self.value);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_parens_block_mut(&mut self.type_name);
+        visitor.visit_list_initialiser_mut(&mut self.value);
+    }
+}
+
+impl Walk for ControlFlowNode {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Ast(ctrl) => visitor.visit_return_ctrl(ctrl),
+            Self::AstColonAst(ctrl) => visitor.visit_ast_colon_ast_ctrl(ctrl),
+            Self::ColonAst(ctrl) => visitor.visit_colon_ast_ctrl(ctrl),
+            Self::ColonIdent(ctrl) => visitor.visit_colon_ident_ctrl(ctrl),
+            Self::Condition(ctrl) => visitor.visit_condition_ctrl(ctrl),
+            Self::DoWhile(ctrl) => visitor.visit_do_while_ctrl(ctrl),
+            Self::IdentBlock(ctrl) => visitor.visit_ident_block_ctrl(ctrl),
+            Self::ParensBlock(ctrl) => visitor.visit_parens_block_ctrl(ctrl),
+            Self::SemiColon(ctrl) => visitor.visit_semi_colon_ctrl(ctrl),
+            Self::Typedef(ctrl) => visitor.visit_typedef_ctrl(ctrl),
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            Self::Ast(ctrl) => visitor.visit_return_ctrl_mut(ctrl),
+            Self::AstColonAst(ctrl) => visitor.visit_ast_colon_ast_ctrl_mut(ctrl),
+            Self::ColonAst(ctrl) => visitor.visit_colon_ast_ctrl_mut(ctrl),
+            Self::ColonIdent(ctrl) => visitor.visit_colon_ident_ctrl_mut(ctrl),
+            Self::Condition(ctrl) => visitor.visit_condition_ctrl_mut(ctrl),
+            Self::DoWhile(ctrl) => visitor.visit_do_while_ctrl_mut(ctrl),
+            Self::IdentBlock(ctrl) => visitor.visit_ident_block_ctrl_mut(ctrl),
+            Self::ParensBlock(ctrl) => visitor.visit_parens_block_ctrl_mut(ctrl),
+            Self::SemiColon(ctrl) => visitor.visit_semi_colon_ctrl_mut(ctrl),
+            Self::Typedef(ctrl) => visitor.visit_typedef_ctrl_mut(ctrl),
+        }
+    }
+}
+
+impl Walk for FunctionCall {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.callee);
+        for arg in &self.arguments {
+            visitor.visit_ast(arg);
+        }
+        if let Some(body) = &self.function_body {
+            visitor.visit_braced_block(body);
+        }
+        for location in self.variadic.iter().chain([&self.parens_location]) {
+            visitor.visit_location(*location);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.callee);
+        for arg in &mut self.arguments {
+            visitor.visit_ast_mut(arg);
+        }
+        if let Some(body) = &mut self.function_body {
+            visitor.visit_braced_block_mut(body);
+        }
+        for location in self.variadic.iter_mut().chain([&mut self.parens_location]) {
+            visitor.visit_location_mut(location);
+        }
+    }
+}
+
+impl Walk for ListInitialiser {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        for elt in &self.elts {
+            visitor.visit_ast(elt);
+        }
+        visitor.visit_location(self.location);
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        for elt in &mut self.elts {
+            visitor.visit_ast_mut(elt);
+        }
+        visitor.visit_location_mut(&mut self.location);
+    }
+}
+
+impl Walk for Ternary {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.condition);
+        visitor.visit_ast(&self.success);
+        if let Some((colon, failure)) = &self.failure {
+            visitor.visit_ast(failure);
+            visitor.visit_location(*colon);
+        }
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.condition);
+        visitor.visit_ast_mut(&mut self.success);
+        if let Some((colon, failure)) = &mut self.failure {
+            visitor.visit_ast_mut(failure);
+            visitor.visit_location_mut(colon);
+        }
+    }
+}
+
+impl Walk for Unary {
+    fn walk<V: Visit + ?Sized>(&self, visitor: &mut V) {
+        visitor.visit_ast(&self.arg);
+        visitor.visit_location(self.op.as_location());
+    }
+
+    fn walk_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+        visitor.visit_ast_mut(&mut self.arg);
+        visitor.visit_location_mut(self.op.as_location_mut());
+    }
+}
@@ -0,0 +1,81 @@
+//! Module to evaluate the conditions of the `#if` and `#elif` directives.
+
+use crate::errors::api::{CompileError, ErrorLocation};
+use crate::lexer::api::{Number, Symbol};
+use crate::preprocessor::eval::Evaluator;
+use crate::preprocessor::state::PpState;
+use crate::preprocessor::token::PpToken;
+
+impl PpState<'_> {
+    /// Evaluates the condition of a `#if` or `#elif` directive.
+    pub fn eval_condition(&mut self, tokens: Vec<PpToken>, location: ErrorLocation) -> bool {
+        let replaced = match self.replace_defined(tokens) {
+            Ok(replaced) => replaced,
+            Err(err) => {
+                self.errors.push(err);
+                return false;
+            }
+        };
+        if replaced.is_empty() {
+            self.errors
+                .push(location.fail("#if with no expression".to_owned()));
+            return false;
+        }
+        let expanded = self.expand(replaced);
+        let mut evaluator = Evaluator { location, pos: 0, tokens: self.lex_numbers(expanded) };
+        match evaluator.expression(true) {
+            Ok(_) if let Some(extra) = evaluator.tokens.get(evaluator.pos) => {
+                self.errors.push(
+                    extra
+                        .location
+                        .fail(format!("Missing binary operator before {}", extra.spelling)),
+                );
+                false
+            }
+            Ok(value) => value != 0,
+            Err(err) => {
+                self.errors.push(err);
+                false
+            }
+        }
+    }
+
+    /// Replaces the `defined X` and `defined(X)` operators by their values.
+    fn replace_defined(&self, tokens: Vec<PpToken>) -> Result<Vec<PpToken>, CompileError> {
+        let mut replaced = vec![];
+        let mut iter = tokens.into_iter();
+        while let Some(token) = iter.next() {
+            if token.ident() != Some("defined") {
+                replaced.push(token);
+                continue;
+            }
+            let missing = || {
+                token
+                    .location
+                    .fail("Operator `defined` requires an identifier".to_owned())
+            };
+            let mut name_tok = iter.next().ok_or_else(missing)?;
+            let parenthesised = name_tok.is_symbol(Symbol::ParenthesisOpen);
+            if parenthesised {
+                name_tok = iter.next().ok_or_else(missing)?;
+            }
+            let name = name_tok.ident().ok_or_else(missing)?;
+            if parenthesised
+                && !iter
+                    .next()
+                    .is_some_and(|close| close.is_symbol(Symbol::ParenthesisClose))
+            {
+                return Err(token
+                    .location
+                    .fail("Missing ')' after `defined`".to_owned()));
+            }
+            let value = self.macros.contains_key(name) || matches!(name, "__FILE__" | "__LINE__");
+            replaced.push(PpToken::number(
+                Number::Int(value.into()),
+                u8::from(value).to_string(),
+                token.location,
+            ));
+        }
+        Ok(replaced)
+    }
+}
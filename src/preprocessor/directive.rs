@@ -0,0 +1,236 @@
+//! Module to handle the preprocessing directives, like `#define` or `#if`.
+
+use crate::errors::api::ErrorLocation;
+use crate::lexer::api::TokenValue;
+use crate::preprocessor::macros::parse_define;
+use crate::preprocessor::source::{Directive, LogicalLine};
+use crate::preprocessor::state::{Condition, PpState};
+use crate::preprocessor::token::PpToken;
+
+/// Directive that opens or continues a conditional block.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum ConditionKind {
+    /// `#elif`
+    Elif,
+    /// `#elifdef` and `#elifndef`, with `true` for `#elifdef`.
+    ElifDef(bool),
+    /// `#else`
+    Else,
+    /// `#endif`
+    Endif,
+    /// `#if`
+    If,
+    /// `#ifdef` and `#ifndef`, with `true` for `#ifdef`.
+    IfDef(bool),
+}
+
+impl PpState<'_> {
+    /// Handles a `#if`, `#ifdef`, `#ifndef`, `#elif`, `#elifdef`, `#elifndef`,
+    /// `#else` or `#endif` directive.
+    fn condition(&mut self, line: &LogicalLine, kind: ConditionKind, location: ErrorLocation) {
+        match kind {
+            ConditionKind::If | ConditionKind::IfDef(_) => {
+                let parent_active = self.is_active();
+                let active = parent_active && self.evaluate(line, kind, location);
+                self.conditions.push(Condition {
+                    active,
+                    done: active || !parent_active,
+                    has_else: false,
+                    location,
+                });
+            }
+            ConditionKind::Elif | ConditionKind::ElifDef(_) | ConditionKind::Else => {
+                let Some(cond) = self.conditions.last() else {
+                    self.errors
+                        .push(location.fail("Found conditional branch without #if".to_owned()));
+                    return;
+                };
+                if cond.has_else {
+                    self.errors
+                        .push(location.fail("Found conditional branch after #else".to_owned()));
+                    return;
+                }
+                let active = !cond.done
+                    && (kind == ConditionKind::Else || self.evaluate(line, kind, location));
+                if let Some(last) = self.conditions.last_mut() {
+                    last.active = active;
+                    last.done |= active;
+                    if kind == ConditionKind::Else {
+                        last.has_else = true;
+                    }
+                }
+            }
+            ConditionKind::Endif =>
+                if self.conditions.pop().is_none() {
+                    self.errors
+                        .push(location.fail("Found #endif without #if".to_owned()));
+                },
+        }
+    }
+
+    /// Handles a `#define` directive.
+    fn define(&mut self, line: &LogicalLine, location: ErrorLocation) {
+        let tokens = self.lex_directive(line);
+        match parse_define(tokens, location) {
+            Ok((name, mac)) => {
+                if let Some(old) = self.macros.get(&name)
+                    && !old.same_definition(&mac)
+                {
+                    self.errors
+                        .push(mac.location.warn(format!("Macro {name} redefined")));
+                }
+                self.macros.insert(name, mac);
+            }
+            Err(err) => self.errors.push(err),
+        }
+    }
+
+    /// Handles a preprocessing directive.
+    pub fn directive(&mut self, line: &LogicalLine, directive: &Directive<'_>) {
+        let location = directive.location;
+        let kind = match directive.name {
+            "if" => Some(ConditionKind::If),
+            "ifdef" => Some(ConditionKind::IfDef(true)),
+            "ifndef" => Some(ConditionKind::IfDef(false)),
+            "elif" => Some(ConditionKind::Elif),
+            "elifdef" => Some(ConditionKind::ElifDef(true)),
+            "elifndef" => Some(ConditionKind::ElifDef(false)),
+            "else" => Some(ConditionKind::Else),
+            "endif" => Some(ConditionKind::Endif),
+            _ => None,
+        };
+        if let Some(cond) = kind {
+            self.condition(line, cond, location);
+            return;
+        }
+        if !self.is_active() {
+            return;
+        }
+        match directive.name {
+            "define" => self.define(line, location),
+            "undef" => self.undef(line, location),
+            "include" => self.include(line, directive),
+            "line" => self.line(line, location),
+            "error" => self
+                .errors
+                .push(location.fail(format!("#error{}", directive.rest.trim_end()))),
+            "warning" => self
+                .errors
+                .push(location.warn(format!("#warning{}", directive.rest.trim_end()))),
+            "pragma" =>
+                if directive.rest.trim() == "once" {
+                    self.once.insert(self.current_file());
+                },
+            "" => (),
+            name => self
+                .errors
+                .push(location.fail(format!("Invalid preprocessing directive #{name}"))),
+        }
+    }
+
+    /// Evaluates the condition of a conditional directive.
+    fn evaluate(
+        &mut self,
+        line: &LogicalLine,
+        kind: ConditionKind,
+        location: ErrorLocation,
+    ) -> bool {
+        let tokens = self.lex_directive(line);
+        match kind {
+            ConditionKind::IfDef(defined) | ConditionKind::ElifDef(defined) => self
+                .single_identifier(tokens, location)
+                .is_some_and(|name| self.macros.contains_key(&name) == defined),
+            ConditionKind::If | ConditionKind::Elif => self.eval_condition(tokens, location),
+            ConditionKind::Else | ConditionKind::Endif => true,
+        }
+    }
+
+    /// Handles a `#line` directive.
+    fn line(&mut self, line: &LogicalLine, location: ErrorLocation) {
+        let tokens = self.lex_directive(line);
+        let mut expanded = self.expand(tokens).into_iter();
+        let Some(number) = expanded.next() else {
+            self.errors
+                .push(location.fail("#line directive requires a line number".to_owned()));
+            return;
+        };
+        let presumed = number
+            .spelling
+            .chars()
+            .all(|ch| ch.is_ascii_digit())
+            .then(|| number.spelling.parse::<u32>().ok())
+            .flatten()
+            .filter(|nb| (1..=i32::MAX.unsigned_abs()).contains(nb));
+        let Some(presumed_line) = presumed else {
+            self.errors.push(
+                number
+                    .location
+                    .fail(format!("\"{}\" after #line is not a positive integer", number.spelling)),
+            );
+            return;
+        };
+        let name = match expanded.next() {
+            Some(PpToken { value: TokenValue::Str(name, _), .. }) => Some(name),
+            Some(token) => {
+                self.errors.push(
+                    token
+                        .location
+                        .fail(format!("Invalid filename \"{}\" in #line", token.spelling)),
+                );
+                return;
+            }
+            None => None,
+        };
+        if let Some(extra) = expanded.next() {
+            self.errors.push(
+                extra
+                    .location
+                    .warn("Extra tokens at end of #line directive".to_owned()),
+            );
+        }
+        if let Some(file) = self.file_stack.last_mut() {
+            let old_name = file.line_map.take().and_then(|(.., old)| old);
+            file.line_map = Some((line.last.saturating_add(1), presumed_line, name.or(old_name)));
+        }
+    }
+
+    /// Returns the identifier of a directive that expects exactly one
+    /// identifier, like `#ifdef` or `#undef`.
+    fn single_identifier(
+        &mut self,
+        tokens: Vec<PpToken>,
+        location: ErrorLocation,
+    ) -> Option<String> {
+        let mut iter = tokens.into_iter();
+        let Some(first) = iter.next() else {
+            self.errors
+                .push(location.fail("Macro name missing".to_owned()));
+            return None;
+        };
+        if let Some(extra) = iter.next() {
+            self.errors.push(
+                extra
+                    .location
+                    .warn("Extra tokens at end of directive".to_owned()),
+            );
+        }
+        if let Some(name) = first.ident() {
+            Some(name.to_owned())
+        } else {
+            self.errors.push(
+                first
+                    .location
+                    .fail("Macro names must be identifiers".to_owned()),
+            );
+            None
+        }
+    }
+
+    /// Handles a `#undef` directive.
+    fn undef(&mut self, line: &LogicalLine, location: ErrorLocation) {
+        let tokens = self.lex_directive(line);
+        if let Some(name) = self.single_identifier(tokens, location) {
+            self.macros.remove(&name);
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Module to evaluate the expressions of the `#if` and `#elif` directives.
+
+use crate::errors::api::{CompileRes, ErrorLocation};
+use crate::lexer::api::{Keyword, Number, Symbol, TokenValue};
+use crate::preprocessor::token::PpToken;
+
+/// Parser and evaluator of a preprocessor condition.
+pub struct Evaluator {
+    /// Location of the directive, to report errors at the end of the
+    /// condition.
+    pub location: ErrorLocation,
+    /// Current position in the list of tokens.
+    pub pos: usize,
+    /// Tokens of the condition, after macro expansion.
+    pub tokens: Vec<PpToken>,
+}
+
+impl Evaluator {
+    /// Applies a binary operator.
+    fn apply(op: Symbol, left: i128, right: i128, token: &PpToken) -> CompileRes<i128> {
+        let overflow = || {
+            token
+                .location
+                .fail("Integer overflow in preprocessor expression".to_owned())
+        };
+        let shift = || u32::try_from(right).ok().filter(|nb| *nb < 64);
+        match op {
+            Symbol::Star => left.checked_mul(right).ok_or_else(overflow),
+            Symbol::Divide | Symbol::Modulo if right == 0 => Err(token
+                .location
+                .fail("Division by zero in preprocessor expression".to_owned())),
+            Symbol::Divide => left.checked_div(right).ok_or_else(overflow),
+            Symbol::Modulo => left.checked_rem(right).ok_or_else(overflow),
+            Symbol::Plus => left.checked_add(right).ok_or_else(overflow),
+            Symbol::Minus => left.checked_sub(right).ok_or_else(overflow),
+            Symbol::ShiftLeft => shift()
+                .and_then(|nb| left.checked_shl(nb))
+                .ok_or_else(overflow),
+            Symbol::ShiftRight => shift()
+                .and_then(|nb| left.checked_shr(nb))
+                .ok_or_else(overflow),
+            Symbol::Lt => Ok((left < right).into()),
+            Symbol::Gt => Ok((left > right).into()),
+            Symbol::Le => Ok((left <= right).into()),
+            Symbol::Ge => Ok((left >= right).into()),
+            Symbol::Equal => Ok((left == right).into()),
+            Symbol::Different => Ok((left != right).into()),
+            Symbol::Ampersand => Ok(left & right),
+            Symbol::BitwiseXor => Ok(left ^ right),
+            Symbol::BitwiseOr => Ok(left | right),
+            Symbol::LogicalAnd => Ok((left != 0 && right != 0).into()),
+            Symbol::LogicalOr => Ok((left != 0 || right != 0).into()),
+            Symbol::AddAssign
+            | Symbol::AndAssign
+            | Symbol::Arrow
+            | Symbol::Assign
+            | Symbol::BitwiseNot
+            | Symbol::BraceClose
+            | Symbol::BraceOpen
+            | Symbol::BracketClose
+            | Symbol::BracketOpen
+            | Symbol::Colon
+            | Symbol::Comma
+            | Symbol::Concat
+            | Symbol::Decrement
+            | Symbol::DivAssign
+            | Symbol::Dot
+            | Symbol::Ellipsis
+            | Symbol::Hash
+            | Symbol::Increment
+            | Symbol::Interrogation
+            | Symbol::LogicalNot
+            | Symbol::ModAssign
+            | Symbol::MulAssign
+            | Symbol::OrAssign
+            | Symbol::ParenthesisClose
+            | Symbol::ParenthesisOpen
+            | Symbol::SemiColon
+            | Symbol::ShiftLeftAssign
+            | Symbol::ShiftRightAssign
+            | Symbol::SubAssign
+            | Symbol::XorAssign => unreachable!("not a binary operator"),
+        }
+    }
+
+    /// Parses and evaluates a binary expression, with operators of precedence
+    /// higher than `min_prec`.
+    ///
+    /// If `evaluated` is false, the expression is only parsed, and arithmetic
+    /// errors are ignored.
+    fn binary(&mut self, min_prec: u32, evaluated: bool) -> CompileRes<i128> {
+        let mut left = self.unary(evaluated)?;
+        while let Some(token) = self.tokens.get(self.pos).cloned()
+            && let TokenValue::Symbol(op) = token.value
+            && let Some(prec) = precedence(op)
+            && prec >= min_prec
+        {
+            self.pos = self.pos.saturating_add(1);
+            let right_evaluated = evaluated
+                && (op != Symbol::LogicalAnd || left != 0)
+                && (op != Symbol::LogicalOr || left == 0);
+            let right = self.binary(prec.saturating_add(1), right_evaluated)?;
+            left = match Self::apply(op, left, right, &token) {
+                Ok(value) => value,
+                Err(err) if evaluated => return Err(err),
+                Err(_) => 0,
+            };
+        }
+        Ok(left)
+    }
+
+    /// Checks that the next token is the expected symbol and consumes it.
+    fn expect(&mut self, symbol: Symbol, msg: &str) -> CompileRes<()> {
+        match self.tokens.get(self.pos) {
+            Some(token) if token.is_symbol(symbol) => {
+                self.pos = self.pos.saturating_add(1);
+                Ok(())
+            }
+            Some(token) => Err(token.location.fail(msg.to_owned())),
+            None => Err(self.location.fail(msg.to_owned())),
+        }
+    }
+
+    /// Parses and evaluates a full expression, with the ternary operator.
+    pub fn expression(&mut self, evaluated: bool) -> CompileRes<i128> {
+        let condition = self.binary(1, evaluated)?;
+        if !self
+            .tokens
+            .get(self.pos)
+            .is_some_and(|token| token.is_symbol(Symbol::Interrogation))
+        {
+            return Ok(condition);
+        }
+        self.pos = self.pos.saturating_add(1);
+        let success = self.expression(evaluated && condition != 0)?;
+        self.expect(Symbol::Colon, "Expected ':' in preprocessor expression")?;
+        let failure = self.expression(evaluated && condition == 0)?;
+        Ok(if condition == 0 { failure } else { success })
+    }
+
+    /// Parses and evaluates a unary expression or a primary expression.
+    fn unary(&mut self, evaluated: bool) -> CompileRes<i128> {
+        let Some(token) = self.tokens.get(self.pos).cloned() else {
+            return Err(self
+                .location
+                .fail("Expected value in preprocessor expression".to_owned()));
+        };
+        self.pos = self.pos.saturating_add(1);
+        match token.value {
+            TokenValue::Symbol(Symbol::Plus) => self.unary(evaluated),
+            TokenValue::Symbol(Symbol::Minus) => self.unary(evaluated).map(i128::saturating_neg),
+            TokenValue::Symbol(Symbol::BitwiseNot) => self.unary(evaluated).map(|value| !value),
+            TokenValue::Symbol(Symbol::LogicalNot) =>
+                self.unary(evaluated).map(|value| (value == 0).into()),
+            TokenValue::Symbol(Symbol::ParenthesisOpen) => {
+                let value = self.expression(evaluated)?;
+                self.expect(Symbol::ParenthesisClose, "Expected ')' in preprocessor expression")?;
+                Ok(value)
+            }
+            TokenValue::Number(number) => number_value(&number).ok_or_else(|| {
+                token
+                    .location
+                    .fail("Floating constant in preprocessor expression".to_owned())
+            }),
+            TokenValue::Char(ch, _) => Ok(u32::from(ch).into()),
+            TokenValue::Keyword(Keyword::True) => Ok(1),
+            TokenValue::Keyword(_) | TokenValue::Ident(_) => Ok(0),
+            TokenValue::Str(..) | TokenValue::Symbol(_) => Err(token.location.fail(format!(
+                "Expected value in preprocessor expression, found {}",
+                token.spelling
+            ))),
+        }
+    }
+}
+
+/// Returns the value of an integer constant.
+fn number_value(number: &Number) -> Option<i128> {
+    match number {
+        Number::Int(value) => Some((*value).into()),
+        Number::Long(value) | Number::LongLong(value) => Some((*value).into()),
+        Number::UInt(value) => Some((*value).into()),
+        Number::ULong(value) | Number::ULongLong(value) => Some((*value).into()),
+        Number::BitInt(value) | Number::UBitInt(value) => value.to_u64().map(Into::into),
+        Number::Float(_) | Number::Double(_) | Number::LongDouble(_) => None,
+    }
+}
+
+/// Returns the precedence of a binary operator in a preprocessor condition.
+const fn precedence(symbol: Symbol) -> Option<u32> {
+    Some(match symbol {
+        Symbol::LogicalOr => 1,
+        Symbol::LogicalAnd => 2,
+        Symbol::BitwiseOr => 3,
+        Symbol::BitwiseXor => 4,
+        Symbol::Ampersand => 5,
+        Symbol::Equal | Symbol::Different => 6,
+        Symbol::Lt | Symbol::Gt | Symbol::Le | Symbol::Ge => 7,
+        Symbol::ShiftLeft | Symbol::ShiftRight => 8,
+        Symbol::Plus | Symbol::Minus => 9,
+        Symbol::Star | Symbol::Divide | Symbol::Modulo => 10,
+        Symbol::AddAssign
+        | Symbol::AndAssign
+        | Symbol::Arrow
+        | Symbol::Assign
+        | Symbol::BitwiseNot
+        | Symbol::BraceClose
+        | Symbol::BraceOpen
+        | Symbol::BracketClose
+        | Symbol::BracketOpen
+        | Symbol::Colon
+        | Symbol::Comma
+        | Symbol::Concat
+        | Symbol::Decrement
+        | Symbol::DivAssign
+        | Symbol::Dot
+        | Symbol::Ellipsis
+        | Symbol::Hash
+        | Symbol::Increment
+        | Symbol::Interrogation
+        | Symbol::LogicalNot
+        | Symbol::ModAssign
+        | Symbol::MulAssign
+        | Symbol::OrAssign
+        | Symbol::ParenthesisClose
+        | Symbol::ParenthesisOpen
+        | Symbol::SemiColon
+        | Symbol::ShiftLeftAssign
+        | Symbol::ShiftRightAssign
+        | Symbol::SubAssign
+        | Symbol::XorAssign => return None,
+    })
+}
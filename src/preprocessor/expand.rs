@@ -0,0 +1,167 @@
+//! Module to expand the macros in a list of tokens.
+
+extern crate alloc;
+use alloc::collections::VecDeque;
+use core::mem;
+
+use crate::lexer::api::{Number, Symbol};
+use crate::preprocessor::macros::Macro;
+use crate::preprocessor::state::PpState;
+use crate::preprocessor::token::PpToken;
+
+/// Argument given to a function-like macro, with the comma that follows it.
+pub type Argument = (Vec<PpToken>, Option<PpToken>);
+
+impl PpState<'_> {
+    /// Checks the number of arguments given to a macro, and merges the
+    /// variadic arguments into one, with exactly the commas of the invocation.
+    fn check_args(
+        &mut self,
+        name: &PpToken,
+        mac: &Macro,
+        mut args: Vec<Argument>,
+    ) -> Option<Vec<Vec<PpToken>>> {
+        let nb_params = mac.params.as_ref().map_or(0, Vec::len);
+        if nb_params == 0 && args.len() == 1 && args.first().is_some_and(|arg| arg.0.is_empty()) {
+            args.clear();
+        }
+        let nb_args = args.len();
+        if nb_args < nb_params || (!mac.variadic && nb_args > nb_params) {
+            self.errors.push(name.location.fail(format!(
+                "Macro {} expects {nb_params}{} arguments, but {nb_args} were given",
+                name.spelling,
+                if mac.variadic { " or more" } else { "" },
+            )));
+            return None;
+        }
+        let variadic = args.split_off(nb_params);
+        let mut merged = args.into_iter().map(|(arg, _)| arg).collect::<Vec<_>>();
+        if mac.variadic {
+            let mut va_args = vec![];
+            for (arg, comma) in variadic {
+                va_args.extend(arg);
+                va_args.extend(comma);
+            }
+            merged.push(va_args);
+        }
+        Some(merged)
+    }
+
+    /// Expands the macros in a list of tokens.
+    pub fn expand(&mut self, tokens: Vec<PpToken>) -> Vec<PpToken> {
+        let mut input = VecDeque::from(tokens);
+        let mut output = vec![];
+        while let Some(token) = input.pop_front() {
+            if token.hideset.is_empty() {
+                self.current_line = token.line();
+            }
+            let Some(name) = token.ident() else {
+                output.push(token);
+                continue;
+            };
+            if token.hideset.contains(name) {
+                output.push(token);
+                continue;
+            }
+            if let Some(builtin) = self.expand_builtin(&token) {
+                output.push(builtin);
+                continue;
+            }
+            let Some(mac) = self.macros.get(name).cloned() else {
+                output.push(token);
+                continue;
+            };
+            let expansion = if mac.params.is_some() {
+                if !input
+                    .front()
+                    .is_some_and(|next| next.is_symbol(Symbol::ParenthesisOpen))
+                {
+                    output.push(token);
+                    continue;
+                }
+                match collect_args(&mut input) {
+                    Ok((args, close)) => {
+                        let hideset = token
+                            .hideset
+                            .intersection(&close.hideset)
+                            .cloned()
+                            .collect();
+                        self.check_args(&token, &mac, args)
+                            .map_or_else(Vec::new, |checked| {
+                                self.substitute(&mac, &checked, &token, hideset)
+                            })
+                    }
+                    Err(consumed) => {
+                        self.errors.push(
+                            token
+                                .location
+                                .fail(format!("Unterminated argument list invoking macro {name}")),
+                        );
+                        output.push(token);
+                        output.extend(consumed);
+                        continue;
+                    }
+                }
+            } else {
+                self.substitute(&mac, &[], &token, token.hideset.clone())
+            };
+            for expanded in expansion.into_iter().rev() {
+                input.push_front(expanded);
+            }
+        }
+        output
+    }
+
+    /// Expands the predefined dynamic macros `__FILE__` and `__LINE__`.
+    fn expand_builtin(&self, token: &PpToken) -> Option<PpToken> {
+        let name = token.ident()?;
+        if name != "__FILE__" && name != "__LINE__" {
+            return None;
+        }
+        let (file, line) = self.presumed_position();
+        let mut builtin = if name == "__FILE__" {
+            PpToken::string(file, token.location)
+        } else {
+            let value = i32::try_from(line).map_or(Number::UInt(line), Number::Int);
+            PpToken::number(value, line.to_string(), token.location)
+        };
+        builtin.space = token.space;
+        builtin.hideset.clone_from(&token.hideset);
+        Some(builtin)
+    }
+}
+
+/// Collects the arguments of a function-like macro invocation.
+///
+/// The input must start with the opening parenthesis.
+///
+/// # Returns
+///
+/// The arguments and the closing parenthesis, or the consumed tokens if the
+/// closing parenthesis was not found.
+fn collect_args(input: &mut VecDeque<PpToken>) -> Result<(Vec<Argument>, PpToken), Vec<PpToken>> {
+    let mut consumed = vec![];
+    let mut args = vec![];
+    let mut current = vec![];
+    let mut depth = 0u32;
+    if let Some(open) = input.pop_front() {
+        consumed.push(open);
+    }
+    while let Some(token) = input.pop_front() {
+        consumed.push(token.clone());
+        if token.is_symbol(Symbol::ParenthesisClose) {
+            if depth == 0 {
+                args.push((current, None));
+                return Ok((args, token));
+            }
+            depth = depth.saturating_sub(1);
+        } else if token.is_symbol(Symbol::ParenthesisOpen) {
+            depth = depth.saturating_add(1);
+        } else if depth == 0 && token.is_symbol(Symbol::Comma) {
+            args.push((mem::take(&mut current), Some(token)));
+            continue;
+        }
+        current.push(token);
+    }
+    Err(consumed)
+}
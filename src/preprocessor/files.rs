@@ -0,0 +1,87 @@
+//! Module to define the options of the preprocessor and the list of the files
+//! it reads.
+
+use std::path::{Path, PathBuf};
+
+use crate::target::TargetInfo;
+use crate::utils::{u32_to_usize, usize_to_u32};
+
+/// Options to configure the preprocessor.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::PreprocessorOptions;
+///
+/// let mut options = PreprocessorOptions::default();
+/// options.include_paths.push("/usr/include".into());
+/// options.defines.push(("DEBUG".to_owned(), "1".to_owned()));
+/// ```
+#[derive(Debug, Default, Clone)]
+#[non_exhaustive]
+pub struct PreprocessorOptions {
+    /// Macros defined before preprocessing the file, as `(name, value)` pairs.
+    ///
+    /// The name can contain a parameter list, like in `("MAX(a, b)", "a > b ?
+    /// a : b")`.
+    pub defines: Vec<(String, String)>,
+    /// Directories in which to look for the included files, in order.
+    ///
+    /// Files included with `#include "file.h"` are first searched in the
+    /// directory of the current file.
+    pub include_paths: Vec<PathBuf>,
+    /// Machine the code is compiled for, used to type the number constants.
+    pub target: TargetInfo,
+}
+
+/// File read by the preprocessor.
+#[derive(Debug)]
+struct SourceFile {
+    /// Content of the file
+    content: String,
+    /// Name of the file, used to display the errors.
+    name: String,
+    /// Path of the file on the filesystem, if it was read from the filesystem.
+    path: Option<PathBuf>,
+}
+
+/// List of the files read by the preprocessor.
+///
+/// The id of a file is its position in the list. These ids are the ones used
+/// in the locations of the tokens and errors.
+#[derive(Debug, Default)]
+pub struct SourceFiles(Vec<SourceFile>);
+
+impl SourceFiles {
+    /// Returns the list of files, in the format expected by
+    /// [`Res::as_displayed_errors`](crate::Res::as_displayed_errors).
+    #[must_use]
+    pub fn as_list(&self) -> Vec<(u32, &str, &str)> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(id, file)| (usize_to_u32(id), file.name.as_str(), file.content.as_str()))
+            .collect()
+    }
+
+    /// Returns the id of a file that was read from the given path.
+    pub(super) fn find(&self, path: &Path) -> Option<u32> {
+        self.0
+            .iter()
+            .position(|file| file.path.as_deref() == Some(path))
+            .map(usize_to_u32)
+    }
+
+    /// Returns the name of a file.
+    pub(super) fn name(&self, id: u32) -> &str {
+        self.0
+            .get(u32_to_usize(id))
+            .map_or("", |file| file.name.as_str())
+    }
+
+    /// Adds a file to the list and returns its id.
+    pub(super) fn push(&mut self, name: String, content: String, path: Option<PathBuf>) -> u32 {
+        self.0.push(SourceFile { content, name, path });
+        usize_to_u32(self.0.len().checked_sub(1).expect("just pushed"))
+    }
+}
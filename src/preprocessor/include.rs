@@ -0,0 +1,118 @@
+//! Module to handle the `#include` directive.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::errors::api::ErrorLocation;
+use crate::lexer::api::{Symbol, TokenValue};
+use crate::preprocessor::source::{Directive, LogicalLine};
+use crate::preprocessor::state::{FileContext, PpState};
+
+/// Maximum depth of nested `#include` directives.
+const MAX_INCLUDE_DEPTH: usize = 200;
+
+impl PpState<'_> {
+    /// Returns the name of the included file and whether it was written
+    /// between quotes, or `None` if the directive is invalid.
+    fn header_name(
+        &mut self,
+        line: &LogicalLine,
+        directive: &Directive<'_>,
+    ) -> Option<(String, bool)> {
+        let rest = directive.rest.trim();
+        for (open, close, quoted) in [('"', '"', true), ('<', '>', false)] {
+            if let Some(after) = rest.strip_prefix(open) {
+                let (name, extra) = after.split_once(close)?;
+                if !extra.trim().is_empty() {
+                    self.errors.push(
+                        directive
+                            .location
+                            .warn("Extra tokens at end of #include directive".to_owned()),
+                    );
+                }
+                return Some((name.to_owned(), quoted));
+            }
+        }
+        let tokens = self.lex_directive(line);
+        let expanded = self.expand(tokens);
+        match expanded.as_slice() {
+            [single] if let TokenValue::Str(name, _) = &single.value =>
+                Some((name.to_owned(), true)),
+            [first, middle @ .., last]
+                if first.is_symbol(Symbol::Lt) && last.is_symbol(Symbol::Gt) =>
+                Some((
+                    middle
+                        .iter()
+                        .map(|token| token.spelling.as_str())
+                        .collect::<String>(),
+                    false,
+                )),
+            _ => None,
+        }
+    }
+
+    /// Handles a `#include` directive.
+    pub fn include(&mut self, line: &LogicalLine, directive: &Directive<'_>) {
+        let location = directive.location;
+        let Some((name, quoted)) = self.header_name(line, directive) else {
+            self.errors
+                .push(location.fail("#include expects \"FILENAME\" or <FILENAME>".to_owned()));
+            return;
+        };
+        if self.file_stack.len() >= MAX_INCLUDE_DEPTH {
+            self.errors.push(
+                location.fail(format!("#include nested depth {MAX_INCLUDE_DEPTH} exceeds maximum")),
+            );
+            return;
+        }
+        let Some(path) = self.resolve(&name, quoted) else {
+            self.errors
+                .push(location.fail(format!("{name}: file not found")));
+            return;
+        };
+        if let Some(id) = self.files.find(&path)
+            && self.once.contains(&id)
+        {
+            return;
+        }
+        self.include_path(&path, location);
+    }
+
+    /// Preprocesses the content of an included file.
+    fn include_path(&mut self, path: &Path, location: ErrorLocation) {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) => {
+                self.errors
+                    .push(location.fail(format!("Failed to read {}: {err}", path.display())));
+                return;
+            }
+        };
+        let id = self.files.find(path).unwrap_or_else(|| {
+            self.files
+                .push(path.display().to_string(), content.clone(), Some(path.to_path_buf()))
+        });
+        self.file_stack.push(FileContext {
+            dir: path.parent().map(Path::to_path_buf),
+            id,
+            line_map: None,
+        });
+        self.process_file(&content);
+        self.file_stack.pop();
+    }
+
+    /// Finds the path of an included file, in the directory of the current
+    /// file for quoted names, and in the include paths.
+    fn resolve(&self, name: &str, quoted: bool) -> Option<PathBuf> {
+        let current_dir = self
+            .file_stack
+            .last()
+            .filter(|_| quoted)
+            .and_then(|file| file.dir.clone());
+        current_dir
+            .into_iter()
+            .chain(self.options.include_paths.iter().cloned())
+            .map(|dir| dir.join(name))
+            .find(|path| path.is_file())
+    }
+}
@@ -0,0 +1,187 @@
+//! Module to define and parse the macros, with the `#define` directive.
+
+use crate::errors::api::{CompileError, CompileRes, ErrorLocation};
+use crate::lexer::api::Symbol;
+use crate::preprocessor::token::PpToken;
+
+/// Name of the variadic arguments inside a macro body.
+pub const VA_ARGS: &str = "__VA_ARGS__";
+/// Name of the C23 variadic optional operator.
+pub const VA_OPT: &str = "__VA_OPT__";
+
+/// Macro defined with `#define`
+#[derive(Debug, Clone)]
+pub struct Macro {
+    /// Replacement list of the macro
+    pub body: Vec<PpToken>,
+    /// Location of the name of the macro, in the `#define` directive.
+    pub location: ErrorLocation,
+    /// Names of the parameters, if the macro is function-like.
+    pub params: Option<Vec<String>>,
+    /// Whether the macro accepts variadic arguments, with `...`.
+    pub variadic: bool,
+}
+
+impl Macro {
+    /// Returns the position of a parameter in the argument list.
+    ///
+    /// The variadic arguments are placed after the named parameters.
+    pub fn param_index(&self, name: &str) -> Option<usize> {
+        let params = self.params.as_ref()?;
+        if self.variadic && name == VA_ARGS {
+            Some(params.len())
+        } else {
+            params.iter().position(|param| param == name)
+        }
+    }
+
+    /// Checks if two macro definitions are identical, in which case a
+    /// redefinition is allowed.
+    pub fn same_definition(&self, other: &Self) -> bool {
+        self.params == other.params
+            && self.variadic == other.variadic
+            && self.body.len() == other.body.len()
+            && self
+                .body
+                .iter()
+                .zip(&other.body)
+                .enumerate()
+                .all(|(idx, (old, new))| {
+                    old.spelling == new.spelling && (idx == 0 || old.space == new.space)
+                })
+    }
+}
+
+/// Returns the error for an unclosed parameter list.
+fn missing_parens(location: ErrorLocation) -> CompileError {
+    location.fail("Missing ')' in macro parameter list".to_owned())
+}
+
+/// Parses the content of a `#define` directive.
+///
+/// # Returns
+///
+/// The name and the value of the macro, or the error if the definition is
+/// invalid.
+pub fn parse_define(
+    mut tokens: Vec<PpToken>,
+    location: ErrorLocation,
+) -> CompileRes<(String, Macro)> {
+    if tokens.is_empty() {
+        return Err(location.fail("Macro name missing".to_owned()));
+    }
+    let name_token = tokens.remove(0);
+    let Some(name) = name_token.ident().map(str::to_owned) else {
+        return Err(name_token
+            .location
+            .fail("Macro names must be identifiers".to_owned()));
+    };
+    if name == "defined" {
+        return Err(name_token
+            .location
+            .fail("`defined` can't be used as a macro name".to_owned()));
+    }
+    let mut this =
+        Macro { body: vec![], location: name_token.location, params: None, variadic: false };
+    let mut body = tokens.into_iter().peekable();
+    if body
+        .peek()
+        .is_some_and(|token| !token.space && token.is_symbol(Symbol::ParenthesisOpen))
+    {
+        body.next();
+        let mut params = vec![];
+        loop {
+            let token = body.next().ok_or_else(|| missing_parens(location))?;
+            if params.is_empty() && token.is_symbol(Symbol::ParenthesisClose) {
+                break;
+            }
+            if token.is_symbol(Symbol::Dot) {
+                return Err(token
+                    .location
+                    .fail("Expected '...' for variadic parameter".to_owned()));
+            }
+            if token.is_symbol(Symbol::Ellipsis) {
+                this.variadic = true;
+                let close = body.next().ok_or_else(|| missing_parens(location))?;
+                if close.is_symbol(Symbol::ParenthesisClose) {
+                    break;
+                }
+                return Err(close.location.fail("Expected ')' after '...'".to_owned()));
+            }
+            let Some(param) = token.ident() else {
+                return Err(token
+                    .location
+                    .fail(format!("Expected parameter name, found {}", token.spelling)));
+            };
+            if param == VA_ARGS || params.iter().any(|old| old == param) {
+                return Err(token
+                    .location
+                    .fail(format!("Invalid or duplicate macro parameter {param}")));
+            }
+            params.push(param.to_owned());
+            let sep = body.next().ok_or_else(|| missing_parens(location))?;
+            if sep.is_symbol(Symbol::ParenthesisClose) {
+                break;
+            }
+            if !sep.is_symbol(Symbol::Comma) {
+                return Err(sep.location.fail(format!(
+                    "Expected ',' or ')' in macro parameter list, found {}",
+                    sep.spelling
+                )));
+            }
+        }
+        this.params = Some(params);
+    }
+    this.body = body.collect();
+    check_body(&this)?;
+    Ok((name, this))
+}
+
+/// Checks that the `#`, `##` and variadic operators are correctly used in the
+/// body of the macro.
+fn check_body(mac: &Macro) -> Result<(), CompileError> {
+    if let Some(first) = mac.body.first()
+        && first.is_symbol(Symbol::Concat)
+    {
+        return Err(first
+            .location
+            .fail("'##' can't appear at either end of a macro expansion".to_owned()));
+    }
+    if let Some(last) = mac.body.last()
+        && last.is_symbol(Symbol::Concat)
+    {
+        return Err(last
+            .location
+            .fail("'##' can't appear at either end of a macro expansion".to_owned()));
+    }
+    let mut tokens = mac.body.iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token.ident() {
+            Some(VA_ARGS | VA_OPT) if !mac.variadic =>
+                return Err(token.location.fail(format!(
+                    "{} can only appear in the expansion of a variadic macro",
+                    token.spelling
+                ))),
+            Some(VA_OPT)
+                if !tokens
+                    .peek()
+                    .is_some_and(|next| next.is_symbol(Symbol::ParenthesisOpen)) =>
+                return Err(token
+                    .location
+                    .fail(format!("{VA_OPT} must be followed by '('"))),
+            Some(_) | None => (),
+        }
+        if mac.params.is_some()
+            && token.is_symbol(Symbol::Hash)
+            && tokens
+                .peek()
+                .and_then(|next| next.ident())
+                .is_none_or(|next| mac.param_index(next).is_none())
+        {
+            return Err(token
+                .location
+                .fail("'#' is not followed by a macro parameter".to_owned()));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Module to preprocess source files before lexing them.
+//!
+//! The preprocessor handles the directives (the lines starting with `#`), like
+//! `#include`, `#define` or `#if`, and expands the macros. The tokens it
+//! produces keep the location where they were written: in the original file,
+//! in an included file or in the definition of a macro.
+
+#[expect(clippy::inline_modules, reason = "clearer api")]
+pub mod api {
+    //! Api module to choose what functions to export.
+
+    #![allow(clippy::pub_use, reason = "expose simple API")]
+
+    pub use super::files::{PreprocessorOptions, SourceFiles};
+    pub use super::preprocess;
+}
+
+mod condition;
+mod directive;
+mod eval;
+mod expand;
+mod files;
+mod include;
+mod macros;
+mod source;
+mod state;
+mod substitute;
+mod token;
+
+use std::path::Path;
+
+use crate::Res;
+use crate::lexer::api::Token;
+use crate::preprocessor::files::{PreprocessorOptions, SourceFiles};
+use crate::preprocessor::state::{FileContext, PpState};
+use crate::preprocessor::token::PpToken;
+
+/// Name of the pseudo-file that contains the predefined macros.
+const BUILTIN_FILE: &str = "<built-in>";
+
+/// Macros defined by the standard.
+const BUILTIN_MACROS: &str = "#define __STDC__ 1
+#define __STDC_HOSTED__ 1
+#define __STDC_VERSION__ 202311L
+#define __STDC_UTF_16__ 1
+#define __STDC_UTF_32__ 1
+";
+
+/// Preprocesses a source file into a list of tokens.
+///
+/// The file and all the files it includes are added to `files`, which can then
+/// be used to display the errors.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::*;
+///
+/// let content = "#define SQUARE(x) ((x) * (x))\nint y = SQUARE(2);";
+/// let mut files = SourceFiles::default();
+/// let tokens = preprocess("file.c", content, &PreprocessorOptions::default(), &mut files)
+///     .unwrap_or_display(&files.as_list())
+///     .unwrap();
+/// let displayed = display_tokens(&tokens);
+/// assert!(
+///     displayed
+///         == "[Keyword(int), Ident(y), Assign, ParenthesisOpen, ParenthesisOpen, 2, \
+///             ParenthesisClose, Star, ParenthesisOpen, 2, ParenthesisClose, \
+///             ParenthesisClose, SemiColon]",
+///     "{displayed}"
+/// );
+/// ```
+#[must_use]
+pub fn preprocess(
+    name: &str,
+    content: &str,
+    options: &PreprocessorOptions,
+    files: &mut SourceFiles,
+) -> Res<Vec<Token>> {
+    let id = files.push(name.to_owned(), content.to_owned(), None);
+    let builtins = options
+        .defines
+        .iter()
+        .fold(BUILTIN_MACROS.to_owned(), |acc, (macro_name, value)| {
+            format!("{acc}#define {macro_name} {value}\n")
+        });
+    let builtin_id = files.push(BUILTIN_FILE.to_owned(), builtins.clone(), None);
+    let mut state = PpState::new(options, files);
+    state
+        .file_stack
+        .push(FileContext { dir: None, id: builtin_id, line_map: None });
+    state.process_file(&builtins);
+    state.file_stack.pop();
+    state.file_stack.push(FileContext {
+        dir: Path::new(name).parent().map(Path::to_path_buf),
+        id,
+        line_map: None,
+    });
+    state.process_file(content);
+    let tokens = state.output.into_iter().map(PpToken::into_token).collect();
+    Res::from((tokens, state.errors))
+}
@@ -0,0 +1,175 @@
+//! Module to split a source file into logical lines.
+//!
+//! Comments are replaced by whitespaces before splitting, to keep the columns
+//! of the remaining characters unchanged, and thus keep the locations of the
+//! tokens valid.
+
+use crate::errors::api::ErrorLocation;
+use crate::utils::usize_to_u32;
+
+/// State of the comment stripper.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum StripState {
+    /// Inside a `/* */` comment.
+    BlockComment,
+    /// Inside a char constant
+    Char,
+    /// Inside a `//` comment.
+    LineComment,
+    /// Outside of any literal or comment
+    Normal,
+    /// Inside a string constant
+    Str,
+}
+
+/// Logical line of a source file.
+///
+/// A logical line is made of one or more physical lines, when lines end with
+/// the `\` line-continuation character.
+#[derive(Debug)]
+pub struct LogicalLine {
+    /// Content of the physical lines, joined by `\n`.
+    pub content: String,
+    /// Number of the first physical line of the logical line, starting at 1.
+    pub first: u32,
+    /// Number of the last physical line of the logical line.
+    pub last: u32,
+}
+
+impl LogicalLine {
+    /// Returns the directive contained in the line, if the line is a
+    /// preprocessing directive.
+    pub fn as_directive(&self, file: u32) -> Option<Directive<'_>> {
+        let start = self
+            .content
+            .len()
+            .checked_sub(self.content.trim_start().len())?;
+        let after_hash = self.content.get(start..)?.strip_prefix('#')?;
+        if after_hash.starts_with('#') {
+            return None;
+        }
+        let name_start = after_hash
+            .len()
+            .checked_sub(after_hash.trim_start().len())?;
+        let trimmed = after_hash.trim_start();
+        let name_len = trimmed
+            .find(|ch: char| !ch.is_alphanumeric() && ch != '_')
+            .unwrap_or(trimmed.len());
+        let name = trimmed.get(..name_len)?;
+        let col = self.content.get(..start)?.chars().count().checked_add(1)?;
+        let len = name_start.checked_add(name_len)?.checked_add(1)?;
+        Some(Directive {
+            location: ErrorLocation::Token(file, self.first, usize_to_u32(col), usize_to_u32(len)),
+            name,
+            rest: trimmed.get(name_len..)?,
+        })
+    }
+}
+
+/// Preprocessing directive, i.e., a line starting with `#`.
+#[derive(Debug)]
+pub struct Directive<'line> {
+    /// Location of the `#name` part of the directive.
+    pub location: ErrorLocation,
+    /// Name of the directive, like `define` or `include`.
+    ///
+    /// The name is empty for the null directive.
+    pub name: &'line str,
+    /// Raw content of the directive, after the name.
+    pub rest: &'line str,
+}
+
+/// Consecutive lines that don't contain any directives.
+#[derive(Debug, Default)]
+pub struct Segment {
+    /// Content of the lines, joined by `\n`.
+    pub content: String,
+    /// Number of the first physical line of the segment.
+    pub first: u32,
+}
+
+impl Segment {
+    /// Adds a line at the end of the segment.
+    pub fn push(&mut self, line: &LogicalLine) {
+        if self.content.is_empty() {
+            self.first = line.first;
+        } else {
+            self.content.push('\n');
+        }
+        self.content.push_str(&line.content);
+    }
+}
+
+/// Splits a source file into logical lines, after removing the comments.
+pub fn logical_lines(content: &str) -> Vec<LogicalLine> {
+    let stripped = strip_comments(content);
+    let mut lines: Vec<LogicalLine> = vec![];
+    let mut continued = false;
+    for (idx, physical) in stripped.lines().enumerate() {
+        let number = usize_to_u32(idx.checked_add(1).expect("less lines than usize::MAX"));
+        if continued && let Some(last) = lines.last_mut() {
+            last.content.push('\n');
+            last.content.push_str(physical);
+            last.last = number;
+        } else {
+            lines.push(LogicalLine { content: physical.to_owned(), first: number, last: number });
+        }
+        continued = physical.trim_end().ends_with('\\');
+    }
+    lines
+}
+
+/// Replaces the comments of a source file by whitespaces.
+///
+/// The newlines inside block comments are kept, to keep the line numbers
+/// valid.
+fn strip_comments(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut state = StripState::Normal;
+    let mut escaped = false;
+    let mut chars = content.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match (state, ch) {
+            (StripState::BlockComment, '*') if chars.peek() == Some(&'/') => {
+                chars.next();
+                stripped.push_str("  ");
+                state = StripState::Normal;
+            }
+            (StripState::BlockComment | StripState::LineComment, '\n') => {
+                if state == StripState::LineComment && !escaped {
+                    state = StripState::Normal;
+                }
+                stripped.push('\n');
+            }
+            (StripState::BlockComment | StripState::LineComment, _) => stripped.push(' '),
+            (StripState::Normal, '/') if chars.peek() == Some(&'*') => {
+                chars.next();
+                stripped.push_str("  ");
+                state = StripState::BlockComment;
+            }
+            (StripState::Normal, '/') if chars.peek() == Some(&'/') => {
+                stripped.push(' ');
+                state = StripState::LineComment;
+            }
+            (StripState::Normal, '"') => {
+                stripped.push(ch);
+                state = StripState::Str;
+            }
+            (StripState::Normal, '\'') => {
+                stripped.push(ch);
+                state = StripState::Char;
+            }
+            (StripState::Str, '"')
+            | (StripState::Char, '\'')
+            | (StripState::Str | StripState::Char, '\n')
+                if !escaped =>
+            {
+                stripped.push(ch);
+                state = StripState::Normal;
+            }
+            (StripState::Normal | StripState::Str | StripState::Char, _) => stripped.push(ch),
+        }
+        escaped = ch == '\\' && !escaped;
+    }
+    stripped
+}
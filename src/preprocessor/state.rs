@@ -0,0 +1,233 @@
+//! Module to define the state of the preprocessor, and the main loop that
+//! reads the lines of a file.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use crate::errors::api::{CompileError, ErrorLocation};
+use crate::lexer::api::{Symbol, TokenValue, lex_from_line, lex_pp_numbers};
+use crate::preprocessor::files::{PreprocessorOptions, SourceFiles};
+use crate::preprocessor::macros::Macro;
+use crate::preprocessor::source::{LogicalLine, Segment, logical_lines};
+use crate::preprocessor::token::PpToken;
+use crate::utils::u32_to_usize;
+
+/// Conditional block, opened by `#if`, `#ifdef` or `#ifndef`.
+#[derive(Debug)]
+pub struct Condition {
+    /// Whether the current branch of the block is active.
+    pub active: bool,
+    /// Whether a branch was already taken, or if the whole block is inside an
+    /// inactive block.
+    pub done: bool,
+    /// Whether the `#else` branch was already found.
+    pub has_else: bool,
+    /// Location of the directive that opened the block.
+    pub location: ErrorLocation,
+}
+
+/// File that is being preprocessed.
+#[derive(Debug)]
+pub struct FileContext {
+    /// Directory of the file, to look for the files included with quotes.
+    pub dir: Option<PathBuf>,
+    /// Id of the file.
+    pub id: u32,
+    /// Presumed line and file name, set by the `#line` directive.
+    ///
+    /// The fields are the physical line from which the directive applies, the
+    /// presumed line number of that physical line, and the presumed file name.
+    pub line_map: Option<(u32, u32, Option<String>)>,
+}
+
+/// State of the preprocessor.
+#[derive(Debug)]
+pub struct PpState<'pp> {
+    /// Stack of the conditional blocks that are opened.
+    pub conditions: Vec<Condition>,
+    /// Line of the last token of the source file that was expanded.
+    pub current_line: u32,
+    /// Errors that occurred while preprocessing.
+    pub errors: Vec<CompileError>,
+    /// Stack of the files that are being preprocessed, the last one being the
+    /// current file.
+    pub file_stack: Vec<FileContext>,
+    /// Files read by the preprocessor.
+    pub files: &'pp mut SourceFiles,
+    /// Macros that are currently defined.
+    pub macros: HashMap<String, Macro>,
+    /// Files that contain `#pragma once`.
+    pub once: HashSet<u32>,
+    /// Options given by the user.
+    pub options: &'pp PreprocessorOptions,
+    /// Preprocessed tokens.
+    pub output: Vec<PpToken>,
+}
+
+impl<'pp> PpState<'pp> {
+    /// Returns the id of the file that is being preprocessed.
+    pub fn current_file(&self) -> u32 {
+        self.file_stack.last().map_or(0, |file| file.id)
+    }
+
+    /// Lexes a segment and pushes the expanded tokens to the output.
+    fn flush(&mut self, file: u32, segment: &mut Segment) {
+        if segment.content.is_empty() {
+            return;
+        }
+        let tokens = self.lex(&segment.content, file, segment.first);
+        let expanded = self.expand(tokens);
+        for token in self.lex_numbers(expanded) {
+            self.push_output(token);
+        }
+        segment.content.clear();
+    }
+
+    /// Checks if the current lines must be kept or skipped.
+    pub fn is_active(&self) -> bool {
+        self.conditions.last().is_none_or(|cond| cond.active)
+    }
+
+    /// Lexes some content into preprocessing tokens.
+    ///
+    /// The number constants are kept as preprocessing numbers, and are only
+    /// lexed after the macro expansion, with [`Self::lex_numbers`].
+    pub fn lex(&mut self, content: &str, file: u32, first: u32) -> Vec<PpToken> {
+        let tokens = lex_pp_numbers(content, file, first.saturating_sub(1), &self.options.target)
+            .store_errors(&mut |err| self.errors.push(err))
+            .unwrap_or_default();
+        PpToken::from_tokens(tokens, content, first)
+    }
+
+    /// Lexes the content of a directive, and returns the tokens after the
+    /// directive name.
+    pub fn lex_directive(&mut self, line: &LogicalLine) -> Vec<PpToken> {
+        let file = self.current_file();
+        let mut tokens = self.lex(&line.content, file, line.first);
+        tokens.drain(..tokens.len().min(2));
+        tokens
+    }
+
+    /// Converts a preprocessing number into a number constant.
+    ///
+    /// The number is lexed at its location, so that the errors point to where
+    /// it was written. `signed` indicates if the number follows a minus sign.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the number isn't a valid number constant.
+    fn lex_number(&mut self, token: PpToken, signed: bool) -> Option<PpToken> {
+        let (line, col) = match token.location {
+            ErrorLocation::Block(_, line, col, ..)
+            | ErrorLocation::Char(_, line, col)
+            | ErrorLocation::Token(_, line, col, _)
+            | ErrorLocation::TwoTokens(_, line, col, ..) => (line, col),
+            ErrorLocation::None => (0, 1),
+        };
+        let mut content = " ".repeat(u32_to_usize(col.saturating_sub(1)));
+        if signed && content.pop().is_some() {
+            content.push('-');
+        }
+        content.push_str(&token.spelling);
+        let (value, _) =
+            lex_from_line(&content, token.file(), line.saturating_sub(1), &self.options.target)
+                .store_errors(&mut |err| self.errors.push(err))?
+                .pop()?
+                .into_value_location();
+        Some(PpToken { value, ..token })
+    }
+
+    /// Converts the preprocessing numbers of expanded tokens into number
+    /// constants.
+    pub fn lex_numbers(&mut self, tokens: Vec<PpToken>) -> Vec<PpToken> {
+        let mut lexed: Vec<PpToken> = vec![];
+        for token in tokens {
+            if token.is_pp_number() {
+                let signed = lexed
+                    .last()
+                    .is_some_and(|prev| prev.is_symbol(Symbol::Minus));
+                lexed.extend(self.lex_number(token, signed));
+            } else {
+                lexed.push(token);
+            }
+        }
+        lexed
+    }
+
+    /// Creates a new preprocessing state.
+    pub fn new(options: &'pp PreprocessorOptions, files: &'pp mut SourceFiles) -> Self {
+        Self {
+            conditions: vec![],
+            current_line: 0,
+            errors: vec![],
+            file_stack: vec![],
+            files,
+            macros: HashMap::new(),
+            once: HashSet::new(),
+            options,
+            output: vec![],
+        }
+    }
+
+    /// Returns the presumed file name and line number of the current line, as
+    /// modified by the `#line` directive.
+    pub fn presumed_position(&self) -> (String, u32) {
+        let Some(file) = self.file_stack.last() else {
+            return (String::new(), self.current_line);
+        };
+        match &file.line_map {
+            Some((physical, presumed, name)) => (
+                name.clone()
+                    .unwrap_or_else(|| self.files.name(file.id).to_owned()),
+                presumed.saturating_add(self.current_line.saturating_sub(*physical)),
+            ),
+            None => (self.files.name(file.id).to_owned(), self.current_line),
+        }
+    }
+
+    /// Preprocesses the content of a file.
+    ///
+    /// The file must already be pushed on the file stack.
+    pub fn process_file(&mut self, content: &str) {
+        let file = self.current_file();
+        let depth = self.conditions.len();
+        let mut segment = Segment::default();
+        for line in logical_lines(content) {
+            if let Some(directive) = line.as_directive(file) {
+                self.flush(file, &mut segment);
+                self.directive(&line, &directive);
+            } else if self.is_active() {
+                segment.push(&line);
+            }
+        }
+        self.flush(file, &mut segment);
+        while self.conditions.len() > depth {
+            if let Some(cond) = self.conditions.pop() {
+                self.errors.push(
+                    cond.location
+                        .fail("Unterminated conditional directive".to_owned()),
+                );
+            }
+        }
+    }
+
+    /// Pushes a token to the output.
+    ///
+    /// If two successive constant strings with compatible encodings are
+    /// found, they are merged.
+    fn push_output(&mut self, token: PpToken) {
+        if let Some(previous) = self.output.last_mut()
+            && let TokenValue::Str(prev_str, prev_encoding) = &mut previous.value
+            && let TokenValue::Str(new_str, new_encoding) = &token.value
+            && let Some(encoding) = prev_encoding.concat(*new_encoding)
+        {
+            prev_str.push_str(new_str);
+            *prev_encoding = encoding;
+            if previous.file() == token.file() && previous.line() <= token.line() {
+                previous.location.extend(token.location);
+            }
+        } else {
+            self.output.push(token);
+        }
+    }
+}
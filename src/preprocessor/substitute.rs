@@ -0,0 +1,194 @@
+//! Module to replace the parameters of a macro by its arguments, and handle
+//! the `#`, `##` and `__VA_OPT__` operators.
+
+extern crate alloc;
+use alloc::collections::BTreeSet;
+
+use crate::errors::api::ErrorLocation;
+use crate::lexer::api::{Symbol, lex_pp_numbers};
+use crate::preprocessor::macros::{Macro, VA_OPT};
+use crate::preprocessor::state::PpState;
+use crate::preprocessor::token::PpToken;
+
+/// Element of the replacement list, before the `##` operators are applied.
+#[derive(Debug)]
+enum Item {
+    /// `##` operator
+    Paste(ErrorLocation),
+    /// Empty argument, that is needed by the `##` operator.
+    Placemarker,
+    /// Token of the replacement list
+    Token(PpToken),
+}
+
+impl PpState<'_> {
+    /// Applies the `##` operator on two tokens.
+    fn paste(&mut self, left: PpToken, right: PpToken, location: ErrorLocation) -> Vec<PpToken> {
+        let spelling = format!("{}{}", left.spelling, right.spelling);
+        let mut failed = false;
+        let mut tokens = lex_pp_numbers(&spelling, left.file(), 0, &self.options.target)
+            .store_errors(&mut |_| failed = true)
+            .unwrap_or_default();
+        if !failed
+            && tokens.len() == 1
+            && let Some(token) = tokens.pop()
+        {
+            let (value, _) = token.into_value_location();
+            let mut hideset = left.hideset;
+            hideset.extend(right.hideset);
+            vec![PpToken { hideset, location: left.location, space: left.space, spelling, value }]
+        } else {
+            self.errors.push(location.fail(format!(
+                "Pasting \"{}\" and \"{}\" doesn't give a valid preprocessing token",
+                left.spelling, right.spelling
+            )));
+            vec![left, right]
+        }
+    }
+
+    /// Applies the `##` operators and removes the placemarkers.
+    fn paste_all(&mut self, items: Vec<Item>) -> Vec<PpToken> {
+        let mut pasted: Vec<Item> = vec![];
+        let mut iter = items.into_iter();
+        while let Some(item) = iter.next() {
+            let Item::Paste(location) = item else {
+                pasted.push(item);
+                continue;
+            };
+            let left = pasted.pop().unwrap_or(Item::Placemarker);
+            let right = iter.next().unwrap_or(Item::Placemarker);
+            match (left, right) {
+                (Item::Token(left_tok), Item::Token(right_tok)) => pasted.extend(
+                    self.paste(left_tok, right_tok, location)
+                        .into_iter()
+                        .map(Item::Token),
+                ),
+                (Item::Placemarker | Item::Paste(_), other)
+                | (other, Item::Placemarker | Item::Paste(_)) => pasted.push(other),
+            }
+        }
+        pasted
+            .into_iter()
+            .filter_map(|item| {
+                if let Item::Token(token) = item {
+                    Some(token)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Replaces a macro invocation by the replacement list of the macro.
+    ///
+    /// The tokens of the replacement list keep the location of the
+    /// definition of the macro, and the tokens of the arguments keep their
+    /// own location.
+    pub fn substitute(
+        &mut self,
+        mac: &Macro,
+        args: &[Vec<PpToken>],
+        invocation: &PpToken,
+        mut hideset: BTreeSet<String>,
+    ) -> Vec<PpToken> {
+        let items = self.substitute_items(mac, &mac.body, args);
+        let mut tokens = self.paste_all(items);
+        hideset.insert(invocation.spelling.clone());
+        for token in &mut tokens {
+            token.hideset.extend(hideset.iter().cloned());
+        }
+        if let Some(first) = tokens.first_mut() {
+            first.space = invocation.space;
+        }
+        tokens
+    }
+
+    /// Replaces the parameters by the arguments in a replacement list.
+    fn substitute_items(
+        &mut self,
+        mac: &Macro,
+        body: &[PpToken],
+        args: &[Vec<PpToken>],
+    ) -> Vec<Item> {
+        let mut items = vec![];
+        let mut idx = 0;
+        while let Some(token) = body.get(idx) {
+            idx = idx.saturating_add(1);
+            let next = body.get(idx);
+            if token.is_symbol(Symbol::Concat) {
+                items.push(Item::Paste(token.location));
+                continue;
+            }
+            if mac.params.is_some()
+                && token.is_symbol(Symbol::Hash)
+                && let Some(arg) = next
+                    .and_then(PpToken::ident)
+                    .and_then(|name| mac.param_index(name))
+                    .and_then(|arg_idx| args.get(arg_idx))
+            {
+                idx = idx.saturating_add(1);
+                let mut string = PpToken::stringify(arg, token.location);
+                string.space = token.space;
+                items.push(Item::Token(string));
+                continue;
+            }
+            if mac.variadic && token.ident() == Some(VA_OPT) {
+                let content_end = matching_parenthesis(body, idx);
+                let content = body
+                    .get(idx.saturating_add(1)..content_end)
+                    .unwrap_or_default();
+                idx = content_end.saturating_add(1);
+                let va_args = args.last().cloned().unwrap_or_default();
+                if self.expand(va_args).is_empty() {
+                    items.push(Item::Placemarker);
+                } else {
+                    items.extend(self.substitute_items(mac, content, args));
+                }
+                continue;
+            }
+            let Some(arg) = token
+                .ident()
+                .and_then(|name| mac.param_index(name))
+                .and_then(|arg_idx| args.get(arg_idx))
+            else {
+                items.push(Item::Token(token.clone()));
+                continue;
+            };
+            let pasted = matches!(items.last(), Some(Item::Paste(_)))
+                || next.is_some_and(|tok| tok.is_symbol(Symbol::Concat));
+            let mut replacement = if pasted {
+                arg.clone()
+            } else {
+                self.expand(arg.clone())
+            };
+            if let Some(first) = replacement.first_mut() {
+                first.space = token.space;
+            }
+            if replacement.is_empty() {
+                items.push(Item::Placemarker);
+            } else {
+                items.extend(replacement.into_iter().map(Item::Token));
+            }
+        }
+        items
+    }
+}
+
+/// Returns the index of the parenthesis that closes the one at the given
+/// index.
+///
+/// If the parenthesis isn't closed, the length of the list is returned.
+fn matching_parenthesis(body: &[PpToken], open: usize) -> usize {
+    let mut depth = 0u32;
+    for (idx, token) in body.iter().enumerate().skip(open) {
+        if token.is_symbol(Symbol::ParenthesisOpen) {
+            depth = depth.saturating_add(1);
+        } else if token.is_symbol(Symbol::ParenthesisClose) {
+            depth = depth.saturating_sub(1);
+            if depth == 0 {
+                return idx;
+            }
+        }
+    }
+    body.len()
+}
@@ -0,0 +1,143 @@
+//! Module to define the [`PpToken`], the token manipulated by the
+//! preprocessor.
+
+extern crate alloc;
+use alloc::collections::BTreeSet;
+
+use crate::errors::api::ErrorLocation;
+use crate::lexer::api::{Encoding, Number, Symbol, Token, TokenValue};
+use crate::utils::u32_to_usize;
+
+/// Token with the information needed by the preprocessor.
+#[derive(Debug, Clone)]
+pub struct PpToken {
+    /// Names of the macros that can't be expanded from this token anymore,
+    /// to prevent infinite recursion.
+    pub hideset: BTreeSet<String>,
+    /// Location of the token, in the file where it was written.
+    pub location: ErrorLocation,
+    /// Whether the token is preceded by whitespace.
+    pub space: bool,
+    /// Text of the token, as it was written.
+    pub spelling: String,
+    /// Value of the token.
+    pub value: TokenValue,
+}
+
+impl PpToken {
+    /// Returns the file id of the location of the token.
+    pub const fn file(&self) -> u32 {
+        match self.location {
+            ErrorLocation::Block(file, ..)
+            | ErrorLocation::Char(file, ..)
+            | ErrorLocation::Token(file, ..)
+            | ErrorLocation::TwoTokens(file, ..) => file,
+            ErrorLocation::None => 0,
+        }
+    }
+
+    /// Makes preprocessing tokens from the tokens of a lexed segment.
+    ///
+    /// `content` is the content that was lexed, and `first` the line number of
+    /// its first line.
+    pub fn from_tokens(tokens: Vec<Token>, content: &str, first: u32) -> Vec<Self> {
+        let lines = content.lines().collect::<Vec<_>>();
+        let mut end = (0, 0);
+        tokens
+            .into_iter()
+            .map(|token| {
+                let (value, location) = token.into_value_location();
+                let (spelling, start, new_end) = match location {
+                    ErrorLocation::Token(_, line, col, len) => (
+                        line.checked_sub(first)
+                            .and_then(|idx| lines.get(u32_to_usize(idx)))
+                            .map(|code| code.chars().skip(u32_to_usize(col.saturating_sub(1))))
+                            .map(|chars| chars.take(u32_to_usize(len)).collect::<String>())
+                            .filter(|_| !matches!(value, TokenValue::Char(..)))
+                            .unwrap_or_else(|| value.spelling()),
+                        (line, col),
+                        (line, col.saturating_add(len)),
+                    ),
+                    ErrorLocation::Block(_, line, col, end_line, end_col) =>
+                        (value.spelling(), (line, col), (end_line, end_col.saturating_add(1))),
+                    ErrorLocation::Char(_, line, col) =>
+                        (value.spelling(), (line, col), (line, col.saturating_add(1))),
+                    ErrorLocation::None | ErrorLocation::TwoTokens(..) =>
+                        (value.spelling(), end, end),
+                };
+                let space = start != end;
+                end = new_end;
+                Self { hideset: BTreeSet::new(), location, space, spelling, value }
+            })
+            .collect()
+    }
+
+    /// Returns the name of the token if it is an identifier or a keyword.
+    pub fn ident(&self) -> Option<&str> {
+        (matches!(self.value, TokenValue::Ident(_) | TokenValue::Keyword(_))
+            && !self.is_pp_number())
+        .then_some(self.spelling.as_str())
+    }
+
+    /// Converts the preprocessing token back into a [`Token`].
+    pub fn into_token(self) -> Token {
+        Token::from_value_location(self.value, self.location)
+    }
+
+    /// Checks if the token is a preprocessing number, that is lexed into a
+    /// number constant only after the macro expansion.
+    pub fn is_pp_number(&self) -> bool {
+        matches!(&self.value, TokenValue::Ident(name) if name.starts_with(|ch: char| ch.is_ascii_digit()))
+    }
+
+    /// Checks if the token is the given symbol.
+    pub fn is_symbol(&self, symbol: Symbol) -> bool {
+        matches!(&self.value, TokenValue::Symbol(sym) if *sym == symbol)
+    }
+
+    /// Returns the line of the location of the token.
+    pub const fn line(&self) -> u32 {
+        match self.location {
+            ErrorLocation::Block(_, line, ..)
+            | ErrorLocation::Char(_, line, _)
+            | ErrorLocation::Token(_, line, ..)
+            | ErrorLocation::TwoTokens(_, line, ..) => line,
+            ErrorLocation::None => 0,
+        }
+    }
+
+    /// Creates a number token, at the given location.
+    pub const fn number(value: Number, spelling: String, location: ErrorLocation) -> Self {
+        Self {
+            hideset: BTreeSet::new(),
+            location,
+            space: true,
+            spelling,
+            value: TokenValue::Number(value),
+        }
+    }
+
+    /// Creates a string token, at the given location.
+    pub fn string(string: String, location: ErrorLocation) -> Self {
+        let value = TokenValue::Str(string, Encoding::Plain);
+        Self {
+            hideset: BTreeSet::new(),
+            location,
+            space: true,
+            spelling: value.spelling(),
+            value,
+        }
+    }
+
+    /// Converts a list of tokens into a string token, for the `#` operator.
+    pub fn stringify(tokens: &[Self], location: ErrorLocation) -> Self {
+        let mut value = String::new();
+        for (idx, token) in tokens.iter().enumerate() {
+            if idx != 0 && token.space {
+                value.push(' ');
+            }
+            value.push_str(&token.spelling);
+        }
+        Self::string(value, location)
+    }
+}
@@ -0,0 +1,174 @@
+//! Defines the [`TargetInfo`], describing the machine the C code is compiled
+//! for.
+
+/// Order of the bytes of the scalars in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Endianness {
+    /// Most significant byte first, like on PowerPC or s390x.
+    Big,
+    /// Least significant byte first, like on x86 or most ARM targets.
+    Little,
+}
+
+/// Size and alignment of a type, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Layout {
+    /// Alignment of the type, in bytes.
+    pub align: u64,
+    /// Size of the type, in bytes.
+    pub size: u64,
+}
+
+impl Layout {
+    /// Creates a layout whose alignment is its size.
+    #[must_use]
+    pub const fn natural(size: u64) -> Self {
+        Self { align: size, size }
+    }
+
+    /// Creates a layout with the given size and alignment.
+    #[must_use]
+    pub const fn new(size: u64, align: u64) -> Self {
+        Self { align, size }
+    }
+}
+
+/// Scalar types whose layout depends on the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Scalar {
+    /// `bool`
+    Bool,
+    /// `char`, `signed char` and `unsigned char`
+    Char,
+    /// `double`
+    Double,
+    /// `float`
+    Float,
+    /// `int`
+    Int,
+    /// `long`
+    Long,
+    /// `long double`
+    LongDouble,
+    /// `long long`
+    LongLong,
+    /// Any pointer
+    Pointer,
+    /// `short`
+    Short,
+}
+
+/// Description of the target machine.
+///
+/// It defines the sizes and alignments of the scalar types, the signedness of
+/// `char` and the endianness. They are used to type the number constants, to
+/// warn on overflows and to compute the layout of the types. The default
+/// target is LP64, as found on 64-bit Linux and macOS.
+///
+/// # Examples
+///
+/// ```
+/// use c_parser::TargetInfo;
+///
+/// let mut target = TargetInfo::ilp32();
+/// target.char_signed = false;
+/// assert!(target.long.size == 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TargetInfo {
+    /// Whether `char` is signed, like on x86, or unsigned, like on ARM.
+    pub char_signed: bool,
+    /// Layout of `double`.
+    pub double: Layout,
+    /// Order of the bytes in memory.
+    pub endianness: Endianness,
+    /// Layout of `int`.
+    pub int: Layout,
+    /// Layout of `long`.
+    pub long: Layout,
+    /// Layout of `long double`.
+    pub long_double: Layout,
+    /// Layout of `long long`.
+    pub long_long: Layout,
+    /// Layout of a pointer.
+    pub pointer: Layout,
+}
+
+impl Default for TargetInfo {
+    fn default() -> Self {
+        Self::lp64()
+    }
+}
+
+impl TargetInfo {
+    /// Returns the number of bits of a scalar type on this target.
+    pub(crate) const fn bits(&self, scalar: Scalar) -> u64 {
+        self.layout(scalar).size.saturating_mul(8)
+    }
+
+    /// Target for 32-bit systems, where `int`, `long` and pointers have 32
+    /// bits, like i386 Linux.
+    #[must_use]
+    pub const fn ilp32() -> Self {
+        Self {
+            char_signed: true,
+            double: Layout { align: 4, size: 8 },
+            endianness: Endianness::Little,
+            int: Layout::natural(4),
+            long: Layout::natural(4),
+            long_double: Layout { align: 4, size: 12 },
+            long_long: Layout { align: 4, size: 8 },
+            pointer: Layout::natural(4),
+        }
+    }
+
+    /// Returns the layout of a scalar type on this target.
+    pub(crate) const fn layout(&self, scalar: Scalar) -> Layout {
+        match scalar {
+            Scalar::Bool | Scalar::Char => Layout::natural(1),
+            Scalar::Double => self.double,
+            Scalar::Float => Layout::natural(4),
+            Scalar::Int => self.int,
+            Scalar::Long => self.long,
+            Scalar::LongDouble => self.long_double,
+            Scalar::LongLong => self.long_long,
+            Scalar::Pointer => self.pointer,
+            Scalar::Short => Layout::natural(2),
+        }
+    }
+
+    /// Target for 64-bit Windows, where `long` has 32 bits but pointers have
+    /// 64 bits.
+    #[must_use]
+    pub const fn llp64() -> Self {
+        Self {
+            char_signed: true,
+            double: Layout::natural(8),
+            endianness: Endianness::Little,
+            int: Layout::natural(4),
+            long: Layout::natural(4),
+            long_double: Layout::natural(8),
+            long_long: Layout::natural(8),
+            pointer: Layout::natural(8),
+        }
+    }
+
+    /// Target for 64-bit Unix systems, where `long` and pointers have 64 bits,
+    /// like `x86_64` Linux.
+    #[must_use]
+    pub const fn lp64() -> Self {
+        Self {
+            char_signed: true,
+            double: Layout::natural(8),
+            endianness: Endianness::Little,
+            int: Layout::natural(4),
+            long: Layout::natural(8),
+            long_double: Layout::natural(16),
+            long_long: Layout::natural(8),
+            pointer: Layout::natural(8),
+        }
+    }
+}
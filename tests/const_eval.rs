@@ -0,0 +1,115 @@
+//! Tests the evaluation of the constant expressions.
+
+#![expect(clippy::restriction, reason = "tests")]
+
+use c_parser::*;
+
+macro_rules! gen_const_eval_test {
+    ($($name:ident: $target:ident $input:expr => $output:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                test_const_eval($input, &$output, TargetInfo::$target())
+            }
+        )*
+    };
+}
+
+macro_rules! gen_const_eval_error_test {
+    ($($name:ident: $input:expr => $error:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                test_const_eval_error($input, $error)
+            }
+        )*
+    };
+}
+
+fn eval(content: &str, target: TargetInfo) -> (Option<Number>, String) {
+    let files = &[(0, "", content)];
+    let tree = parse(lex(content, 0).unwrap_or_display(files).unwrap())
+        .unwrap_or_display(files)
+        .unwrap();
+    assert!(tree.elts.len() == 1, "Parser error: expected one expression in {content}");
+    const_eval_with(tree.elts.first().unwrap(), target).as_displayed_errors(files)
+}
+
+fn test_const_eval(content: &str, expected: &Number, target: TargetInfo) {
+    let (value, errors) = eval(content, target);
+    assert!(errors.is_empty(), "Evaluation error on {content}:\n{errors}");
+    let computed = value.unwrap();
+    assert!(
+        computed == *expected,
+        "Evaluation error: computed wrong value for {content}: Expected: {expected:?}\n != Computed: {computed:?}"
+    );
+}
+
+fn test_const_eval_error(content: &str, expected: &str) {
+    let (value, errors) = eval(content, TargetInfo::default());
+    assert!(value.is_none(), "Evaluation error: {content} evaluated to {value:?}");
+    assert!(
+        errors.contains(expected),
+        "Evaluation error: expected error {expected:?} for {content}, but found:\n{errors}"
+    );
+}
+
+gen_const_eval_test!(
+    const_eval_add: lp64 "1 + 2 * 3" => Number::Int(7);
+    const_eval_parens: lp64 "(1 + 2) * 3" => Number::Int(9);
+    const_eval_divide: lp64 "-7 / 2" => Number::Int(-3);
+    const_eval_modulo: lp64 "-7 % 2" => Number::Int(-1);
+    const_eval_shift: lp64 "(1 << 4) | 3" => Number::Int(19);
+    const_eval_shift_right: lp64 "-16 >> 2" => Number::Int(-4);
+    const_eval_bitwise: lp64 "~0 ^ 0xf0 & 0xff" => Number::Int(-241);
+    const_eval_unsigned_wrap: lp64 "0u - 1" => Number::UInt(u32::MAX);
+    const_eval_unsigned_compare: lp64 "-1 < 1u" => Number::Int(0);
+    const_eval_long_compare: lp64 "-1L < 1u" => Number::Int(1);
+    const_eval_ilp32_long_compare: ilp32 "-1L < 1u" => Number::Int(0);
+    const_eval_common_long: lp64 "1u + 2L" => Number::Long(3);
+    const_eval_ilp32_common_long: ilp32 "1u + 2L" => Number::ULong(3);
+    const_eval_common_ulong: lp64 "1ul + 2LL" => Number::ULongLong(3);
+    const_eval_float: lp64 "1.5f * 2" => Number::Float(3.0);
+    const_eval_double: lp64 "1 / 4.0 + 1.5f" => Number::Double(1.75);
+    const_eval_logical: lp64 "!0 && (2 || 1 / 0)" => Number::Int(1);
+    const_eval_short_circuit: lp64 "0 && 1 / 0" => Number::Int(0);
+    const_eval_ternary: lp64 "1 ? 2 : 3.0" => Number::Double(2.0);
+    const_eval_ternary_unevaluated: lp64 "0 ? 1 / 0 : 4" => Number::Int(4);
+    const_eval_char: lp64 "'a' + 1" => Number::Int(98);
+    const_eval_bool: lp64 "true + true" => Number::Int(2);
+    const_eval_cast_int: lp64 "(int)3.9" => Number::Int(3);
+    const_eval_cast_char: lp64 "(unsigned char)300" => Number::Int(44);
+    const_eval_cast_signed_char: lp64 "(signed char)200" => Number::Int(-56);
+    const_eval_cast_bool: lp64 "(bool)0.5" => Number::Int(1);
+    const_eval_cast_unsigned: lp64 "(unsigned)-1" => Number::UInt(u32::MAX);
+    const_eval_cast_double: lp64 "(double)1 / 2" => Number::Double(0.5);
+    const_eval_sizeof: lp64 "sizeof(long)" => Number::ULong(8);
+    const_eval_ilp32_sizeof: ilp32 "sizeof(long)" => Number::ULong(4);
+    const_eval_llp64_sizeof: llp64 "sizeof(int *)" => Number::ULongLong(8);
+    const_eval_sizeof_expression: lp64 "sizeof 1 + sizeof(char)" => Number::ULong(5);
+    const_eval_alignof: lp64 "alignof(double) * 2" => Number::ULong(16);
+    const_eval_generic: lp64 "_Generic(1.0, double: 4, default: 5) + 1" => Number::Int(5);
+);
+
+gen_const_eval_error_test!(
+    const_eval_division_by_zero: "1 / (2 - 2)" => "1:3: error: Division by zero in constant expression";
+    const_eval_modulo_by_zero: "1 % 0" => "Division by zero in constant expression";
+    const_eval_overflow: "2147483647 + 1" => "1:12: error: Overflow in constant expression: 2147483648 doesn't fit in int";
+    const_eval_overflow_negate: "-(-2147483647 - 1)" => "Overflow in constant expression: 2147483648 doesn't fit in int";
+    const_eval_overflow_shift: "1 << 31" => "Overflow in constant expression: 2147483648 doesn't fit in int";
+    const_eval_shift_range: "1 << 32" => "1:3: error: Shift count 32 is out of range for type int of 32 bits";
+    const_eval_shift_negative: "1 >> -1" => "Shift count -1 is out of range for type int of 32 bits";
+    const_eval_shift_negative_value: "-1 << 1" => "Left shift of negative value -1";
+    const_eval_float_to_int: "(int)1e10" => "Overflow in constant expression: 10000000000 doesn't fit in int";
+    const_eval_float_modulo: "1.0 % 2" => "Invalid operands of types double and double for operator %";
+    const_eval_variable: "x + 1" => "Expected constant expression, found x";
+    const_eval_comma: "(1, 2)" => "Operator , is not allowed in constant expressions";
+    const_eval_assign: "1 = 2" => "Operator = is not allowed in constant expressions";
+    const_eval_string: "\"abc\"" => "Expected constant expression, found \"abc\"";
+    const_eval_cast_pointer: "(int *)0" => "Cast to non-arithmetic type int * is not allowed in constant expressions";
+    const_eval_sizeof_void: "sizeof(void)" => "Invalid application of `sizeof` to incomplete type void";
+    const_eval_call: "f(1)" => "Function call f is not allowed in constant expressions";
+    const_eval_missing_rhs: "1 +" => "Missing RHS of binary operator";
+    const_eval_missing_operand: "-" => "Missing operand of `-`";
+    const_eval_empty_parens: "1 + ()" => "Expected expression in parenthesis";
+);
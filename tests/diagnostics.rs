@@ -0,0 +1,82 @@
+//! Tests the machine-readable outputs of the errors.
+
+#![expect(clippy::restriction, reason = "tests")]
+
+use c_parser::*;
+
+const CONTENT: &str = "_Complex _Decimal32 x;\nchar *s = \"a\\q\";";
+
+const FILES: &[(u32, &str, &str)] = &[(0, "dir/file.c", CONTENT)];
+
+fn linearised() -> Res<()> {
+    let ast = parse(lex(CONTENT, 0).unwrap_or_display(FILES).unwrap())
+        .unwrap_or_display(FILES)
+        .unwrap();
+    linearise(ast).map(|_| ())
+}
+
+#[test]
+fn diagnostics_two_tokens() {
+    let diagnostics = linearised().as_diagnostics(FILES);
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.level, ErrorLevel::Fault);
+    assert_eq!(diagnostic.file, "dir/file.c");
+    assert_eq!(
+        (diagnostic.span.start_line, diagnostic.span.start_col, diagnostic.span.end_col),
+        (1, 1, 9)
+    );
+    let secondary = diagnostic.secondary.unwrap();
+    assert_eq!((secondary.start_line, secondary.start_col, secondary.end_col), (1, 10, 20));
+}
+
+#[test]
+fn diagnostics_multi_line() {
+    let content = "void f(unsigned u) {\n    char c = (u\n        + 1);\n}";
+    let files = &[(0, "file.c", content)];
+    let ast = parse(lex(content, 0).unwrap_or_display(files).unwrap())
+        .unwrap_or_display(files)
+        .unwrap();
+    let diagnostics = linearise(ast).as_diagnostics(files);
+    assert_eq!(diagnostics.len(), 1);
+    let span = diagnostics[0].span;
+    assert_eq!((span.start_line, span.start_col, span.end_line, span.end_col), (2, 14, 3, 13));
+}
+
+#[test]
+fn json_lines_escapes() {
+    let (tokens, errors) = lex(CONTENT, 0).as_json_lines(FILES);
+    assert!(tokens.is_some());
+    assert_eq!(
+        errors,
+        r#"{"level":"warning","message":"Escape ignored. Escaping character 'q' has no effect. Please remove the '\\'.","file":"dir/file.c","start_line":2,"start_col":13,"end_line":2,"end_col":15,"secondary":null}
+"#
+    );
+}
+
+#[test]
+fn json_lines_two_tokens() {
+    let (_, errors) = linearised().as_json_lines(FILES);
+    assert_eq!(
+        errors,
+        r#"{"level":"fault","message":"Decimal can't be complex, only real","file":"dir/file.c","start_line":1,"start_col":1,"end_line":1,"end_col":9,"secondary":{"start_line":1,"start_col":10,"end_line":1,"end_col":20}}
+"#
+    );
+}
+
+#[test]
+fn sarif_empty() {
+    let (_, log) = lex("int x;", 0).as_sarif(FILES);
+    assert!(log.ends_with(
+        r#""columnKind":"unicodeCodePoints","results":[]}]}
+"#
+    ));
+}
+
+#[test]
+fn sarif_related_location() {
+    let (_, log) = linearised().as_sarif(FILES);
+    assert!(log.contains(r#""tool":{"driver":{"name":"c-parser","#));
+    assert!(log.ends_with(r#""results":[{"level":"error","message":{"text":"Decimal can't be complex, only real"},"locations":[{"physicalLocation":{"artifactLocation":{"uri":"dir/file.c"},"region":{"startLine":1,"startColumn":1,"endLine":1,"endColumn":9}}}],"relatedLocations":[{"id":0,"physicalLocation":{"artifactLocation":{"uri":"dir/file.c"},"region":{"startLine":1,"startColumn":10,"endLine":1,"endColumn":20}}}]}]}]}
+"#));
+}
@@ -0,0 +1,3 @@
+#define BROKEN(x) x ## +
+
+int broken = BROKEN(1);
@@ -0,0 +1,186 @@
+//! Tests that re-lexing and re-parsing a file after an edit gives the same
+//! tokens and AST as processing the whole edited file.
+
+#![expect(clippy::restriction, reason = "tests")]
+
+use c_parser::*;
+
+macro_rules! gen_incremental_test {
+    ($($name:ident: $input:expr, $range:expr => $text:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                test_incremental($input, &TextEdit::new($range, $text.to_owned()))
+            }
+        )*
+    };
+}
+
+/// Visitor that collects the locations of an AST, as they are not printed in
+/// its debug representation.
+struct Locations(Vec<ErrorLocation>);
+
+impl Visit for Locations {
+    fn visit_location(&mut self, location: ErrorLocation) {
+        self.0.push(location);
+    }
+}
+
+fn locations(ast: Option<&BracedBlock>) -> Vec<ErrorLocation> {
+    let mut visitor = Locations(vec![]);
+    if let Some(block) = ast {
+        visitor.visit_braced_block(block);
+    }
+    visitor.0
+}
+
+/// Applies an edit with [`relex`] and [`reparse`], and checks that the tokens
+/// and the AST are the same as when processing the whole edited file.
+fn edit_and_compare(
+    content: &mut String,
+    tokens: &mut Vec<Token>,
+    ast: BracedBlock,
+    edit: &TextEdit,
+) -> Option<BracedBlock> {
+    let target = TargetInfo::default();
+    let edited_content = edit.apply(content);
+    let change = relex(content, tokens, edit, 0, &target)
+        .into_parts()
+        .0
+        .unwrap();
+    assert_eq!(*content, edited_content, "{edit:?}");
+    let full_tokens = lex(content, 0).into_parts().0.unwrap();
+    assert_eq!(format!("{tokens:?}"), format!("{full_tokens:?}"), "{edit:?}");
+    let new_ast = reparse(ast, tokens, &change).into_parts().0;
+    let full_ast = parse(full_tokens).into_parts().0;
+    assert_eq!(format!("{new_ast:?}"), format!("{full_ast:?}"), "{edit:?}");
+    assert_eq!(
+        format!("{:?}", locations(new_ast.as_ref())),
+        format!("{:?}", locations(full_ast.as_ref())),
+        "{edit:?}"
+    );
+    new_ast
+}
+
+fn test_incremental(content: &str, edit: &TextEdit) {
+    let mut tokens = lex(content, 0).into_parts().0.unwrap();
+    let ast = parse(tokens.clone()).into_parts().0.unwrap();
+    edit_and_compare(&mut content.to_owned(), &mut tokens, ast, edit);
+}
+
+const FILE: &str = "int f(int a) {
+  int b = a + 1;
+  if (b) {
+    b = 2;
+  }
+  return b;
+}
+int x = 1; int y = 2;
+/* comment
+   on many lines */
+char *s = \"a\\\"b\" \"c\";
+long z = -5L;
+struct s { int a; int b; };
+";
+
+gen_incremental_test!(
+    append_ident: FILE, 22..22 => "c";
+    close_comment: FILE, 104..104 => "*/";
+    delete_all: FILE, 0..FILE.len() => "";
+    delete_semi_colon: FILE, 30..31 => "";
+    edit_at_end: FILE, FILE.len()..FILE.len() => "int w";
+    edit_at_start: FILE, 0..3 => "long";
+    edit_function_body: FILE, 51..52 => "3";
+    edit_in_string: FILE, 137..137 => "\"; int t = \"";
+    insert_newline: FILE, 83..83 => "\n";
+    insert_statement: FILE, 72..72 => "int q = 3;\n";
+    open_block: FILE, 72..72 => "{";
+    open_comment: FILE, 83..83 => "/*";
+    same_line_statement: FILE, 91..92 => "42";
+    shorten_number: FILE, 157..159 => "5";
+    unchanged: FILE, 5..6 => "(";
+);
+
+gen_incremental_test!(
+    attribute_after_edit: "int x;\nint y;\n[[nodiscard]] int f(void);\n", 4..5 => "z";
+    backslash_before_comment: "int*/\\\n*/ comment\n", 18..18 => "L";
+    backslash_in_function: "int main(void) {\n  int x;\n}\n", 13..16 => "\\";
+    case_after_expression: "in/case[* a\n b */ int x);\n", 20..20 => "";
+    comment_in_parameters: "int f(/* a\n b */ int x);\n", 12..16 => "";
+    line_comment_after_star: "int x =*/// comment\n", 15..16 => "}";
+    spliced_brace: "int f(void) {}\\\nnt x = 1;\n/* a\n b */\nint y;\n", 30..31 => " ";
+);
+
+/// Pieces of C code inserted by [`random_edits`].
+const PIECES: [&str; 32] = [
+    " ",
+    "\n",
+    "\\\n",
+    "\\",
+    "/*",
+    "*/",
+    "//",
+    "\"",
+    "'",
+    "(",
+    ")",
+    "{",
+    "}",
+    "[",
+    "]",
+    ";",
+    ",",
+    "-",
+    "*",
+    "/",
+    "=",
+    "1",
+    "x",
+    "int",
+    "L",
+    "case",
+    ":",
+    "typedef",
+    "struct",
+    "if (",
+    "return",
+    "[[nodiscard]]",
+];
+
+/// Xorshift pseudo-random number generator, to generate reproducible edits.
+struct Random(u64);
+
+impl Random {
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        usize::try_from(self.0 % u64::try_from(bound).unwrap()).unwrap()
+    }
+}
+
+#[test]
+fn random_edits() {
+    let files = [
+        FILE,
+        "int main(void) {\n  int x;\n}\n",
+        "int f(/* a\n b */ int x);\n",
+        "int x =*/// comment\n",
+    ];
+    let mut random = Random(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..1000 {
+        let mut content = files[random.below(files.len())].to_owned();
+        let mut tokens = lex(&content, 0).into_parts().0.unwrap();
+        let mut ast = parse(tokens.clone()).into_parts().0;
+        for _ in 0..4 {
+            let Some(old_ast) = ast else { break };
+            let start = random.below(content.len() + 1);
+            let end = (start + random.below(5)).min(content.len());
+            let text = (0..random.below(3))
+                .map(|_| PIECES[random.below(PIECES.len())])
+                .collect::<String>();
+            let edit = TextEdit::new(start..end, text);
+            ast = edit_and_compare(&mut content, &mut tokens, old_ast, &edit);
+        }
+    }
+}
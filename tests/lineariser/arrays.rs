@@ -0,0 +1,41 @@
+//! Lineariser tests for array types.
+
+crate::ssa!(
+
+array_types: "int a[10][20]; char *argv[4]; typedef int A[3]; A b[2]; const A c;"
+
+array_sizeof: "int a[10][20]; unsigned long s = sizeof a, t = sizeof a[0], u = alignof(int[3]), v = sizeof(int[2][3]);"
+
+array_typedef_sizeof: "typedef int A[3]; A b[2]; unsigned long s = sizeof b, t = sizeof(A);"
+
+array_parameters: "void f(int a[static 10], int b[const 5], int c[*], int d[][3]);"
+
+array_subscript: "int f() { int a[3]; int *p = a; int x = a[0] + 1[a] + p[2]; return *a; }"
+
+array_address: "int a[3]; &a; *a;"
+
+array_invalid_subscript: "int x; int y = x[1]; int *p; int z = p[1.0];"
+
+array_invalid_sizes: "int a[-1]; int b[1.5]; int n; int c[n];"
+
+array_incomplete_element: "int a[3][]; struct T; struct T b[2];"
+
+array_unspecified_variable: "int a[*];"
+
+array_qualifiers_not_parameter: "int a[const 3]; void f(int b[2][static 3]);"
+
+array_static_without_size: "void f(int a[static]);"
+
+array_vla: "void f(int n) { int a[n][4]; unsigned long s = sizeof a; a[1][2]; }"
+
+array_vla_member: "void f(int n) { struct S { int a[n]; }; }"
+
+array_flexible_member: "struct S { int len; int data[]; }; unsigned long s = sizeof(struct S);"
+
+array_flexible_not_last: "struct S { int data[]; int len; };"
+
+array_of_functions: "int f[2](void);"
+
+array_struct_declarators: "struct S { int x; } a[3], *b[2]; unsigned long s = sizeof a, t = sizeof b;"
+
+);
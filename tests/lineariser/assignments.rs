@@ -0,0 +1,37 @@
+crate::ssa!(
+
+assign_local: "int f(int x) { int y; y = x; x = 2; return x + y; }"
+
+assign_objects: "struct P { int x; }; void f(int *p, int a[2], struct P s, struct P *q) { *p = 1; a[1] = 2; s.x = 3; q->x = 4; (*q).x = 5; s = *q; }"
+
+assign_conversion: "double f(int i) { double d; char c = d = i; c = d; return d; }"
+
+compound_assign: "int f(int x, int *p, double d) { x += 1; *p *= x; d /= x; p += 2; return x; }"
+
+compound_assign_narrowing: "void f(char c, int i) { c -= i; c -= 1; }"
+
+compound_assign_value: "int f(int x) { int y = (x <<= 1); return y; }"
+
+increment_values: "int f(int x) { int y = x++; int z = ++x; x--; --x; return x + y + z; }"
+
+increment_types: "void f(char c, bool b, double d, int *p, int a[2]) { c++; --b; d++; p++; a[0]--; ++*p; }"
+
+increment_struct: "struct P { int x; }; void f(struct P s) { s++; --s; }"
+
+assign_rvalue: "int g(); void f(int x, int y) { 1 = 2; x + 1 = 3; g() = 1; (long)x = 1; (x, y) = 2; (x ? x : y) = 3; (x = y) = 4; }"
+
+assign_enum_constant: "enum { A }; void f() { A = 1; A++; }"
+
+assign_const: "void f(const int x, const int *p, int *const q) { x = 1; *p = 2; q = 0; *q = 3; x++; --*p; }"
+
+assign_const_member: "struct P { int x; const int y; }; struct Q { struct P p; }; void f(struct P s, const struct P c, struct Q t, const struct P *r) { s.y = 1; c.x = 2; s = c; t = t; t.p.x = 3; r->x = 4; }"
+
+assign_array: "void f(int a[2]) { int b[2]; b = a; b++; }"
+
+assign_string: r#"void f() { "ab" = 0; "ab"[0] = 1; *"ab" = 2; "ab"[1]++; }"#
+
+assign_function: "int g(); void f() { g = 0; g++; }"
+
+increment_rvalue: "void f(int x) { (x + 1)++; --(x++); ++-x; }"
+
+);
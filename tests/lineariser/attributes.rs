@@ -0,0 +1,23 @@
+//! Lineariser tests for the standard attributes.
+
+crate::ssa!(
+
+lin_attr_nodiscard: r#"[[nodiscard("check it")]] int f(); int main() { f(); int x = f(); return x; }"#
+
+lin_attr_nodiscard_variable: "[[nodiscard]] int x;"
+
+lin_attr_deprecated: r#"[[deprecated("use y")]] int x; int y = x;"#
+
+lin_attr_deprecated_function: "[[deprecated]] int f(); int main() { return f(); }"
+
+lin_attr_deprecated_tag: r#"struct [[deprecated("use T")]] S { int x; }; struct S s; struct [[deprecated]] U; enum [[deprecated]] E { A } e; struct U *u; int y = sizeof(enum E);"#
+
+lin_attr_fallthrough: "int main() { int x = 0; switch (x) { case 1: x = 2; [[fallthrough]]; case 2: x = 3; [[fallthrough]]; default: x = 4; } return x; }"
+
+lin_attr_fallthrough_misplaced: "int main() { int x = 0; switch (x) { case 1: [[fallthrough]]; x = 4; case 2: [[fallthrough]]; } [[fallthrough]]; return x; }"
+
+lin_attr_fallthrough_declaration: "[[fallthrough]] int x;"
+
+lin_attr_unknown: "[[foo, gnu::bar, maybe_unused]] int x;"
+
+);
@@ -0,0 +1,35 @@
+crate::ssa!(
+
+cast_arithmetic: "double f(long l) { int i = (int)l; char c = (char)l; return (double)i + (float)c; }"
+
+cast_same_type: "int f(int i) { return (int)i; }"
+
+cast_typedef: "typedef unsigned long size_t; size_t f(int i) { return (size_t)i; }"
+
+cast_nested: "long f(double d) { return (long)(unsigned char)d; }"
+
+cast_pointer: "typedef int T; char *f(int *p) { return (char *)p; } T g(void *p) { return *(T *)p; }"
+
+cast_pointer_integer: "long f(int *p) { return (long)p; } int *g(long l) { return (int *)l; }"
+
+cast_array: "long f() { int a[2]; return (long)a; }"
+
+cast_void: "int g(); void f(int x) { (void)x; (void)g(); }"
+
+cast_void_value: "void g(); int f(int x) { int y = (void)x; return (int)g(); }"
+
+cast_struct: "struct P { int x; }; int f(struct P p) { return (int)p; } struct P g(int x) { return (struct P)x; }"
+
+cast_floating_pointer: "int *f(double d) { return (int *)d; } float g(int *p) { return (float)p; }"
+
+cast_drop_qualifiers: "char *f(const char *s) { return (char *)s; } int *g(const volatile int *p) { return (int *)p; }"
+
+cast_add_qualifiers: "const char *f(char *s) { return (const char *)s; }"
+
+cast_function_pointer: "typedef void (*fp)(void); void *f(fp g) { return (void *)g; } fp h(int *p) { return (fp)p; }"
+
+cast_initialiser_parenthesised: "long f(int y) { long j = (long)(y + 1); int i = (int)(y); return j + i; }"
+
+cast_initialiser_nested: "char f(int y) { unsigned i = (unsigned)(int)y; char *p = (char *)(void *)0; return p[i]; }"
+
+);
@@ -0,0 +1,31 @@
+crate::ssa!(
+
+compound_literal_scalar: "int f() { return (int){1} + (const int){}; }"
+
+compound_literal_array: "int f() { return (int[]){1, [3] = 2}[3]; }"
+
+compound_literal_struct: "struct P { int x, y; }; int f() { return (struct P){.y = 2}.y; }"
+
+compound_literal_file_scope: "int *p = (int[]){1, 2};"
+
+compound_literal_static: "int *f() { return (static int[2]){1}; }"
+
+compound_literal_constexpr: "int f() { return (constexpr int){4}; }"
+
+compound_literal_address: "int f() { int *p = &(int){1}; return *p; }"
+
+compound_literal_address_storage: "int *f() { int q = (register int){2} + 1; int r = (constexpr int){3}; return &(static int){1}; }"
+
+compound_literal_string: "unsigned long s = sizeof((char[]){\"abc\"}); unsigned long t = sizeof((int[]){1, [4] = 2});"
+
+compound_literal_storage: "int f() { return (extern int){1} + (auto int){2}; }"
+
+compound_literal_incomplete: "int f() { return (struct Q){1}.x; }"
+
+compound_literal_variable_length: "int f(int n) { return (int[n]){1}[0]; }"
+
+compound_literal_excess: "int f() { return (int[1]){1, 2}[0]; }"
+
+compound_literal_argument: "struct P { int x, y; }; int g(int *a, struct P p); int f() { return g((int[]){1, 2}, (struct P){3, 4}); }"
+
+);
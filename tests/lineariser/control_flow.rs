@@ -0,0 +1,65 @@
+crate::ssa!(
+
+if_else_phi: "int f(int x) { int y = 0; if (x) y = 1; else y = 2; return y; }"
+
+if_no_else_phi: "int f(int x) { int y = 0; if (x) { y = x + 1; } return y; }"
+
+if_both_return: "int f(int x) { if (x) return 1; else return 2; }"
+
+if_unchanged: "int f(int x) { int y = 3; if (x) x = 2; return y; }"
+
+while_loop: "int f(int n) { int s = 0; while (n) { s = s + n; n = n - 1; } return s; }"
+
+for_loop: "int f(int n) { int s = 0; for (int i = 0; i < n; i++) s += i; return s; }"
+
+for_empty_clauses: "int f(int n) { for (;;) { if (n) break; } for (; n;) n--; for (;; n++) break; return n; }"
+
+for_missing_clause: "void f(int n) { for (n) {} }"
+
+do_while_loop: "int f(int n) { do { n = n - 1; } while (n); return n; }"
+
+break_continue: "int f(int n) { int s = 0; while (n) { n--; if (n == 3) continue; if (n == 5) break; s += n; } return s; }"
+
+nested_loops: "int f(int n) { int s = 0; for (int i = 0; i < n; i++) for (int j = 0; j < i; j++) s += j; return s; }"
+
+switch_cases: "int f(int x) { int y = 0; switch (x) { case 1: y = 10; break; case 2: y = 20; case 3: y++; break; default: y = 1; } return y; }"
+
+switch_no_default: "int f(int x) { switch (x) { case 1: return 1; } return 0; }"
+
+switch_case_not_constant: "void f(int x, int y) { switch (x) { case y: x++; case 1: x--; } }"
+
+switch_case_not_integer: "void f(int x) { switch (x) { case 2.5: x++; case 'a': x--; } }"
+
+switch_duplicate_case: "void f(int x) { switch (x) { case 1: x++; case 2: x--; case 3 - 2: break; } }"
+
+switch_promoted_cases: "void f(char c, unsigned long n) { switch (c) { case 'a': c++; } switch (n) { case -1: n++; } }"
+
+switch_not_integer: "void f(double d) { switch (d) { case 1: d++; } }"
+
+void_return: "void f(int x) { if (x) return; x++; }"
+
+return_without_value: "int f(void) { return; }"
+
+case_without_value: "void f(int x) { switch (x) { case : x++; default: ; } }"
+
+empty_condition: "void f(int x) { if () x++; }"
+
+empty_controlling_expressions: "void f(int x) { switch () ; if () x++; while () x--; }"
+
+goto_forward: "int f(int x) { if (x) goto end; x = 2; end: return x; }"
+
+goto_backward: "int f(int x) { again: x--; if (x) goto again; return x; }"
+
+break_outside: "void f() { break; }"
+
+continue_in_switch: "void f(int x) { switch (x) { case 1: continue; } }"
+
+case_outside: "void f(int x) { case 1: x++; }"
+
+multiple_defaults: "void f(int x) { switch (x) { default: x++; default: x--; } }"
+
+undeclared_label: "void f() { goto nowhere; }"
+
+label_redefinition: "void f(int x) { a: x++; a: x--; }"
+
+);
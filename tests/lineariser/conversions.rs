@@ -0,0 +1,47 @@
+crate::ssa!(
+
+conversion_promotion: "char a; short b; int c = a + b; int d = -a;"
+
+conversion_arithmetic: "char c; double d; double x = c + d; unsigned u; long l; long y = u * l;"
+
+conversion_sign: "int i; unsigned u; unsigned x = i + u;"
+
+conversion_literal: "unsigned u = 1; unsigned char c = 200; double d = 2; float f = 1.5;"
+
+conversion_narrowing: "long l; short s = l;"
+
+conversion_floating: "double d; int i = d;"
+
+conversion_precision: "long long l; double d = l;"
+
+conversion_pointer_arithmetic: "int *p; char c; int *q = p + c; int *r = 1 + p; long diff = q - p;"
+
+conversion_pointer_comparison: "int *p; int a = p == 0; int b = p != nullptr; int c = p < p;"
+
+conversion_pointer_integer: "int *p; int i; int a = p == i;"
+
+conversion_invalid_operands: "int *p; int *q; int *r = p + q;"
+
+conversion_pointer_assignment: "int *p = 5;"
+
+conversion_compound: "int i; double d; i += 2; i <<= 1u; d += i; long l; l -= i; bool b; b += 1;"
+
+conversion_compound_lossy: "int i; i *= 1.5;"
+
+conversion_shift: "char c; long l; long x = l << c;"
+
+conversion_variadic: "int printf(const char *fmt, ...); float f; char c; int x = printf(\"\", f, c);"
+
+conversion_argument: "void g(double d); void f(int i) { g(i); }"
+
+conversion_return: "double f(int i) { return i; }"
+
+conversion_conditional: "int c; int i; double d; double x = c ? i : d; int *p = c ? 0 : nullptr;"
+
+conversion_conditional_incompatible: "int c; int *p; double d; double x = c ? p : d;"
+
+conversion_pointer_bool: "int *p; bool b = p; b = p;"
+
+conversion_pointer_types: "void *v; int *r = v; const int *t = r; void *u = r; r = nullptr;"
+
+);
@@ -0,0 +1,44 @@
+//! Lineariser tests for the function types and the calls through function
+//! pointers.
+
+crate::ssa!(
+
+fn_empty_parameter_and_callee: "void f(int, , char); void g() { ()(1); }"
+
+fn_ptr_declaration: "int (*cb)(int, char *); void (*table[4])(void); unsigned long s = sizeof table;"
+
+fn_ptr_from_function: "int add(int a, int b); int (*cb)(int, int) = add; int (*cc)(int, int) = &add;"
+
+fn_ptr_call: "int add(int a, int b); int (*cb)(int, int); int f() { return cb(1, 2) + (*cb)(3, 4); }"
+
+fn_ptr_table_call: "int add(int a, int b); int (*table[2])(int, int); int f(int i) { return (*table[i])(i, 1); }"
+
+fn_ptr_typedef: "typedef void (*handler)(int); void on(int sig); handler h = on; void f() { h(1); }"
+
+fn_ptr_typedef_table: "typedef void (*handler)(int); typedef handler (*table)[4]; table t; void f() { t[0][1](2); }"
+
+fn_type_typedef: "typedef int F(int); typedef F *G; int inc(int v); G g = inc; int x = g(1);"
+
+fn_ptr_parameter: "void apply(int cb(int), int (*other)(int), int x); int inc(int v); void f() { apply(inc, &inc, 2); }"
+
+fn_ptr_wrong_count: "int (*cb)(int); void f() { cb(); cb(1, 2); }"
+
+fn_ptr_wrong_type: "struct S { int x; } s; void take(int *p); void f() { char c; take(&c); take(0); take(1); take(s); }"
+
+fn_not_callable: "int x; void f() { x(1); }"
+
+fn_variadic: "int printf(const char *, ...); void f() { printf(\"%d %d\", 1, 2); printf(); }"
+
+fn_void_parameters: "int f(void); int g(void, int); int h(); void k() { f(); h(1); }"
+
+fn_variadic_redeclaration: "int g(int, ...); int g(int);"
+
+fn_unnamed_parameters: "int f(int, char *); int f(int a, char *b) { return a; }"
+
+fn_unnamed_prototype: "int g(int);"
+
+fn_unnamed_in_definition: "int k(int, char c) { return c; }"
+
+fn_returning_array: "typedef int A[3]; typedef A (*G)(void);"
+
+);
@@ -0,0 +1,29 @@
+//! Lineariser tests for the `_Generic` selections.
+
+crate::ssa!(
+
+lin_generic_variable: "int x = 1; int a = _Generic(x, int: 1, double: 2, default: 3);"
+
+lin_generic_default: "double x = 1.0; int a = _Generic(x, int: 1, default: 3);"
+
+lin_generic_qualifiers: "const int x = 1; int a = _Generic(x, const int: 1, int: 2);"
+
+lin_generic_typedef: "typedef char *str; str s; int a = _Generic(s, char *: 1, default: 0);"
+
+lin_generic_string: r#"int a = _Generic("s", char *: 1, const char *: 2);"#
+
+lin_generic_decay: r#"const int arr[2]; int a = _Generic("abc", char *: 1, default: 0), b = _Generic(arr, const int *: 1, default: 0);"#
+
+lin_generic_call: "int f(); int a = _Generic(f(), int: 5);"
+
+lin_generic_nested: "int x = _Generic(_Generic(1, int: 2.0, default: 3), double: 4, float: 5);"
+
+lin_generic_enum: "enum E { A = _Generic(1, int: 3, default: 4), B };"
+
+lin_generic_no_match: "char c; int a = _Generic(c, int: 1);"
+
+lin_generic_duplicate: "typedef long T; T v; int a = _Generic(v, long: 1, T: 2);"
+
+lin_generic_undeclared: "int a = _Generic(y, int: 1);"
+
+);
@@ -0,0 +1,57 @@
+crate::ssa!(
+
+initialiser_array: "int a[4] = {1, 2};"
+
+initialiser_array_unknown_length: "int a[] = {1, [4] = 2, 3};"
+
+initialiser_array_nested: "int a[2][3] = {{1}, 2, 3, 4};"
+
+initialiser_array_excess: "int a[2] = {1, 2, 3};"
+
+initialiser_struct: "struct P { int x; double y; } p = {1, 2};"
+
+initialiser_struct_nested: "struct P { int x, y; }; struct L { struct P a, b; } l = {{1}, 3, 4};"
+
+initialiser_struct_copy: "struct P { int x, y; }; void f(struct P p) { struct P q[2] = {p, p}; }"
+
+initialiser_union: "union U { char c; int i; } u = {1};"
+
+initialiser_scalar: "int x = {3}; int y = {}; int *p = {};"
+
+initialiser_scalar_braces: "int x = {{1}, 2};"
+
+initialiser_empty: "struct P { int x, y; } p = {}; int a[3] = {};"
+
+initialiser_string: "char s[] = \"abc\"; char t[3] = \"abc\"; char u[8] = {\"ab\"}; unsigned short w[] = u\"ab\";"
+
+initialiser_string_automatic: "void f(void) { char s[] = \"abc\"; char t[8] = \"ab\"; }"
+
+initialiser_string_too_long: "char s[2] = \"abc\";"
+
+initialiser_string_in_struct: "struct S { char name[8]; int len; } s = {\"hello\", 5};"
+
+initialiser_designators: "struct P { int x, y; }; struct P pts[] = {[1].y = 2, {3, 4}, 5};"
+
+initialiser_designators_nested: "int a[2][3] = {[1] = {1, 2}, [0][2] = 3};"
+
+initialiser_designators_anonymous: "struct P { int x; union { int u; float f; }; } p = {.f = 1.5f, .x = 2};"
+
+initialiser_designators_elided: "struct P { int x, y; }; struct L { struct P a, b; } l = {1, 2, .b.y = 4, 5};"
+
+initialiser_override: "int a[2] = {1, 2, [0] = 3}; union U { int i; char c; } u = {.c = 1, .i = 2};"
+
+initialiser_designator_out_of_bounds: "int a[2] = {[2] = 1}; int b[2] = {[-1] = 1}; int c[2] = {[c] = 1};"
+
+initialiser_designator_invalid: "struct P { int x; } p = {.y = 1, [0] = 2}; int a[2] = {.x = 1}; int x = {.y = 2};"
+
+initialiser_array_not_list: "int a[] = 5; int b[2] = b;"
+
+initialiser_vla: "void f(int n) { int v[n] = {}; int w[n] = {1}; }"
+
+initialiser_automatic: "void f(int i) { int a[3] = {i, [2] = i + 1}; static int s[2] = {1}; }"
+
+initialiser_missing: "int x = ; struct { int y; } s = ; int a[2] = {[] = 1};"
+
+initialiser_not_allowed: "int a; a = {1}; void f() { int b; b = {2, 3}; }"
+
+);
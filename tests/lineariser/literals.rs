@@ -10,6 +10,18 @@ lin_lit_null: "int *x = nullptr"
 
 lin_lit_str: r#"int *x = "abcdef"; "#
 
+lin_lit_str_typed: r#"char *x = "abcdef"; "#
+
+lin_lit_wide_char: "int x = L'c'"
+
+lin_lit_utf8_char: "int x = u8'c'"
+
+lin_lit_utf16_str: r#"unsigned short *x = u"abc"; "#
+
+lin_lit_utf32_str: r#"unsigned *x = U"abc"; "#
+
+lin_lit_wide_str: r#"int *x = L"abc" "def"; "#
+
 lin_lit_int: "int x = 1"
 
 lin_lit_long: "int x = 1L"
@@ -28,4 +40,18 @@ lin_lit_double: "int x = 1.2"
 
 lin_lit_long_double: "int x = 1.2l"
 
+lin_lit_float_typed: "float x = 1.2f"
+
+lin_lit_double_typed: "double x = 1.2"
+
+lin_lit_long_double_typed: "long double x = 1.2l"
+
+lin_lit_bit_int: "int x = 5wb"
+
+lin_lit_ubit_int: "unsigned x = 5uwb"
+
+lin_lit_bit_int_minus: "int x = -1wb"
+
+lin_lit_bit_int_arithmetic: "long x = 3uwb * 2wb; long y = 1000wb + 1; long z = 100uwb - 300000000000wb; double d = 1wb + 1.0; unsigned long s = sizeof(100000000000000000000wb);"
+
 );
@@ -1,6 +1,21 @@
 //! Lineariser tests.
 
+mod arrays;
+mod assignments;
+mod attributes;
+mod casts;
+mod compound_literals;
+mod control_flow;
+mod conversions;
+mod function_pointers;
+mod generic;
+mod initialisers;
 mod literals;
+mod parenthesis;
+mod sizeof;
+mod static_assert;
+mod typedefs;
+mod user_types;
 
 crate::ssa!(
 
@@ -20,6 +35,8 @@ declaration_wrong_type: "int y = 2; char y;"
 
 multiple_declarations: "int x; int x = nullptr; int x; int x;"
 
+multiple_declarations_pointer: "int *x; int *x = nullptr; int *x; int *x;"
+
 fn_decl: "const char* func(static volatile int** first_argument, struct custom * arg2)"
 
 function_definition: "int**f(int v) {}"
@@ -134,6 +151,8 @@ struct_ident_val: "struct a = 0"
 
 todo_attr: "_Generic alignas _BigInt typeof typeof_unqual static int x = 1"
 
+todo_attr_no_generic: "alignas _BigInt typeof typeof_unqual static int x = 1"
+
 indirection_end_name: "int ** a = 0; *a"
 
 sizeof_type: "sizeof(int)"
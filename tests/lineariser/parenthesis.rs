@@ -0,0 +1,19 @@
+crate::ssa!(
+
+parens_value: "int f(int a, int b) { return (a + b) * (a); }"
+
+parens_lvalue: "int f() { int x = 0; (x) = 3; (x)++; ((x)) += 2; return x; }"
+
+parens_empty: "int f() { return (); }"
+
+parens_null_pointer: "int *f() { return (0); }"
+
+comma_value: "int f(int a, int b) { int x = (a, b); return x; }"
+
+comma_side_effects: "int g(); void h(); int f(int a) { int x = (g(), a); x = (h(), a, x + 1); return x; }"
+
+comma_for: "int f(int n) { int x = 0; for (int i = 0, j = n; i < j; i++, j--) x = x + i; return x; }"
+
+comma_void_rhs: "void h(); int f() { int x = (1, h()); return x; }"
+
+);
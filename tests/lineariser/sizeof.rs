@@ -0,0 +1,39 @@
+//! Lineariser tests for the `sizeof` and `alignof` operators.
+
+crate::ssa!(
+
+sizeof_scalars: "unsigned long a = sizeof(char), b = sizeof(short) + sizeof(long long), c = alignof(double);"
+
+sizeof_expression: "int x; unsigned long a = sizeof x, b = sizeof(x) * 2; double c = sizeof x + 1.0;"
+
+sizeof_not_evaluated: "int x = 1; unsigned long a = sizeof x++;"
+
+sizeof_struct: "struct S { char c; double d; }; unsigned long a = sizeof(struct S), b = sizeof(struct S *), c = alignof(struct S);"
+
+sizeof_typedef_or_variable: "typedef long T; int U; unsigned long a = sizeof(T), b = sizeof(U);"
+
+sizeof_dereference: "int *p; unsigned long a = sizeof *p + 1;"
+
+sizeof_comma: "char c; unsigned long a = sizeof(1, c);"
+
+sizeof_enum_constant: "struct S { int x; long y; }; enum { A = sizeof(long) }; static_assert(A == sizeof(struct S) / 2);"
+
+sizeof_flexible_member: "struct S { char c; double d[]; }; struct T { int n; int fam[]; }; static_assert(sizeof(struct T) == 4); unsigned long a = sizeof(struct S), b = alignof(struct S);"
+
+sizeof_incomplete_struct: "struct S; unsigned long a = sizeof(struct S);"
+
+sizeof_void: "unsigned long a = sizeof(void);"
+
+sizeof_function: "int f(); unsigned long a = sizeof f;"
+
+sizeof_function_pointer: "int f(); int g(void); int (*p)() = f, (*q)(void) = g; unsigned long a = sizeof p;"
+
+sizeof_missing_operand: "unsigned long a = sizeof(), b = alignof;"
+
+sizeof_undeclared: "unsigned long a = sizeof y;"
+
+sizeof_string_literal: "unsigned long a = sizeof \"abc\", b = sizeof u\"ab\", c = sizeof(\"abc\" + 1);"
+
+
+sizeof_variable_length: "void f(int n, unsigned long m) { int v[n][m]; unsigned long a = sizeof v; }"
+);
@@ -0,0 +1,29 @@
+//! Lineariser tests for the static assertions.
+
+crate::ssa!(
+
+static_assert_holds: r#"static_assert(1 + 1 == 2, "math"); static_assert(sizeof(int) == 4); int x = 1;"#
+
+static_assert_fails: r#"static_assert(sizeof(int) == 8, "int is " "too small");"#
+
+static_assert_fails_no_message: "static_assert(2 > 3);"
+
+static_assert_in_function: r#"int f() { static_assert(0, "in f"); return 0; }"#
+
+static_assert_in_struct: r#"struct S { int x; static_assert(sizeof(long) == 4, "LP64"); };"#
+
+static_assert_enum: "enum { A = 3 }; static_assert(A == 3); static_assert(A < 2);"
+
+static_assert_not_constant: "int x; static_assert(x);"
+
+static_assert_not_integer: "static_assert(1.5);"
+
+static_assert_division_by_zero: r#"static_assert(1 / 0, "div");"#
+
+static_assert_bad_message: "static_assert(1, 2);"
+
+static_assert_arguments: r#"static_assert(); static_assert(1, "a", "b");"#
+
+static_assert_empty_argument: r#"static_assert(, "a");"#
+
+);
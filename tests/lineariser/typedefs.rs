@@ -0,0 +1,29 @@
+crate::ssa!(
+
+typedef_basic: "typedef unsigned long size; size s = 2; size f() { size t = s; return t; }"
+
+typedef_pointer_to_struct: "typedef struct node *Node; struct node { int v; Node next; }; Node n; int f() { return n->next->v; }"
+
+typedef_anonymous_struct: "typedef struct { int a; char b; } S; S s; int x = s.a;"
+
+typedef_enum: "typedef enum { A, B } E; E e = B;"
+
+typedef_qualified: "typedef const int CI; typedef int *P; CI c = 2; const P p = nullptr; int x = *p;"
+
+typedef_scoped: "typedef int T; void f() { typedef char *T; T x; } T y;"
+
+typedef_shadowed: "typedef int T; void f() { int T = 1; T y; }"
+
+typedef_redefinition: "typedef int T; typedef int T; typedef long T; int U; typedef int U;"
+
+typedef_errors: "typedef int; typedef int I; unsigned I x; unknown y; typedef struct { int a; } S; S s; int z = s.b;"
+
+typedef_as_expression: "typedef int T; int f() { return T; }"
+
+typedef_set_to_other: "typedef int a; typedef int c; typedef int e; a b() { c d; e f = d; }"
+
+typedef_use_undeclared: "typedef int a; typedef int c; a b() { c d = e; }"
+
+typedef_binary_fn: "typedef int a; a b(int x, int y) { return x+y; }"
+
+);
@@ -0,0 +1,47 @@
+crate::ssa!(
+
+struct_padding: "struct A { char c; int i; double d; } a; double f() { a.c; a.i; return a.d; }"
+
+struct_pointer_access: "struct A { int x; struct A *next; } *p = nullptr; int y = p->next->x;"
+
+struct_nested: "struct A { char c; struct B { short s; long l; } b; } a; long l = a.b.l; struct B b;"
+
+struct_anonymous_member: "struct A { int x; union { char c; double d; }; } a; double d = a.d;"
+
+struct_bitfields: "struct A { int a : 3, b : 5; char c; int d : 30; } s; int x = s.c + s.d;"
+
+struct_declarators: "struct A { int x; } a, *b = nullptr; int x = a.x + b->x;"
+
+union_offsets: "union U { char c; long l; float f; } u; long l = u.l; float f = u.f;"
+
+enum_values: "enum E { A, B = 5, C, D = A + C * 2, F = -1 } e; int x = D;"
+
+enum_in_function: "int f() { enum { X = 'a', Y }; return Y; }"
+
+enum_not_constant: "int v; enum E { A = v, B = 1 / 0, C = 18446744073709551615u };"
+
+enum_constant_expressions: "enum E { A = sizeof(long) / 2, B = 257 % 256, C = A > B ? A : B };"
+
+enum_not_integer: "enum E { A = 1.5, B = 2.5 > 1 };"
+
+enum_widened: "enum E { A = -1, B = 4000000000, C } e = C; unsigned long s = sizeof(enum E);"
+
+no_member: "struct A { int x; } a; int y = a.y;"
+
+member_of_non_record: "int a; int b = a.x; int *c; int d = c->x;"
+
+member_wrong_operator: "struct A { int x; } a, *p; int b = a->x; int c = p.x;"
+
+member_incomplete: "struct A; struct A *p; int x = p->x;"
+
+object_incomplete: "struct A; extern struct A a; void f() { struct A b; void v; int c[]; int d[] = {1}; }"
+
+member_invalid_fields: "struct A { int x; int x; void v; struct Z z; float f : 2; int w : 40; int n : 0; };"
+
+tag_redefinition: "struct A { int x; }; struct A { int y; }; union A { int z; };"
+
+missing_values: "enum E { A, B = }; struct S { int x = ; } *;"
+
+tag_scoped: "struct A { int x; } a; int f() { struct A { char y; } b; return b.y; } int g = a.x;"
+
+);
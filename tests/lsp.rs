@@ -0,0 +1,262 @@
+//! Tests the language server with scripted JSON-RPC sessions.
+
+#![expect(clippy::restriction, reason = "tests")]
+
+use core::fmt::Write as _;
+use std::io::Write as _;
+use std::process::{Command, Stdio};
+
+const URI: &str = "file:///main.c";
+
+const CONTENT: &str = "int x = 1;\\nint f(int a) {\\n    return a + x;\\n}\\nint main() {\\n    unknown;\\n    return f(x);\\n}\\n";
+
+/// Sends the requests to a new server and returns its messages.
+fn run_session(messages: &[String]) -> Vec<String> {
+    let mut input = String::new();
+    for message in messages {
+        write!(input, "Content-Length: {}\r\n\r\n{message}", message.len()).unwrap();
+    }
+    run_raw(&input)
+}
+
+/// Sends raw input to a new server and returns its messages.
+fn run_raw(input: &str) -> Vec<String> {
+    let mut server = Command::new(env!("CARGO_BIN_EXE_c-parser-lsp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = server.stdin.take().unwrap();
+    stdin.write_all(input.as_bytes()).unwrap();
+    drop(stdin);
+    let output = server.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let mut stdout = String::from_utf8(output.stdout).unwrap();
+    let mut replies = vec![];
+    while let Some((header, rest)) = stdout.split_once("\r\n\r\n") {
+        let length: usize = header
+            .strip_prefix("Content-Length: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        replies.push(rest[..length].to_owned());
+        stdout = rest[length..].to_owned();
+    }
+    assert!(stdout.is_empty());
+    replies
+}
+
+fn request(id: u32, method: &str, params: &str) -> String {
+    format!(r#"{{"jsonrpc":"2.0","id":{id},"method":"{method}","params":{params}}}"#)
+}
+
+fn notification(method: &str, params: &str) -> String {
+    format!(r#"{{"jsonrpc":"2.0","method":"{method}","params":{params}}}"#)
+}
+
+fn position_params(line: u32, character: u32) -> String {
+    format!(
+        r#"{{"textDocument":{{"uri":"{URI}"}},"position":{{"line":{line},"character":{character}}}}}"#
+    )
+}
+
+fn session(requests: &[String]) -> Vec<String> {
+    session_with(CONTENT, requests)
+}
+
+fn session_with(content: &str, requests: &[String]) -> Vec<String> {
+    session_init(r#"{"capabilities":{}}"#, URI, content, requests)
+}
+
+fn session_init(init: &str, uri: &str, content: &str, requests: &[String]) -> Vec<String> {
+    let mut messages = vec![
+        request(0, "initialize", init),
+        notification("initialized", "{}"),
+        notification(
+            "textDocument/didOpen",
+            &format!(
+                r#"{{"textDocument":{{"uri":"{uri}","languageId":"c","version":1,"text":"{content}"}}}}"#
+            ),
+        ),
+    ];
+    messages.extend_from_slice(requests);
+    messages.push(request(99, "shutdown", "null"));
+    messages.push(notification("exit", "null"));
+    let replies = run_session(&messages);
+    assert!(replies[0].contains(r#""hoverProvider":true"#), "{}", replies[0]);
+    assert_eq!(replies.last().unwrap(), r#"{"jsonrpc":"2.0","id":99,"result":null}"#);
+    replies[1..replies.len() - 1].to_vec()
+}
+
+#[test]
+fn definition() {
+    let replies = session(&[
+        request(1, "textDocument/definition", &position_params(6, 11)),
+        request(2, "textDocument/definition", &position_params(2, 15)),
+        request(3, "textDocument/definition", &position_params(0, 0)),
+    ]);
+    assert_eq!(
+        replies[1],
+        r#"{"jsonrpc":"2.0","id":1,"result":{"uri":"file:///main.c","range":{"start":{"line":1,"character":4},"end":{"line":1,"character":5}}}}"#
+    );
+    assert_eq!(
+        replies[2],
+        r#"{"jsonrpc":"2.0","id":2,"result":{"uri":"file:///main.c","range":{"start":{"line":0,"character":4},"end":{"line":0,"character":5}}}}"#
+    );
+    assert_eq!(replies[3], r#"{"jsonrpc":"2.0","id":3,"result":null}"#);
+}
+
+#[test]
+fn diagnostics() {
+    let replies = session(&[
+        notification(
+            "textDocument/didChange",
+            &format!(
+                r#"{{"textDocument":{{"uri":"{URI}","version":2}},"contentChanges":[{{"text":"int main() {{\n    return 1 +;\n}}\n"}}]}}"#
+            ),
+        ),
+        notification("textDocument/didClose", &format!(r#"{{"textDocument":{{"uri":"{URI}"}}}}"#)),
+    ]);
+    assert_eq!(
+        replies[0],
+        r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///main.c","diagnostics":[{"range":{"start":{"line":5,"character":4},"end":{"line":5,"character":11}},"severity":1,"source":"c-parser","message":"Use of undeclared variable unknown"}]}}"#
+    );
+    assert!(replies[1].contains(r#""severity":1"#), "{}", replies[1]);
+    assert!(
+        replies[1].contains(r#""range":{"start":{"line":1,"character":"#),
+        "{}",
+        replies[1]
+    );
+    assert_eq!(
+        replies[2],
+        r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///main.c","diagnostics":[]}}"#
+    );
+}
+
+#[test]
+fn directives() {
+    let replies = session_with(
+        "#include <stdio.h>\\nint x;\\n",
+        &[request(1, "textDocument/hover", &position_params(1, 4))],
+    );
+    assert_eq!(
+        replies[0],
+        r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///main.c","diagnostics":[{"range":{"start":{"line":0,"character":0},"end":{"line":0,"character":8}},"severity":1,"source":"c-parser","message":"stdio.h: file not found"}]}}"#
+    );
+    assert_eq!(
+        replies[1],
+        r#"{"jsonrpc":"2.0","id":1,"result":{"contents":{"kind":"markdown","value":"```c\nint x\n```"}}}"#
+    );
+    let replies = session_with(
+        "#define N 2\\nint x = N;\\nint f(void) { return x; }\\n",
+        &[request(
+            1,
+            "textDocument/definition",
+            &position_params(2, 21),
+        )],
+    );
+    assert_eq!(
+        replies[0],
+        r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"uri":"file:///main.c","diagnostics":[]}}"#
+    );
+    assert_eq!(
+        replies[1],
+        r#"{"jsonrpc":"2.0","id":1,"result":{"uri":"file:///main.c","range":{"start":{"line":1,"character":4},"end":{"line":1,"character":5}}}}"#
+    );
+}
+
+#[test]
+fn document_symbols() {
+    let replies = session(&[request(
+        1,
+        "textDocument/documentSymbol",
+        &format!(r#"{{"textDocument":{{"uri":"{URI}"}}}}"#),
+    )]);
+    assert!(replies[1].contains(r#"{"name":"x","kind":13,"#), "{}", replies[1]);
+    assert!(
+        replies[1].contains(r#"{"name":"f","kind":12,"range":{"start":{"line":1,"character":0},"end":{"line":3,"character":1}},"#),
+        "{}",
+        replies[1]
+    );
+    assert!(replies[1].contains(r#"{"name":"main","kind":12,"#), "{}", replies[1]);
+}
+
+#[test]
+fn hover() {
+    let replies = session(&[
+        request(1, "textDocument/hover", &position_params(6, 11)),
+        request(2, "textDocument/hover", &position_params(2, 11)),
+    ]);
+    assert_eq!(
+        replies[1],
+        r#"{"jsonrpc":"2.0","id":1,"result":{"contents":{"kind":"markdown","value":"```c\nint f(int)\n```"}}}"#
+    );
+    assert_eq!(
+        replies[2],
+        r#"{"jsonrpc":"2.0","id":2,"result":{"contents":{"kind":"markdown","value":"```c\nint a\n```"}}}"#
+    );
+}
+
+#[test]
+fn include_paths() {
+    let root = std::env::temp_dir().join(format!("c-parser-lsp-{}", std::process::id()));
+    let (project, include) = (root.join("my project"), root.join("include"));
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::create_dir_all(&include).unwrap();
+    std::fs::write(project.join("local.h"), "int local(void);\n").unwrap();
+    std::fs::write(include.join("lib.h"), "int lib(int);\n").unwrap();
+    let uri = format!("file://{}/my%20project/main.c", root.display());
+    let replies = session_init(
+        &format!(
+            r#"{{"capabilities":{{}},"initializationOptions":{{"includePaths":["{}"],"defines":{{"N":"2"}}}}}}"#,
+            include.display()
+        ),
+        &uri,
+        "#include \\\"local.h\\\"\\n#include <lib.h>\\nint x = N;\\nint f(void) { return lib(x) + local(); }\\n",
+        &[request(
+            1,
+            "textDocument/hover",
+            &format!(
+                r#"{{"textDocument":{{"uri":"{uri}"}},"position":{{"line":3,"character":22}}}}"#
+            ),
+        )],
+    );
+    std::fs::remove_dir_all(&root).unwrap();
+    assert_eq!(
+        replies[0],
+        format!(
+            r#"{{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{{"uri":"{uri}","diagnostics":[]}}}}"#
+        )
+    );
+    assert_eq!(
+        replies[1],
+        r#"{"jsonrpc":"2.0","id":1,"result":{"contents":{"kind":"markdown","value":"```c\nint lib(int)\n```"}}}"#
+    );
+}
+
+#[test]
+fn invalid_messages() {
+    let replies = run_session(&[
+        "{".to_owned(),
+        request(1, "workspace/unknown", "{}"),
+        notification("exit", "null"),
+    ]);
+    assert_eq!(
+        replies,
+        [
+            r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"message":"Expected string key in JSON object"}}"#,
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32601,"message":"Method not found"}}"#,
+        ]
+    );
+}
+
+#[test]
+fn oversized_message() {
+    let replies = run_raw("Content-Length: 100000000000\r\n\r\n{}");
+    assert_eq!(
+        replies,
+        [
+            r#"{"jsonrpc":"2.0","id":null,"error":{"code":-32700,"message":"Message of 100000000000 bytes is longer than the maximum of 67108864 bytes"}}"#
+        ]
+    );
+}
@@ -11,4 +11,5 @@
 
 mod lineariser;
 mod parser;
+mod preprocessor;
 mod runner;
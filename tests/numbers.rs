@@ -10,14 +10,25 @@ macro_rules! gen_number_test {
         $(
             #[test]
             fn $name() {
-                test_number($input, &$output)
+                test_number($input, &$output, &TargetInfo::default())
             }
         )*
     };
 }
 
-fn test_number(content: &str, expected: &Number) {
-    let tokens = lex(content, 0)
+macro_rules! gen_target_number_test {
+    ($($name:ident: $target:ident $input:expr => $output:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                test_number($input, &$output, &TargetInfo::$target())
+            }
+        )*
+    };
+}
+
+fn test_number(content: &str, expected: &Number, target: &TargetInfo) {
+    let tokens = lex_with(content, 0, target)
         .unwrap_or_display(&[(0, "", content)])
         .unwrap();
     assert!(
@@ -68,7 +79,7 @@ gen_number_test!(
     numbers_29: "7.89E-2" => Number::Double(7.89e-2);
     numbers_30: "1.23E+100" => Number::Double(1.23e100);
     numbers_31: "1.23F" => Number::Float(1.23);
-    // numbers_32: "4.56L" => Number::LongDouble(4.56); // long double not supported
+    numbers_32: "4.56L" => Number::LongDouble(4.56);
     numbers_33: ".5" => Number::Double(0.5);
     numbers_34: "5." => Number::Double(5.);
     numbers_35: "1e10" => Number::Double(1e10);
@@ -81,5 +92,62 @@ gen_number_test!(
     numbers_42: "789.0123" => Number::Double(789.0123);
     numbers_43: "0.0001e5f" => Number::Float(10.);
     numbers_44: "0x0.123456789abcdefp0" => Number::Double(0.07111111111111111);
+    numbers_45: "1.5L" => Number::LongDouble(1.5);
+    numbers_46: "0.1L" => Number::LongDouble(0.1);
+    numbers_47: "0x1.8p3L" => Number::LongDouble(12.);
+    numbers_48: "1e4000L" => Number::LongDouble(1e4000);
+    numbers_49: "1e-4940L" => Number::LongDouble(1e-4940);
+    numbers_50: "1.23456789012345678901234567890123456789L" => Number::LongDouble(1.23456789012345678901234567890123456789);
+    numbers_51: "0x1.ffffffffffffffffffffffffffff8p0L" => Number::LongDouble(2.);
+    numbers_52: "1.18973149535723176508575932662800702e4932L" => Number::LongDouble(1.18973149535723176508575932662800702e4932);
+    numbers_53: "1'000'000" => Number::Int(1000000);
+    numbers_54: "0xFF'FF" => Number::Int(65535);
+    numbers_55: "0u" => Number::UInt(0);
+    numbers_56: "0l" => Number::Long(0);
+
+);
+
+macro_rules! gen_bit_int_test {
+    ($($name:ident: $input:expr => $width:expr, $value:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                test_bit_int($input, $width, $value)
+            }
+        )*
+    };
+}
+
+fn test_bit_int(content: &str, width: u64, value: &str) {
+    let tokens = lex(content, 0)
+        .unwrap_or_display(&[(0, "", content)])
+        .unwrap();
+    let token_value = tokens.first().unwrap().as_value();
+    if let TokenValue::Number(Number::BitInt(nb) | Number::UBitInt(nb)) = token_value {
+        assert_eq!(nb.width(), width, "Lexer error: wrong width for {content}");
+        assert_eq!(nb.to_string(), value, "Lexer error: wrong value for {content}");
+    } else {
+        unreachable!("Lexer error: waiting for BitInt, but lexer returned {token_value:?}")
+    }
+}
+
+gen_bit_int_test!(
+    bit_int_1: "0wb" => 2, "0";
+    bit_int_2: "1uwb" => 1, "1";
+    bit_int_3: "128wb" => 9, "128";
+    bit_int_4: "255UWB" => 8, "255";
+    bit_int_5: "0x1'0000'0000'0000'0000'0000wb" => 82, "1208925819614629174706176";
+);
 
+gen_target_number_test!(
+    target_ilp32_int: ilp32 "2147483647" => Number::Int(2147483647);
+    target_ilp32_long: ilp32 "2147483647L" => Number::Long(2147483647);
+    target_ilp32_decimal_overflow: ilp32 "3000000000" => Number::LongLong(3000000000);
+    target_ilp32_hex_overflow: ilp32 "0xB2D05E00" => Number::UInt(3000000000);
+    target_ilp32_long_overflow: ilp32 "3000000000L" => Number::LongLong(3000000000);
+    target_ilp32_to_long_long: ilp32 "0x1234567AF" => Number::LongLong(0x1234567AF);
+    target_ilp32_ulong_overflow: ilp32 "10999999999999999999u" => Number::ULongLong(10999999999999999999);
+    target_llp64_long_overflow: llp64 "0x1234567AFl" => Number::LongLong(0x1234567AF);
+    target_lp64_long: lp64 "3000000000L" => Number::Long(3000000000);
+    target_lp64_to_long: lp64 "0x1234567AF" => Number::Long(0x1234567AF);
 );
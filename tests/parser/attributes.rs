@@ -0,0 +1,41 @@
+//! Parser tests for the attribute specifiers.
+
+crate::ast!(
+
+attr_nodiscard: "[[nodiscard]] int f();"
+
+attr_deprecated_message: r#"[[deprecated("use g")]] int x = 1;"#
+
+attr_multiple: "[[maybe_unused, , deprecated]] [[nodiscard]] int f();"
+
+attr_after_name: "int x [[maybe_unused]] = 1;"
+
+attr_parameter: "void f([[maybe_unused]] int x, int y [[maybe_unused]]);"
+
+attr_prefixed: "[[gnu::aligned(8), __gnu__::__const__]] int x;"
+
+attr_balanced_args: "[[vendor::attr(a, (b, [c]), {d})]] int x;"
+
+attr_statement: "switch (x) { case 1: f(); [[fallthrough]]; case 2: break; }"
+
+attr_after_value: "int x = 1 [[maybe_unused]];"
+
+attr_after_expression: "x + [[nodiscard]] y;"
+
+attr_no_name: "[[1]] int x;"
+
+attr_missing_comma: "[[a b]] int x;"
+
+attr_single_colon: "[[gnu:a]] int x;"
+
+attr_single_bracket: "[[a] int x;"
+
+attr_unclosed: "[[a"
+
+attr_unclosed_args: "[[a(b]] int x;"
+
+attr_mismatched_args: "[[a(b]) int x;"
+
+
+attr_struct_tag: "struct [[deprecated]] S { int x; } s; typedef union [[maybe_unused]] U { int y; } U;"
+);
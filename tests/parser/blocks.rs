@@ -44,6 +44,9 @@ blocks:
 list_initialiser_in_body: "a ? {1, 2, 3} : {4, 5, 6}"
 list_initialiser_unary: "!{1, 2, 3}"
 
+list_init_designators: "a = {[2] = 1, .x.y[3] = 2, .z = {.w = 3}, 4}"
+list_init_designators_after_definition: "struct A { int x; } a = {.x = 1}, b = {[0] = 2}"
+
 list_init_cast: "(a)b{c}"
 list_init_cast_full: "(a)(b){c}"
 
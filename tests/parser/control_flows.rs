@@ -8,6 +8,8 @@ enumeration: "enum A { FIRST, SECOND }; enum A a;"
 
 union: "union A { bool active }; union A a;"
 
+struct_declarators: "struct A { int x; } a, *b = 0; a.x = b->x;"
+
 successive_ctrl_flow: "break; return 0*1; for(int x = 2; x<10;x++) x"
 
 conditional_simple: "if (a) b else if (c) d else e; if(x) y;z"
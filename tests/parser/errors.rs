@@ -34,4 +34,20 @@ bitfield_operator: "const int a : +"
 
 bitfield_not_number: "const int a : 'b'"
 
+semicolon_in_arguments: "int y(2; 3);"
+
+colon_in_empty_block: ": x"
+
+block_after_parameter: "void h(int a { return; }"
+
+block_after_variable: "for (x 1"
+
+stray_hash: "int # x;"
+
+keyword_after_expression: "x = 1 return 2;"
+
+declaration_in_operator: "a ? int y : z = 2;"
+
+unary_before_declaration: "- int x = 1;"
+
 );
@@ -14,6 +14,24 @@ alignoff: "int x = alignof(int);"
 
 ualignof: "int x = _Alignof(int);"
 
+function_pointer: "int (*cb)(int, char *), (*table[4])(void);"
+
+function_pointer_array: "int (*p[2])[3];"
+
+function_pointer_qualified: "void (* const cb)(int) = f;"
+
+function_typedef: "typedef void (*handler)(int, ...); typedef int F(int);"
+
+variadic_declaration: "int printf(const char *, ...);"
+
+variadic_only_ellipsis: "void f(...);"
+
+indirect_calls: "cb(1, s); (*table[i])(x); s.f(1)(2); a[0]();"
+
+function_returning_function: "int (*f)(int)(char);"
+
+misplaced_ellipsis: "void f(int, ..., char);"
+
 keywords_attributes_functions_err: "int main() {
     const int volatile static short _Thread_local y;
     static_assert(sizeof(x = 2) + 1 == 2);
@@ -30,4 +48,6 @@ keywords_attributes_functions: "int main() {
 
 heavy: "inline _Noreturn long auto extern signed _Atomic _BigInt default unsigned register restrict _Complex _Generic constexpr _Decimal64 _Imaginary _Decimal32 _Decimal128 _AlignAs alignas f();"
 
+heavy_no_generic: "inline _Noreturn long auto extern signed _Atomic _BigInt default unsigned register restrict _Complex constexpr _Decimal64 _Imaginary _Decimal32 _Decimal128 _AlignAs alignas f();"
+
 );
@@ -0,0 +1,29 @@
+//! Parser tests for the `_Generic` selections.
+
+crate::ast!(
+
+generic_simple: "int a = _Generic(x, int: 1, double: 2, default: 3);"
+
+generic_pointer_type: "int y = _Generic(x, char *: a ? b : c, struct s: 2) + 1;"
+
+generic_nested: "int x = _Generic(_Generic(1, int: 2.0, default: 3), double: 4, float: f(1, 2));"
+
+generic_cast: "(int)_Generic(1, int: 2)"
+
+generic_no_parens: "_Generic x"
+
+generic_no_association: "_Generic(x);"
+
+generic_no_colon: "_Generic(x, int 1);"
+
+generic_not_type: "_Generic(x, 1: 2);"
+
+generic_no_controlling: "_Generic(, int: 1)"
+
+generic_no_value: "_Generic(x, int:)"
+
+generic_multiple_defaults: "_Generic(x, int: 1, default: 2, default: 3);"
+
+generic_unclosed: "_Generic(x, int: (1)"
+
+);
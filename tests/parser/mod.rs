@@ -1,7 +1,9 @@
+mod attributes;
 mod blocks;
 mod control_flows;
 mod errors;
 mod functions;
+mod generic;
 mod numbers;
 mod operators;
 mod strings;
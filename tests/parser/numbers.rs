@@ -34,6 +34,12 @@ overflow_exp: "0x0.0p999999999999999999"
 
 empty_exp: "0x0.0p"
 
+long_double: "1.5L + 0x1.8p3L"
+
+long_double_overflow: "1e5000L + 0x1p16384L"
+
+long_double_underflow: "1e-5000L + 0x1p-16495L"
+
 overflow_unsigned: "999999999999999999999u
     -999999999999999999999"
 
@@ -46,4 +52,15 @@ invalid_suffix: "1uu
 7.u
 "
 
+separators: "x = 1'000'000, 0xFF'FF, 0b1'0, 07'7, 1'0.2'5e1'0"
+
+misplaced_separators: "1' 1''0 0x'1 1'u 1'.0 1.'0"
+
+bit_int: "x = 0wb, 1wb, 1uwb, 128wb, 255UWB, 0xFFFF'FFFF'FFFF'FFFF'FFFFuwb"
+
+invalid_bit_int: "1uwbu
+1.0wb
+1wbl
+"
+
 );
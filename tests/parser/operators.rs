@@ -13,14 +13,44 @@ add_assign: "x += 1"
 
 incr_comment: "a*b++/*x*/"
 
+prefix_increment: "x = ++y; z = - --y; w = y++ + ++y; ++y"
+
 unfinished_ternary: "a ? b"
 
 cast_list_initialiser: "(int*){1, 2, (int)PI}"
 
+compound_literal_subscript: "x = (int[]){1, [3] = 2}[3]"
+
+compound_literal_member: "x = 1 + (struct P){.x = -1}.x"
+
+compound_literal_address: "p = &(static const int){1}"
+
+compound_literal_in_return: "int f() { return (T){1} * 2; }"
+
+compound_literal_in_list: "int a[] = {(int){1}, (2), (int)3}"
+
+compound_literal_nested: "x = (int[][2]){(int[]){1, 2}[0], {3}}"
+
+compound_literal_in_arguments: "g((int[]){1, 2}, 3); f(1, (struct P){1, {2, 3}}, h(4, 5))"
+
 ternary_function: "a ? f(x) : o(y)"
 
 ternary_cast: "a ? (int)f : (void*)o"
 
 ternary_cast_function: "a ? (int)f(x) : (void*)o(y, z)"
 
+sizeof_without_parens: "x = sizeof y + 1"
+
+sizeof_minus: "x = sizeof(int) - 1"
+
+sizeof_negate: "x = -sizeof(int) * 2"
+
+sizeof_dereference: "x = sizeof *p + 1"
+
+sizeof_cast: "x = (long)sizeof(int)"
+
+sizeof_subscript: "x = sizeof (a)[1]"
+
+parens_initialiser: "int x = -(1), y = (2);"
+
 );
@@ -41,6 +41,22 @@ escape_not_char_long2: "\"\\Uffffffffo\""
 
 escape_not_char_short: "'\\uD900'"
 
+prefixed_strings: r#"a = L"wide" "plain"; b = u8"utf8"; c = "plain" u"utf16"; d = U"utf32";"#
+
+prefixed_chars: "L'a' + u8'b' + u'c' + U'd'"
+
+prefix_not_merged: r#"u"a" L"b""#
+
+prefix_ident: "L + u8 + L'a'"
+
+escape_hex_wide: r#"L"\x1234" u"\x12345" "\x1234""#
+
+escape_octal_wide: r#"u"\777""#
+
+escape_not_one_unit: "u8'\\U0001F600' + u'\\U0001F600' + U'\\U0001F600'"
+
+char_not_one_unit: "u8'\u{e9}'"
+
 digraphs:
     "
 %:include <stdio.h>
@@ -6,6 +6,20 @@ indirection: "int *a *b = *c * d + e"
 
 array_access: "*a->b[3] = c[3].d[1]"
 
+array_declarators: "int a[10][20], *argv[], b = a[1][2];"
+
+array_parameters: "void f(int a[static 10], int b[const restrict n], int c[*], int d[][3]);"
+
+array_member: "struct S { int len; char data[]; };"
+
+array_typedef: "typedef int A[3];"
+
+array_parens_subscript: "int x = (y)[0];"
+
+array_sizeof_type: "sizeof(int[2][3])"
+
+array_name_after_declarator: "int a[2] b;"
+
 multiline_string: "\"multi\"
      \"line\\
      strings\"
@@ -25,6 +39,16 @@ cast_struct_access: "(float)data.int_val"
 
 cast_higher_precedence: "(float)x+y"
 
+cast_typedef: "x = (T)y + (T)3"
+
+cast_typedef_pointer: "x = (T *)p + *(T **)q"
+
+cast_nested: "x = (unsigned)(int)y"
+
+cast_prefix_increment: "x = (int)++y"
+
+parens_lvalue: "(x) = 3; (x)++; y = (x) / 2; z = (s).m"
+
 escape_ok: "\"\\111\""
 
 escape_0: "'\0'"
@@ -0,0 +1,39 @@
+crate::pp!(
+
+ifdef: "#define A\n#ifdef A\nyes\n#else\nno\n#endif"
+
+ifndef: "#ifndef A\nyes\n#else\nno\n#endif"
+
+if_elif: "#if 0\na\n#elif 1 + 1 == 2\nb\n#elif 1\nc\n#else\nd\n#endif"
+
+nested_conditions: "#if 0\n#if 1\na\n#else\nb\n#endif\n#else\n#if 1\nc\n#endif\n#endif"
+
+if_defined: "#define A\n#if defined A && defined(A) && !defined B\nyes\n#endif"
+
+if_macro: "#define V 3\n#if V * 2 > 5 ? V : 0\nyes\n#endif"
+
+if_undefined_ident: "#if UNKNOWN\nno\n#else\nyes\n#endif"
+
+if_short_circuit: "#if 0 && 1 / 0\nno\n#elif 1 || 1 / 0\nyes\n#endif"
+
+if_division_by_zero: "#if 1 / 0\n#endif"
+
+if_no_expression: "#if\n#endif"
+
+if_missing_operator: "#if 1 2\n#endif"
+
+if_string: "#if \"a\"\n#endif"
+
+if_unsigned_and_chars: "#if 'a' == 97 && 0x10u == 16\nyes\n#endif"
+
+inactive_invalid_directive: "#if 0\n#foo\n#error not reached\n#endif\nok"
+
+elif_after_else: "#if 0\n#else\n#elif 1\n#endif"
+
+else_without_if: "#else"
+
+endif_without_if: "#endif"
+
+unterminated_if: "#ifdef A\nint x;"
+
+);
@@ -0,0 +1,29 @@
+crate::pp!(
+
+include_guard: "#include \"guarded.h\"\n#include <guarded.h>\nguarded"
+
+include_pragma_once: "#include <once.h>\n#include <once.h>"
+
+include_nested: "#include <nested.h>\nint main = __LINE__;"
+
+include_macro_name: "#define HEADER <once.h>\n#include HEADER"
+
+include_error_location: "#include <broken.h>"
+
+include_not_found: "#include \"missing.h\""
+
+include_invalid: "#include missing"
+
+line_directive: "#line 100 \"other.c\"\n__LINE__ __FILE__\n__LINE__"
+
+line_invalid: "#line 0\n#line x\n#line 10 \"f.c\" extra"
+
+error_directive: "#error Something went wrong\nint x;"
+
+warning_directive: "#warning Be careful\nint x;"
+
+null_directive: "#\nint x;"
+
+unknown_directive: "#foo bar"
+
+);
@@ -0,0 +1,68 @@
+//! Preprocessor tests.
+
+mod conditionals;
+mod directives;
+
+crate::pp!(
+
+object_like: "#define N 10\nint x = N;"
+
+function_like: "#define SQUARE(x) ((x) * (x))\nint y = SQUARE(1 + 2);"
+
+function_like_without_args: "#define F(x) x\nint F = F;"
+
+nested_macros: "#define A B + 1\n#define B C * 2\n#define C 3\nA"
+
+recursive_macro: "#define foo foo + 1\nfoo"
+
+mutually_recursive_macros: "#define a b\n#define b a\na b"
+
+nested_calls: "#define f(x) g(x)\n#define g(x) x * 2\nf(f(1))"
+
+stringify: "#define str(x) #x\nstr(a  +   \"b\\n\")"
+
+stringify_not_param: "#define str(x) # y"
+
+paste: "#define cat(a, b) a ## b\ncat(x, 1) cat(+, =) cat(, y) cat(,)"
+
+paste_invalid: "#define cat(a, b) a ## b\ncat(+, -)"
+
+paste_at_edge: "#define f(a) ## a"
+
+paste_numbers: "#define cat(a, b) a ## b\ncat(0x, 1F) cat(10, ul) cat(1e, 5) cat(1.5, f)"
+
+paste_invalid_number: "#define cat(a, b) a ## b\ncat(0x, ) - cat(1, u)"
+
+stringify_numbers: "#define str(x) #x\nstr(0x), str(1'000)"
+
+variadic: "#define f(a, ...) a(__VA_ARGS__)\nf(g) f(g, 1) f(g, 1, 2, 3)"
+
+variadic_trailing_comma: "#define G(...) h(__VA_ARGS__)\nG(a,) G(a, ,) G(,)"
+
+variadic_opt: "#define f(a, ...) a(0 __VA_OPT__(,) __VA_ARGS__)\nf(g) f(g, 1, 2)"
+
+va_args_not_variadic: "#define f(a) __VA_ARGS__"
+
+wrong_arg_count: "#define f(a, b) a b\nf(1) f(1, 2, 3)"
+
+unterminated_call: "#define f(a) a\nf(1, 2"
+
+multiline_call: "#define f(a, b) a + b\nf(1,\n2)\nint x;"
+
+continued_define: "#define LONG 1 + \\\n 2\nLONG"
+
+redefinition: "#define A 1\n#define A 1\n#define A 2\nA"
+
+undef: "#define A 1\n#undef A\nA"
+
+comments: "#define A /* comment */ 1 // other\nA /* multi\nline */ A"
+
+builtins: "__STDC__ __STDC_VERSION__ __LINE__ __FILE__ FROM_OPTIONS"
+
+string_concat: "#define S \"b\"\n\"a\" S \"c\""
+
+defined_name: "#define defined"
+
+invalid_param: "#define f(1) 1\n#define g(a, a) a\n#define h(a"
+
+);
@@ -30,6 +30,14 @@ macro_rules! ast_no_error {
     };
 }
 
+/// Convenience macro to create preprocessor tests.
+#[macro_export]
+macro_rules! pp {
+    ($($name:ident: $input:expr)*) => {
+        $($crate::one_test!($name, Preprocess, $input);)*
+    };
+}
+
 /// Convenience macro to create ssa tests.
 #[macro_export]
 macro_rules! ssa {
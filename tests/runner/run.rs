@@ -1,4 +1,8 @@
-use c_parser::{BracedBlock, Token, display_tokens, lex, linearise, parse};
+use std::path::PathBuf;
+
+use c_parser::{
+    BracedBlock, PreprocessorOptions, SourceFiles, Token, display_tokens, lex, linearise, parse, preprocess
+};
 
 use crate::runner::{_LINEAR_, _PARSED_, _TOKENS_, C0, CONTENTS, SIDE};
 
@@ -15,6 +19,7 @@ macro_rules! ret_err {
 pub enum TestScope {
     Ast,
     AstNoError,
+    Preprocess,
     Ssa,
 }
 
@@ -49,10 +54,29 @@ impl TestScope {
         }
     }
 
+    fn preprocess(content: &str) -> String {
+        eprintln!("{SIDE}{_TOKENS_}{SIDE}{C0}");
+        let mut options = PreprocessorOptions::default();
+        options.include_paths.push(PathBuf::from("tests/include"));
+        options
+            .defines
+            .push(("FROM_OPTIONS".to_owned(), "42".to_owned()));
+        let mut files = SourceFiles::default();
+        let res = preprocess("", content, &options, &mut files);
+        let (tokens, err) = res.as_displayed_errors(&files.as_list());
+        let displayed = display_tokens(tokens.as_ref().unwrap());
+        eprintln!("\x1b[32m{displayed}{C0}");
+        if err.is_empty() { displayed } else { err }
+    }
+
     pub fn run(self, content: &str) -> String {
         let files = &[(0, "", content)];
         eprintln!("{SIDE}{CONTENTS}{SIDE}{C0}\n{content}");
 
+        if matches!(self, Self::Preprocess) {
+            return Self::preprocess(content);
+        }
+
         let tokens = ret_err!(Self::lex(content));
         let tree = ret_err!(self.parse(tokens, files));
 
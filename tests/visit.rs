@@ -0,0 +1,110 @@
+//! Tests the visitors of the AST.
+
+#![expect(clippy::restriction, reason = "tests")]
+
+use c_parser::*;
+
+const CONTENT: &str = "
+typedef struct Point { int x; } Point;
+int y = 1, z;
+int f(int a) {
+    int b = a ? 1 : 2;
+    do { b--; } while (b > 0);
+    for (int i = 0; i < 3; i++) { if (i) continue; else b += i; }
+    switch (b) { case 1: b = (int)a; break; default: goto end; }
+    char s[] = {'a', 'b'};
+    end: return g(b, (char)s[0]);
+}";
+
+fn parse_content(content: &str) -> BracedBlock {
+    let files = &[(0, "", content)];
+    parse(lex(content, 0).unwrap_or_display(files).unwrap())
+        .unwrap_or_display(files)
+        .unwrap()
+}
+
+/// Counts the visited nodes.
+#[derive(Default)]
+struct Counter {
+    ctrls: usize,
+    literals: usize,
+    names: Vec<String>,
+}
+
+impl Visit for Counter {
+    fn visit_control_flow(&mut self, node: &ControlFlowNode) {
+        self.ctrls += 1;
+        node.walk(self);
+    }
+
+    fn visit_literal(&mut self, _node: &Literal, _location: ErrorLocation) {
+        self.literals += 1;
+    }
+
+    fn visit_variable_name(&mut self, node: &VariableName, _location: ErrorLocation) {
+        if let VariableName::UserDefined(name) = node {
+            self.names.push(name.clone());
+        }
+    }
+}
+
+#[test]
+fn visit_counts() {
+    let mut counter = Counter::default();
+    counter.visit_braced_block(&parse_content(CONTENT));
+    assert_eq!(counter.ctrls, 13);
+    assert_eq!(counter.literals, 10);
+    assert_eq!(
+        counter.names,
+        [
+            "a", "b", "b", "i", "i", "i", "b", "i", "b", "b", "a", "g", "b", "s"
+        ]
+    );
+}
+
+/// Skips the bodies of the functions.
+#[derive(Default)]
+struct Declarations(Vec<String>);
+
+impl Visit for Declarations {
+    fn visit_declaration(&mut self, node: &Declaration) {
+        self.0.push(node.name.as_value().clone());
+        node.walk(self);
+    }
+
+    fn visit_function_call(&mut self, _node: &FunctionCall) {}
+}
+
+#[test]
+fn visit_skips_children() {
+    let mut declarations = Declarations::default();
+    declarations.visit_braced_block(&parse_content(CONTENT));
+    assert_eq!(declarations.0, ["x", "y", "z"]);
+}
+
+/// Renames the variables and increments the literals.
+struct Rename;
+
+impl VisitMut for Rename {
+    fn visit_literal_mut(&mut self, node: &mut Literal, _location: ErrorLocation) {
+        if let Literal::Char(ch, _) = node {
+            *ch = ch.to_ascii_uppercase();
+        }
+    }
+
+    fn visit_variable_name_mut(&mut self, node: &mut VariableName, _location: ErrorLocation) {
+        if let VariableName::UserDefined(name) = node {
+            name.push('_');
+        }
+    }
+}
+
+#[test]
+fn visit_mut_renames() {
+    let mut ast = parse_content("if (x) y = 'a'; else { z = f(x, 'b'); }");
+    Rename.visit_braced_block_mut(&mut ast);
+    assert_eq!(
+        ast.to_string(),
+        parse_content("if (x_) y_ = 'A'; else { z_ = f_(x_, 'B'); }").to_string()
+    );
+}